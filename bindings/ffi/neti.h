@@ -0,0 +1,32 @@
+/* C interface to the neti analysis core (bindings/ffi). */
+#ifndef NETI_H
+#define NETI_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Analyzes one NUL-terminated UTF-8 buffer of `lang` source ("rust",
+ * "python", "typescript", "swift" or a file extension) with the default
+ * rules. Returns a JSON array of violations, to be released with
+ * neti_string_free, or NULL if an argument is NULL, not UTF-8 or names an
+ * unknown language. */
+char *neti_scan_source(const char *lang, const char *source);
+
+/* Releases a string returned by neti_scan_source. NULL is ignored. */
+void neti_string_free(char *s);
+
+/* Allocates `size` bytes for a host (such as a WASM runtime) to write an
+ * argument into; release with neti_dealloc. */
+unsigned char *neti_alloc(size_t size);
+
+/* Releases a buffer from neti_alloc with the same `size`. */
+void neti_dealloc(unsigned char *ptr, size_t size);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,137 @@
+//! C ABI for single-buffer analysis, for hosts that cannot spawn `neti`:
+//! editor plugins load the `cdylib`, and a `wasm32` build of this crate
+//! exports the same functions to browser playgrounds.
+//!
+//! Strings cross the boundary as NUL-terminated UTF-8. Results are JSON and
+//! owned by the caller until passed back to [`neti_string_free`]. See
+//! `neti.h` for the C declarations.
+
+use neti_core::{Config, Lang};
+use std::ffi::{c_char, CStr, CString};
+
+/// Analyzes `source` as `lang` with the default rules, returning a JSON
+/// array of violations, or null if an argument is null, not UTF-8 or names
+/// an unknown language.
+///
+/// # Safety
+/// `lang` and `source` must be null or point to NUL-terminated strings that
+/// stay valid for the duration of the call.
+#[no_mangle]
+pub unsafe extern "C" fn neti_scan_source(
+    lang: *const c_char,
+    source: *const c_char,
+) -> *mut c_char {
+    // SAFETY: the caller upholds the contract documented above.
+    let (lang, source) = unsafe { (text(lang), text(source)) };
+    let (Some(lang), Some(source)) = (lang.and_then(Lang::from_name), source) else {
+        return std::ptr::null_mut();
+    };
+    scan_json(lang, source)
+        .and_then(|json| CString::new(json).ok())
+        .map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Releases a string returned by [`neti_scan_source`]. Null is ignored.
+///
+/// # Safety
+/// `s` must be null or a pointer from [`neti_scan_source`] that has not
+/// been freed.
+#[no_mangle]
+pub unsafe extern "C" fn neti_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: `s` came from `CString::into_raw` and is freed once.
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+/// Allocates `size` bytes for the host to write an argument into.
+#[no_mangle]
+pub extern "C" fn neti_alloc(size: usize) -> *mut u8 {
+    let mut buf = Vec::<u8>::with_capacity(size);
+    let ptr = buf.as_mut_ptr();
+    std::mem::forget(buf);
+    ptr
+}
+
+/// Releases a buffer from [`neti_alloc`].
+///
+/// # Safety
+/// `ptr` must come from [`neti_alloc`] with the same `size` and not have
+/// been released.
+#[no_mangle]
+pub unsafe extern "C" fn neti_dealloc(ptr: *mut u8, size: usize) {
+    if !ptr.is_null() {
+        // SAFETY: `ptr` and `size` describe the allocation made above.
+        drop(unsafe { Vec::from_raw_parts(ptr, 0, size) });
+    }
+}
+
+/// The violations in `source` as a JSON array.
+#[must_use]
+pub fn scan_json(lang: Lang, source: &str) -> Option<String> {
+    let violations = neti_core::scan_source(lang, source, &Config::default());
+    serde_json::to_string(&violations).ok()
+}
+
+/// # Safety
+/// `ptr` must be null or point to a NUL-terminated string.
+unsafe fn text<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: non-null and NUL-terminated per the caller's contract.
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(lang: &str, source: &str) -> Option<String> {
+        let (lang, source) = (CString::new(lang).ok()?, CString::new(source).ok()?);
+        // SAFETY: both arguments are valid C strings for the call.
+        let out = unsafe { neti_scan_source(lang.as_ptr(), source.as_ptr()) };
+        if out.is_null() {
+            return None;
+        }
+        // SAFETY: `out` is a live string from `neti_scan_source`.
+        let json = unsafe { CStr::from_ptr(out) }
+            .to_str()
+            .ok()
+            .map(String::from);
+        // SAFETY: freed exactly once.
+        unsafe { neti_string_free(out) };
+        json
+    }
+
+    #[test]
+    fn buffers_are_scanned_to_json() {
+        let json = call("rust", "fn f() { let v: Option<u8> = None; v.unwrap(); }\n");
+        let violations: serde_json::Value =
+            serde_json::from_str(&json.unwrap_or_default()).unwrap_or_default();
+        let rules: Vec<&str> = violations
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v["law"].as_str())
+            .collect();
+        assert!(rules.contains(&"LAW OF PARANOIA"), "{violations}");
+    }
+
+    #[test]
+    fn bad_arguments_return_null() {
+        assert_eq!(call("cobol", "x"), None);
+        // SAFETY: null is accepted.
+        assert!(unsafe { neti_scan_source(std::ptr::null(), std::ptr::null()) }.is_null());
+        // SAFETY: null is ignored.
+        unsafe { neti_string_free(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn host_buffers_round_trip() {
+        let ptr = neti_alloc(16);
+        assert!(!ptr.is_null());
+        // SAFETY: same pointer and size as allocated.
+        unsafe { neti_dealloc(ptr, 16) };
+    }
+}
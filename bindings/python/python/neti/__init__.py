@@ -0,0 +1,28 @@
+"""Python bindings for the neti analysis engine.
+
+    import neti
+
+    report = neti.scan("src")
+    for file in report["files"]:
+        for v in file["violations"]:
+            print(file["path"], v["row"], v["message"])
+
+    neti.count_tokens(open("README.md").read(), tokenizer="o200k")
+"""
+
+import json
+import os
+
+from ._neti import __version__, count_tokens, scan_json
+
+__all__ = ["__version__", "count_tokens", "scan"]
+
+
+def scan(*paths):
+    """Scan ``paths`` (the whole project when none are given) and return the
+    report as a dict, as ``neti scan --json`` would print it.
+
+    Paths are relative to the current directory, whose ``neti.toml`` is
+    used for configuration.
+    """
+    return json.loads(scan_json([os.fspath(p) for p in paths]))
@@ -0,0 +1,42 @@
+//! Python bindings for `neti_core`, built with maturin as `neti._neti`.
+//!
+//! Reports cross the boundary as JSON; the `neti` package wrapping this
+//! module decodes them into dicts and lists. Scans release the GIL.
+
+use neti_core::config::Config;
+use neti_core::tokens::{Tokenizer, TokenizerKind};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use std::path::PathBuf;
+
+/// Scans `paths` (all of the project when empty) from the current
+/// directory, using its `neti.toml`. Returns the report as JSON.
+#[pyfunction]
+#[pyo3(signature = (paths))]
+fn scan_json(py: Python<'_>, paths: Vec<PathBuf>) -> PyResult<String> {
+    py.allow_threads(|| {
+        let config = Config::load();
+        let report = neti_core::scan(&paths, &config)
+            .map_err(|e| PyRuntimeError::new_err(format!("{e:#}")))?;
+        serde_json::to_string(&neti_core::schema::versioned(&report))
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    })
+}
+
+/// Counts the tokens in `text` with the named tokenizer (`cl100k`,
+/// `o200k`, `claude` or `whitespace`).
+#[pyfunction]
+#[pyo3(signature = (text, tokenizer = "cl100k"))]
+fn count_tokens(text: &str, tokenizer: &str) -> PyResult<usize> {
+    let kind: TokenizerKind = serde_json::from_value(tokenizer.into())
+        .map_err(|_| PyValueError::new_err(format!("unknown tokenizer '{tokenizer}'")))?;
+    Ok(Tokenizer::count_as(kind, text))
+}
+
+#[pymodule]
+fn _neti(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(scan_json, m)?)?;
+    m.add_function(wrap_pyfunction!(count_tokens, m)?)?;
+    Ok(())
+}
@@ -12,6 +12,7 @@ use crate::types::Violation;
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct FileAnalysis {
     pub path_str: String,
+    #[serde(serialize_with = "super::scope::sorted_map")]
     pub scopes: HashMap<String, Scope>,
     pub violations: Vec<Violation>,
 }
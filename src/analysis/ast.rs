@@ -2,11 +2,15 @@ use super::checks::{self, CheckContext};
 use super::cognitive::CognitiveAnalyzer;
 use crate::config::RuleConfig;
 use crate::lang::{Lang, QueryKind};
+use crate::profile;
+use crate::query_cache;
+use crate::tokens::TokenizerKind;
 use crate::types::{Violation, ViolationDetails};
-use anyhow::{anyhow, Result};
-use tree_sitter::{Language, Parser, Query, QueryCursor};
+use tree_sitter::QueryCursor;
 
-pub struct Analyzer;
+pub struct Analyzer {
+    tokenizer: TokenizerKind,
+}
 
 impl Default for Analyzer {
     fn default() -> Self {
@@ -22,7 +26,15 @@ pub struct AnalysisResult {
 impl Analyzer {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            tokenizer: TokenizerKind::default(),
+        }
+    }
+
+    /// Measures per-function token budgets with `tokenizer`.
+    #[must_use]
+    pub fn with_tokenizer(tokenizer: TokenizerKind) -> Self {
+        Self { tokenizer }
     }
 
     #[must_use]
@@ -33,25 +45,17 @@ impl Analyzer {
         content: &str,
         config: &RuleConfig,
     ) -> AnalysisResult {
-        Self::run_analysis(lang, filename, content, config)
+        self.run_analysis(lang, filename, content, config)
     }
 
     fn run_analysis(
+        &self,
         lang: Lang,
         filename: &str,
         content: &str,
         config: &RuleConfig,
     ) -> AnalysisResult {
-        let grammar = lang.grammar();
-        let mut parser = Parser::new();
-        if parser.set_language(&grammar).is_err() {
-            return AnalysisResult {
-                violations: vec![],
-                max_complexity: 0,
-            };
-        }
-
-        let Some(tree) = parser.parse(content, None) else {
+        let Some(tree) = lang.parse(content) else {
             return AnalysisResult {
                 violations: vec![],
                 max_complexity: 0,
@@ -65,18 +69,28 @@ impl Analyzer {
             source: content,
             filename,
             config,
+            tokenizer: self.tokenizer,
         };
 
-        if let Ok(q) = compile_query(&grammar, lang.query(QueryKind::Naming)) {
-            checks::check_naming(&ctx, &q, &mut violations);
-        }
+        profile::time("naming", filename, || {
+            if let Some(q) = query_cache::get(lang, lang.query(QueryKind::Naming)) {
+                checks::check_naming(&ctx, lang, q, &mut violations);
+            }
+        });
 
-        let max_complexity = compute_max_complexity(&grammar, lang, &ctx, &mut violations);
+        let max_complexity = profile::time("complexity", filename, || {
+            compute_max_complexity(lang, &ctx, &mut violations)
+        });
 
-        checks::check_syntax(&ctx, &mut violations);
+        profile::time("syntax", filename, || {
+            checks::check_syntax(&ctx, &mut violations);
+        });
+        profile::time("sizes", filename, || {
+            checks::check_sizes(&ctx, &mut violations)
+        });
 
         if lang == Lang::Rust {
-            Self::check_rust_specifics(&grammar, &ctx, &mut violations);
+            Self::check_rust_specifics(&ctx, &mut violations);
         }
 
         AnalysisResult {
@@ -85,19 +99,32 @@ impl Analyzer {
         }
     }
 
-    fn check_rust_specifics(grammar: &Language, ctx: &CheckContext, out: &mut Vec<Violation>) {
-        let banned_query_str = r"
+    fn check_rust_specifics(ctx: &CheckContext, out: &mut Vec<Violation>) {
+        let banned_query_str = r#"
             (call_expression
                 function: (field_expression field: (field_identifier) @method)
-                (#match? @method ^(unwrap|expect)$))
-        ";
-        if let Ok(q) = compile_query(grammar, banned_query_str) {
-            checks::check_banned(ctx, &q, out);
-        }
-
-        if let Ok(q) = compile_query(grammar, "") {
-            super::safety::check_safety(ctx, &q, out);
-        }
+                (#match? @method "^(unwrap|expect)$"))
+            (macro_invocation
+                macro: (identifier) @macro
+                (#eq? @macro "panic"))
+        "#;
+        let file = ctx.filename;
+        profile::time("banned", file, || {
+            if let Some(q) = query_cache::rust(banned_query_str) {
+                checks::check_banned(ctx, q, out);
+            }
+        });
+        profile::time("error_hygiene", file, || {
+            checks::check_error_hygiene(ctx, out)
+        });
+        profile::time("doc_contracts", file, || {
+            checks::check_doc_contracts(ctx, out)
+        });
+        profile::time("safety", file, || {
+            if let Some(q) = query_cache::rust("") {
+                super::safety::check_safety(ctx, q, out);
+            }
+        });
     }
 }
 
@@ -141,17 +168,16 @@ fn score_capture(
 }
 
 fn compute_max_complexity(
-    grammar: &Language,
     lang: Lang,
     ctx: &CheckContext,
     violations: &mut Vec<Violation>,
 ) -> usize {
-    let Ok(q_defs) = compile_query(grammar, lang.query(QueryKind::Defs)) else {
+    let Some(q_defs) = query_cache::get(lang, lang.query(QueryKind::Defs)) else {
         return 0;
     };
     let mut cursor = QueryCursor::new();
     let captures: Vec<_> = cursor
-        .matches(&q_defs, ctx.root, ctx.source.as_bytes())
+        .matches(q_defs, ctx.root, ctx.source.as_bytes())
         .flat_map(|m| m.captures.iter().copied())
         .collect();
 
@@ -164,7 +190,3 @@ fn compute_max_complexity(
     }
     max_complexity
 }
-
-fn compile_query(lang: &Language, pattern: &str) -> Result<Query> {
-    Query::new(lang, pattern).map_err(|e| anyhow!("Invalid tree-sitter query: {e}"))
-}
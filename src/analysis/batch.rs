@@ -0,0 +1,103 @@
+// src/analysis/batch.rs
+//! Memory-bounded batching for `[discovery] max_memory_mb`.
+//!
+//! Per-file analysis holds the source, its syntax trees and the detectors'
+//! working sets, which together run to a multiple of the file's size. With
+//! a ceiling set, files are grouped so each batch's estimated peak fits in
+//! it, and a batch finishes before the next starts, so the source bytes in
+//! flight never exceed the ceiling's share. Finished reports are not
+//! counted. Without a ceiling, all files form a single batch and rayon
+//! schedules them freely.
+
+use std::ops::Range;
+use std::path::PathBuf;
+
+use crate::config::DiscoveryConfig;
+use crate::tokens::STREAM_CHUNK_BYTES;
+
+/// Estimated peak bytes of analysis per byte of source.
+pub const PEAK_BYTES_PER_SOURCE_BYTE: u64 = 32;
+
+/// Splits `files` into consecutive batches that fit the configured ceiling.
+#[must_use]
+pub fn plan(files: &[PathBuf], discovery: &DiscoveryConfig) -> Vec<Range<usize>> {
+    if discovery.max_memory_mb == 0 {
+        return std::iter::once(0..files.len()).collect();
+    }
+    let costs: Vec<u64> = files
+        .iter()
+        .map(|f| {
+            let size = std::fs::metadata(f).map_or(0, |m| m.len());
+            peak_bytes(size, discovery.max_analysis_bytes)
+        })
+        .collect();
+    let batches = split(&costs, discovery.max_memory_mb.saturating_mul(1024 * 1024));
+    log::debug!(
+        "max_memory_mb = {}: {} files in {} batches",
+        discovery.max_memory_mb,
+        files.len(),
+        batches.len()
+    );
+    batches
+}
+
+/// Estimated peak memory for analyzing a file of `size` bytes. Files over
+/// `max_analysis_bytes` are streamed a chunk at a time.
+#[must_use]
+pub fn peak_bytes(size: u64, max_analysis_bytes: u64) -> u64 {
+    let held = if size > max_analysis_bytes {
+        STREAM_CHUNK_BYTES as u64
+    } else {
+        size
+    };
+    held.saturating_mul(PEAK_BYTES_PER_SOURCE_BYTE)
+}
+
+/// Greedy consecutive batches whose summed `costs` stay within `budget`.
+/// A file over the budget on its own gets a batch to itself.
+#[must_use]
+pub fn split(costs: &[u64], budget: u64) -> Vec<Range<usize>> {
+    let mut batches = Vec::new();
+    let (mut start, mut used) = (0, 0u64);
+    for (i, &cost) in costs.iter().enumerate() {
+        if i > start && used.saturating_add(cost) > budget {
+            batches.push(start..i);
+            (start, used) = (i, 0);
+        }
+        used = used.saturating_add(cost);
+    }
+    if start < costs.len() {
+        batches.push(start..costs.len());
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_fit_the_budget() {
+        assert_eq!(split(&[4, 4, 4, 4], 8), vec![0..2, 2..4]);
+        assert_eq!(split(&[3, 20, 3], 10), vec![0..1, 1..2, 2..3]);
+        assert_eq!(
+            split(&[1, 1, 1], 100),
+            std::iter::once(0..3).collect::<Vec<_>>()
+        );
+        assert!(split(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn streamed_files_cost_one_chunk() {
+        let chunk = STREAM_CHUNK_BYTES as u64 * PEAK_BYTES_PER_SOURCE_BYTE;
+        assert_eq!(peak_bytes(10 << 30, 1 << 20), chunk);
+        assert_eq!(peak_bytes(100, 1 << 20), 100 * PEAK_BYTES_PER_SOURCE_BYTE);
+    }
+
+    #[test]
+    fn no_ceiling_means_one_batch() {
+        let files = vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")];
+        let batches = plan(&files, &DiscoveryConfig::default());
+        assert_eq!(batches, std::iter::once(0..2).collect::<Vec<_>>());
+    }
+}
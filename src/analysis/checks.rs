@@ -3,16 +3,25 @@
 
 mod banned;
 mod complexity;
+mod conventions;
+mod doc_contract;
+mod errors;
 mod naming;
+mod size;
 mod syntax;
 
 use tree_sitter::Node;
 
 use crate::config::RuleConfig;
+use crate::normalize;
+use crate::tokens::TokenizerKind;
 
 pub use banned::check_banned;
 pub use complexity::check_metrics;
+pub use doc_contract::check_doc_contracts;
+pub use errors::check_error_hygiene;
 pub use naming::check_naming;
+pub use size::check_sizes;
 pub use syntax::check_syntax;
 
 /// Context for running checks on a single file.
@@ -21,4 +30,21 @@ pub struct CheckContext<'a> {
     pub source: &'a str,
     pub filename: &'a str,
     pub config: &'a RuleConfig,
+    /// Tokenizer for per-function token budgets.
+    pub tokenizer: TokenizerKind,
+}
+
+fn is_test_file(filename: &str) -> bool {
+    let name = normalize::file_name(filename);
+    name.contains("test") || name.contains("spec")
+}
+
+/// Binary targets (`main.rs`, `src/bin/`, `build.rs`, examples, benches)
+/// own the process and may abort it; libraries may not.
+fn is_binary_file(filename: &str) -> bool {
+    let mut dirs: Vec<&str> = normalize::segments(filename).collect();
+    let name = dirs.pop().unwrap_or("");
+    matches!(name, "main.rs" | "build.rs")
+        || dirs.windows(2).any(|w| w == ["src", "bin"])
+        || dirs.iter().any(|d| matches!(*d, "examples" | "benches"))
 }
@@ -1,16 +1,18 @@
 // src/analysis/checks/banned.rs
 //! Banned construct checks (Law of Paranoia).
 
-use std::path::Path;
-use tree_sitter::{Query, QueryCursor, QueryMatch};
+use omni_ast::SemanticLanguage;
+use tree_sitter::{Node, Query, QueryCursor, QueryMatch};
 
-use crate::types::{Violation, ViolationDetails};
+use crate::analysis::patterns::performance_test_ctx::is_test_context;
+use crate::types::{Confidence, Violation, ViolationDetails};
 
-use super::CheckContext;
+use super::{is_binary_file, is_test_file, CheckContext};
 
-/// Checks for banned constructs (`.unwrap()` and `.expect()` calls).
+/// Checks for banned constructs (`.unwrap()`, `.expect()` and `panic!`) in
+/// library code. Tests and binary targets may panic.
 pub fn check_banned(ctx: &CheckContext, banned_query: &Query, out: &mut Vec<Violation>) {
-    if is_test_file(ctx.filename) {
+    if is_test_file(ctx.filename) || is_binary_file(ctx.filename) {
         return;
     }
 
@@ -22,46 +24,105 @@ pub fn check_banned(ctx: &CheckContext, banned_query: &Query, out: &mut Vec<Viol
     }
 }
 
-fn is_test_file(filename: &str) -> bool {
-    let path = Path::new(filename);
-    path.file_name()
-        .and_then(|n| n.to_str())
-        .is_some_and(|name| name.contains("test") || name.contains("spec"))
-}
-
 fn process_match(m: &QueryMatch, ctx: &CheckContext, out: &mut Vec<Violation>) {
     for capture in m.captures {
         if let Ok(text) = capture.node.utf8_text(ctx.source.as_bytes()) {
             let row = capture.node.start_position().row + 1;
-            let kind = capture.node.kind();
-            if is_banned_call(kind, text) {
+            if is_banned_call(capture.node, text) && !in_test(ctx, capture.node) {
                 add_violation(text, row, out);
             }
         }
     }
 }
 
-fn is_banned_call(kind: &str, text: &str) -> bool {
-    kind == "identifier" && (text == "unwrap" || text == "expect")
+fn is_banned_call(node: Node, text: &str) -> bool {
+    match node.kind() {
+        "field_identifier" => text == "unwrap" || text == "expect",
+        "identifier" => text == "panic",
+        _ => false,
+    }
+}
+
+fn in_test(ctx: &CheckContext, node: Node) -> bool {
+    is_test_context(ctx.source, node, SemanticLanguage::Rust)
 }
 
 fn add_violation(text: &str, row: usize, out: &mut Vec<Violation>) {
-    let suggestion = if text == "unwrap" {
-        "Use `?` operator or `ok_or_else()` to propagate errors"
-    } else {
-        "Use `?` with `context()` or `with_context()` for better errors"
+    let (call, suggestion) = match text {
+        "unwrap" => (
+            ".unwrap()",
+            "Use `?` operator or `ok_or_else()` to propagate errors",
+        ),
+        "expect" => (
+            ".expect()",
+            "Use `?` with `context()` or `with_context()` for better errors",
+        ),
+        _ => (
+            "panic!()",
+            "Return an error; leave the decision to abort to the binary",
+        ),
     };
 
     let details = ViolationDetails {
         function_name: None,
-        analysis: vec![format!("Found `.{text}()` call")],
+        analysis: vec![format!("Found `{call}` call")],
         suggestion: Some(suggestion.to_string()),
     };
 
-    out.push(Violation::with_details(
+    let mut v = Violation::with_details(
         row,
-        format!("Banned: '.{text}()' found. Use ? or proper error handling."),
+        format!("Banned: '{call}' found. Use ? or proper error handling."),
         "LAW OF PARANOIA",
         details,
-    ));
+    );
+    if text == "panic" {
+        v.confidence = Confidence::Medium;
+        v.confidence_reason = Some("may guard an invariant on constant input".into());
+    }
+    out.push(v);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::ast::Analyzer;
+    use crate::config::RuleConfig;
+    use crate::lang::Lang;
+
+    fn banned(code: &str, filename: &str) -> Vec<String> {
+        Analyzer::new()
+            .analyze(Lang::Rust, filename, code, &RuleConfig::default())
+            .violations
+            .into_iter()
+            .filter(|v| v.law == "LAW OF PARANOIA")
+            .map(|v| v.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_library_code_may_not_panic() {
+        let code = r#"
+fn a(x: Option<u8>) -> u8 { x.unwrap() }
+fn b(x: Option<u8>) -> u8 { x.expect("set") }
+fn c() { panic!("no"); }
+fn d(x: Option<u8>) -> u8 { x.unwrap_or(0) }
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() { Some(1).unwrap(); }
+}
+"#;
+        assert_eq!(
+            banned(code, "src/lib.rs"),
+            vec![
+                "Banned: '.unwrap()' found. Use ? or proper error handling.",
+                "Banned: '.expect()' found. Use ? or proper error handling.",
+                "Banned: 'panic!()' found. Use ? or proper error handling.",
+            ]
+        );
+        assert!(banned(code, "src/main.rs").is_empty());
+        assert!(banned(code, "src/bin/tool.rs").is_empty());
+        assert!(banned(code, "examples/demo.rs").is_empty());
+        assert!(banned(code, r"src\bin\tool.rs").is_empty());
+        assert!(banned(code, r"examples\demo.rs").is_empty());
+    }
 }
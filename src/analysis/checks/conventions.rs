@@ -0,0 +1,162 @@
+// src/analysis/checks/conventions.rs
+//! Naming conventions (N01): names checked against the `[rules.naming]`
+//! policy for the file's language.
+
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::config::NamingPolicy;
+use crate::lang::Lang;
+use crate::normalize;
+use crate::types::{Violation, ViolationDetails};
+
+use super::CheckContext;
+
+#[cfg(test)]
+#[path = "conventions_test.rs"]
+mod tests;
+
+/// Rule code for naming-convention findings.
+pub const LAW: &str = "N01";
+
+/// Named things a policy governs, in `NamingPolicy::entries` order.
+#[derive(Clone, Copy)]
+enum Kind {
+    Function,
+    Type,
+    Constant,
+    Module,
+    File,
+}
+
+impl Kind {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Function => "Function",
+            Self::Type => "Type",
+            Self::Constant => "Constant",
+            Self::Module => "Module",
+            Self::File => "File",
+        }
+    }
+}
+
+/// A policy compiled for one file; kinds without a pattern go unchecked.
+struct Compiled {
+    patterns: [Option<Regex>; 5],
+}
+
+impl Compiled {
+    fn new(policy: &NamingPolicy) -> Self {
+        Self {
+            patterns: policy
+                .entries()
+                .map(|(_, p)| (!p.is_empty()).then(|| Regex::new(p).ok()).flatten()),
+        }
+    }
+
+    fn get(&self, kind: Kind) -> Option<&Regex> {
+        self.patterns.get(kind as usize)?.as_ref()
+    }
+}
+
+/// Checks every declared name, and the file name, against the policy.
+pub fn check_conventions(ctx: &CheckContext, lang: Lang, out: &mut Vec<Violation>) {
+    if !ctx.config.naming.enabled {
+        return;
+    }
+    let compiled = Compiled::new(&ctx.config.naming.policy(lang));
+    let name = normalize::file_name(ctx.filename);
+    if let Some(pattern) = compiled.get(Kind::File) {
+        if !pattern.is_match(name) {
+            out.push(mismatch(Kind::File, name, pattern, 1));
+        }
+    }
+    visit(ctx.root, ctx, lang, &compiled, out);
+}
+
+fn visit(
+    node: Node,
+    ctx: &CheckContext,
+    lang: Lang,
+    compiled: &Compiled,
+    out: &mut Vec<Violation>,
+) {
+    if let Some(kind) = kind_of(lang, node.kind()) {
+        check_name(node, kind, ctx, compiled, out);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, ctx, lang, compiled, out);
+    }
+}
+
+fn kind_of(lang: Lang, node_kind: &str) -> Option<Kind> {
+    let kind = match (lang, node_kind) {
+        (Lang::Rust, "function_item" | "function_signature_item")
+        | (Lang::Python, "function_definition")
+        | (
+            Lang::TypeScript,
+            "function_declaration" | "generator_function_declaration" | "method_definition",
+        )
+        | (Lang::Swift, "function_declaration") => Kind::Function,
+        (Lang::Rust, "struct_item" | "enum_item" | "trait_item" | "type_item" | "union_item")
+        | (Lang::Python, "class_definition")
+        | (
+            Lang::TypeScript,
+            "class_declaration"
+            | "abstract_class_declaration"
+            | "interface_declaration"
+            | "type_alias_declaration"
+            | "enum_declaration",
+        )
+        | (Lang::Swift, "class_declaration" | "protocol_declaration") => Kind::Type,
+        (Lang::Rust, "const_item" | "static_item") => Kind::Constant,
+        (Lang::Rust, "mod_item") => Kind::Module,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+fn check_name(
+    node: Node,
+    kind: Kind,
+    ctx: &CheckContext,
+    compiled: &Compiled,
+    out: &mut Vec<Violation>,
+) {
+    let Some(pattern) = compiled.get(kind) else {
+        return;
+    };
+    let Some(name_node) = node.child_by_field_name("name") else {
+        return;
+    };
+    if name_node.kind().contains("computed") {
+        return;
+    }
+    let Ok(text) = name_node.utf8_text(ctx.source.as_bytes()) else {
+        return;
+    };
+    let name = text.trim_start_matches("r#").trim_start_matches('#');
+    if !pattern.is_match(name) {
+        out.push(mismatch(kind, name, pattern, node.start_position().row + 1));
+    }
+}
+
+fn mismatch(kind: Kind, name: &str, pattern: &Regex, row: usize) -> Violation {
+    Violation::with_details(
+        row,
+        format!(
+            "{} name '{name}' does not match the naming policy",
+            kind.label()
+        ),
+        LAW,
+        ViolationDetails {
+            function_name: matches!(kind, Kind::Function).then(|| name.to_string()),
+            analysis: vec![format!("Expected to match `{}`", pattern.as_str())],
+            suggestion: Some(
+                "Rename it, or adjust the pattern under [rules.naming] in neti.toml.".into(),
+            ),
+        },
+    )
+}
@@ -0,0 +1,86 @@
+use crate::analysis::ast::Analyzer;
+use crate::config::{NamingPolicy, RuleConfig};
+use crate::lang::Lang;
+
+fn naming(lang: Lang, filename: &str, code: &str, config: &RuleConfig) -> Vec<String> {
+    Analyzer::new()
+        .analyze(lang, filename, code, config)
+        .violations
+        .into_iter()
+        .filter(|v| v.law == "N01")
+        .map(|v| v.message)
+        .collect()
+}
+
+#[test]
+fn test_rust_defaults() {
+    let code = r"
+struct good_struct;
+enum Shape { Round }
+const lower: u8 = 1;
+static UPPER: u8 = 2;
+fn BadFn() {}
+fn r#match() {}
+mod Inner {}
+";
+    assert_eq!(
+        naming(Lang::Rust, "src/lib.rs", code, &RuleConfig::default()),
+        vec![
+            "Type name 'good_struct' does not match the naming policy",
+            "Constant name 'lower' does not match the naming policy",
+            "Function name 'BadFn' does not match the naming policy",
+            "Module name 'Inner' does not match the naming policy",
+        ]
+    );
+}
+
+#[test]
+fn test_file_name_checked() {
+    assert_eq!(
+        naming(Lang::Rust, "src/MyModule.rs", "", &RuleConfig::default()),
+        vec!["File name 'MyModule.rs' does not match the naming policy"]
+    );
+}
+
+#[test]
+fn test_typescript_defaults() {
+    let code =
+        "class widget {}\ninterface Props {}\nfunction useThing() {}\nfunction snake_case() {}\n";
+    assert_eq!(
+        naming(Lang::TypeScript, "src/app.ts", code, &RuleConfig::default()),
+        vec![
+            "Type name 'widget' does not match the naming policy",
+            "Function name 'snake_case' does not match the naming policy",
+        ]
+    );
+}
+
+#[test]
+fn test_custom_policy_overrides_defaults() {
+    let mut config = RuleConfig::default();
+    config.naming.rust = NamingPolicy {
+        function: "^(db|http)_[a-z_]+$".into(),
+        types: ".*".into(),
+        ..NamingPolicy::default()
+    };
+    let code = "fn db_load() {}\nfn load() {}\nstruct lowercase;\nconst bad: u8 = 0;\n";
+    assert_eq!(
+        naming(Lang::Rust, "src/lib.rs", code, &config),
+        vec![
+            "Function name 'load' does not match the naming policy",
+            "Constant name 'bad' does not match the naming policy",
+        ]
+    );
+}
+
+#[test]
+fn test_disabled_and_ignored_files() {
+    let code = "fn BadFn() {}\n";
+    let mut config = RuleConfig {
+        ignore_naming_on: vec!["generated".into()],
+        ..RuleConfig::default()
+    };
+    assert!(naming(Lang::Rust, "src/generated/api.rs", code, &config).is_empty());
+    config.naming.enabled = false;
+    assert!(naming(Lang::Rust, "src/lib.rs", code, &config).is_empty());
+}
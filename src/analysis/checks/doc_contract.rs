@@ -0,0 +1,205 @@
+// src/analysis/checks/doc_contract.rs
+//! D02: public functions whose docs leave out part of their contract.
+//!
+//! A `pub fn` returning `Result` needs `# Errors`, one that can panic needs
+//! `# Panics`, and an `unsafe fn` needs `# Safety` — the sections rustdoc
+//! readers look for, as clippy's `missing_*_doc` lints require. Panics are
+//! found syntactically (`unwrap`, `expect`, `panic!`, indexing), so that
+//! finding is medium confidence.
+//!
+//! A finding is suppressed by the matching clippy `allow` on the item or
+//! crate, or by `neti:allow(D02)` in the comments above the item.
+
+use omni_ast::SemanticLanguage;
+use tree_sitter::Node;
+
+use crate::analysis::patterns::performance_test_ctx::is_test_context;
+use crate::types::{Confidence, Violation, ViolationDetails};
+
+use super::{is_binary_file, is_test_file, CheckContext};
+
+#[cfg(test)]
+#[path = "doc_contract_test.rs"]
+mod tests;
+
+/// Rule code for missing doc sections.
+pub const LAW: &str = "D02";
+
+/// A doc section and what makes it required.
+struct Section {
+    heading: &'static str,
+    lint: &'static str,
+    reason: &'static str,
+    certain: bool,
+}
+
+const ERRORS: Section = Section {
+    heading: "# Errors",
+    lint: "missing_errors_doc",
+    reason: "returns Result",
+    certain: true,
+};
+
+const PANICS: Section = Section {
+    heading: "# Panics",
+    lint: "missing_panics_doc",
+    reason: "can panic",
+    certain: false,
+};
+
+const SAFETY: Section = Section {
+    heading: "# Safety",
+    lint: "missing_safety_doc",
+    reason: "is unsafe",
+    certain: true,
+};
+
+const PANIC_MACROS: [&str; 4] = ["panic", "unreachable", "todo", "unimplemented"];
+
+/// Checks every public function in a library source file.
+pub fn check_doc_contracts(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if is_test_file(ctx.filename) || is_binary_file(ctx.filename) {
+        return;
+    }
+    visit(ctx.root, ctx, out);
+}
+
+fn visit(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if node.kind() == "function_item" && is_public(node, ctx.source) {
+        check_function(node, ctx, out);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, ctx, out);
+    }
+}
+
+fn check_function(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if is_test_context(ctx.source, node, SemanticLanguage::Rust) {
+        return;
+    }
+    let preamble = preamble(ctx.source, node.start_position().row);
+    if preamble.contains(&format!("neti:allow({LAW})")) {
+        return;
+    }
+    let required = [
+        (ERRORS, returns_result(node, ctx.source)),
+        (
+            PANICS,
+            node.child_by_field_name("body")
+                .is_some_and(|b| can_panic(b, ctx.source)),
+        ),
+        (SAFETY, is_unsafe(node)),
+    ];
+    for (section, needed) in required {
+        let documented = preamble
+            .lines()
+            .any(|l| doc_line(l) == Some(section.heading));
+        let allowed = preamble.contains(section.lint) || crate_allows(ctx.source, section.lint);
+        if needed && !documented && !allowed {
+            out.push(missing(node, ctx.source, &section));
+        }
+    }
+}
+
+/// Doc comments, attributes and plain comments directly above `row`.
+fn preamble(source: &str, row: usize) -> String {
+    let lines: Vec<&str> = source.lines().take(row).collect();
+    let start = lines
+        .iter()
+        .rposition(|l| {
+            let t = l.trim_start();
+            !(t.starts_with("//") || t.starts_with("#["))
+        })
+        .map_or(0, |i| i + 1);
+    lines.get(start..).unwrap_or_default().join("\n")
+}
+
+fn doc_line(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("///").map(str::trim)
+}
+
+fn crate_allows(source: &str, lint: &str) -> bool {
+    source
+        .lines()
+        .any(|l| l.trim_start().starts_with("#![allow(") && l.contains(lint))
+}
+
+fn is_public(function: Node, source: &str) -> bool {
+    let mut cursor = function.walk();
+    let public = function
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "visibility_modifier")
+        .any(|c| c.utf8_text(source.as_bytes()) == Ok("pub"));
+    public
+}
+
+fn returns_result(function: Node, source: &str) -> bool {
+    function
+        .child_by_field_name("return_type")
+        .and_then(|t| t.utf8_text(source.as_bytes()).ok())
+        .is_some_and(|t| t.starts_with("Result") || t.contains("::Result"))
+}
+
+fn is_unsafe(function: Node) -> bool {
+    let mut cursor = function.walk();
+    let found = function
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "function_modifiers")
+        .any(|m| {
+            let mut inner = m.walk();
+            let is_unsafe = m.children(&mut inner).any(|k| k.kind() == "unsafe");
+            is_unsafe
+        });
+    found
+}
+
+/// Whether `node` contains a panic path, not counting nested functions
+/// and closures, which panic on their own account.
+fn can_panic(node: Node, source: &str) -> bool {
+    let text = |n: Node| n.utf8_text(source.as_bytes()).unwrap_or("");
+    let here = match node.kind() {
+        "index_expression" => true,
+        "macro_invocation" => node
+            .child_by_field_name("macro")
+            .is_some_and(|m| PANIC_MACROS.contains(&text(m))),
+        "call_expression" => node
+            .child_by_field_name("function")
+            .and_then(|f| f.child_by_field_name("field"))
+            .is_some_and(|f| matches!(text(f), "unwrap" | "expect")),
+        "function_item" | "closure_expression" => return false,
+        _ => false,
+    };
+    let mut cursor = node.walk();
+    let nested = node.children(&mut cursor).any(|c| can_panic(c, source));
+    here || nested
+}
+
+fn missing(node: Node, source: &str, section: &Section) -> Violation {
+    let name = node
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .unwrap_or("<anonymous>");
+    let mut v = Violation::with_details(
+        node.start_position().row + 1,
+        format!(
+            "Public fn `{name}` {} but its docs have no `{}` section",
+            section.reason, section.heading
+        ),
+        LAW,
+        ViolationDetails {
+            function_name: Some(name.to_string()),
+            analysis: vec![format!("Mirrors clippy::{}.", section.lint)],
+            suggestion: Some(format!(
+                "Add a `/// {}` section describing when this happens.",
+                section.heading
+            )),
+        },
+    );
+    if !section.certain {
+        v.confidence = Confidence::Medium;
+        v.confidence_reason =
+            Some("panic paths found syntactically; some may be unreachable".into());
+    }
+    v
+}
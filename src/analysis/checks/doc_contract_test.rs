@@ -0,0 +1,80 @@
+// src/analysis/checks/doc_contract_test.rs
+
+use super::*;
+use crate::config::RuleConfig;
+use crate::tokens::TokenizerKind;
+use tree_sitter::Parser;
+
+fn run(code: &str, filename: &str) -> Vec<String> {
+    let mut parser = Parser::new();
+    assert!(parser
+        .set_language(&tree_sitter_rust::LANGUAGE.into())
+        .is_ok());
+    let Some(tree) = parser.parse(code, None) else {
+        return Vec::new();
+    };
+    let config = RuleConfig::default();
+    let ctx = CheckContext {
+        root: tree.root_node(),
+        source: code,
+        filename,
+        config: &config,
+        tokenizer: TokenizerKind::Whitespace,
+    };
+    let mut out = Vec::new();
+    check_doc_contracts(&ctx, &mut out);
+    out.into_iter().map(|v| v.message).collect()
+}
+
+#[test]
+fn test_missing_sections_reported() {
+    let code = r"
+/// Loads it.
+pub fn load() -> io::Result<u8> { Ok(0) }
+
+/// Reads the first byte.
+pub fn first(v: &[u8]) -> u8 { v[0] }
+
+/// Frees it.
+pub unsafe fn free(p: *mut u8) {}
+
+fn private() -> Result<(), E> { Ok(()) }
+pub(crate) fn internal() -> Result<(), E> { Ok(()) }
+";
+    assert_eq!(
+        run(code, "src/lib.rs"),
+        vec![
+            "Public fn `load` returns Result but its docs have no `# Errors` section",
+            "Public fn `first` can panic but its docs have no `# Panics` section",
+            "Public fn `free` is unsafe but its docs have no `# Safety` section",
+        ]
+    );
+    assert!(run(code, "src/main.rs").is_empty());
+}
+
+#[test]
+fn test_documented_or_suppressed_items_pass() {
+    let code = r#"
+/// Loads it.
+///
+/// # Errors
+/// Returns error if the file is missing.
+///
+/// # Panics
+/// Panics if the file is empty.
+pub fn load() -> Result<u8> { Ok(read()?[0]) }
+
+// neti:allow(D02) Internal API, documented in the module docs.
+pub fn quiet() -> Result<u8> { Ok(0) }
+
+#[allow(clippy::missing_errors_doc)]
+pub fn lint_allowed() -> Result<u8> { Ok(0) }
+
+pub fn handled(v: &[u8]) -> u8 {
+    v.iter().map(|b| [*b][0]).sum()
+}
+"#;
+    assert!(run(code, "src/lib.rs").is_empty());
+    let crate_wide = "#![allow(clippy::missing_errors_doc)]\npub fn f() -> Result<u8> { Ok(0) }";
+    assert!(run(crate_wide, "src/lib.rs").is_empty());
+}
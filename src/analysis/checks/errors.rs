@@ -0,0 +1,194 @@
+// src/analysis/checks/errors.rs
+//! Error-handling hygiene: E01 (`let _ =` discarding a `Result`) and E02
+//! (`Box<dyn Error>` returned from a public function).
+//!
+//! Without type information a call is known to return `Result` only when
+//! it is declared in the same file; common std I/O calls are assumed to,
+//! at lower confidence. A comment on the line, or above it in the same
+//! function, marks a discard as deliberate.
+
+use std::collections::HashSet;
+
+use omni_ast::SemanticLanguage;
+use tree_sitter::Node;
+
+use crate::analysis::patterns::performance_test_ctx::is_test_context;
+use crate::types::{Confidence, Violation, ViolationDetails};
+
+use super::{is_binary_file, is_test_file, CheckContext};
+
+#[cfg(test)]
+#[path = "errors_test.rs"]
+mod tests;
+
+/// Std calls that return `Result` wherever they appear.
+const KNOWN_FALLIBLE: [&str; 14] = [
+    "remove_file",
+    "remove_dir",
+    "remove_dir_all",
+    "create_dir",
+    "create_dir_all",
+    "rename",
+    "copy",
+    "set_permissions",
+    "sync_all",
+    "sync_data",
+    "flush",
+    "write_all",
+    "send",
+    "kill",
+];
+
+/// Checks discarded results and boxed public errors.
+pub fn check_error_hygiene(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if is_test_file(ctx.filename) {
+        return;
+    }
+    let mut fallible = HashSet::new();
+    collect_result_fns(ctx.root, ctx.source, &mut fallible);
+    visit(ctx.root, ctx, &fallible, out);
+}
+
+fn visit(node: Node, ctx: &CheckContext, fallible: &HashSet<&str>, out: &mut Vec<Violation>) {
+    let found = match node.kind() {
+        "let_declaration" => check_discard(node, ctx, fallible),
+        "function_item" => check_boxed_error(node, ctx),
+        _ => None,
+    };
+    if let Some(v) = found {
+        if !is_test_context(ctx.source, node, SemanticLanguage::Rust) {
+            out.push(v);
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, ctx, fallible, out);
+    }
+}
+
+/// Names of functions in this file declared to return `Result`.
+fn collect_result_fns<'a>(node: Node, source: &'a str, out: &mut HashSet<&'a str>) {
+    if node.kind() == "function_item" {
+        let returns_result = return_type(node, source)
+            .is_some_and(|t| t.starts_with("Result") || t.contains("::Result"));
+        let name = node
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok());
+        if let (true, Some(name)) = (returns_result, name) {
+            out.insert(name);
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_result_fns(child, source, out);
+    }
+}
+
+fn return_type<'a>(function: Node, source: &'a str) -> Option<&'a str> {
+    function
+        .child_by_field_name("return_type")?
+        .utf8_text(source.as_bytes())
+        .ok()
+}
+
+fn check_discard(node: Node, ctx: &CheckContext, fallible: &HashSet<&str>) -> Option<Violation> {
+    let pattern = node.child_by_field_name("pattern")?;
+    if pattern.utf8_text(ctx.source.as_bytes()).ok()? != "_" {
+        return None;
+    }
+    let value = node.child_by_field_name("value")?;
+    if value.kind() != "call_expression" || is_explained(ctx.source, node) {
+        return None;
+    }
+    let name = callee_name(value, ctx.source)?;
+    let declared = fallible.contains(name);
+    if !declared && !KNOWN_FALLIBLE.contains(&name) {
+        return None;
+    }
+    let mut v = Violation::with_details(
+        node.start_position().row + 1,
+        format!("Result of `{name}()` discarded with `let _`"),
+        "E01",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![format!("`{name}` returns Result; its error is dropped silently.")],
+            suggestion: Some(
+                "Handle or propagate it with `?`. If ignoring it is deliberate, say why in a comment."
+                    .into(),
+            ),
+        },
+    );
+    if !declared {
+        v.confidence = Confidence::Medium;
+        v.confidence_reason = Some("return type assumed from the std API of that name".into());
+    }
+    Some(v)
+}
+
+fn callee_name<'a>(call: Node, source: &'a str) -> Option<&'a str> {
+    let function = call.child_by_field_name("function")?;
+    let name = match function.kind() {
+        "identifier" => function,
+        "scoped_identifier" => function.child_by_field_name("name")?,
+        "field_expression" => function.child_by_field_name("field")?,
+        _ => return None,
+    };
+    name.utf8_text(source.as_bytes()).ok()
+}
+
+/// A comment on the statement's line, or above it in the same function.
+fn is_explained(source: &str, node: Node) -> bool {
+    let row = node.start_position().row;
+    let mut top = node;
+    while let Some(parent) = top.parent() {
+        top = parent;
+        if matches!(top.kind(), "function_item" | "closure_expression") {
+            break;
+        }
+    }
+    let start = top.start_position().row;
+    source
+        .lines()
+        .skip(start)
+        .take(row + 1 - start)
+        .any(|line| line.contains("//"))
+}
+
+fn check_boxed_error(node: Node, ctx: &CheckContext) -> Option<Violation> {
+    if is_binary_file(ctx.filename) || !is_public(node, ctx.source) {
+        return None;
+    }
+    let returns = return_type(node, ctx.source)?;
+    if !(returns.contains("Box<dyn") && returns.contains("Error")) {
+        return None;
+    }
+    let name = node
+        .child_by_field_name("name")?
+        .utf8_text(ctx.source.as_bytes())
+        .ok()?;
+    let mut v = Violation::with_details(
+        node.start_position().row + 1,
+        format!("Public fn `{name}` returns `Box<dyn Error>`"),
+        "E02",
+        ViolationDetails {
+            function_name: Some(name.to_string()),
+            analysis: vec![format!("Returns `{returns}`")],
+            suggestion: Some(
+                "Return a concrete error type (an enum per failure) so callers can match on it."
+                    .into(),
+            ),
+        },
+    );
+    v.confidence = Confidence::Medium;
+    v.confidence_reason = Some("reachability from outside the crate is not resolved".into());
+    Some(v)
+}
+
+fn is_public(function: Node, source: &str) -> bool {
+    let mut cursor = function.walk();
+    let public = function
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "visibility_modifier")
+        .any(|c| c.utf8_text(source.as_bytes()) == Ok("pub"));
+    public
+}
@@ -0,0 +1,87 @@
+// src/analysis/checks/errors_test.rs
+
+use super::*;
+use crate::config::RuleConfig;
+use crate::tokens::TokenizerKind;
+use tree_sitter::Parser;
+
+fn run(code: &str, filename: &str) -> Vec<(&'static str, Confidence, usize)> {
+    let mut parser = Parser::new();
+    assert!(parser
+        .set_language(&tree_sitter_rust::LANGUAGE.into())
+        .is_ok());
+    let Some(tree) = parser.parse(code, None) else {
+        return Vec::new();
+    };
+    let config = RuleConfig::default();
+    let ctx = CheckContext {
+        root: tree.root_node(),
+        source: code,
+        filename,
+        config: &config,
+        tokenizer: TokenizerKind::Whitespace,
+    };
+    let mut out = Vec::new();
+    check_error_hygiene(&ctx, &mut out);
+    out.into_iter()
+        .map(|v| (v.law, v.confidence, v.row))
+        .collect()
+}
+
+#[test]
+fn e01_flags_discarded_results() {
+    let code = r#"
+fn save() -> io::Result<()> { Ok(()) }
+fn count() -> usize { 0 }
+fn run(path: &Path) {
+    let _ = save();
+    let _ = count();
+    let _ = fs::remove_file(path);
+    let _guard = save();
+}
+fn cleanup(path: &Path) {
+    // Best effort: the temp file may already be gone.
+    if path.exists() {
+        let _ = fs::remove_file(path);
+    }
+    let _ = writer.flush(); // reported elsewhere
+}
+"#;
+    assert_eq!(
+        run(code, "src/store.rs"),
+        vec![("E01", Confidence::High, 5), ("E01", Confidence::Medium, 7)]
+    );
+}
+
+#[test]
+fn e02_flags_boxed_errors_in_public_library_fns() {
+    let code = r"
+pub fn load() -> Result<(), Box<dyn std::error::Error>> { Ok(()) }
+fn helper() -> Result<(), Box<dyn Error>> { Ok(()) }
+pub(crate) fn inner() -> Result<(), Box<dyn Error>> { Ok(()) }
+";
+    assert_eq!(
+        run(code, "src/lib.rs"),
+        vec![("E02", Confidence::Medium, 2)]
+    );
+    assert!(run(code, "src/bin/tool.rs").is_empty());
+    assert!(run(code, "src/main.rs").is_empty());
+}
+
+#[test]
+fn test_code_is_exempt() {
+    let code = r"
+fn save() -> io::Result<()> { Ok(()) }
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() { let _ = save(); }
+}
+";
+    assert!(run(code, "src/store.rs").is_empty());
+    assert!(run(
+        "fn a() { let _ = fs::remove_file(p); }",
+        "tests/store_test.rs"
+    )
+    .is_empty());
+}
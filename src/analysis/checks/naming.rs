@@ -1,20 +1,25 @@
 // src/analysis/checks/naming.rs
-//! Function naming checks (Law of Complexity).
+//! Naming checks: function name length (Law of Complexity) and the
+//! configured naming conventions (N01).
 
 use omni_ast::swum::{expand_identifier, split_identifier};
 use tree_sitter::{Query, QueryCursor, QueryMatch};
 
+use crate::lang::Lang;
 use crate::types::{Violation, ViolationDetails};
 
+use super::conventions::check_conventions;
 use super::CheckContext;
 
-/// Checks for naming violations (function name word count).
-pub fn check_naming(ctx: &CheckContext, query: &Query, out: &mut Vec<Violation>) {
+/// Checks for naming violations: function name word count, then the
+/// `[rules.naming]` policy for `lang`.
+pub fn check_naming(ctx: &CheckContext, lang: Lang, query: &Query, out: &mut Vec<Violation>) {
     for pattern in &ctx.config.ignore_naming_on {
         if ctx.filename.contains(pattern) {
             return;
         }
     }
+    check_conventions(ctx, lang, out);
 
     let mut cursor = QueryCursor::new();
     let matches = cursor.matches(query, ctx.root, ctx.source.as_bytes());
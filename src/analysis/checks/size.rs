@@ -0,0 +1,202 @@
+// src/analysis/checks/size.rs
+//! Per-item size limits (Law of Atomicity): lines and tokens per function,
+//! fields per struct. A small file can still hide a 900-line function.
+
+use tree_sitter::Node;
+
+use crate::tokens::Tokenizer;
+use crate::types::{Violation, ViolationDetails};
+
+use super::CheckContext;
+
+const FUNCTION_KINDS: [&str; 5] = [
+    "function_item",
+    "function_definition",
+    "method_definition",
+    "function_declaration",
+    "init_declaration",
+];
+
+const STRUCT_KINDS: [&str; 4] = [
+    "struct_item",
+    "class_declaration",
+    "interface_declaration",
+    "class_definition",
+];
+
+const FIELD_KINDS: [&str; 4] = [
+    "field_declaration",
+    "public_field_definition",
+    "property_signature",
+    "property_declaration",
+];
+
+/// Checks every function and struct-like item in the file.
+pub fn check_sizes(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    visit(ctx.root, ctx, out);
+}
+
+fn visit(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if FUNCTION_KINDS.contains(&node.kind()) {
+        check_function(node, ctx, out);
+    } else if STRUCT_KINDS.contains(&node.kind()) {
+        check_fields(node, ctx, out);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, ctx, out);
+    }
+}
+
+fn check_function(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let lines = node.end_position().row - node.start_position().row + 1;
+    let limits = ctx.config;
+    if lines > limits.max_function_lines {
+        out.push(size_violation(
+            node,
+            ctx,
+            format!("has {lines} lines (Max: {})", limits.max_function_lines),
+            "Extract cohesive steps into helper functions.",
+        ));
+        return;
+    }
+    // A token spans at least one byte, so short bodies need no counting.
+    let Some(text) = ctx.source.get(node.byte_range()) else {
+        return;
+    };
+    if text.len() <= limits.max_function_tokens {
+        return;
+    }
+    let tokens = Tokenizer::count_as(ctx.tokenizer, text);
+    if tokens > limits.max_function_tokens {
+        out.push(size_violation(
+            node,
+            ctx,
+            format!("is {tokens} tokens (Max: {})", limits.max_function_tokens),
+            "Extract cohesive steps into helper functions.",
+        ));
+    }
+}
+
+fn check_fields(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let Some(body) = body_of(node) else {
+        return;
+    };
+    let mut cursor = body.walk();
+    let fields = body
+        .named_children(&mut cursor)
+        .filter(|c| FIELD_KINDS.contains(&c.kind()))
+        .count();
+    if fields > ctx.config.max_struct_fields {
+        out.push(size_violation(
+            node,
+            ctx,
+            format!(
+                "has {fields} fields (Max: {})",
+                ctx.config.max_struct_fields
+            ),
+            "Group related fields into their own types.",
+        ));
+    }
+}
+
+fn body_of(node: Node) -> Option<Node> {
+    node.child_by_field_name("body").or_else(|| {
+        let mut cursor = node.walk();
+        let found = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "field_declaration_list");
+        found
+    })
+}
+
+fn size_violation(node: Node, ctx: &CheckContext, measure: String, fix: &str) -> Violation {
+    let name = node
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+        .unwrap_or("<anonymous>");
+    let item = if FUNCTION_KINDS.contains(&node.kind()) {
+        "Function"
+    } else {
+        "Type"
+    };
+    Violation::with_details(
+        node.start_position().row + 1,
+        format!("{item} '{name}' {measure}"),
+        "LAW OF ATOMICITY",
+        ViolationDetails {
+            function_name: Some(name.to_string()),
+            analysis: vec![format!(
+                "Spans lines {}-{}",
+                node.start_position().row + 1,
+                node.end_position().row + 1
+            )],
+            suggestion: Some(fix.into()),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleConfig;
+    use crate::lang::Lang;
+    use crate::tokens::TokenizerKind;
+    use tree_sitter::Parser;
+
+    fn run(code: &str, lang: Lang, config: &RuleConfig) -> Vec<String> {
+        let mut parser = Parser::new();
+        assert!(parser.set_language(&lang.grammar()).is_ok());
+        let Some(tree) = parser.parse(code, None) else {
+            return Vec::new();
+        };
+        let ctx = CheckContext {
+            root: tree.root_node(),
+            source: code,
+            filename: "test",
+            config,
+            tokenizer: TokenizerKind::Whitespace,
+        };
+        let mut out = Vec::new();
+        check_sizes(&ctx, &mut out);
+        out.into_iter().map(|v| v.message).collect()
+    }
+
+    #[test]
+    fn test_long_function_reported_by_lines_then_tokens() {
+        let config = RuleConfig {
+            max_function_lines: 4,
+            max_function_tokens: 12,
+            ..RuleConfig::default()
+        };
+        let long = format!("fn long() {{\n{}}}\n", "    step();\n".repeat(5));
+        assert_eq!(
+            run(&long, Lang::Rust, &config),
+            vec!["Function 'long' has 7 lines (Max: 4)"]
+        );
+        let wide = "fn wide() { a(); b(); c(); d(); e(); f(); g(); h(); i(); j(); }";
+        assert_eq!(
+            run(wide, Lang::Rust, &config),
+            vec!["Function 'wide' is 14 tokens (Max: 12)"]
+        );
+        assert!(run("fn ok() { a(); }", Lang::Rust, &config).is_empty());
+    }
+
+    #[test]
+    fn test_struct_fields() {
+        let config = RuleConfig {
+            max_struct_fields: 2,
+            ..RuleConfig::default()
+        };
+        let rust = "struct Wide { a: u8, b: u8, c: u8 }\nstruct Narrow { a: u8 }";
+        assert_eq!(
+            run(rust, Lang::Rust, &config),
+            vec!["Type 'Wide' has 3 fields (Max: 2)"]
+        );
+        let ts = "interface Wide { a: number; b: number; c: number }";
+        assert_eq!(
+            run(ts, Lang::TypeScript, &config),
+            vec!["Type 'Wide' has 3 fields (Max: 2)"]
+        );
+    }
+}
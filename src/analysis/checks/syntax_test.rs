@@ -15,6 +15,7 @@ fn run_syntax_check(code: &str, filename: &str) -> Vec<Violation> {
         source: code,
         filename,
         config: &config,
+        tokenizer: crate::tokens::TokenizerKind::default(),
     };
     let mut violations = Vec::new();
     check_syntax(&ctx, &mut violations);
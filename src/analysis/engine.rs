@@ -4,19 +4,30 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rayon::prelude::{IntoParallelRefIterator, ParallelExtend, ParallelIterator};
 
+use crate::api;
 use crate::config::Config;
-use crate::types::{FileReport, ScanReport, Violation};
+use crate::debt;
+use crate::logging;
+use crate::profile;
+use crate::secrets;
+use crate::types::{Category, FileReport, ScanReport, Violation};
 
 use super::aggregator::Aggregator;
+use super::batch;
 use super::deep::DeepAnalyzer;
+use super::testing;
 use super::worker;
 
 /// Source files below this threshold skip structural metrics (LCOM4, CBO, AHF, SFOUT).
 /// Rationale: For small projects, modularity metrics are noise, not signal.
 pub const SMALL_CODEBASE_THRESHOLD: usize = 10;
 
+/// Discovery walks the working directory, so project files such as the
+/// secrets and API baselines are found relative to it.
+const SCAN_ROOT: &str = ".";
+
 /// The main analysis engine.
 /// Orchestrates file scanning, pattern detection, and structural analysis.
 pub struct Engine;
@@ -39,50 +50,108 @@ impl Engine {
         F: Fn(&Path) + Sync,
         S: Fn(&str) + Sync,
     {
+        let _span = logging::span("scan");
+        log::debug!("scanning {} files", files.len());
         let start = std::time::Instant::now();
+        let accepted = secrets::Baseline::load(Path::new(SCAN_ROOT));
 
         // Phase 1: Local Analysis (Parallel)
         // Token counts, patterns, basic checks, scope extraction
-        let mut results: Vec<FileReport> = files
-            .par_iter()
-            .inspect(|path| {
-                on_progress(path);
-            })
-            .map(|path| worker::scan_file(path, config))
-            .collect();
-
-        // Phase 2: Deep Analysis (Sequential/Aggregated)
-        // Structural metrics (LCOM4, CBO) for sufficiently large codebases
-        on_status("Running Deep Analysis (LCOM4/CBO)...");
-
-        if should_run_deep_analysis(&results) {
-            let deep_violations = run_deep_analysis(config, &results);
-            merge_violations(&mut results, &deep_violations);
+        let mut results = scan_batches(config, files, &accepted, on_progress);
+
+        if config.categories.includes(Category::Structure) {
+            project_checks(config, &mut results, files, on_status);
         }
 
-        finalize_report(results, start)
+        finalize_report(config, results, start)
     }
 
     /// Entry point for scanning files without progress callbacks.
     #[must_use]
     pub fn scan(config: &Config, files: &[PathBuf]) -> ScanReport {
+        let _span = logging::span("scan");
+        log::debug!("scanning {} files", files.len());
         let start = std::time::Instant::now();
+        let accepted = secrets::Baseline::load(Path::new(SCAN_ROOT));
 
-        let mut results: Vec<FileReport> = files
-            .par_iter()
-            .map(|path| worker::scan_file(path, config))
-            .collect();
-
-        if should_run_deep_analysis(&results) {
-            let deep_violations = run_deep_analysis(config, &results);
-            merge_violations(&mut results, &deep_violations);
+        let mut results = scan_batches(config, files, &accepted, &|_| {});
+        if config.categories.includes(Category::Structure) {
+            project_checks(config, &mut results, files, &|_| {});
         }
 
-        finalize_report(results, start)
+        finalize_report(config, results, start)
+    }
+}
+
+/// Checks that need every file's results: structural metrics, untested
+/// modules, aged debt and API compatibility. All of them report structure
+/// findings, so a scan limited to other categories skips them.
+fn project_checks<S>(
+    config: &Config,
+    results: &mut Vec<FileReport>,
+    files: &[PathBuf],
+    on_status: &S,
+) where
+    S: Fn(&str),
+{
+    // Phase 2: Deep Analysis (Sequential/Aggregated)
+    // Structural metrics (LCOM4, CBO) for sufficiently large codebases
+    on_status("Running Deep Analysis (LCOM4/CBO)...");
+
+    if should_run_deep_analysis(results) {
+        let deep_violations = run_deep_analysis(config, results);
+        merge_violations(results, &deep_violations);
+    }
+    let untested = profile::time_stage("untested", || testing::check(config, results));
+    merge_violations(results, &untested);
+    let aged = profile::time_stage("debt", || debt::check(config, results));
+    merge_violations(results, &aged);
+
+    // Phase 3: API Compatibility (only once a baseline has been snapshotted)
+    on_status("Checking API compatibility...");
+    merge_compatibility(Path::new(SCAN_ROOT), results, files);
+}
+
+/// Scans `files` in parallel, one memory-bounded batch at a time.
+fn scan_batches<F>(
+    config: &Config,
+    files: &[PathBuf],
+    accepted: &secrets::Baseline,
+    on_progress: &F,
+) -> Vec<FileReport>
+where
+    F: Fn(&Path) + Sync,
+{
+    let mut results = Vec::with_capacity(files.len());
+    for range in batch::plan(files, &config.discovery) {
+        let Some(batch) = files.get(range) else {
+            continue;
+        };
+        results.par_extend(
+            batch
+                .par_iter()
+                .inspect(|path| on_progress(path))
+                .map(|path| scan_one(path, config, accepted)),
+        );
     }
+    results
+}
+
+fn scan_one(path: &Path, config: &Config, accepted: &secrets::Baseline) -> FileReport {
+    let start = std::time::Instant::now();
+    let report = worker::scan_file(path, config, accepted);
+    log::trace!(
+        "{}: {} tokens, {} violations in {} ms",
+        path.display(),
+        report.token_count,
+        report.violations.len(),
+        start.elapsed().as_millis()
+    );
+    report
 }
 
 fn run_deep_analysis(config: &Config, results: &[FileReport]) -> HashMap<PathBuf, Vec<Violation>> {
+    let _span = logging::span("deep");
     // Aggregate scopes from all files
     let mut aggregator = Aggregator::new();
     for report in results {
@@ -92,17 +161,27 @@ fn run_deep_analysis(config: &Config, results: &[FileReport]) -> HashMap<PathBuf
     }
 
     // Run deep inspector
-    let deep_analyzer = DeepAnalyzer::new(&config.rules);
-    deep_analyzer.compute_violations(&aggregator)
+    let deep_analyzer = DeepAnalyzer::new(config);
+    profile::time_stage("deep", || deep_analyzer.compute_violations(&aggregator))
 }
 
-fn finalize_report(results: Vec<FileReport>, start: std::time::Instant) -> ScanReport {
-    ScanReport {
+fn finalize_report(
+    config: &Config,
+    results: Vec<FileReport>,
+    start: std::time::Instant,
+) -> ScanReport {
+    let mut report = ScanReport {
         total_violations: results.iter().map(|r| r.violations.len()).sum(),
         total_tokens: results.iter().map(|r| r.token_count).sum(),
         files: results,
-        duration_ms: start.elapsed().as_millis(),
+        duration_ms: 0,
+    };
+    report.retain_categories(&config.categories);
+    if config.preferences.stable_order {
+        report.sort();
     }
+    report.duration_ms = start.elapsed().as_millis();
+    report
 }
 
 fn should_run_deep_analysis(results: &[FileReport]) -> bool {
@@ -114,14 +193,52 @@ fn should_run_deep_analysis(results: &[FileReport]) -> bool {
 fn merge_violations(results: &mut [FileReport], deep: &HashMap<PathBuf, Vec<Violation>>) {
     for r in results {
         if let Some(v) = deep.get(&r.path) {
-            r.violations.extend(v.clone());
+            let visible = v.iter().filter(|v| !r.suppressions.covers(v));
+            r.violations.extend(visible.cloned());
+        }
+    }
+}
+
+/// Attaches LAW OF COMPATIBILITY violations; removed files get their own report.
+fn merge_compatibility(root: &Path, results: &mut Vec<FileReport>, files: &[PathBuf]) {
+    let _span = logging::span("api");
+    let found = match profile::time_stage("api", || api::check(root, files)) {
+        Ok(found) => found,
+        Err(e) => {
+            log::warn!("{e}");
+            return;
+        }
+    };
+
+    let index: HashMap<PathBuf, usize> = results
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (crate::normalize::relative_to(root, &r.path), i))
+        .collect();
+
+    for (path, violations) in found {
+        match index.get(&path).and_then(|&i| results.get_mut(i)) {
+            Some(report) => {
+                let visible = violations
+                    .into_iter()
+                    .filter(|v| !report.suppressions.covers(v));
+                report.violations.extend(visible);
+            }
+            None => results.push(FileReport {
+                path,
+                token_count: 0,
+                complexity_score: 0,
+                violations,
+                analysis: None,
+                suppressions: Default::default(),
+            }),
         }
     }
 }
 
 /// Returns true if path is a source file (not test/bench/example).
 fn is_source_file(path: &Path) -> bool {
-    let path_str = path.to_string_lossy();
+    let path_str = crate::normalize::path_str(path);
 
     if !path_str.contains("src/") && !path_str.starts_with("src/") {
         return false;
@@ -3,7 +3,8 @@
 //! Method extraction moved to `extract_impl.rs` for Atomicity.
 
 use super::scope::{FieldInfo, Scope};
-use tree_sitter::{Node, Query, QueryCursor};
+use crate::query_cache;
+use tree_sitter::{Node, QueryCursor};
 
 pub struct RustExtractor;
 
@@ -30,12 +31,12 @@ impl RustExtractor {
         out: &mut std::collections::HashMap<String, Scope>,
     ) {
         let query_str = "(field_declaration) @field";
-        let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+        let Some(query) = query_cache::rust(query_str) else {
             return;
         };
         let mut cursor = QueryCursor::new();
         let nodes: Vec<_> = cursor
-            .matches(&query, root, source.as_bytes())
+            .matches(query, root, source.as_bytes())
             .flat_map(|m| m.captures.iter().map(|c| c.node))
             .collect();
 
@@ -131,14 +132,14 @@ impl RustExtractor {
         source: &str,
         root: Node,
         out: &mut std::collections::HashMap<String, Scope>,
-        query_str: &str,
+        query_str: &'static str,
         is_enum: bool,
     ) {
-        let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+        let Some(query) = query_cache::rust(query_str) else {
             return;
         };
         let mut cursor = QueryCursor::new();
-        for m in cursor.matches(&query, root, source.as_bytes()) {
+        for m in cursor.matches(query, root, source.as_bytes()) {
             extract_single_type_def(source, &m, out, is_enum);
         }
     }
@@ -4,18 +4,19 @@
 
 use super::cognitive::CognitiveAnalyzer;
 use super::scope::{Method, Scope};
+use crate::query_cache;
 use std::collections::HashMap;
-use tree_sitter::{Node, Query, QueryCursor, TreeCursor};
+use tree_sitter::{Node, QueryCursor, TreeCursor};
 
 #[allow(clippy::implicit_hasher)]
 pub fn extract(source: &str, root: Node, out: &mut HashMap<String, Scope>) {
     let q_str = "(impl_item type: (type_identifier) @name body: (declaration_list) @body)";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q_str) else {
+    let Some(query) = query_cache::rust(q_str) else {
         return;
     };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         process_impl_match(source, &m, out);
     }
 }
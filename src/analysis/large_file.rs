@@ -0,0 +1,85 @@
+// src/analysis/large_file.rs
+//! Shallow analysis for files over `[discovery] max_analysis_bytes`.
+//!
+//! The file is streamed once in line-aligned chunks: each chunk is token
+//! counted and scanned for secrets, then dropped. Pattern, AST and scope
+//! passes need the whole tree in memory, so they are skipped and the report
+//! says so with an Info finding instead of quietly taking minutes.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::file_class;
+use crate::secrets;
+use crate::tokens::{self, Tokenizer};
+use crate::types::{Confidence, FileReport, Violation, ViolationDetails};
+
+use super::worker::atomicity_violation;
+
+/// Fills `report` for a file of `size` bytes without reading it whole.
+pub(super) fn scan(
+    path: &Path,
+    size: u64,
+    config: &Config,
+    accepted: &secrets::Baseline,
+    report: &mut FileReport,
+) {
+    log::debug!(
+        "{}: {size} bytes, over max_analysis_bytes; streaming",
+        path.display()
+    );
+    let kind = config.preferences.tokenizer;
+    let Ok(file) = File::open(path) else {
+        return;
+    };
+    let mut lines_before = 0;
+    let streamed = tokens::for_each_chunk(BufReader::new(file), |chunk| {
+        report.token_count += Tokenizer::count_as(kind, chunk);
+        let found = secrets::violations(path, chunk, accepted);
+        report
+            .violations
+            .extend(found.into_iter().map(|v| shift(v, lines_before)));
+        lines_before += chunk.matches('\n').count();
+    });
+    if streamed.is_err() {
+        return;
+    }
+    if !file_class::classify(path).is_governed() {
+        return;
+    }
+    let rules = config.rules_for(path);
+    report
+        .violations
+        .extend(atomicity_violation(path, report.token_count, rules));
+    report
+        .violations
+        .push(skipped_notice(size, config.discovery.max_analysis_bytes));
+}
+
+fn shift(mut violation: Violation, lines_before: usize) -> Violation {
+    violation.row += lines_before;
+    violation
+}
+
+fn skipped_notice(size: u64, limit: u64) -> Violation {
+    let mut v = Violation::with_details(
+        1,
+        format!("File too large ({size} bytes, limit {limit}); skipped deep analysis"),
+        "LAW OF ATOMICITY",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![
+                "Token count and secrets were checked; patterns, complexity and cohesion were not."
+                    .into(),
+            ],
+            suggestion: Some(
+                "Split the file, or raise [discovery] max_analysis_bytes to analyze it fully."
+                    .into(),
+            ),
+        },
+    );
+    v.confidence = Confidence::Info;
+    v
+}
@@ -2,6 +2,7 @@
 
 pub mod aggregator;
 pub mod ast;
+pub mod batch;
 pub mod checks;
 pub mod cognitive;
 pub mod deep;
@@ -13,10 +14,15 @@ pub mod patterns;
 pub mod safety;
 pub mod scope;
 pub mod structural;
+pub mod suppress;
+pub mod testing;
 pub mod visitor;
 pub mod worker;
 
+#[cfg(feature = "fs")]
 mod engine;
+mod large_file;
 
 pub use aggregator::FileAnalysis;
+#[cfg(feature = "fs")]
 pub use engine::Engine;
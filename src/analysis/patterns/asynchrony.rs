@@ -0,0 +1,181 @@
+// src/analysis/patterns/asynchrony.rs
+//! Async patterns: A01 (blocking call in async code), A02 (`block_on` in
+//! async code).
+//!
+//! Async context is an `async fn` or `async` block. A closure inside one is
+//! not, since that is how work gets handed to `spawn_blocking`.
+
+use crate::types::{Confidence, Violation, ViolationDetails};
+use tree_sitter::Node;
+
+#[cfg(test)]
+#[path = "asynchrony_test.rs"]
+mod tests;
+
+/// A blocking call and what to do instead.
+struct Blocking {
+    law: &'static str,
+    what: String,
+    fix: &'static str,
+    /// Why the finding is not certain, when it is not.
+    doubt: Option<&'static str>,
+}
+
+/// Detects blocking calls made from async code.
+#[must_use]
+pub fn detect(source: &str, root: Node) -> Vec<Violation> {
+    let mut out = Vec::new();
+    visit(source, root, &mut out);
+    out
+}
+
+fn visit(source: &str, node: Node, out: &mut Vec<Violation>) {
+    if node.kind() == "call_expression" {
+        if let Some(v) = check_call(source, node) {
+            out.push(v);
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(source, child, out);
+    }
+}
+
+fn check_call(source: &str, call: Node) -> Option<Violation> {
+    let function = call.child_by_field_name("function")?;
+    let blocking = match function.kind() {
+        "scoped_identifier" => path_call(source, function, call)?,
+        "field_expression" => method_call(source, function, call)?,
+        _ => return None,
+    };
+    let context = async_context(call)?;
+    Some(build(source, call, context, blocking))
+}
+
+fn path_call(source: &str, function: Node, call: Node) -> Option<Blocking> {
+    let path = function.utf8_text(source.as_bytes()).ok()?;
+    let segments: Vec<&str> = path.split("::").collect();
+    let (name, prefix) = segments.split_last()?;
+    if *name == "block_on" {
+        return Some(block_on(path));
+    }
+    if *name == "sleep" && prefix.last() == Some(&"thread") {
+        return Some(Blocking {
+            law: "A01",
+            what: format!("`{path}`"),
+            fix: "Use `tokio::time::sleep(..).await` (or your runtime's timer).",
+            doubt: None,
+        });
+    }
+    let std_fs = prefix.starts_with(&["std", "fs"]);
+    let bare_fs = prefix.first() == Some(&"fs");
+    if !(std_fs || bare_fs) || is_awaited(call) {
+        return None;
+    }
+    let imported = source.contains("use std::fs");
+    Some(Blocking {
+        law: "A01",
+        what: format!("`{path}`"),
+        fix: "Use `tokio::fs` / `async_std::fs`, or move the I/O into `spawn_blocking`.",
+        doubt: (!std_fs && !imported).then_some("`fs` not resolved to std::fs"),
+    })
+}
+
+fn method_call(source: &str, function: Node, call: Node) -> Option<Blocking> {
+    let method = function
+        .child_by_field_name("field")?
+        .utf8_text(source.as_bytes())
+        .ok()?;
+    match method {
+        "block_on" => Some(block_on(".block_on()")),
+        "blocking_recv" | "blocking_send" | "blocking_lock" => Some(Blocking {
+            law: "A01",
+            what: format!("`.{method}()`"),
+            fix: "`.await` the async form; tokio's `blocking_*` methods panic inside a runtime.",
+            doubt: None,
+        }),
+        "recv" | "recv_timeout" if !is_awaited(call) => Some(Blocking {
+            law: "A01",
+            what: format!("channel `.{method}()`"),
+            fix:
+                "Use an async channel and `.recv().await`, or move the wait into `spawn_blocking`.",
+            doubt: Some(
+                "receiver type not resolved; an un-awaited recv is the blocking std/crossbeam form",
+            ),
+        }),
+        _ => None,
+    }
+}
+
+fn block_on(what: &str) -> Blocking {
+    Blocking {
+        law: "A02",
+        what: format!("`{what}`"),
+        fix:
+            "`.await` the future instead; `block_on` inside a runtime stalls its worker or panics.",
+        doubt: None,
+    }
+}
+
+fn is_awaited(call: Node) -> bool {
+    call.parent()
+        .is_some_and(|p| p.kind() == "await_expression")
+}
+
+/// The nearest enclosing `async fn` or `async` block, if the call runs in
+/// one.
+fn async_context(node: Node) -> Option<Node> {
+    let mut cur = node.parent()?;
+    loop {
+        match cur.kind() {
+            "async_block" => return Some(cur),
+            "function_item" => return is_async_fn(cur).then_some(cur),
+            "closure_expression" => return None,
+            _ => cur = cur.parent()?,
+        }
+    }
+}
+
+fn is_async_fn(function: Node) -> bool {
+    let mut cursor = function.walk();
+    let found = function
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "function_modifiers")
+        .any(|m| {
+            let mut inner = m.walk();
+            let is_async = m.children(&mut inner).any(|k| k.kind() == "async");
+            is_async
+        });
+    found
+}
+
+fn build(source: &str, call: Node, context: Node, blocking: Blocking) -> Violation {
+    let function_name = (context.kind() == "function_item")
+        .then(|| context.child_by_field_name("name"))
+        .flatten()
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .map(String::from);
+    let place = function_name
+        .as_deref()
+        .map_or_else(|| "async block".to_string(), |n| format!("async fn `{n}`"));
+    let message = if blocking.law == "A02" {
+        format!("{} inside {place}", blocking.what)
+    } else {
+        format!("Blocking call {} inside {place}", blocking.what)
+    };
+    let mut v = Violation::with_details(
+        call.start_position().row + 1,
+        message,
+        blocking.law,
+        ViolationDetails {
+            function_name,
+            analysis: vec!["Blocking here stalls the executor thread and every task on it.".into()],
+            suggestion: Some(blocking.fix.into()),
+        },
+    );
+    if let Some(reason) = blocking.doubt {
+        v.confidence = Confidence::Medium;
+        v.confidence_reason = Some(reason.into());
+    }
+    v
+}
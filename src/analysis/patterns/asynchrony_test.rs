@@ -0,0 +1,87 @@
+// src/analysis/patterns/asynchrony_test.rs
+
+use super::*;
+use tree_sitter::Parser;
+
+fn parse_and_detect(code: &str) -> Vec<Violation> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_rust::LANGUAGE.into())
+        .unwrap();
+    let tree = parser.parse(code, None).unwrap();
+    detect(code, tree.root_node())
+}
+
+fn laws(code: &str) -> Vec<(&'static str, Confidence)> {
+    parse_and_detect(code)
+        .into_iter()
+        .map(|v| (v.law, v.confidence))
+        .collect()
+}
+
+#[test]
+fn a01_flags_blocking_calls_in_async_fn() {
+    let code = r#"
+        async fn load(path: &Path, rx: Receiver<u8>) -> u8 {
+            let text = std::fs::read_to_string(path).unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+            rx.recv().unwrap()
+        }
+    "#;
+    assert_eq!(
+        laws(code),
+        vec![
+            ("A01", Confidence::High),
+            ("A01", Confidence::High),
+            ("A01", Confidence::Medium),
+        ]
+    );
+    let v = &parse_and_detect(code)[0];
+    assert_eq!(
+        v.message,
+        "Blocking call `std::fs::read_to_string` inside async fn `load`"
+    );
+}
+
+#[test]
+fn a01_ignores_awaited_and_sync_code() {
+    let code = r#"
+        use tokio::fs;
+        async fn load(path: &Path, mut rx: Receiver<u8>) {
+            let text = fs::read_to_string(path).await;
+            let n = rx.recv().await;
+            tokio::task::spawn_blocking(|| std::fs::read("a")).await;
+        }
+        fn sync_load(path: &Path) {
+            std::fs::read_to_string(path).unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    "#;
+    assert!(laws(code).is_empty());
+}
+
+#[test]
+fn a01_bare_fs_confidence_depends_on_import() {
+    let code = "async fn f() { fs::write(\"a\", b\"x\"); }";
+    assert_eq!(laws(code), vec![("A01", Confidence::Medium)]);
+    let code = "use std::fs;\nasync fn f() { fs::write(\"a\", b\"x\"); }";
+    assert_eq!(laws(code), vec![("A01", Confidence::High)]);
+}
+
+#[test]
+fn a02_flags_block_on_in_async_block() {
+    let code = r#"
+        fn main() {
+            let fut = async {
+                let rt = Runtime::new().unwrap();
+                rt.block_on(work());
+                futures::executor::block_on(work());
+            };
+            Runtime::new().unwrap().block_on(fut);
+        }
+    "#;
+    let found = parse_and_detect(code);
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(|v| v.law == "A02"));
+    assert_eq!(found[0].message, "`.block_on()` inside async block");
+}
@@ -11,9 +11,10 @@
 //! Async mutexes yield on contention. Holding them across `.await` causes
 //! head-of-line blocking rather than deadlock.
 
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[cfg(test)]
 #[path = "concurrency_lock_test.rs"]
@@ -34,12 +35,12 @@ pub fn detect_c03(source: &str, root: Node) -> Vec<Violation> {
     let mut violations = Vec::new();
     let query_str = r"(function_item (function_modifiers) @mods body: (block) @body) @fn";
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+    let Some(query) = query_cache::rust(query_str) else {
         return violations;
     };
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         if let Some(v) = check_async_fn(source, &m) {
             violations.push(v);
         }
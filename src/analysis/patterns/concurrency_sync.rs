@@ -1,9 +1,10 @@
 // src/analysis/patterns/concurrency_sync.rs
 //! C04: Undocumented synchronization primitives
 
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 /// C04: Arc<Mutex<T>> without documentation
 #[must_use]
@@ -15,12 +16,12 @@ pub fn detect_c04(source: &str, root: Node) -> Vec<Violation> {
 
 fn detect_sync_fields(source: &str, root: Node, out: &mut Vec<Violation>) {
     let query_str = r"(field_declaration name: (field_identifier) @name) @field";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+    let Some(query) = query_cache::rust(query_str) else {
         return;
     };
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         if let Some(v) = check_sync_field(source, &m) {
             out.push(v);
         }
@@ -2,8 +2,9 @@
 //! Database anti-patterns: P03 (N+1 queries)
 
 use super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[must_use]
 pub fn detect(source: &str, root: Node) -> Vec<Violation> {
@@ -19,7 +20,7 @@ fn detect_p03(source: &str, root: Node, out: &mut Vec<Violation>) {
         (while_expression body: (block) @body) @loop
     ";
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), loop_q) else {
+    let Some(query) = query_cache::rust(loop_q) else {
         return;
     };
     let idx_pat = query.capture_index_for_name("pat");
@@ -27,7 +28,7 @@ fn detect_p03(source: &str, root: Node, out: &mut Vec<Violation>) {
 
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let loop_var_node = get_capture_node(&m, idx_pat);
         let body_node = get_capture_node(&m, idx_body);
 
@@ -71,17 +72,17 @@ fn check_db_calls(source: &str, body: Node, loop_var: &str, out: &mut Vec<Violat
 fn check_pattern(
     source: &str,
     body: Node,
-    pattern: &str,
+    pattern: &'static str,
     loop_var: &str,
     out: &mut Vec<Violation>,
 ) {
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), pattern) else {
+    let Some(query) = query_cache::rust(pattern) else {
         return;
     };
     let idx_call = query.capture_index_for_name("call");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, body, source.as_bytes()) {
+    for m in cursor.matches(query, body, source.as_bytes()) {
         let Some(call) = get_capture_node(&m, idx_call) else {
             continue;
         };
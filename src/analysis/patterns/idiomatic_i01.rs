@@ -3,18 +3,19 @@
 //!
 //! Reported as INFO — style suggestion, not a correctness issue.
 
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 /// Detects manual `From` implementations. Reported as INFO — style suggestion.
 pub(super) fn detect_i01(source: &str, root: Node, out: &mut Vec<Violation>) {
     let q = r"(impl_item) @impl";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let Some(impl_node) = m.captures.first().map(|c| c.node) else {
             continue;
         };
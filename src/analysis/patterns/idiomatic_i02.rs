@@ -5,9 +5,10 @@
 //! different inner types produce textually identical bodies that CANNOT be fused.
 //! I02 must detect this and suppress the suggestion.
 
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
 use std::collections::HashMap;
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[cfg(test)]
 #[path = "idiomatic_i02_test.rs"]
@@ -15,14 +16,14 @@ mod tests;
 
 pub(super) fn detect_i02(source: &str, root: Node, out: &mut Vec<Violation>) {
     let q = r"(match_expression body: (match_block) @block) @match";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_match = query.capture_index_for_name("match");
     let idx_block = query.capture_index_for_name("block");
 
     let mut cursor = QueryCursor::new();
-    let matches: Vec<_> = cursor.matches(&query, root, source.as_bytes()).collect();
+    let matches: Vec<_> = cursor.matches(query, root, source.as_bytes()).collect();
 
     for m in &matches {
         let match_node = find_capture(m, idx_match);
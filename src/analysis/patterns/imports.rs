@@ -0,0 +1,148 @@
+// src/analysis/patterns/imports.rs
+//! Import hygiene: I03 (wildcard import), I04 (relative import that climbs
+//! too many directories).
+//!
+//! Wildcards hide where a name comes from and let upstream additions
+//! shadow local ones; preludes exist to be glob-imported, so paths through
+//! a `wildcard_allow` segment pass. Deep relative imports tie a file to the
+//! folder layout far above it — the coupling the locality graph measures,
+//! reported here at the line that creates it.
+
+use std::path::Path;
+
+use omni_ast::SemanticLanguage;
+use tree_sitter::Node;
+
+use crate::config::ImportsConfig;
+use crate::lang::Lang;
+use crate::normalize;
+use crate::types::{Violation, ViolationDetails};
+
+use super::performance_test_ctx::is_test_context;
+
+#[cfg(test)]
+#[path = "imports_test.rs"]
+mod tests;
+
+/// Detects wildcard and deep relative imports in a parsed file.
+#[must_use]
+pub fn detect(path: &Path, source: &str, root: Node, config: &ImportsConfig) -> Vec<Violation> {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let Some(lang) = Lang::from_ext(ext) else {
+        return Vec::new();
+    };
+    if !config.enabled || is_test_file(path) {
+        return Vec::new();
+    }
+    let mut out = Vec::new();
+    visit(source, root, lang, config, &mut out);
+    out
+}
+
+fn is_test_file(path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    let name = normalize::file_name(&path);
+    name.contains("test") || name.contains("spec")
+}
+
+fn visit(source: &str, node: Node, lang: Lang, config: &ImportsConfig, out: &mut Vec<Violation>) {
+    let found = match (lang, node.kind()) {
+        (Lang::Rust, "use_wildcard") => rust_wildcard(source, node, config),
+        (Lang::Python, "import_from_statement") => python_import(source, node, config),
+        (Lang::TypeScript, "import_statement" | "export_statement") => {
+            typescript_import(source, node, config)
+        }
+        _ => None,
+    };
+    out.extend(found);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(source, child, lang, config, out);
+    }
+}
+
+fn rust_wildcard(source: &str, node: Node, config: &ImportsConfig) -> Option<Violation> {
+    let text = node.utf8_text(source.as_bytes()).ok()?;
+    let allowed = text
+        .split("::")
+        .any(|s| config.wildcard_allow.iter().any(|a| a == s));
+    if allowed || is_test_context(source, node, SemanticLanguage::Rust) {
+        return None;
+    }
+    Some(wildcard(node, &format!("use {text}")))
+}
+
+fn python_import(source: &str, node: Node, config: &ImportsConfig) -> Option<Violation> {
+    let module = node
+        .child_by_field_name("module_name")?
+        .utf8_text(source.as_bytes())
+        .ok()?;
+    let climbs = module
+        .chars()
+        .take_while(|&c| c == '.')
+        .count()
+        .saturating_sub(1);
+    if climbs > config.max_relative_depth {
+        return Some(too_deep(node, module, climbs, config));
+    }
+    let mut cursor = node.walk();
+    let is_wildcard = node
+        .children(&mut cursor)
+        .any(|c| c.kind() == "wildcard_import");
+    let allowed = module
+        .split('.')
+        .any(|s| config.wildcard_allow.iter().any(|a| a == s));
+    (is_wildcard && !allowed).then(|| wildcard(node, &format!("from {module} import *")))
+}
+
+fn typescript_import(source: &str, node: Node, config: &ImportsConfig) -> Option<Violation> {
+    let specifier = node
+        .child_by_field_name("source")?
+        .utf8_text(source.as_bytes())
+        .ok()?
+        .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+    let climbs = specifier
+        .trim_start_matches("./")
+        .split('/')
+        .take_while(|s| *s == "..")
+        .count();
+    (climbs > config.max_relative_depth).then(|| too_deep(node, specifier, climbs, config))
+}
+
+fn wildcard(node: Node, import: &str) -> Violation {
+    Violation::with_details(
+        node.start_position().row + 1,
+        format!("Wildcard import `{import}`"),
+        "I03",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![
+                "Readers cannot tell where the imported names come from.".into(),
+                "A name added upstream can silently shadow a local one.".into(),
+            ],
+            suggestion: Some(
+                "Import the names you use. Preludes are exempt; add others to [rules.imports] wildcard_allow."
+                    .into(),
+            ),
+        },
+    )
+}
+
+fn too_deep(node: Node, import: &str, climbs: usize, config: &ImportsConfig) -> Violation {
+    Violation::with_details(
+        node.start_position().row + 1,
+        format!(
+            "Relative import `{import}` climbs {climbs} directories (limit: {})",
+            config.max_relative_depth
+        ),
+        "I04",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec!["The import depends on the folder layout far above this file.".into()],
+            suggestion: Some(
+                "Import from the package root or a path alias, or move the module closer to what it uses."
+                    .into(),
+            ),
+        },
+    )
+}
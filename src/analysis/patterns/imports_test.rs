@@ -0,0 +1,95 @@
+use super::detect;
+use crate::config::ImportsConfig;
+use crate::lang::Lang;
+use std::path::Path;
+use tree_sitter::Parser;
+
+fn imports(filename: &str, source: &str, config: &ImportsConfig) -> Vec<String> {
+    let path = Path::new(filename);
+    let lang = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(Lang::from_ext)
+        .expect("supported extension");
+    let mut parser = Parser::new();
+    parser.set_language(&lang.grammar()).expect("grammar");
+    let tree = parser.parse(source, None).expect("parse");
+    detect(path, source, tree.root_node(), config)
+        .into_iter()
+        .map(|v| format!("{} {}", v.law, v.message))
+        .collect()
+}
+
+#[test]
+fn test_rust_wildcards_outside_preludes_and_tests() {
+    let source = r"
+use std::io::*;
+use rayon::prelude::*;
+use std::{collections::*, fmt};
+#[cfg(test)]
+mod tests {
+    use super::*;
+}
+";
+    assert_eq!(
+        imports("src/lib.rs", source, &ImportsConfig::default()),
+        vec![
+            "I03 Wildcard import `use std::io::*`",
+            "I03 Wildcard import `use collections::*`",
+        ]
+    );
+    assert!(imports("tests/io_test.rs", source, &ImportsConfig::default()).is_empty());
+}
+
+#[test]
+fn test_wildcard_allow_is_configurable() {
+    let config = ImportsConfig {
+        wildcard_allow: vec!["prelude".into(), "io".into()],
+        ..ImportsConfig::default()
+    };
+    assert!(imports("src/lib.rs", "use std::io::*;\n", &config).is_empty());
+}
+
+#[test]
+fn test_typescript_relative_depth() {
+    let source = r#"
+import { a } from "../../shared/a";
+import { b } from "../../../../core/b";
+import c from './c';
+export * from "./../../../index";
+"#;
+    assert_eq!(
+        imports("src/app/view.ts", source, &ImportsConfig::default()),
+        vec![
+            "I04 Relative import `../../../../core/b` climbs 4 directories (limit: 2)",
+            "I04 Relative import `./../../../index` climbs 3 directories (limit: 2)",
+        ]
+    );
+}
+
+#[test]
+fn test_python_relative_depth_and_wildcards() {
+    let source =
+        "from . import a\nfrom ...pkg import b\nfrom ....deep import c\nfrom os.path import *\n";
+    assert_eq!(
+        imports("pkg/mod.py", source, &ImportsConfig::default()),
+        vec![
+            "I04 Relative import `....deep` climbs 3 directories (limit: 2)",
+            "I03 Wildcard import `from os.path import *`",
+        ]
+    );
+    let strict = ImportsConfig {
+        max_relative_depth: 0,
+        ..ImportsConfig::default()
+    };
+    assert_eq!(imports("pkg/mod.py", source, &strict).len(), 3);
+}
+
+#[test]
+fn test_disabled() {
+    let config = ImportsConfig {
+        enabled: false,
+        ..ImportsConfig::default()
+    };
+    assert!(imports("src/lib.rs", "use std::io::*;\n", &config).is_empty());
+}
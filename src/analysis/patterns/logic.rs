@@ -9,6 +9,7 @@ mod logic_l02;
 #[path = "logic_l03.rs"]
 mod logic_l03;
 
+use crate::profile;
 use crate::types::Violation;
 use omni_ast::{semantics_for, LangSemantics, SemanticContext, SemanticLanguage};
 use std::path::Path;
@@ -29,16 +30,23 @@ pub fn detect(source: &str, root: Option<Node>, path: &Path) -> Vec<Violation> {
     };
 
     if language != SemanticLanguage::Rust {
-        return detect_shared_semantics(source, language);
+        return profile::time("logic/L02", &path.to_string_lossy(), || {
+            detect_shared_semantics(source, language)
+        });
     }
 
     let Some(root) = root else {
         return Vec::new();
     };
 
+    let file = path.to_string_lossy();
     let mut out = Vec::new();
-    logic_l02::detect_l02(source, root, &mut out);
-    logic_l03::detect_l03(source, root, &mut out);
+    profile::time("logic/L02", &file, || {
+        logic_l02::detect_l02(source, root, &mut out);
+    });
+    profile::time("logic/L03", &file, || {
+        logic_l03::detect_l03(source, root, &mut out);
+    });
     out
 }
 
@@ -3,19 +3,20 @@
 
 use crate::types::{Violation, ViolationDetails};
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 use super::logic_helpers::{is_index_variable, is_literal};
+use crate::query_cache;
 
 pub(super) fn detect_l02(source: &str, root: Node, out: &mut Vec<Violation>) {
     let semantics = semantics_for(SemanticLanguage::Rust);
     let q = r"(binary_expression) @cmp";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let Some(cmp) = m.captures.first().map(|c| c.node) else {
             continue;
         };
@@ -5,12 +5,13 @@ use std::collections::HashSet;
 
 use crate::types::{Confidence, Violation, ViolationDetails};
 use omni_ast::{semantics_for, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 use super::logic_helpers::{
     can_find_local_declaration, has_chunks_exact_context, has_explicit_guard,
 };
 use super::logic_proof::{extract_receiver, is_fixed_size_array_access};
+use crate::query_cache;
 
 #[cfg(test)]
 #[path = "logic_l03_test.rs"]
@@ -30,12 +31,12 @@ fn detect_index_zero(
 ) {
     let semantics = semantics_for(SemanticLanguage::Rust);
     let q = r"(index_expression) @idx";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let Some(idx_node) = m.captures.first().map(|c| c.node) else {
             continue;
         };
@@ -139,12 +140,12 @@ fn classify_l03_confidence(
 fn detect_first_last_unwrap(source: &str, root: Node, out: &mut Vec<Violation>) {
     let semantics = semantics_for(SemanticLanguage::Rust);
     let q = r"(call_expression) @call";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let Some(call) = m.captures.first().map(|c| c.node) else {
             continue;
         };
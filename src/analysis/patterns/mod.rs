@@ -1,10 +1,12 @@
 //! AST pattern detection for violations.
 
+pub mod asynchrony;
 pub mod concurrency;
 pub mod concurrency_lock;
 pub mod concurrency_sync;
 pub mod db_patterns;
 pub mod idiomatic;
+pub mod imports;
 pub mod logic;
 pub mod logic_helpers;
 pub mod logic_proof;
@@ -16,10 +18,10 @@ pub mod semantic;
 pub mod state;
 
 use crate::lang::Lang;
+use crate::profile;
 use crate::types::Violation;
 use omni_ast::SemanticLanguage;
 use std::path::Path;
-use tree_sitter::Parser;
 
 /// Runs all pattern detections on a file.
 #[must_use]
@@ -28,27 +30,36 @@ pub fn detect_all(path: &Path, source: &str) -> Vec<Violation> {
         return Vec::new();
     };
 
+    let file = path.to_string_lossy();
     if language != SemanticLanguage::Rust {
         let mut out = Vec::new();
-        out.extend(performance::detect(source, None, path));
+        out.extend(profile::time("performance", &file, || {
+            performance::detect(source, None, path)
+        }));
         out.extend(logic::detect(source, None, path));
         return out;
     }
 
-    let Some(tree) = parse_source(source, Lang::Rust) else {
+    let Some(tree) = Lang::Rust.parse(source) else {
         return Vec::new();
     };
     let root = tree.root_node();
 
     let mut out = Vec::new();
-    out.extend(state::detect(source, root));
-    out.extend(concurrency::detect(source, root));
-    out.extend(performance::detect(source, Some(root), path));
-    out.extend(db_patterns::detect(source, root));
-    out.extend(security::detect(source, root));
-    out.extend(semantic::detect(source, root));
-    out.extend(resource::detect(source, root));
-    out.extend(idiomatic::detect(source, root));
+    let mut run = |rule: &'static str, detect: &dyn Fn() -> Vec<Violation>| {
+        out.extend(profile::time(rule, &file, detect));
+    };
+    run("state", &|| state::detect(source, root));
+    run("concurrency", &|| concurrency::detect(source, root));
+    run("asynchrony", &|| asynchrony::detect(source, root));
+    run("performance", &|| {
+        performance::detect(source, Some(root), path)
+    });
+    run("db_patterns", &|| db_patterns::detect(source, root));
+    run("security", &|| security::detect(source, root));
+    run("semantic", &|| semantic::detect(source, root));
+    run("resource", &|| resource::detect(source, root));
+    run("idiomatic", &|| idiomatic::detect(source, root));
     out.extend(logic::detect(source, Some(root), path));
     out
 }
@@ -59,12 +70,6 @@ fn semantic_language(path: &Path) -> Option<SemanticLanguage> {
         .and_then(SemanticLanguage::from_ext)
 }
 
-fn parse_source(source: &str, lang: Lang) -> Option<tree_sitter::Tree> {
-    let mut parser = Parser::new();
-    parser.set_language(&lang.grammar()).ok()?;
-    parser.parse(source, None)
-}
-
 /// Helper to get a node from a capture by index.
 #[must_use]
 pub fn get_capture_node<'a>(
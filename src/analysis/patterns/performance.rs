@@ -19,6 +19,7 @@ use tree_sitter::Node;
 
 use crate::types::Violation;
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
+use crate::query_cache;
 
 #[must_use]
 pub fn detect(source: &str, root: Option<Node>, path: &Path) -> Vec<Violation> {
@@ -45,7 +46,7 @@ pub fn detect(source: &str, root: Option<Node>, path: &Path) -> Vec<Violation> {
 }
 
 fn should_skip(path: &Path) -> bool {
-    let s = path.to_string_lossy();
+    let s = crate::normalize::path_str(path);
     s.contains("/cli/")
         || s.contains("/ui/")
         || s.contains("/tui/")
@@ -82,14 +83,14 @@ fn detect_shared_semantics(source: &str, path: &Path, language: SemanticLanguage
 fn detect_loops(source: &str, root: Node, out: &mut Vec<Violation>) {
     use super::get_capture_node;
     use super::performance_test_ctx::is_test_context;
-    use tree_sitter::{Query, QueryCursor};
+    use tree_sitter::QueryCursor;
 
     let q = r"
         (for_expression pattern: _ @pat body: (block) @body) @loop
         (while_expression body: (block) @body) @loop
         (loop_expression body: (block) @body) @loop
     ";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let language = SemanticLanguage::Rust;
@@ -97,7 +98,7 @@ fn detect_loops(source: &str, root: Node, out: &mut Vec<Violation>) {
     let idx_body = query.capture_index_for_name("body");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let loop_var = extract_loop_var(source, &m, idx_pat);
 
         let Some(body) = get_capture_node(&m, idx_body) else {
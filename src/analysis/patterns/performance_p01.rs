@@ -2,9 +2,10 @@
 //! P01: `.clone()` inside a loop.
 
 use super::super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[cfg(test)]
 #[path = "performance_p01_test.rs"]
@@ -20,14 +21,14 @@ pub(super) fn check_p01(
     let q = r#"(call_expression function: (field_expression
         value: (_) @recv field: (field_identifier) @m)
         (#eq? @m "clone")) @call"#;
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_call = query.capture_index_for_name("call");
     let idx_recv = query.capture_index_for_name("recv");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, body, source.as_bytes()) {
+    for m in cursor.matches(query, body, source.as_bytes()) {
         let Some(call) = get_capture_node(&m, idx_call) else {
             continue;
         };
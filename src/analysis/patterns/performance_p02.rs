@@ -2,8 +2,9 @@
 //! P02: String conversion (`.to_string()` / `.to_owned()`) inside a loop.
 
 use super::super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 pub(super) fn check_p02(
     source: &str,
@@ -14,14 +15,14 @@ pub(super) fn check_p02(
     let q = r#"(call_expression function: (field_expression
         value: (_) @recv field: (field_identifier) @m)
         (#match? @m "^(to_string|to_owned)$")) @call"#;
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_call = query.capture_index_for_name("call");
     let idx_recv = query.capture_index_for_name("recv");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, body, source.as_bytes()) {
+    for m in cursor.matches(query, body, source.as_bytes()) {
         let call = get_capture_node(&m, idx_call);
         let recv = get_capture_node(&m, idx_recv).and_then(|c| c.utf8_text(source.as_bytes()).ok());
 
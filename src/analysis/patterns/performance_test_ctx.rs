@@ -14,10 +14,10 @@ pub fn is_test_context(source: &str, node: Node, language: SemanticLanguage) ->
         let Some(p) = cur.parent() else { break };
 
         match p.kind() {
-            "function_item" | "mod_item" => {
-                if semantics.is_test_context(&context_for_node(source, p)) {
-                    return true;
-                }
+            "function_item" | "mod_item"
+                if semantics.is_test_context(&context_for_node(source, p)) =>
+            {
+                return true;
             }
             "source_file" => break,
             _ => {}
@@ -2,8 +2,9 @@
 //! Resource patterns: R07 (missing flush)
 
 use super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[must_use]
 pub fn detect(source: &str, root: Node) -> Vec<Violation> {
@@ -18,13 +19,13 @@ fn detect_r07(source: &str, root: Node, out: &mut Vec<Violation>) {
         function: (scoped_identifier path: (identifier) @type name: (identifier) @method)
         (#eq? @type "BufWriter") (#eq? @method "new")) @call"#;
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_call = query.capture_index_for_name("call");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let Some(call) = get_capture_node(&m, idx_call) else {
             continue;
         };
@@ -2,18 +2,19 @@
 //! X01: SQL Injection — format!() used to build SQL strings.
 
 use super::super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 pub(super) fn detect_x01_sql(source: &str, root: Node, out: &mut Vec<Violation>) {
     let q = r#"(macro_invocation macro: (identifier) @mac (token_tree) @args (#eq? @mac "format")) @fmt"#;
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_args = query.capture_index_for_name("args");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         if let Some(arg_node) = get_capture_node(&m, idx_args) {
             let args = arg_node.utf8_text(source.as_bytes()).unwrap_or("");
             if is_suspicious_sql(args) {
@@ -6,8 +6,9 @@
 //! **Safe / no flag** — const, config context, or allowlist match.
 
 use super::super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[cfg(test)]
 #[path = "security_x02_test.rs"]
@@ -19,7 +20,7 @@ pub(super) fn detect_x02_command(source: &str, root: Node, out: &mut Vec<Violati
         arguments: (arguments (identifier) @arg)
         (#eq? @method "new")) @call"#;
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_call = query.capture_index_for_name("call");
@@ -27,7 +28,7 @@ pub(super) fn detect_x02_command(source: &str, root: Node, out: &mut Vec<Violati
     let idx_arg = query.capture_index_for_name("arg");
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let call = get_capture_node(&m, idx_call);
         let path = get_capture_node(&m, idx_path);
         let arg = get_capture_node(&m, idx_arg);
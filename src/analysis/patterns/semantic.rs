@@ -2,9 +2,10 @@
 //! Semantic patterns: M03, M04, M05
 
 use super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Violation, ViolationDetails};
 use omni_ast::{semantics_for, Concept, LangSemantics, SemanticContext, SemanticLanguage};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 #[must_use]
 pub fn detect(source: &str, root: Node) -> Vec<Violation> {
@@ -23,7 +24,7 @@ fn detect_m03(source: &str, root: Node, out: &mut Vec<Violation>) {
         parameters: (parameters (self_parameter) @self)
         (#match? @name "^(get_|is_|has_)")) @fn"#;
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_fn = query.capture_index_for_name("fn");
@@ -32,7 +33,7 @@ fn detect_m03(source: &str, root: Node, out: &mut Vec<Violation>) {
 
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let fn_cap = get_capture_node(&m, idx_fn);
         let name_cap = get_capture_node(&m, idx_name);
         let self_cap = get_capture_node(&m, idx_self);
@@ -66,7 +67,7 @@ fn detect_m04(source: &str, root: Node, out: &mut Vec<Violation>) {
         return_type: (_) @ret
         (#match? @name "^(is_|has_|can_|should_)")) @fn"#;
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_fn = query.capture_index_for_name("fn");
@@ -75,7 +76,7 @@ fn detect_m04(source: &str, root: Node, out: &mut Vec<Violation>) {
 
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         check_m04_match(source, &m, idx_fn, idx_name, idx_ret, out);
     }
 }
@@ -123,7 +124,7 @@ fn detect_m05(source: &str, root: Node, out: &mut Vec<Violation>) {
         parameters: (parameters (self_parameter) @self)
         (#match? @name "^(calculate_|compute_|count_|sum_)")) @fn"#;
 
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), q) else {
+    let Some(query) = query_cache::rust(q) else {
         return;
     };
     let idx_fn = query.capture_index_for_name("fn");
@@ -132,7 +133,7 @@ fn detect_m05(source: &str, root: Node, out: &mut Vec<Violation>) {
 
     let mut cursor = QueryCursor::new();
 
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let fn_cap = get_capture_node(&m, idx_fn);
         let name_cap = get_capture_node(&m, idx_name);
         let self_cap = get_capture_node(&m, idx_self);
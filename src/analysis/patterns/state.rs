@@ -1,8 +1,9 @@
 //! State pattern detection: S01, S02, S03
 
 use super::get_capture_node;
+use crate::query_cache;
 use crate::types::{Confidence, Violation, ViolationDetails};
-use tree_sitter::{Node, Query, QueryCursor};
+use tree_sitter::{Node, QueryCursor};
 
 /// Detects state-related violations in Rust code.
 #[must_use]
@@ -17,13 +18,13 @@ pub fn detect(source: &str, root: Node) -> Vec<Violation> {
 /// S01: Global mutable declaration - `static mut`
 fn detect_s01(source: &str, root: Node, out: &mut Vec<Violation>) {
     let query_str = r"(static_item (mutable_specifier) @mut) @item";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+    let Some(query) = query_cache::rust(query_str) else {
         return;
     };
     let idx_mut = query.capture_index_for_name("mut");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         if let Some(cap) = get_capture_node(&m, idx_mut) {
             let row = cap.start_position().row;
             let text = extract_first_line(source, cap);
@@ -55,7 +56,7 @@ fn build_s01_violation(row: usize, text: &str) -> Violation {
 /// S02: Exported mutable - `pub static` (non-const)
 fn detect_s02(source: &str, root: Node, out: &mut Vec<Violation>) {
     let query_str = r"(static_item (visibility_modifier) @vis name: (identifier) @name) @item";
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+    let Some(query) = query_cache::rust(query_str) else {
         return;
     };
     let idx_vis = query.capture_index_for_name("vis");
@@ -63,7 +64,7 @@ fn detect_s02(source: &str, root: Node, out: &mut Vec<Violation>) {
     let idx_item = query.capture_index_for_name("item");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         let vis = get_capture_node(&m, idx_vis);
         let name = get_capture_node(&m, idx_name);
         let item = get_capture_node(&m, idx_item);
@@ -118,13 +119,13 @@ fn build_s02_violation(row: usize, name: &str) -> Violation {
 fn detect_s03(source: &str, root: Node, out: &mut Vec<Violation>) {
     let query_str =
         r#"(macro_invocation macro: (identifier) @mac (#match? @mac "^lazy_static$")) @item"#;
-    let Ok(query) = Query::new(&tree_sitter_rust::LANGUAGE.into(), query_str) else {
+    let Some(query) = query_cache::rust(query_str) else {
         return;
     };
     let idx_item = query.capture_index_for_name("item");
 
     let mut cursor = QueryCursor::new();
-    for m in cursor.matches(&query, root, source.as_bytes()) {
+    for m in cursor.matches(query, root, source.as_bytes()) {
         if let Some(item) = get_capture_node(&m, idx_item) {
             if let Some(v) = check_s03_container(source, item) {
                 out.push(v);
@@ -1,5 +1,5 @@
-use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 /// Represents a cohesion and coupling scope (Class, Struct+Impl, Enum).
 #[derive(Debug, Clone, Serialize)]
@@ -7,8 +7,11 @@ pub struct Scope {
     name: String,
     row: usize,
     is_enum: bool,
+    #[serde(serialize_with = "sorted_map")]
     fields: HashMap<String, FieldInfo>,
+    #[serde(serialize_with = "sorted_map")]
     methods: HashMap<String, Method>,
+    #[serde(serialize_with = "sorted_set")]
     derives: HashSet<String>,
 }
 
@@ -28,15 +31,31 @@ impl FieldInfo {
     }
 }
 
+/// Serializes a hash map in key order so JSON reports are reproducible.
+pub(crate) fn sorted_map<V: Serialize, S: Serializer>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Serializes a hash set in sorted order; see [`sorted_map`].
+fn sorted_set<S: Serializer>(set: &HashSet<String>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(set.iter().collect::<BTreeSet<_>>())
+}
+
 /// Represents a method within a scope.
 #[derive(Debug, Clone, Serialize)]
 pub struct Method {
     pub name: String,
     /// Fields accessed by this method
+    #[serde(serialize_with = "sorted_set")]
     pub field_access: HashSet<String>,
     /// Other methods in the same scope called by this method (Cohesion)
+    #[serde(serialize_with = "sorted_set")]
     pub internal_calls: HashSet<String>,
     /// Calls to things outside this scope (Coupling/SFOUT)
+    #[serde(serialize_with = "sorted_set")]
     pub external_calls: HashSet<String>,
     /// Human-understandability score
     pub cognitive_complexity: usize,
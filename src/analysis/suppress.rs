@@ -0,0 +1,180 @@
+// src/analysis/suppress.rs
+//! Region suppression with `neti:off` / `neti:on` comments.
+//!
+//! A comment that starts with `neti:off` silences every rule until the next
+//! `neti:on` (or the end of the file); `neti:off(P01, X02)` silences only
+//! the listed rules. Anything after the directive is free text, so the
+//! reason can sit on the same line. Whole-file findings such as the file
+//! size are not affected: a region cannot hide how big the file is.
+
+use serde::Serialize;
+
+use crate::types::Violation;
+
+/// Findings about the file as a whole, which regions never suppress.
+const WHOLE_FILE: [&str; 2] = ["LAW OF ATOMICITY", "LAW OF INTEGRITY"];
+
+/// Comment openers a directive may follow.
+const COMMENT_STARTS: [&str; 5] = ["//", "#", "/*", "<!--", "--"];
+
+/// One `neti:off` region, by 1-based line numbers, inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub start: usize,
+    pub end: usize,
+    /// Rules silenced in the region; empty means all of them.
+    pub rules: Vec<String>,
+}
+
+impl Region {
+    fn covers(&self, v: &Violation) -> bool {
+        (self.start..=self.end).contains(&v.row)
+            && (self.rules.is_empty() || self.rules.iter().any(|r| r.eq_ignore_ascii_case(v.law)))
+    }
+}
+
+/// The suppressed regions of one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Suppressions {
+    #[serde(skip)]
+    regions: Vec<Region>,
+    /// Lines inside at least one region.
+    #[serde(rename = "suppressed_lines")]
+    lines: usize,
+}
+
+enum Directive {
+    Off(Vec<String>),
+    On,
+}
+
+impl Suppressions {
+    /// Finds the regions in `source`.
+    #[must_use]
+    pub fn parse(source: &str) -> Self {
+        let mut regions = Vec::new();
+        let mut open: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut last = 0;
+        for (i, line) in source.lines().enumerate() {
+            last = i + 1;
+            match directive(line) {
+                Some(Directive::Off(rules)) => open.push((last, rules)),
+                Some(Directive::On) => {
+                    regions.extend(open.drain(..).map(|(start, rules)| Region {
+                        start,
+                        end: last,
+                        rules,
+                    }))
+                }
+                None => {}
+            }
+        }
+        regions.extend(open.into_iter().map(|(start, rules)| Region {
+            start,
+            end: last,
+            rules,
+        }));
+        Self::from_regions(regions)
+    }
+
+    fn from_regions(regions: Vec<Region>) -> Self {
+        let mut spans: Vec<(usize, usize)> = regions.iter().map(|r| (r.start, r.end)).collect();
+        spans.sort_unstable();
+        let (mut lines, mut covered_to) = (0, 0);
+        for (start, end) in spans {
+            let from = start.max(covered_to + 1);
+            if end >= from {
+                lines += end - from + 1;
+                covered_to = end;
+            }
+        }
+        Self { regions, lines }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    #[must_use]
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// Lines inside at least one region.
+    #[must_use]
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    /// True if `v` falls in a region that silences its rule.
+    #[must_use]
+    pub fn covers(&self, v: &Violation) -> bool {
+        !WHOLE_FILE.contains(&v.law) && self.regions.iter().any(|r| r.covers(v))
+    }
+
+    /// Drops the violations this file's regions silence.
+    pub fn retain_visible(&self, violations: &mut Vec<Violation>) {
+        if !self.is_empty() {
+            violations.retain(|v| !self.covers(v));
+        }
+    }
+
+    /// A warning when regions cover more than `max_percent` of the file's
+    /// `total_lines`.
+    #[must_use]
+    pub fn overuse(&self, total_lines: usize, max_percent: usize) -> Option<Violation> {
+        if total_lines == 0 || self.lines * 100 <= total_lines * max_percent {
+            return None;
+        }
+        let mut v = Violation::simple(
+            self.regions.first().map_or(1, |r| r.start),
+            format!(
+                "neti:off regions cover {} of {total_lines} lines ({}%, limit {max_percent}%)",
+                self.lines,
+                self.lines * 100 / total_lines
+            ),
+            "D03",
+        );
+        v.confidence = crate::types::Confidence::Medium;
+        v.confidence_reason = Some("generated code may legitimately need it".into());
+        Some(v)
+    }
+}
+
+fn directive(line: &str) -> Option<Directive> {
+    let line = line.trim_start();
+    let text = COMMENT_STARTS
+        .iter()
+        .find_map(|opener| line.strip_prefix(opener))?
+        .trim_start_matches(['/', '!', '*', '#', '-'])
+        .trim_start();
+    if let Some(rest) = text.strip_prefix("neti:off") {
+        return ends_word(rest).then(|| Directive::Off(rule_list(rest)));
+    }
+    let rest = text.strip_prefix("neti:on")?;
+    ends_word(rest).then_some(Directive::On)
+}
+
+/// True if the directive name is not the prefix of a longer word.
+fn ends_word(rest: &str) -> bool {
+    rest.chars()
+        .next()
+        .is_none_or(|c| c == '(' || !c.is_alphanumeric() && c != '_' && c != ':')
+}
+
+fn rule_list(rest: &str) -> Vec<String> {
+    let Some(list) = rest.strip_prefix('(').and_then(|r| r.split_once(')')) else {
+        return Vec::new();
+    };
+    list.0
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "suppress_test.rs"]
+mod tests;
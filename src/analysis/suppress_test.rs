@@ -0,0 +1,68 @@
+use super::*;
+
+fn violation(row: usize, law: &'static str) -> Violation {
+    Violation::simple(row, String::new(), law)
+}
+
+#[test]
+fn regions_run_from_off_to_on() {
+    let source = "a\n// neti:off generated below\nb\nc\n// neti:on\nd\n";
+    let s = Suppressions::parse(source);
+    assert_eq!(
+        s.regions(),
+        [Region {
+            start: 2,
+            end: 5,
+            rules: Vec::new()
+        }]
+    );
+    assert_eq!(s.lines(), 4);
+    assert!(s.covers(&violation(3, "P01")));
+    assert!(!s.covers(&violation(6, "P01")));
+    assert!(!s.covers(&violation(1, "P01")));
+}
+
+#[test]
+fn rule_lists_limit_what_is_silenced() {
+    let source = "# neti:off(P01, x02)\nx = 1\n";
+    let s = Suppressions::parse(source);
+    assert!(s.covers(&violation(2, "P01")));
+    assert!(s.covers(&violation(2, "X02")));
+    assert!(!s.covers(&violation(2, "L03")));
+}
+
+#[test]
+fn unclosed_regions_reach_the_end_and_overlaps_count_once() {
+    let source = "/* neti:off(P01) */\n// neti:off\nb\nc\n";
+    let s = Suppressions::parse(source);
+    assert_eq!(s.regions().len(), 2);
+    assert!(s.regions().iter().all(|r| r.end == 4));
+    assert_eq!(s.lines(), 4);
+}
+
+#[test]
+fn whole_file_findings_are_never_suppressed() {
+    let s = Suppressions::parse("// neti:off\nbig\n");
+    assert!(!s.covers(&violation(1, "LAW OF ATOMICITY")));
+    let mut found = vec![violation(1, "LAW OF ATOMICITY"), violation(2, "P02")];
+    s.retain_visible(&mut found);
+    assert_eq!(found.len(), 1);
+}
+
+#[test]
+fn only_comment_directives_count() {
+    let source = "let s = \"neti:off\";\n// neti:offline cache\n// see neti:on\nx\n";
+    assert!(Suppressions::parse(source).is_empty());
+}
+
+#[test]
+fn heavy_suppression_is_reported() {
+    let source = "// neti:off\na\nb\n// neti:on\nc\n";
+    let s = Suppressions::parse(source);
+    assert!(s.overuse(5, 80).is_none());
+    let warning = s.overuse(5, 50);
+    assert_eq!(
+        warning.map(|v| v.message),
+        Some("neti:off regions cover 4 of 5 lines (80%, limit 50%)".into())
+    );
+}
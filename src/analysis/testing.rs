@@ -0,0 +1,209 @@
+// src/analysis/testing.rs
+//! T01: complex modules without tests.
+//!
+//! A module's tests are found by convention: an inline `#[cfg(test)]`
+//! block, a sibling file (`foo_test.rs`, `foo.test.ts`, `foo.spec.ts`,
+//! `test_foo.py`, `tests.rs` beside a `mod.rs`), or a file under a `tests`
+//! directory that mentions the module by name. The ratio of test lines to
+//! implementation lines is a rough signal, so findings are never errors.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, TestingConfig};
+use crate::file_class;
+use crate::types::{Confidence, FileReport, Violation, ViolationDetails};
+
+#[cfg(test)]
+#[path = "testing_test.rs"]
+mod tests;
+
+/// Rule code for test-coverage findings.
+pub const LAW: &str = "T01";
+
+const INLINE_MARKER: &str = "#[cfg(test)]";
+
+/// File stems that name their directory rather than themselves.
+const DIR_STEMS: [&str; 5] = ["mod", "lib", "main", "index", "__init__"];
+
+/// A test file: its path and content.
+struct TestFile {
+    path: PathBuf,
+    text: String,
+}
+
+/// T01 findings, keyed by file.
+#[must_use]
+pub fn check(config: &Config, results: &[FileReport]) -> HashMap<PathBuf, Vec<Violation>> {
+    let tests: Vec<TestFile> = results
+        .iter()
+        .filter(|r| is_test_path(&r.path))
+        .filter_map(|r| read_test(&r.path))
+        .collect();
+    let test_paths: HashSet<&Path> = tests.iter().map(|t| t.path.as_path()).collect();
+
+    let mut found = HashMap::new();
+    for report in results.iter().filter(|r| !is_test_path(&r.path)) {
+        let rules = &config.rules_for(&report.path).testing;
+        if let Some(v) = check_module(report, rules, &tests, &test_paths) {
+            found.insert(report.path.clone(), vec![v]);
+        }
+    }
+    found
+}
+
+fn check_module(
+    report: &FileReport,
+    rules: &TestingConfig,
+    tests: &[TestFile],
+    test_paths: &HashSet<&Path>,
+) -> Option<Violation> {
+    let expected = rules.enabled
+        && report.complexity_score >= rules.min_complexity
+        && file_class::classify(&report.path).is_governed();
+    if !expected {
+        return None;
+    }
+    let source = crate::normalize::read_text(&report.path).ok()?;
+    let inline = inline_test_lines(&source);
+    let impl_lines = source.lines().count().saturating_sub(inline).max(1);
+    let test_lines = inline + associated_lines(&report.path, tests, test_paths);
+    if test_lines == 0 {
+        return Some(untested(report.complexity_score, rules));
+    }
+    let ratio = test_lines as f64 / impl_lines as f64;
+    (ratio < rules.min_test_ratio).then(|| thin(ratio, rules))
+}
+
+/// Whether `path` holds tests rather than implementation.
+#[must_use]
+pub fn is_test_path(path: &Path) -> bool {
+    let in_test_dir = path
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .any(|c| matches!(c.as_os_str().to_str(), Some("tests" | "test" | "__tests__")));
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    in_test_dir
+        || name == "tests.rs"
+        || name.contains("_test.")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+        || (name.starts_with("test_") && name.ends_with(".py"))
+}
+
+fn read_test(path: &Path) -> Option<TestFile> {
+    let text = crate::normalize::read_text(path).ok()?;
+    Some(TestFile {
+        path: normalize(path),
+        text,
+    })
+}
+
+/// Lines from the first `#[cfg(test)]` to the end of the file.
+fn inline_test_lines(source: &str) -> usize {
+    source
+        .lines()
+        .position(|l| l.trim_start().starts_with(INLINE_MARKER))
+        .map_or(0, |start| source.lines().count() - start)
+}
+
+fn associated_lines(path: &Path, tests: &[TestFile], test_paths: &HashSet<&Path>) -> usize {
+    let path = normalize(path);
+    let siblings: HashSet<PathBuf> = sibling_candidates(&path)
+        .into_iter()
+        .filter(|p| test_paths.contains(p.as_path()))
+        .collect();
+    let name = module_name(&path);
+    tests
+        .iter()
+        .filter(|t| {
+            siblings.contains(&t.path)
+                || (is_suite(&t.path) && name.is_some_and(|n| mentions(&t.text, n)))
+        })
+        .map(|t| t.text.lines().count())
+        .sum()
+}
+
+fn sibling_candidates(path: &Path) -> Vec<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    let mut names = vec![
+        format!("{stem}_test.{ext}"),
+        format!("{stem}.test.{ext}"),
+        format!("{stem}.spec.{ext}"),
+        format!("test_{stem}.{ext}"),
+    ];
+    if DIR_STEMS.contains(&stem) {
+        names.push(format!("tests.{ext}"));
+    }
+    names.into_iter().map(|n| dir.join(n)).collect()
+}
+
+/// Test files under a `tests` directory exercise modules by name.
+fn is_suite(path: &Path) -> bool {
+    path.components()
+        .any(|c| matches!(c.as_os_str().to_str(), Some("tests" | "test" | "__tests__")))
+}
+
+/// The name other code uses for this module: its stem, or its directory
+/// for `mod.rs`, `index.ts` and the like.
+fn module_name(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    if DIR_STEMS.contains(&stem) {
+        return path.parent()?.file_name()?.to_str();
+    }
+    Some(stem)
+}
+
+fn mentions(text: &str, name: &str) -> bool {
+    text.match_indices(name).any(|(i, _)| {
+        let before = text.get(..i).and_then(|s| s.chars().next_back());
+        let after = text.get(i + name.len()..).and_then(|s| s.chars().next());
+        !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char)
+    })
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+fn untested(score: usize, rules: &TestingConfig) -> Violation {
+    let mut v = Violation::with_details(
+        1,
+        format!(
+            "Module has cognitive complexity {score} but no associated tests (threshold: {})",
+            rules.min_complexity
+        ),
+        LAW,
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![
+                "No #[cfg(test)] block, sibling test file, or test suite naming this module."
+                    .into(),
+            ],
+            suggestion: Some("Add tests for the complex paths in this module.".into()),
+        },
+    );
+    v.confidence = Confidence::Medium;
+    v.confidence_reason = Some("tests found by naming convention only".into());
+    v
+}
+
+fn thin(ratio: f64, rules: &TestingConfig) -> Violation {
+    let mut v = Violation::simple(
+        1,
+        format!(
+            "Test-to-code ratio is {ratio:.2} (Min: {:.2})",
+            rules.min_test_ratio
+        ),
+        LAW,
+    );
+    v.confidence = Confidence::Info;
+    v
+}
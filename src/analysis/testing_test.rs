@@ -0,0 +1,40 @@
+// src/analysis/testing_test.rs
+
+use super::*;
+
+#[test]
+fn test_test_paths() {
+    assert!(is_test_path(Path::new("tests/secrets_test.rs")));
+    assert!(is_test_path(Path::new(
+        "src/analysis/checks/syntax_test.rs"
+    )));
+    assert!(is_test_path(Path::new("web/src/app.spec.ts")));
+    assert!(is_test_path(Path::new("pkg/test_parser.py")));
+    assert!(!is_test_path(Path::new("src/analysis/testing.rs")));
+    assert!(!is_test_path(Path::new("src/contest.rs")));
+}
+
+#[test]
+fn test_inline_lines_start_at_marker() {
+    let src = "fn a() {}\n\n#[cfg(test)]\nmod tests {\n}\n";
+    assert_eq!(inline_test_lines(src), 3);
+    assert_eq!(inline_test_lines("fn a() {}\n"), 0);
+}
+
+#[test]
+fn test_suites_match_module_names_as_words() {
+    let suite = TestFile {
+        path: PathBuf::from("tests/secrets_test.rs"),
+        text: "use neti_core::secrets::Baseline;\n".into(),
+    };
+    let paths = HashSet::new();
+    let tests = [suite];
+    assert_eq!(
+        associated_lines(Path::new("./src/secrets/mod.rs"), &tests, &paths),
+        1
+    );
+    assert_eq!(
+        associated_lines(Path::new("src/secret.rs"), &tests, &paths),
+        0
+    );
+}
@@ -2,101 +2,178 @@
 //! Worker module for file parsing and analysis.
 //!
 //! Orchestrates the per-file analysis pipeline:
-//! 1. File classification — only source code is structurally governed
-//! 2. Token counting and LAW OF ATOMICITY check
-//! 3. Pattern detection (AST-based anti-patterns)
-//! 4. AST analysis (complexity, naming, safety)
-//! 5. Scope extraction (for deep LCOM4/CBO analysis)
+//! Files over `[discovery] max_analysis_bytes` take the streamed path in
+//! `large_file` instead: tokens, secrets and atomicity only.
+//!
+//! 1. Secrets scanning — source, config and env files
+//! 2. File classification — only source code is structurally governed
+//! 3. Token counting and LAW OF ATOMICITY check
+//! 4. Pattern detection (AST-based anti-patterns, import hygiene)
+//! 5. AST analysis (complexity, naming, safety)
+//! 6. Scope extraction (for deep LCOM4/CBO analysis)
+//! 7. Dropping findings inside `neti:off` regions
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::Path;
 
-use tree_sitter::Parser;
-
-use crate::config::Config;
+use crate::config::{Config, RuleConfig};
 use crate::file_class;
 use crate::lang::Lang;
+use crate::normalize;
+use crate::profile;
+use crate::secrets;
 use crate::tokens::Tokenizer;
-use crate::types::{FileReport, Violation};
+use crate::types::{Confidence, FileReport, Violation, ViolationDetails};
+
+use super::large_file;
 
 use super::aggregator::FileAnalysis;
 use super::ast;
 use super::patterns;
+use super::suppress::Suppressions;
 use super::visitor::AstVisitor;
 
+thread_local! {
+    /// Each worker thread's read buffer, reused for every file it scans.
+    /// It grows to the largest file read, at most `max_analysis_bytes`.
+    static READ_BUF: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
 /// Scans a single file and returns a `FileReport`.
 ///
 /// For non-source files (HTML, JSON, SVG, etc.), returns a report with only
 /// the token count populated. No structural violations are emitted for assets
 /// or config files — applying LAW OF ATOMICITY to a generated JSON bundle is
-/// a false positive, not a real governance concern.
+/// a false positive, not a real governance concern. Secrets are the
+/// exception: they are reported wherever they appear, unless `accepted`.
+#[must_use]
+pub fn scan_file(path: &Path, config: &Config, accepted: &secrets::Baseline) -> FileReport {
+    let mut report = empty_report(path);
+
+    let size = std::fs::metadata(path).map_or(0, |m| m.len());
+    if size > config.discovery.max_analysis_bytes {
+        large_file::scan(path, size, config, accepted, &mut report);
+        return report;
+    }
+
+    // CRLF checkouts must count and match exactly like LF ones, and files
+    // with invalid UTF-8 are still scanned, with a notice.
+    READ_BUF.with_borrow_mut(|buf| {
+        let Ok((source, invalid_utf8_line)) = normalize::read_source_into(path, buf) else {
+            return;
+        };
+        if let Some(line) = invalid_utf8_line {
+            report.violations.push(encoding_notice(line));
+        }
+        analyze(path, &source, config, accepted, &mut report);
+    });
+    report
+}
+
+/// Analyzes `source` as if it were the file at `path`, without touching the
+/// filesystem: no size check and no secrets baseline. This is the
+/// single-buffer path for editor and browser integrations.
 #[must_use]
-pub fn scan_file(path: &Path, config: &Config) -> FileReport {
-    let mut report = FileReport {
+pub fn scan_text(path: &Path, source: &str, config: &Config) -> FileReport {
+    let mut report = empty_report(path);
+    analyze(
+        path,
+        source,
+        config,
+        &secrets::Baseline::default(),
+        &mut report,
+    );
+    report
+}
+
+fn empty_report(path: &Path) -> FileReport {
+    FileReport {
         path: path.to_path_buf(),
         token_count: 0,
         complexity_score: 0,
         violations: Vec::new(),
         analysis: None,
-    };
+        suppressions: Default::default(),
+    }
+}
 
-    let Ok(source) = std::fs::read_to_string(path) else {
-        return report;
-    };
+/// Runs every detector, then drops what `neti:off` regions silence.
+fn analyze(
+    path: &Path,
+    source: &str,
+    config: &Config,
+    accepted: &secrets::Baseline,
+    report: &mut FileReport,
+) {
+    report.suppressions = Suppressions::parse(source);
+    detect(path, source, config, accepted, report);
+    report.suppressions.retain_visible(&mut report.violations);
+    let max_percent = config.rules_for(path).debt.max_suppressed_percent;
+    report.violations.extend(
+        report
+            .suppressions
+            .overuse(source.lines().count(), max_percent),
+    );
+}
 
-    report.token_count = Tokenizer::count(&source);
+fn detect(
+    path: &Path,
+    source: &str,
+    config: &Config,
+    accepted: &secrets::Baseline,
+    report: &mut FileReport,
+) {
+    report.token_count = Tokenizer::count_as(config.preferences.tokenizer, source);
+    let file = path.to_string_lossy();
+    report
+        .violations
+        .extend(profile::time("secrets", &file, || {
+            secrets::violations(path, source, accepted)
+        }));
 
     // Only apply structural governance to source code files.
     // Config files, assets, and data must not trigger token-limit violations.
     if !file_class::classify(path).is_governed() {
-        return report;
+        return;
     }
 
-    let effective_config = determine_effective_config(&source, config);
+    let effective_config = determine_effective_config(path, source, config);
 
-    if report.token_count > effective_config.rules.max_file_tokens
-        && !is_ignored(path, &effective_config.rules.ignore_tokens_on)
-    {
-        report.violations.push(Violation::simple(
-            1,
-            format!(
-                "File size is {} tokens (Limit: {})",
-                report.token_count, effective_config.rules.max_file_tokens
-            ),
-            "LAW OF ATOMICITY",
-        ));
-    }
+    report.violations.extend(atomicity_violation(
+        path,
+        report.token_count,
+        &effective_config.rules,
+    ));
 
     let Some(lang) = Lang::from_ext(path.extension().and_then(|s| s.to_str()).unwrap_or("")) else {
-        return report;
+        return;
     };
 
-    let mut parser = Parser::new();
-    if parser.set_language(&lang.grammar()).is_err() {
-        return report;
-    }
-
-    let Some(tree) = parser.parse(&source, None) else {
-        return report;
+    let Some(tree) = lang.parse(source) else {
+        return;
     };
 
     let root = tree.root_node();
 
+    report.violations.extend(patterns::detect_all(path, source));
     report
         .violations
-        .extend(patterns::detect_all(path, &source));
+        .extend(profile::time("imports", &file, || {
+            patterns::imports::detect(path, source, root, &effective_config.rules.imports)
+        }));
 
-    let ast_result = ast::Analyzer::new().analyze(
+    let ast_result = ast::Analyzer::with_tokenizer(config.preferences.tokenizer).analyze(
         lang,
         path.to_str().unwrap_or(""),
-        &source,
+        source,
         &effective_config.rules,
     );
     report.violations.extend(ast_result.violations);
     report.complexity_score = ast_result.max_complexity;
 
     let scopes = if lang == Lang::Rust {
-        let visitor = AstVisitor::new(&source, lang);
+        let visitor = AstVisitor::new(source, lang);
         visitor.extract_scopes(root)
     } else {
         HashMap::new()
@@ -107,26 +184,61 @@ pub fn scan_file(path: &Path, config: &Config) -> FileReport {
         scopes,
         violations: Vec::new(),
     });
+}
 
-    report
+/// LAW OF ATOMICITY: a file over its token budget.
+pub(super) fn atomicity_violation(
+    path: &Path,
+    token_count: usize,
+    rules: &RuleConfig,
+) -> Option<Violation> {
+    (token_count > rules.max_file_tokens && !is_ignored(path, &rules.ignore_tokens_on)).then(|| {
+        Violation::simple(
+            1,
+            format!(
+                "File size is {} tokens (Limit: {})",
+                token_count, rules.max_file_tokens
+            ),
+            "LAW OF ATOMICITY",
+        )
+    })
+}
+
+/// LAW OF INTEGRITY notice: the file is not valid UTF-8 and was analyzed
+/// with the invalid bytes replaced.
+fn encoding_notice(line: usize) -> Violation {
+    let mut v = Violation::with_details(
+        line,
+        "File is not valid UTF-8; invalid bytes were replaced for analysis".into(),
+        "LAW OF INTEGRITY",
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![
+                "Findings that depend on the replaced text (names, string contents) may be off."
+                    .into(),
+            ],
+            suggestion: Some("Re-encode the file as UTF-8.".into()),
+        },
+    );
+    v.confidence = Confidence::Info;
+    v
 }
 
-/// Returns an adjusted config for "systems programming" files.
+/// Returns the config for one file: the rules of its closest `neti.toml`,
+/// adjusted for "systems programming" files.
 ///
 /// Files containing `unsafe`, `no_std`, raw pointers, etc. indicate
 /// low-level code where standard structural limits are too tight.
-fn determine_effective_config(source: &str, base_config: &Config) -> Config {
-    let score = calculate_systems_score(source);
-    if score >= 3 {
-        let mut sys_cfg = base_config.clone();
-        sys_cfg.rules.max_file_tokens = 10_000;
-        sys_cfg.rules.max_cognitive_complexity = 50;
-        sys_cfg.rules.max_lcom4 = 100;
-        sys_cfg.rules.max_cbo = 100;
-        sys_cfg
-    } else {
-        base_config.clone()
+fn determine_effective_config(path: &Path, source: &str, base_config: &Config) -> Config {
+    let mut cfg = base_config.clone();
+    cfg.rules = base_config.rules_for(path).clone();
+    if calculate_systems_score(source) >= 3 {
+        cfg.rules.max_file_tokens = 10_000;
+        cfg.rules.max_cognitive_complexity = 50;
+        cfg.rules.max_lcom4 = 100;
+        cfg.rules.max_cbo = 100;
     }
+    cfg
 }
 
 fn calculate_systems_score(source: &str) -> usize {
@@ -0,0 +1,140 @@
+// src/api/compare.rs
+//! Compares the current API surface against the baseline.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use super::surface::ApiSymbol;
+use super::LAW;
+use crate::types::{Confidence, Violation, ViolationDetails};
+
+/// Returns compatibility violations keyed by file.
+///
+/// Removed symbols are provable breakage (High). A changed signature line is
+/// Medium: the text differs, but the change may still be source-compatible.
+/// Added symbols are never violations.
+#[must_use]
+pub fn compare(baseline: &[ApiSymbol], current: &[ApiSymbol]) -> HashMap<PathBuf, Vec<Violation>> {
+    // Line numbers drift with unrelated edits, so match on identity + signature.
+    let unchanged: HashSet<_> = current
+        .iter()
+        .map(|c| (c.identity(), c.signature.as_str()))
+        .collect();
+    let by_identity: HashMap<_, &ApiSymbol> = current.iter().map(|c| (c.identity(), c)).collect();
+    let mut by_file: HashMap<PathBuf, Vec<Violation>> = HashMap::new();
+
+    for old in baseline {
+        // Baselines from before owners were recorded: match on the bare name.
+        let legacy = old.owner.is_none().then(|| {
+            current
+                .iter()
+                .filter(|c| (&c.file, &c.name, &c.kind) == (&old.file, &old.name, &old.kind))
+                .collect::<Vec<_>>()
+        });
+        if let Some(candidates) = &legacy {
+            if candidates.iter().any(|c| c.signature == old.signature) {
+                continue;
+            }
+        } else if unchanged.contains(&(old.identity(), old.signature.as_str())) {
+            continue;
+        }
+        let matched = match &legacy {
+            Some(candidates) => candidates.first().copied(),
+            None => by_identity.get(&old.identity()).copied(),
+        };
+        let violation = match matched {
+            Some(new) => changed(old, new),
+            None => removed(old),
+        };
+        by_file.entry(old.file.clone()).or_default().push(violation);
+    }
+
+    by_file
+}
+
+fn removed(old: &ApiSymbol) -> Violation {
+    Violation::with_details(
+        old.line,
+        format!("Public {} `{}` was removed", old.kind, old.qualified_name()),
+        LAW,
+        ViolationDetails {
+            function_name: Some(old.name.clone()),
+            analysis: vec![format!("Baseline: {}", old.signature)],
+            suggestion: Some(
+                "Restore the symbol (or deprecate it first), or run `neti api snapshot` if the break is intended."
+                    .into(),
+            ),
+        },
+    )
+}
+
+fn changed(old: &ApiSymbol, new: &ApiSymbol) -> Violation {
+    let mut violation = Violation::with_details(
+        new.line,
+        format!("Public {} `{}` changed signature", new.kind, new.qualified_name()),
+        LAW,
+        ViolationDetails {
+            function_name: Some(new.name.clone()),
+            analysis: vec![
+                format!("Baseline: {}", old.signature),
+                format!("Current:  {}", new.signature),
+            ],
+            suggestion: Some(
+                "Keep the old signature for callers, or run `neti api snapshot` if the break is intended."
+                    .into(),
+            ),
+        },
+    );
+    violation.confidence = Confidence::Medium;
+    violation.confidence_reason =
+        Some("signature text changed; the change may still be source-compatible".into());
+    violation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, signature: &str) -> ApiSymbol {
+        ApiSymbol {
+            file: PathBuf::from("src/lib.rs"),
+            name: name.into(),
+            kind: "function".into(),
+            line: 3,
+            signature: signature.into(),
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn unchanged_and_added_symbols_are_clean() {
+        let baseline = vec![symbol("run", "pub fn run()")];
+        let mut moved = symbol("run", "pub fn run()");
+        moved.line = 40;
+        let current = vec![moved, symbol("new_fn", "pub fn new_fn()")];
+        assert!(compare(&baseline, &current).is_empty());
+    }
+
+    #[test]
+    fn removal_is_high_and_change_is_medium() {
+        let baseline = vec![
+            symbol("run", "pub fn run()"),
+            symbol("stop", "pub fn stop()"),
+        ];
+        let current = vec![symbol("run", "pub fn run(fast: bool)")];
+        let found = compare(&baseline, &current);
+        let violations = found
+            .get(&PathBuf::from("src/lib.rs"))
+            .cloned()
+            .unwrap_or_default();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations
+            .iter()
+            .any(|v| v.message.contains("`run` changed") && v.confidence == Confidence::Medium));
+        assert!(violations
+            .iter()
+            .any(|v| v.message.contains("`stop` was removed") && v.confidence == Confidence::High));
+        assert!(violations.iter().all(|v| v.law == LAW));
+    }
+}
@@ -0,0 +1,78 @@
+// src/api/mod.rs
+//! Public API stability tracking (`neti api snapshot` / `neti api check`).
+//!
+//! The exported surface is snapshotted into `.neti/api-baseline.json`. Once a
+//! baseline exists, every scan reports removed or changed exports under the
+//! LAW OF COMPATIBILITY.
+
+mod compare;
+mod surface;
+
+pub use compare::compare;
+pub use surface::{extract_surface, public_symbols, ApiSymbol};
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fsutil::atomic_write;
+use crate::types::Violation;
+
+/// Law name for API compatibility violations.
+pub const LAW: &str = "LAW OF COMPATIBILITY";
+
+/// Baseline location, relative to the project root.
+pub const BASELINE_PATH: &str = ".neti/api-baseline.json";
+
+/// Writes the current API surface as the new baseline. Returns the symbol count.
+///
+/// # Errors
+/// Returns error if the baseline cannot be written.
+pub fn snapshot(root: &Path, files: &[PathBuf]) -> Result<usize> {
+    let symbols = extract_surface(root, files);
+    let path = root.join(BASELINE_PATH);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&symbols)?;
+    atomic_write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(symbols.len())
+}
+
+/// Loads the baseline, if one has been snapshotted.
+///
+/// # Errors
+/// Returns error if the baseline exists but cannot be parsed.
+pub fn load_baseline(root: &Path) -> Result<Option<Vec<ApiSymbol>>> {
+    let path = root.join(BASELINE_PATH);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    let symbols = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid API baseline {}", path.display()))?;
+    Ok(Some(symbols))
+}
+
+/// Checks the files against the baseline. Empty when no baseline exists.
+///
+/// Only baseline symbols of the scanned `files` are compared, plus those of
+/// files that no longer exist under `root`, so scanning part of a project
+/// does not report the rest of its API as removed.
+///
+/// # Errors
+/// Returns error if the baseline exists but cannot be parsed.
+pub fn check(root: &Path, files: &[PathBuf]) -> Result<HashMap<PathBuf, Vec<Violation>>> {
+    let Some(baseline) = load_baseline(root)? else {
+        return Ok(HashMap::new());
+    };
+    let scanned: HashSet<PathBuf> = files
+        .iter()
+        .map(|f| crate::normalize::relative_to(root, f))
+        .collect();
+    let in_scope: Vec<ApiSymbol> = baseline
+        .into_iter()
+        .filter(|s| scanned.contains(&s.file) || !root.join(&s.file).exists())
+        .collect();
+    Ok(compare(&in_scope, &extract_surface(root, files)))
+}
@@ -0,0 +1,160 @@
+// src/api/surface.rs
+//! Extracts the exported API surface from source files.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::file_class;
+use crate::graph::defs::{self, DefKind, Definition};
+
+/// One exported definition in the API surface.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ApiSymbol {
+    pub file: PathBuf,
+    pub name: String,
+    pub kind: String,
+    pub line: usize,
+    pub signature: String,
+    /// The impl, trait or class the symbol belongs to. Absent in baselines
+    /// written before owners were recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+}
+
+impl ApiSymbol {
+    /// Identifies the symbol independently of its signature and position.
+    #[must_use]
+    pub fn identity(&self) -> (&Path, Option<&str>, &str, &str) {
+        (&self.file, self.owner.as_deref(), &self.name, &self.kind)
+    }
+
+    /// The name with its owner, e.g. `Parser::new`.
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        match &self.owner {
+            Some(owner) => format!("{owner}::{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Collects the exported symbols of all governed source files. Paths in
+/// the result are relative to `root`.
+#[must_use]
+pub fn extract_surface(root: &Path, files: &[PathBuf]) -> Vec<ApiSymbol> {
+    let mut symbols: Vec<ApiSymbol> = files
+        .iter()
+        .filter(|path| file_class::classify(path).is_governed())
+        .filter_map(|path| {
+            let content = crate::normalize::read_text(path).ok()?;
+            let relative = path.strip_prefix(root).unwrap_or(path);
+            Some(public_symbols(relative, &content))
+        })
+        .flatten()
+        .collect();
+
+    symbols.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    symbols
+}
+
+/// Extracts the exported symbols of a single file.
+#[must_use]
+pub fn public_symbols(path: &Path, content: &str) -> Vec<ApiSymbol> {
+    let file = normalize(path);
+    defs::extract(path, content)
+        .into_iter()
+        .filter(|def| def.kind != DefKind::Impl && is_exported(path, def))
+        .map(|def| ApiSymbol {
+            file: file.clone(),
+            kind: format!("{:?}", def.kind).to_lowercase(),
+            line: def.line,
+            signature: normalize_signature(&def.signature),
+            name: def.name,
+            owner: def.owner,
+        })
+        .collect()
+}
+
+/// Applies each language's export convention to the definition's first line.
+fn is_exported(path: &Path, def: &Definition) -> bool {
+    let sig = def.signature.trim_start();
+    match path.extension().and_then(|e| e.to_str()).unwrap_or("") {
+        // `pub(crate)` and friends don't start with "pub " and stay private.
+        "rs" => sig.starts_with("pub "),
+        "py" => !def.name.starts_with('_'),
+        "ts" | "tsx" | "js" | "jsx" => sig.starts_with("export "),
+        "swift" => sig.starts_with("public ") || sig.starts_with("open "),
+        _ => false,
+    }
+}
+
+/// Drops the body opener so formatting of the block doesn't register as a change.
+fn normalize_signature(signature: &str) -> String {
+    let sig = signature.trim();
+    // One-line bodies (`pub fn stop() {}`, `pub struct Id { .. }`) keep only the head.
+    let head = match sig.rfind(" {") {
+        Some(idx) if sig.ends_with('}') => sig.get(..idx).unwrap_or(sig),
+        _ => sig,
+    };
+    head.trim_end_matches('{')
+        .trim_end_matches(':')
+        .trim_end()
+        .to_string()
+}
+
+/// Discovery yields `./src/x.rs`; the baseline stores `src/x.rs`.
+fn normalize(path: &Path) -> PathBuf {
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rust_surface_keeps_only_pub_items() {
+        let code = "pub struct User { name: String }\n\
+                    pub(crate) fn internal() {}\n\
+                    fn helper() -> bool { true }\n\
+                    pub fn greet(name: &str) -> String {\n    name.into()\n}\n";
+        let symbols = public_symbols(Path::new("./src/lib.rs"), code);
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["User", "greet"]);
+        assert_eq!(
+            symbols.first().map(|s| s.signature.as_str()),
+            Some("pub struct User")
+        );
+
+        let greet = symbols.iter().find(|s| s.name == "greet");
+        assert_eq!(
+            greet.map(|s| s.signature.as_str()),
+            Some("pub fn greet(name: &str) -> String")
+        );
+        assert_eq!(
+            greet.map(|s| s.file.clone()),
+            Some(PathBuf::from("src/lib.rs"))
+        );
+    }
+
+    #[test]
+    fn python_surface_skips_private_names() {
+        let code = "def run():\n    pass\n\ndef _hidden():\n    pass\n";
+        let symbols = public_symbols(Path::new("app.py"), code);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols.first().map(|s| s.name.as_str()), Some("run"));
+    }
+
+    #[test]
+    fn methods_of_different_impls_stay_distinct() {
+        let code = "pub struct A;\npub struct B;\n\
+                    impl A {\n    pub fn new() -> Self { A }\n}\n\
+                    impl B {\n    pub fn new() -> Self { B }\n}\n";
+        let symbols = public_symbols(Path::new("src/lib.rs"), code);
+        let news: Vec<String> = symbols
+            .iter()
+            .filter(|s| s.name == "new")
+            .map(ApiSymbol::qualified_name)
+            .collect();
+        assert_eq!(news, ["A::new", "B::new"]);
+    }
+}
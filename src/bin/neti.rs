@@ -5,6 +5,33 @@ use neti_core::exit::NetiExit;
 
 fn main() -> NetiExit {
     let cli = Cli::parse();
+    if let Err(e) = neti_core::logging::init(cli.log_level, cli.log_file.as_deref()) {
+        eprintln!("{} {}", "Error:".red(), e);
+        return NetiExit::Error;
+    }
+    if let Some(profile) = &cli.profile {
+        std::env::set_var(neti_core::config::overrides::PROFILE_ENV, profile);
+    }
+    let mut overrides = cli.overrides.clone();
+    if cli.no_ignore {
+        overrides.push((
+            "discovery.ignore_files".to_string(),
+            toml::Value::Boolean(false),
+        ));
+    }
+    if cli.unsorted {
+        overrides.push((
+            "preferences.stable_order".to_string(),
+            toml::Value::Boolean(false),
+        ));
+    }
+    if let Some(mb) = cli.max_mem {
+        overrides.push((
+            "discovery.max_memory_mb".to_string(),
+            toml::Value::Integer(i64::try_from(mb).unwrap_or(i64::MAX)),
+        ));
+    }
+    neti_core::config::layers::set_cli_overrides(overrides);
 
     let result = if let Some(cmd) = cli.command {
         cli::dispatch::execute(cmd)
@@ -0,0 +1,129 @@
+// src/branch/commit.rs
+//! `git commit` construction honoring `[git]` signing and identity settings.
+
+use crate::config::GitConfig;
+use anyhow::{Context, Result};
+use std::process::Command;
+
+/// Builds a `git commit` invocation honoring `[git]` signing and identity settings.
+///
+/// # Errors
+/// Returns error if `signing_format` is unknown or an identity is not `Name <email>`.
+pub fn commit_command(message: &str, git: &GitConfig) -> Result<Command> {
+    let mut cmd = Command::new("git");
+
+    if let Some(format) = git.signing_format.as_deref() {
+        if !matches!(format, "openpgp" | "ssh" | "x509") {
+            anyhow::bail!("Unknown [git] signing_format '{format}'. Use openpgp, ssh, or x509.");
+        }
+        cmd.args(["-c", &format!("gpg.format={format}")]);
+    }
+
+    cmd.args(["commit", "-m", message]);
+
+    if git.sign {
+        match git.signing_key.as_deref() {
+            Some(key) => cmd.arg(format!("-S{key}")),
+            None => cmd.arg("-S"),
+        };
+    }
+
+    if let Some(author) = git.author.as_deref() {
+        parse_identity(author)?;
+        cmd.arg(format!("--author={author}"));
+    }
+
+    if let Some(committer) = git.effective_committer() {
+        let (name, email) = parse_identity(committer)?;
+        cmd.env("GIT_COMMITTER_NAME", name);
+        cmd.env("GIT_COMMITTER_EMAIL", email);
+    }
+
+    Ok(cmd)
+}
+
+/// Splits a `Name <email>` identity into its parts.
+fn parse_identity(identity: &str) -> Result<(&str, &str)> {
+    let parsed = identity
+        .trim()
+        .strip_suffix('>')
+        .and_then(|rest| rest.split_once('<'))
+        .map(|(name, email)| (name.trim(), email.trim()))
+        .filter(|(name, email)| !name.is_empty() && email.contains('@'));
+
+    parsed.with_context(|| format!("Invalid git identity '{identity}'. Expected 'Name <email>'."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args_of(cmd: &Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|a| a.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn commit_command_defaults_are_plain() {
+        let Ok(cmd) = commit_command("msg", &GitConfig::default()) else {
+            panic!("default config rejected");
+        };
+        assert_eq!(args_of(&cmd), vec!["commit", "-m", "msg"]);
+    }
+
+    #[test]
+    fn commit_command_signs_with_key_and_format() {
+        let git = GitConfig {
+            sign: true,
+            signing_key: Some("ABC123".into()),
+            signing_format: Some("ssh".into()),
+            ..GitConfig::default()
+        };
+        let Ok(cmd) = commit_command("msg", &git) else {
+            panic!("valid signing config rejected");
+        };
+        assert_eq!(
+            args_of(&cmd),
+            vec!["-c", "gpg.format=ssh", "commit", "-m", "msg", "-SABC123"]
+        );
+    }
+
+    #[test]
+    fn commit_command_sets_author_and_committer() {
+        let git = GitConfig {
+            author: Some("Neti Bot <bot@example.com>".into()),
+            ..GitConfig::default()
+        };
+        let Ok(cmd) = commit_command("msg", &git) else {
+            panic!("valid identity rejected");
+        };
+        assert!(args_of(&cmd).contains(&"--author=Neti Bot <bot@example.com>".to_string()));
+        let envs: Vec<_> = cmd
+            .get_envs()
+            .map(|(k, v)| {
+                (
+                    k.to_string_lossy().to_string(),
+                    v.map(|v| v.to_string_lossy().to_string()),
+                )
+            })
+            .collect();
+        assert!(envs.contains(&("GIT_COMMITTER_NAME".into(), Some("Neti Bot".into()))));
+        assert!(envs.contains(&("GIT_COMMITTER_EMAIL".into(), Some("bot@example.com".into()))));
+    }
+
+    #[test]
+    fn commit_command_rejects_bad_identity_and_format() {
+        let bad_author = GitConfig {
+            author: Some("no email here".into()),
+            ..GitConfig::default()
+        };
+        assert!(commit_command("msg", &bad_author).is_err());
+
+        let bad_format = GitConfig {
+            signing_format: Some("pgp".into()),
+            ..GitConfig::default()
+        };
+        assert!(commit_command("msg", &bad_format).is_err());
+    }
+}
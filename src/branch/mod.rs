@@ -0,0 +1,294 @@
+// src/branch/mod.rs
+//! Git branch workflow for AI agents.
+
+mod commit;
+mod naming;
+mod publish;
+
+pub use commit::commit_command;
+pub use naming::resolve_work_branch;
+
+use crate::config::{Config, GitConfig};
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Checks if we're in a git repository.
+pub(crate) fn in_git_repo() -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--git-dir"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Gets the current branch name.
+///
+/// # Errors
+/// Returns error if git cannot be run.
+pub fn current_branch() -> Result<String> {
+    let output = Command::new("git")
+        .args(["branch", "--show-current"])
+        .output()
+        .context("Failed to run git")?;
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Checks if a branch exists.
+pub(crate) fn branch_exists(name: &str) -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--verify", name])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Checks if there are uncommitted changes.
+fn has_uncommitted_changes() -> bool {
+    Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .map(|o| !o.stdout.is_empty())
+        .unwrap_or(false)
+}
+
+/// Counts modified files on the work branch.
+#[must_use]
+pub fn count_modified_files() -> usize {
+    let output = Command::new("git").args(["status", "--porcelain"]).output();
+
+    match output {
+        Ok(o) => String::from_utf8_lossy(&o.stdout).lines().count(),
+        Err(_) => 0,
+    }
+}
+
+/// Counts files that differ between main and the work branch.
+#[must_use]
+pub fn count_promoted_files(work: &str) -> usize {
+    let range = format!("main...{work}");
+    let output = Command::new("git")
+        .args(["diff", "--name-only", &range])
+        .output();
+
+    match output {
+        Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout).lines().count(),
+        _ => 0,
+    }
+}
+
+/// Pushes the current branch to its upstream.
+///
+/// # Errors
+/// Returns error if `git push` fails.
+pub fn push() -> Result<()> {
+    run_git(&["push"])
+}
+
+/// Creates or resets the work branch.
+///
+/// # Errors
+/// Returns error if git commands fail.
+pub fn init_branch(force: bool, work: &str) -> Result<BranchResult> {
+    if !in_git_repo() {
+        anyhow::bail!("Not a git repository. Run 'git init' first.");
+    }
+
+    let on_work_branch = current_branch()? == work;
+
+    if branch_exists(work) && !on_work_branch {
+        if force {
+            // Delete and recreate
+            run_git(&["branch", "-D", work])?;
+        } else {
+            anyhow::bail!("Branch '{work}' already exists. Use --force to reset it.");
+        }
+    }
+
+    if on_work_branch {
+        if force {
+            // Reset current branch to main
+            run_git(&["checkout", "main"])?;
+            run_git(&["branch", "-D", work])?;
+            run_git(&["checkout", "-b", work])?;
+            return Ok(BranchResult::Reset);
+        }
+        return Ok(BranchResult::AlreadyOnBranch);
+    }
+
+    // Create and switch to work branch
+    run_git(&["checkout", "-b", work])?;
+    Ok(BranchResult::Created)
+}
+
+/// Promotes the work branch: squash-merges into main, or under the
+/// `per-goal` strategy pushes it (and optionally opens a PR) for review.
+///
+/// # Errors
+/// Returns error if git commands fail or checks don't pass.
+pub fn promote(
+    dry_run: bool,
+    custom_msg: Option<String>,
+    git: &GitConfig,
+    work: &str,
+) -> Result<PromoteResult> {
+    if !in_git_repo() {
+        anyhow::bail!("Not a git repository.");
+    }
+
+    let current = current_branch()?;
+    if current != work {
+        anyhow::bail!(
+            "Not on work branch '{work}'. Currently on '{current}'. Run 'neti branch' first."
+        );
+    }
+
+    if has_uncommitted_changes() {
+        anyhow::bail!("Uncommitted changes. Commit or stash before promoting.");
+    }
+
+    if dry_run {
+        return Ok(PromoteResult::DryRun);
+    }
+
+    let msg = custom_msg.unwrap_or_else(|| format!("chore: promote {work}"));
+
+    if git.per_goal_branches() {
+        publish::publish(work, &msg, git.open_pr)?;
+        return Ok(PromoteResult::Published {
+            pr_opened: git.open_pr,
+        });
+    }
+
+    // Merge into main
+    run_git(&["checkout", "main"])?;
+
+    // Use --squash to avoid duplicate commits in history when merging feature branches
+    // This creates a single clean commit on main.
+    run_git(&["merge", "--squash", work])?;
+
+    // Commit the squashed changes
+    run_command(commit_command(&msg, git)?, "git commit")?;
+
+    // Delete the work branch
+    run_git(&["branch", "-D", work])?;
+
+    Ok(PromoteResult::Merged)
+}
+
+/// Aborts work branch and returns to main.
+///
+/// # Errors
+/// Returns error if git commands fail.
+pub fn abort(work: &str) -> Result<()> {
+    if !in_git_repo() {
+        anyhow::bail!("Not a git repository.");
+    }
+
+    let current = current_branch()?;
+
+    if current == work {
+        run_git(&["checkout", "main"])?;
+    }
+
+    if branch_exists(work) {
+        run_git(&["branch", "-D", work])?;
+    }
+
+    Ok(())
+}
+
+/// The top of the working tree, or the current directory outside a repo.
+#[must_use]
+pub fn repo_root() -> PathBuf {
+    Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| PathBuf::from(String::from_utf8_lossy(&o.stdout).trim()))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// `.neti/pending_goal` at the repository root, so every subdirectory sees
+/// the same goal.
+#[must_use]
+pub fn goal_path() -> PathBuf {
+    repo_root().join(".neti").join("pending_goal")
+}
+
+/// The goal recorded in [`goal_path`], if any.
+#[must_use]
+pub fn pending_goal() -> Option<String> {
+    crate::status::pending_goal(&repo_root())
+}
+
+/// Returns the work branch for this project's `[git] branch_strategy` and
+/// pending goal.
+#[must_use]
+pub fn work_branch_name() -> String {
+    resolve_work_branch(&Config::load().git, pending_goal().as_deref())
+}
+
+/// Checks if we're on the work branch.
+#[must_use]
+pub fn on_work_branch() -> bool {
+    current_branch().is_ok_and(|b| b == work_branch_name())
+}
+
+fn run_git(args: &[&str]) -> Result<()> {
+    let mut cmd = Command::new("git");
+    cmd.args(args);
+    run_command(cmd, &format!("git {}", args.join(" ")))
+}
+
+fn run_command(mut cmd: Command, label: &str) -> Result<()> {
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run: {label}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{label} failed: {stderr}");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum BranchResult {
+    Created,
+    Reset,
+    AlreadyOnBranch,
+}
+
+#[derive(Debug)]
+pub enum PromoteResult {
+    Merged,
+    Published { pr_opened: bool },
+    DryRun,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_branch_name() {
+        let single = GitConfig::default();
+        assert_eq!(
+            resolve_work_branch(&single, Some("Add layers")),
+            "neti-work"
+        );
+        let per_goal = GitConfig {
+            branch_strategy: "per-goal".into(),
+            ..GitConfig::default()
+        };
+        assert_eq!(
+            resolve_work_branch(&per_goal, Some("Add layers")),
+            "neti/add-layers"
+        );
+        assert_eq!(resolve_work_branch(&per_goal, None), "neti-work");
+    }
+}
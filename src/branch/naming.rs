@@ -0,0 +1,87 @@
+// src/branch/naming.rs
+//! Work branch naming for the `[git] branch_strategy` setting.
+
+use crate::config::GitConfig;
+
+/// The shared work branch used by the default `single` strategy.
+pub const WORK_BRANCH: &str = "neti-work";
+
+/// Prefix for branches created by the `per-goal` strategy.
+const GOAL_PREFIX: &str = "neti/";
+
+/// Longest slug kept from a goal, so branch names stay readable.
+const MAX_SLUG_LEN: usize = 48;
+
+/// Resolves the work branch for the configured strategy.
+///
+/// `per-goal` yields `neti/<slug-of-goal>`; without a usable goal, or under
+/// `single`, the shared `neti-work` branch is used.
+#[must_use]
+pub fn resolve_work_branch(git: &GitConfig, goal: Option<&str>) -> String {
+    if !git.per_goal_branches() {
+        return WORK_BRANCH.to_string();
+    }
+
+    let slug = goal.map(slugify).unwrap_or_default();
+    if slug.is_empty() {
+        WORK_BRANCH.to_string()
+    } else {
+        format!("{GOAL_PREFIX}{slug}")
+    }
+}
+
+/// Converts free-form goal text into a branch-safe slug.
+#[must_use]
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.truncate(MAX_SLUG_LEN);
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn per_goal() -> GitConfig {
+        GitConfig {
+            branch_strategy: "per-goal".into(),
+            ..GitConfig::default()
+        }
+    }
+
+    #[test]
+    fn slugify_collapses_punctuation() {
+        assert_eq!(slugify("  Fix: crash in Parser!! "), "fix-crash-in-parser");
+        assert_eq!(slugify("***"), "");
+    }
+
+    #[test]
+    fn slugify_truncates_long_goals() {
+        let slug = slugify(&"word ".repeat(40));
+        assert!(slug.len() <= MAX_SLUG_LEN);
+        assert!(!slug.ends_with('-'));
+    }
+
+    #[test]
+    fn single_strategy_uses_shared_branch() {
+        let name = resolve_work_branch(&GitConfig::default(), Some("add layers"));
+        assert_eq!(name, WORK_BRANCH);
+    }
+
+    #[test]
+    fn per_goal_strategy_names_branch_from_goal() {
+        assert_eq!(
+            resolve_work_branch(&per_goal(), Some("Add locality layers")),
+            "neti/add-locality-layers"
+        );
+        assert_eq!(resolve_work_branch(&per_goal(), None), WORK_BRANCH);
+    }
+}
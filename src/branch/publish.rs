@@ -0,0 +1,61 @@
+// src/branch/publish.rs
+//! Per-goal publishing: push the work branch and optionally open a pull request.
+
+use super::{run_command, run_git};
+use anyhow::Result;
+use std::process::Command;
+
+/// Pushes `branch` to `origin` and, if requested, opens a PR against main via `gh`.
+///
+/// # Errors
+/// Returns error if the push or `gh pr create` fails.
+pub fn publish(branch: &str, message: &str, open_pr: bool) -> Result<()> {
+    run_git(&["push", "-u", "origin", branch])?;
+
+    if open_pr {
+        run_command(pr_command(branch, message), "gh pr create")?;
+    }
+
+    Ok(())
+}
+
+/// Builds a `gh pr create` invocation using the commit message as title and body.
+pub(super) fn pr_command(branch: &str, message: &str) -> Command {
+    let (title, body) = message
+        .split_once("\n\n")
+        .unwrap_or((message.lines().next().unwrap_or(message), ""));
+
+    let mut cmd = Command::new("gh");
+    cmd.args(["pr", "create", "--base", "main", "--head", branch])
+        .args(["--title", title.trim(), "--body", body.trim()]);
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pr_command_splits_title_and_body() {
+        let cmd = pr_command("neti/fix-parser", "fix: parser\n\n2 files");
+        let args: Vec<String> = cmd
+            .get_args()
+            .map(|a| a.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                "pr",
+                "create",
+                "--base",
+                "main",
+                "--head",
+                "neti/fix-parser",
+                "--title",
+                "fix: parser",
+                "--body",
+                "2 files"
+            ]
+        );
+    }
+}
@@ -1,4 +1,7 @@
 // src/clean.rs
+use crate::branch;
+use crate::config::Config;
+use crate::fsutil::atomic_write;
 use anyhow::{Context, Result};
 use colored::Colorize;
 use std::fs;
@@ -58,7 +61,7 @@ fn ensure_gitignore() -> Result<bool> {
         format!("{content}\n{CONTEXT_FILE}\n")
     };
 
-    fs::write(path, new_content).context("Failed to write .gitignore")?;
+    atomic_write(path, new_content).context("Failed to write .gitignore")?;
     Ok(true)
 }
 
@@ -85,8 +88,8 @@ fn commit_changes(actions: &[&str]) -> Result<()> {
         .output()
         .context("Failed to stage .gitignore")?;
 
-    let output = Command::new("git")
-        .args(["commit", "-m", &message])
+    let config = Config::load();
+    let output = branch::commit_command(&message, &config.git)?
         .output()
         .context("Failed to commit")?;
 
@@ -0,0 +1,44 @@
+// src/cli/actions.rs
+//! Subcommands of the `neti` commands that group several actions.
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Validate the config and show each effective value with its source
+    Doctor,
+}
+
+#[derive(Subcommand)]
+pub enum ApiAction {
+    /// Record the current public API as the baseline
+    Snapshot,
+    /// Report removed or changed public symbols
+    Check {
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SecretsAction {
+    /// Accept every current secret finding by recording it in the baseline
+    Baseline,
+}
+
+#[derive(Subcommand)]
+pub enum RemoteAction {
+    /// Restore `.neti/cache/` from the remote to pre-warm incremental runs
+    Pull,
+}
+
+#[derive(Subcommand)]
+pub enum DebtAction {
+    /// List every debt comment, oldest first
+    List {
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
@@ -0,0 +1,85 @@
+// src/cli/api.rs
+//! Handlers for public API snapshots and compatibility checks.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::time::Instant;
+
+use crate::api;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::reporting;
+use crate::types::{FileReport, ScanReport};
+
+use super::handlers::get_repo_root;
+
+/// Handles `neti api snapshot`.
+///
+/// # Errors
+/// Returns error if discovery fails or the baseline cannot be written.
+pub fn handle_snapshot() -> Result<NetiExit> {
+    let files = discovery::discover(&Config::load())?;
+    let count = api::snapshot(&get_repo_root(), &files)?;
+    println!(
+        "{}",
+        format!(
+            "  Snapshotted {count} public symbols to {}",
+            api::BASELINE_PATH
+        )
+        .green()
+    );
+    Ok(NetiExit::Success)
+}
+
+/// Handles `neti api check`.
+///
+/// # Errors
+/// Returns error if discovery fails or the baseline is unreadable.
+pub fn handle_check(json: bool) -> Result<NetiExit> {
+    let start = Instant::now();
+    let root = get_repo_root();
+
+    if api::load_baseline(&root)?.is_none() {
+        eprintln!(
+            "{}",
+            "No API baseline. Run 'neti api snapshot' first.".yellow()
+        );
+        return Ok(NetiExit::Error);
+    }
+
+    let files = discovery::discover(&Config::load())?;
+    let mut reports: Vec<FileReport> = api::check(&root, &files)?
+        .into_iter()
+        .map(|(path, violations)| FileReport {
+            path,
+            token_count: 0,
+            complexity_score: 0,
+            violations,
+            analysis: None,
+            suppressions: Default::default(),
+        })
+        .collect();
+    reports.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let report = ScanReport {
+        total_violations: reports.iter().map(FileReport::violation_count).sum(),
+        total_tokens: 0,
+        files: reports,
+        duration_ms: start.elapsed().as_millis(),
+    };
+
+    if json {
+        reporting::print_json(&report)?;
+    } else if report.has_errors() {
+        reporting::print_report(&report)?;
+    } else {
+        println!("{}", "  Public API matches the baseline.".green());
+    }
+
+    Ok(if report.has_errors() {
+        NetiExit::CheckFailed
+    } else {
+        NetiExit::Success
+    })
+}
@@ -1,11 +1,15 @@
-use clap::{Parser, Subcommand};
+use clap::Subcommand;
+use std::path::PathBuf;
 
-#[derive(Parser)]
-#[command(name = "neti", version, about = "AI Code Quality Guardian")]
-pub struct Cli {
-    #[command(subcommand)]
-    pub command: Option<Commands>,
-}
+pub use super::actions::{ApiAction, ConfigAction, DebtAction, RemoteAction, SecretsAction};
+pub use super::options::Cli;
+
+use crate::codeowners::GroupBy;
+use crate::graph::export::GraphFormat;
+use crate::metrics::MetricsFormat;
+use crate::project::Strictness;
+use crate::schema::ReportKind;
+use crate::types::CategoryFilter;
 
 #[derive(Subcommand)]
 pub enum Commands {
@@ -14,6 +18,19 @@ pub enum Commands {
         /// Output results as JSON
         #[arg(long)]
         json: bool,
+        /// Print only the one-line summary
+        #[arg(long, short, conflicts_with_all = ["json", "porcelain"])]
+        quiet: bool,
+        /// Print one tab-separated line per finding: path, line, rule,
+        /// confidence, message (stable across versions)
+        #[arg(long, conflicts_with = "json")]
+        porcelain: bool,
+        /// Push the JSON report and `.neti/cache/` to the `[remote]` store
+        #[arg(long)]
+        upload: bool,
+        /// Fail only if a rule has more violations than `.neti/ratchet.json`
+        #[arg(long)]
+        ratchet: bool,
     },
 
     /// Scan for violations
@@ -23,11 +40,113 @@ pub enum Commands {
         /// Run topology/locality analysis [EXPERIMENTAL]
         #[arg(long, short)]
         locality: bool,
+        /// List dependency cycles with the edges that break them
+        #[arg(long, requires = "locality")]
+        cycles: bool,
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+        /// Print only the one-line summary
+        #[arg(
+            long,
+            short,
+            conflicts_with_all = ["json", "porcelain", "group_by", "compare"]
+        )]
+        quiet: bool,
+        /// Print one tab-separated line per finding: path, line, rule,
+        /// confidence, message (stable across versions)
+        #[arg(long, conflicts_with_all = ["json", "group_by", "compare"])]
+        porcelain: bool,
+        /// Limit results to one workspace member (name or directory)
+        #[arg(long, short)]
+        package: Option<String>,
+        /// Group results by owner from CODEOWNERS
+        #[arg(long, value_enum, value_name = "KEY")]
+        group_by: Option<GroupBy>,
+        /// With --group-by, also write neti-report-team-<team>.txt per group
+        #[arg(long, requires = "group_by")]
+        team_reports: bool,
+        /// Show only what changed since a previous `neti scan --json` report
+        #[arg(long, value_name = "OLD_JSON", conflicts_with = "group_by")]
+        compare: Option<PathBuf>,
+        /// With --compare, fail only when there are new violations
+        #[arg(long, requires = "compare")]
+        fail_on_new: bool,
+        /// Time each rule and print the slowest rules and files
+        #[arg(long)]
+        profile_rules: bool,
+        #[command(flatten)]
+        categories: CategoryFilter,
+    },
+
+    /// Export the dependency graph for rendering
+    Graph {
+        /// Output format
+        #[arg(long, short, value_enum, default_value = "dot")]
+        format: GraphFormat,
+        /// Only files under this path plus their direct neighbors; ranks are
+        /// personalized toward it
+        #[arg(long)]
+        focus: Option<PathBuf>,
+    },
+
+    /// Show project health: scan summary, branch state, recent events
+    Status {
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export scan, locality and mutation metrics for dashboards
+    Metrics {
+        /// Output format
+        #[arg(long, short, value_enum, default_value = "prometheus")]
+        format: MetricsFormat,
+    },
+
+    /// Export scan results as a spreadsheet for remediation tracking
+    Report {
+        /// Write an Excel workbook (Summary and Violations sheets) to FILE
+        #[arg(long, value_name = "FILE")]
+        xlsx: PathBuf,
+    },
+
+    /// Search the repo-wide symbol index
+    Symbols {
+        /// Fuzzy query (lists all symbols when omitted)
+        query: Option<String>,
+        /// Maximum number of results
+        #[arg(long, short, default_value = "20")]
+        limit: usize,
         /// Output results as JSON
         #[arg(long)]
         json: bool,
     },
 
+    /// Track public API stability against a baseline
+    Api {
+        #[command(subcommand)]
+        action: ApiAction,
+    },
+
+    /// Manage accepted hardcoded-secret findings
+    Secrets {
+        #[command(subcommand)]
+        action: SecretsAction,
+    },
+
+    /// Share analysis state through the `[remote]` store
+    Remote {
+        #[command(subcommand)]
+        action: RemoteAction,
+    },
+
+    /// List TODO/FIXME/HACK comments with their age
+    Debt {
+        #[command(subcommand)]
+        action: DebtAction,
+    },
+
     /// Create or reset the work branch
     Branch {
         #[arg(long, short)]
@@ -43,18 +162,50 @@ pub enum Commands {
     /// Abort work branch and return to main
     Abort,
 
+    /// Check grammars, clipboard, git, config, work branch, terminal and cache
+    Doctor {
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print the JSON Schema of a machine-readable report
+    Schema {
+        #[arg(value_enum)]
+        report: ReportKind,
+    },
+
     /// Clean up artifacts
     Clean {
         #[arg(long, short)]
         commit: bool,
     },
 
+    /// Write a starter neti.toml and .netiignore for this project
+    Init {
+        /// Accept defaults without prompting
+        #[arg(long, short)]
+        yes: bool,
+        /// Overwrite an existing neti.toml
+        #[arg(long, short)]
+        force: bool,
+        /// Rule levels to start from
+        #[arg(long, value_enum)]
+        strictness: Option<Strictness>,
+        /// Install a pre-commit hook running `neti check`
+        #[arg(long)]
+        hooks: bool,
+    },
+
     /// Interactive configuration editor
-    Config,
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+    },
 
     /// Run mutation testing to find test gaps [EXPERIMENTAL]
     Mutate {
-        /// Number of parallel workers (reserved for future use)
+        /// Number of parallel workers, each testing in its own project copy
         #[arg(long, short)]
         workers: Option<usize>,
         /// Test timeout in seconds
@@ -66,5 +217,20 @@ pub enum Commands {
         /// Filter files by path pattern
         #[arg(long, short)]
         filter: Option<String>,
+        /// Coverage report (llvm-cov, coverage.py, or istanbul JSON); uncovered mutants are not run
+        #[arg(long)]
+        coverage: Option<PathBuf>,
+        /// Omit uncovered mutants from the report entirely
+        #[arg(long, requires = "coverage")]
+        only_covered: bool,
+        /// Only mutate functions changed since this git ref; unchanged results are cached
+        #[arg(long)]
+        since: Option<String>,
+        /// Write an HTML report of surviving mutants to this file
+        #[arg(long, value_name = "FILE")]
+        html: Option<PathBuf>,
+        /// Write an SVG mutation score badge to this file
+        #[arg(long, value_name = "FILE")]
+        badge: Option<PathBuf>,
     },
 }
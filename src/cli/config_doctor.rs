@@ -0,0 +1,58 @@
+// src/cli/config_doctor.rs
+//! Handler for `neti config doctor`.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::doctor::{self, Setting, Source};
+use crate::config::layers;
+use crate::config::overrides::active_profile;
+use crate::config::validate;
+use crate::config::Config;
+use crate::exit::NetiExit;
+
+/// Prints the effective config with provenance, then any problems found.
+///
+/// # Errors
+/// Currently infallible; returns `Result` for dispatch uniformity.
+pub fn handle_doctor() -> Result<NetiExit> {
+    let root = Path::new(".");
+    let config = Config::load();
+    let profile = active_profile();
+
+    match &profile {
+        Some(name) => println!("{}", format!("Effective config (profile {name})").bold()),
+        None => println!("{}", "Effective config".bold()),
+    }
+    let settings = doctor::explain(&config, root, profile.as_deref(), &layers::current());
+    let width = settings.iter().map(|s| s.key.len()).max().unwrap_or(0);
+    for setting in &settings {
+        print_setting(setting, width);
+    }
+
+    let diagnostics = validate::diagnose(&config, root);
+    println!();
+    if diagnostics.is_empty() {
+        println!("{}", "No problems found.".green());
+        return Ok(NetiExit::Success);
+    }
+    println!(
+        "{}",
+        format!("{} problem(s):", diagnostics.len()).red().bold()
+    );
+    for diagnostic in &diagnostics {
+        println!("  {diagnostic}");
+    }
+    Ok(NetiExit::CheckFailed)
+}
+
+fn print_setting(setting: &Setting, width: usize) {
+    let line = format!("  {:width$} = {}", setting.key, setting.value);
+    let source = setting.source.to_string();
+    match setting.source {
+        Source::File { .. } => println!("{line}  {}", source.cyan()),
+        Source::Env(_) | Source::Cli => println!("{line}  {}", source.yellow()),
+        Source::Default | Source::ProjectDefault => println!("{line}  {}", source.dimmed()),
+    }
+}
@@ -3,5 +3,6 @@ pub mod editor;
 pub mod items;
 pub mod logic;
 pub mod render;
+pub mod theme;
 
 pub use editor::run_config_editor;
@@ -1,9 +1,10 @@
 use super::items::ConfigItem;
+use super::theme::Palette;
 use crate::config::Config;
 use anyhow::Result;
 use crossterm::{
     cursor, execute,
-    style::{Color, Print, ResetColor, SetForegroundColor},
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
     terminal::{Clear, ClearType},
 };
 use std::io::{stdout, Write};
@@ -14,48 +15,60 @@ use std::io::{stdout, Write};
 /// Returns error if terminal manipulation fails.
 pub fn draw(items: &[ConfigItem], selected: usize, config: &Config) -> Result<()> {
     let mut stdout = stdout();
+    let palette = Palette::from_config(&config.tui);
 
     // Ensure we start at top-left and clear screen
     execute!(stdout, Clear(ClearType::All), cursor::MoveTo(0, 0))?;
 
-    draw_header(&mut stdout)?;
+    draw_header(&mut stdout, &palette)?;
 
     for (i, item) in items.iter().enumerate() {
         let row = u16::try_from(i + 2).unwrap_or(u16::MAX);
         execute!(stdout, cursor::MoveTo(0, row))?;
-        draw_item(&mut stdout, *item, i == selected, config)?;
+        draw_item(&mut stdout, *item, i == selected, config, &palette)?;
     }
 
     let footer_row = u16::try_from(items.len() + 3).unwrap_or(u16::MAX);
     execute!(stdout, cursor::MoveTo(0, footer_row))?;
-    draw_footer(&mut stdout)?;
+    draw_footer(&mut stdout, &palette)?;
 
     stdout.flush()?;
     Ok(())
 }
 
-fn draw_header(stdout: &mut std::io::Stdout) -> Result<()> {
+fn draw_header(stdout: &mut std::io::Stdout, palette: &Palette) -> Result<()> {
+    set_color(stdout, palette.accent)?;
     execute!(
         stdout,
-        SetForegroundColor(Color::Cyan),
         Print("┌─ Neti Configuration ──────────────────"),
         ResetColor
     )?;
     Ok(())
 }
 
+fn set_color(stdout: &mut std::io::Stdout, color: Option<Color>) -> Result<()> {
+    if let Some(color) = color {
+        execute!(stdout, SetForegroundColor(color))?;
+    }
+    Ok(())
+}
+
 fn draw_item(
     stdout: &mut std::io::Stdout,
     item: ConfigItem,
     is_selected: bool,
     config: &Config,
+    palette: &Palette,
 ) -> Result<()> {
     let prefix = if is_selected { "│ >" } else { "│  " };
     let value = item.get_value(config);
     let label = item.label();
 
     if is_selected {
-        execute!(stdout, SetForegroundColor(Color::Yellow))?;
+        set_color(stdout, palette.highlight)?;
+        if palette.reverse_selection {
+            execute!(stdout, SetAttribute(Attribute::Reverse))?;
+        }
     }
 
     // Explicit \r\n to ensure cursor returns to start of line if wrapped
@@ -64,17 +77,16 @@ fn draw_item(
     write!(stdout, "{prefix} {label:<25} {value}")?;
 
     if is_selected {
-        execute!(stdout, ResetColor)?;
+        execute!(stdout, SetAttribute(Attribute::Reset), ResetColor)?;
     }
     Ok(())
 }
 
-fn draw_footer(stdout: &mut std::io::Stdout) -> Result<()> {
+fn draw_footer(stdout: &mut std::io::Stdout, palette: &Palette) -> Result<()> {
+    execute!(stdout, Print("│\r\n"), Print("│  [S]ave  [Esc] Cancel\r\n"))?;
+    set_color(stdout, palette.accent)?;
     execute!(
         stdout,
-        Print("│\r\n"),
-        Print("│  [S]ave  [Esc] Cancel\r\n"),
-        SetForegroundColor(Color::Cyan),
         Print("└───────────────────────────────────────────"),
         ResetColor
     )?;
@@ -0,0 +1,123 @@
+// src/cli/config_ui/theme.rs
+//! Resolves `[tui]` settings into the colors the editor draws with.
+
+use crate::config::TuiConfig;
+use crossterm::style::Color;
+
+/// Colors for the config editor. `None` draws in the terminal's default color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub accent: Option<Color>,
+    pub highlight: Option<Color>,
+    /// Render the selected row in reverse video instead of (only) a color.
+    pub reverse_selection: bool,
+}
+
+impl Palette {
+    /// Builds the palette for the configured theme. `NO_COLOR` always wins.
+    #[must_use]
+    pub fn from_config(tui: &TuiConfig) -> Self {
+        if std::env::var_os("NO_COLOR").is_some() {
+            return Self::plain();
+        }
+        Self::for_theme(tui)
+    }
+
+    fn for_theme(tui: &TuiConfig) -> Self {
+        match tui.theme.as_str() {
+            "light" => Self::colored(Color::DarkBlue, Color::DarkMagenta),
+            "high-contrast" => Self {
+                accent: Some(Color::White),
+                highlight: Some(Color::White),
+                reverse_selection: true,
+            },
+            "none" => Self::plain(),
+            "custom" => Self {
+                accent: parse_color(&tui.palette.accent),
+                highlight: parse_color(&tui.palette.highlight),
+                reverse_selection: false,
+            },
+            _ => Self::colored(Color::Cyan, Color::Yellow),
+        }
+    }
+
+    fn colored(accent: Color, highlight: Color) -> Self {
+        Self {
+            accent: Some(accent),
+            highlight: Some(highlight),
+            reverse_selection: false,
+        }
+    }
+
+    /// No colors; the selection stays visible through reverse video.
+    fn plain() -> Self {
+        Self {
+            accent: None,
+            highlight: None,
+            reverse_selection: true,
+        }
+    }
+}
+
+/// Parses a crossterm color name (`cyan`, `dark_blue`, ...) or `#rrggbb`.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+        };
+        if hex.len() != 6 {
+            return None;
+        }
+        return Some(Color::Rgb {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+    Color::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn theme(name: &str) -> TuiConfig {
+        TuiConfig {
+            theme: name.into(),
+            ..TuiConfig::default()
+        }
+    }
+
+    #[test]
+    fn builtin_themes() {
+        assert_eq!(Palette::for_theme(&theme("dark")).accent, Some(Color::Cyan));
+        assert_eq!(
+            Palette::for_theme(&theme("light")).accent,
+            Some(Color::DarkBlue)
+        );
+        assert!(Palette::for_theme(&theme("high-contrast")).reverse_selection);
+
+        let none = Palette::for_theme(&theme("none"));
+        assert_eq!((none.accent, none.highlight), (None, None));
+    }
+
+    #[test]
+    fn custom_palette_parses_names_and_hex() {
+        let mut tui = theme("custom");
+        tui.palette.accent = "dark_green".into();
+        tui.palette.highlight = "#ff8800".into();
+        let palette = Palette::for_theme(&tui);
+        assert_eq!(palette.accent, Some(Color::DarkGreen));
+        assert_eq!(
+            palette.highlight,
+            Some(Color::Rgb {
+                r: 0xff,
+                g: 0x88,
+                b: 0x00
+            })
+        );
+        assert_eq!(parse_color("#12"), None);
+        assert_eq!(parse_color("chartreuse"), None);
+    }
+}
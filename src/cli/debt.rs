@@ -0,0 +1,55 @@
+// src/cli/debt.rs
+//! Handler for listing intentional debt.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::debt::{self, DebtItem};
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::reporting;
+
+/// Handles `neti debt list`.
+///
+/// # Errors
+/// Returns error if discovery fails.
+pub fn handle_list(json: bool) -> Result<NetiExit> {
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let items = debt::collect_all(&config, &files, chrono::Local::now().date_naive());
+
+    if json {
+        reporting::print_json(&items)?;
+    } else if items.is_empty() {
+        println!("{}", "No debt comments.".green());
+    } else {
+        for item in &items {
+            print_item(item);
+        }
+        let overdue = items.iter().filter(|i| i.overdue).count();
+        println!(
+            "\n  {} debt comments, {} overdue",
+            items.len(),
+            overdue.to_string().yellow()
+        );
+    }
+    Ok(NetiExit::Success)
+}
+
+fn print_item(item: &DebtItem) {
+    let age = item
+        .age_days
+        .map_or_else(|| "new".to_string(), |d| format!("{d}d"));
+    let age = if item.overdue {
+        format!("{age:>6}").yellow()
+    } else {
+        format!("{age:>6}").dimmed()
+    };
+    println!(
+        "{age} {:<5} {} {}",
+        item.marker.bold(),
+        format!("{}:{}", item.file.display(), item.line).cyan(),
+        item.text
+    );
+}
@@ -1,11 +1,14 @@
 //! Command dispatch logic extracted from binary to reduce main function size.
 
 use super::{
-    args::Commands,
+    args::{ApiAction, Commands, ConfigAction, DebtAction, RemoteAction, SecretsAction},
     git_ops::{handle_abort, handle_branch, handle_promote},
-    handlers::{handle_check, handle_scan},
+    handlers::{handle_check, handle_scan, OutputMode, ScanOptions},
 };
+use crate::config::Config;
 use crate::exit::NetiExit;
+use crate::init::InitOptions;
+use crate::mutate::MutateOptions;
 use anyhow::{anyhow, Result};
 
 /// Executes the parsed command.
@@ -14,42 +17,127 @@ use anyhow::{anyhow, Result};
 /// Returns error if the command handler fails.
 pub fn execute(command: Commands) -> Result<NetiExit> {
     match command {
-        Commands::Check { .. } | Commands::Scan { .. } | Commands::Mutate { .. } => {
-            handle_analysis(command)
-        }
+        Commands::Check { .. }
+        | Commands::Scan { .. }
+        | Commands::Graph { .. }
+        | Commands::Api { .. }
+        | Commands::Secrets { .. }
+        | Commands::Remote { .. }
+        | Commands::Debt { .. }
+        | Commands::Symbols { .. }
+        | Commands::Status { .. }
+        | Commands::Metrics { .. }
+        | Commands::Report { .. }
+        | Commands::Mutate { .. } => handle_analysis(command),
 
         Commands::Branch { .. } | Commands::Promote { .. } | Commands::Abort => {
             handle_git_ops(&command)
         }
 
-        Commands::Clean { .. } | Commands::Config => handle_core_ops(&command),
+        Commands::Clean { .. }
+        | Commands::Init { .. }
+        | Commands::Config { .. }
+        | Commands::Doctor { .. }
+        | Commands::Schema { .. } => handle_core_ops(&command),
     }
 }
 
 fn handle_analysis(command: Commands) -> Result<NetiExit> {
+    if matches!(command, Commands::Check { .. } | Commands::Scan { .. }) {
+        Config::load().validate()?;
+    }
     match command {
-        Commands::Check { json } => handle_check(json),
+        Commands::Check {
+            json,
+            quiet,
+            porcelain,
+            upload,
+            ratchet,
+        } => handle_check(
+            OutputMode::from_flags(json, quiet, porcelain),
+            upload,
+            ratchet,
+        ),
         Commands::Scan {
             verbose,
             locality,
+            cycles,
             json,
-        } => {
+            quiet,
+            porcelain,
+            package,
+            group_by,
+            team_reports,
+            compare,
+            fail_on_new,
+            profile_rules,
+            categories,
+        } => super::profile::wrap(profile_rules, || {
+            let package = package.as_deref();
+            if cycles {
+                return super::locality::handle_cycles(json, package);
+            }
             if locality {
-                return super::locality::handle_locality();
+                return super::locality::handle_locality(package);
             }
-            handle_scan(verbose, false, json)
+            handle_scan(&ScanOptions {
+                verbose,
+                output: OutputMode::from_flags(json, quiet, porcelain),
+                package,
+                group_by,
+                team_reports,
+                compare: compare.as_deref(),
+                fail_on_new,
+                categories,
+            })
+        }),
+        Commands::Graph { format, focus } => super::graph::handle_graph(format, focus.as_deref()),
+        Commands::Status { json } => super::status::handle_status(json),
+        Commands::Metrics { format } => super::metrics::handle_metrics(format),
+        Commands::Report { xlsx } => super::report::handle_report(&xlsx),
+        Commands::Symbols { query, limit, json } => {
+            super::symbols::handle_symbols(query.as_deref(), limit, json)
         }
+        Commands::Api { action } => match action {
+            ApiAction::Snapshot => super::api::handle_snapshot(),
+            ApiAction::Check { json } => super::api::handle_check(json),
+        },
+        Commands::Secrets {
+            action: SecretsAction::Baseline,
+        } => super::secrets::handle_baseline(),
+        Commands::Remote {
+            action: RemoteAction::Pull,
+        } => super::remote::handle_pull(),
+        Commands::Debt {
+            action: DebtAction::List { json },
+        } => super::debt::handle_list(json),
         Commands::Mutate {
             workers,
             timeout,
             json,
             filter,
-        } => super::mutate_handler::handle_mutate(workers, timeout, json, filter),
+            coverage,
+            only_covered,
+            since,
+            html,
+            badge,
+        } => super::mutate_handler::handle_mutate(MutateOptions {
+            workers,
+            timeout_secs: timeout,
+            json,
+            filter,
+            coverage,
+            only_covered,
+            since,
+            html,
+            badge,
+        }),
         _ => Err(anyhow!("Internal error: Invalid analysis command")),
     }
 }
 
 fn handle_git_ops(command: &Commands) -> Result<NetiExit> {
+    Config::load().validate()?;
     match command {
         Commands::Branch { force } => handle_branch(*force),
         Commands::Promote { dry_run } => handle_promote(*dry_run),
@@ -64,7 +152,29 @@ fn handle_core_ops(command: &Commands) -> Result<NetiExit> {
             crate::clean::run(*commit)?;
             Ok(NetiExit::Success)
         }
-        Commands::Config => {
+        Commands::Init {
+            yes,
+            force,
+            strictness,
+            hooks,
+        } => {
+            crate::init::run(InitOptions {
+                yes: *yes,
+                force: *force,
+                strictness: *strictness,
+                hooks: *hooks,
+            })?;
+            Ok(NetiExit::Success)
+        }
+        Commands::Doctor { json } => super::doctor::handle_doctor(*json),
+        Commands::Schema { report } => {
+            crate::reporting::print_json(&report.schema())?;
+            Ok(NetiExit::Success)
+        }
+        Commands::Config {
+            action: Some(ConfigAction::Doctor),
+        } => super::config_doctor::handle_doctor(),
+        Commands::Config { action: None } => {
             super::config_ui::run_config_editor()?;
             Ok(NetiExit::Success)
         }
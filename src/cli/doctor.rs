@@ -0,0 +1,53 @@
+// src/cli/doctor.rs
+//! Handler for `neti doctor`.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::doctor::{self, Check, Status};
+use crate::exit::NetiExit;
+use crate::reporting;
+
+/// Runs every environment check and prints the results with fixes.
+///
+/// # Errors
+/// Returns error if JSON output cannot be written.
+pub fn handle_doctor(json: bool) -> Result<NetiExit> {
+    let config = Config::load();
+    let checks = doctor::run(Path::new("."), &config);
+    if json {
+        reporting::print_json(&checks)?;
+    } else {
+        print_text(&checks);
+    }
+    if doctor::has_failures(&checks) {
+        Ok(NetiExit::CheckFailed)
+    } else {
+        Ok(NetiExit::Success)
+    }
+}
+
+fn print_text(checks: &[Check]) {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for check in checks {
+        let mark = match check.status {
+            Status::Ok => "✓".green(),
+            Status::Warn => "!".yellow(),
+            Status::Fail => "✗".red(),
+        };
+        println!("  {mark} {:width$}  {}", check.name, check.detail);
+        if let Some(fix) = &check.fix {
+            println!("    {:width$}  {} {fix}", "", "fix:".bold());
+        }
+    }
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let (warnings, failures) = (count(Status::Warn), count(Status::Fail));
+    println!();
+    if warnings + failures == 0 {
+        println!("{}", "All checks passed.".green());
+    } else {
+        println!("{failures} failed, {warnings} warning(s).");
+    }
+}
@@ -3,7 +3,10 @@
 
 use super::handlers::get_repo_root;
 use crate::branch;
+use crate::commit_message::{self, MessageContext};
+use crate::config::Config;
 use crate::exit::NetiExit;
+use crate::last_check;
 use anyhow::Result;
 use colored::Colorize;
 use std::fs;
@@ -13,15 +16,17 @@ use std::fs;
 /// # Errors
 /// Returns error if branch operations fail.
 pub fn handle_branch(force: bool) -> Result<NetiExit> {
-    match branch::init_branch(force)? {
+    let work = branch::work_branch_name();
+
+    match branch::init_branch(force, &work)? {
         branch::BranchResult::Created => {
-            println!("{}", "  Created work branch 'neti-work'".blue());
+            println!("{}", format!("  Created work branch '{work}'").blue());
         }
         branch::BranchResult::Reset => {
-            println!("{}", "  Reset work branch 'neti-work'".blue());
+            println!("{}", format!("  Reset work branch '{work}'").blue());
         }
         branch::BranchResult::AlreadyOnBranch => {
-            println!("{}", "  Already on 'neti-work'".green());
+            println!("{}", format!("  Already on '{work}'").green());
         }
     }
     Ok(NetiExit::Success)
@@ -32,23 +37,57 @@ pub fn handle_branch(force: bool) -> Result<NetiExit> {
 /// # Errors
 /// Returns error if promotion fails.
 pub fn handle_promote(dry_run: bool) -> Result<NetiExit> {
-    let root = get_repo_root();
-    let goal_path = root.join(".neti").join("pending_goal");
-    let msg = fs::read_to_string(&goal_path)
-        .ok()
-        .map(|s| format!("feat: {} (promoted)", s.trim()));
+    let config = Config::load();
+    let goal = branch::pending_goal();
+    let work = branch::resolve_work_branch(&config.git, goal.as_deref());
+    let rules = if config.git.commit_template.contains("{rules}") {
+        let recorded = last_check::load(&get_repo_root());
+        if recorded.is_none() {
+            log::warn!("no recorded check results; run `neti check` to fill {{rules}}");
+        }
+        recorded
+    } else {
+        None
+    };
 
-    match branch::promote(dry_run, msg)? {
+    let msg = commit_message::build(
+        &config.git,
+        &MessageContext {
+            goal: goal.as_deref(),
+            files: branch::count_promoted_files(&work),
+            branch: &work,
+            rules: rules.as_ref(),
+        },
+    );
+
+    match branch::promote(dry_run, Some(msg.clone()), &config.git, &work)? {
         branch::PromoteResult::DryRun => {
+            let action = if config.git.per_goal_branches() {
+                format!("push '{work}' for review")
+            } else {
+                format!("merge '{work}' into main")
+            };
+            println!("{}", format!("[DRY RUN] Would {action}.").yellow());
             println!(
-                "{}",
-                "[DRY RUN] Would merge 'neti-work' into main.".yellow()
+                "  Commit message: {}",
+                msg.lines().next().unwrap_or("").dimmed()
             );
         }
         branch::PromoteResult::Merged => {
-            println!("{}", "  Merged 'neti-work' into main.".green().bold());
+            println!("{}", format!("  Merged '{work}' into main.").green().bold());
             // Clean up pending goal
-            let _ = fs::remove_file(goal_path);
+            let _ = fs::remove_file(branch::goal_path());
+            if config.git.push {
+                branch::push()?;
+                println!("{}", "  Pushed main.".green());
+            }
+        }
+        branch::PromoteResult::Published { pr_opened } => {
+            println!("{}", format!("  Pushed '{work}'.").green().bold());
+            if pr_opened {
+                println!("{}", "  Opened pull request against main.".green());
+            }
+            let _ = fs::remove_file(branch::goal_path());
         }
     }
     Ok(NetiExit::Success)
@@ -59,7 +98,7 @@ pub fn handle_promote(dry_run: bool) -> Result<NetiExit> {
 /// # Errors
 /// Returns error if abort fails.
 pub fn handle_abort() -> Result<NetiExit> {
-    branch::abort()?;
+    branch::abort(&branch::work_branch_name())?;
     println!("{}", "  Aborted. Work branch deleted.".yellow());
     Ok(NetiExit::Success)
 }
@@ -0,0 +1,90 @@
+// src/cli/graph.rs
+//! Handler for dependency graph export.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::analysis::Engine;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::graph::export::{self, DependencyGraph, GraphFormat, GraphNode};
+use crate::graph::locality::{
+    classify, collect_edges, compute_coupling, validate_graph, NodeIdentity,
+};
+use crate::graph::rank::pagerank;
+
+use super::handlers::get_repo_root;
+
+/// Prints the dependency graph as DOT, Mermaid, GraphML, or JSON.
+///
+/// # Errors
+/// Returns error if file discovery or import extraction fails.
+pub fn handle_graph(format: GraphFormat, focus: Option<&Path>) -> Result<NetiExit> {
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let report = Engine::scan(&config, &files);
+    let edges = collect_edges(&get_repo_root(), &files)?;
+    let edge_refs = || edges.iter().map(|(a, b)| (a.as_path(), b.as_path()));
+
+    let couplings = compute_coupling(edge_refs());
+    let validator = config.rules.locality.to_validator_config()?;
+    let failed_edges: HashSet<(PathBuf, PathBuf)> = validate_graph(edge_refs(), &validator)
+        .failed()
+        .iter()
+        .map(|e| (e.from.clone(), e.to.clone()))
+        .collect();
+    let ranks = rank_files(&edges, focus);
+
+    let nodes = report
+        .files
+        .iter()
+        .map(|file| {
+            let path = normalize(&file.path);
+            let coupling = couplings.get(&path).cloned().unwrap_or_default();
+            GraphNode {
+                is_hub: classify(&coupling, &validator.classifier) == NodeIdentity::StableHub,
+                rank: ranks.get(&path).copied().unwrap_or_default(),
+                afferent: coupling.afferent(),
+                efferent: coupling.efferent(),
+                tokens: file.token_count,
+                violations: file.violations.len(),
+                path,
+            }
+        })
+        .collect();
+
+    let mut graph = DependencyGraph {
+        nodes,
+        edges,
+        failed_edges,
+    };
+    if let Some(focus) = focus {
+        graph = graph.focused(focus);
+    }
+    print!("{}", export::render(&graph, format));
+    Ok(NetiExit::Success)
+}
+
+/// `PageRank` over import edges, personalized toward `focus` when it is a file.
+fn rank_files(edges: &[(PathBuf, PathBuf)], focus: Option<&Path>) -> HashMap<PathBuf, f64> {
+    let mut weights: HashMap<PathBuf, HashMap<PathBuf, usize>> = HashMap::new();
+    let mut all_files = HashSet::new();
+    for (from, to) in edges {
+        *weights
+            .entry(from.clone())
+            .or_default()
+            .entry(to.clone())
+            .or_default() += 1;
+        all_files.insert(from.clone());
+        all_files.insert(to.clone());
+    }
+    let anchor = focus.map(normalize);
+    pagerank::compute(&weights, &all_files, anchor.as_ref())
+}
+
+/// Discovery yields `./src/x.rs`; edges are root-relative `src/x.rs`.
+fn normalize(path: &Path) -> PathBuf {
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
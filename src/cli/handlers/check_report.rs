@@ -1,7 +1,7 @@
 // src/cli/handlers/check_report.rs
 //! Report building and scorecard display for `neti check`.
 
-use crate::types::{CommandResult, LocalityReport, ScanReport};
+use crate::types::{CheckReport, CommandResult, LocalityReport, ScanReport};
 use crate::verification::VerificationReport;
 use crate::{cli::handlers::scan_report, reporting};
 use colored::Colorize;
@@ -69,6 +69,16 @@ fn append_locality_result(out: &mut String, loc: &LocalityReport) {
         out.push_str(&format!("  cycle {}: {}\n", i + 1, paths.join(" → ")));
     }
 
+    for e in &loc.expired_exemptions {
+        out.push_str(&format!(
+            "  {} → {} (exemption expired {}, owner: {})\n",
+            e.from.display(),
+            e.to.display(),
+            e.expires,
+            e.owner,
+        ));
+    }
+
     if loc.passed {
         out.push_str("Result: PASS\n");
     } else {
@@ -121,6 +131,16 @@ pub fn print_locality_scorecard(loc: &LocalityReport) {
         if loc.cycle_count > 0 {
             println!("{} {} dependency cycles", "✗".red().bold(), loc.cycle_count);
         }
+        for e in &loc.expired_exemptions {
+            println!(
+                "  {} {} → {} (exemption expired {}, owner: {})",
+                "✗".red().bold(),
+                e.from.display(),
+                e.to.display(),
+                e.expires,
+                e.owner,
+            );
+        }
     }
     println!();
 }
@@ -153,3 +173,15 @@ pub fn print_commands_scorecard(report: &VerificationReport) {
         );
     }
 }
+
+/// The one-line detail sent with a check notification.
+pub fn notification_summary(report: &CheckReport) -> String {
+    let locality = report.locality.as_ref().map_or(0, |l| l.violation_count);
+    let passed = report.commands.iter().filter(|c| c.passed()).count();
+    format!(
+        "{} errors, {} warnings, {locality} locality violations, {passed}/{} commands passed",
+        report.scan.error_count(),
+        report.scan.warning_count(),
+        report.commands.len()
+    )
+}
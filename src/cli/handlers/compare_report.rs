@@ -0,0 +1,71 @@
+// src/cli/handlers/compare_report.rs
+//! Delta output for `neti scan --compare`.
+
+use crate::compare::{self, Finding, ScanDiff};
+use crate::exit::NetiExit;
+use crate::reporting;
+use crate::types::ScanReport;
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use super::{OutputMode, ScanOptions};
+
+/// Prints the delta between `report` and the report at `old`.
+///
+/// Without `--fail-on-new` the exit code is the usual scan result; with it,
+/// only new violations fail, so existing debt does not block a change.
+///
+/// # Errors
+/// Returns error if the old report cannot be read.
+pub fn run(old: &Path, report: &ScanReport, opts: &ScanOptions) -> Result<NetiExit> {
+    let delta = compare::diff(compare::load(old)?, compare::findings(report));
+    if opts.output == OutputMode::Json {
+        reporting::print_json(&delta)?;
+    } else {
+        print(&delta, opts.verbose);
+    }
+    let failed = if opts.fail_on_new {
+        !delta.new.is_empty()
+    } else {
+        report.has_errors()
+    };
+    Ok(if failed {
+        NetiExit::CheckFailed
+    } else {
+        NetiExit::Success
+    })
+}
+
+fn print(delta: &ScanDiff, verbose: bool) {
+    println!();
+    println!(
+        "{} {} new │ {} fixed │ {} persisting",
+        "COMPARE".cyan().bold(),
+        delta.new.len().to_string().red().bold(),
+        delta.fixed.len().to_string().green().bold(),
+        delta.persisting.len()
+    );
+    print_section("New", &delta.new);
+    print_section("Fixed", &delta.fixed);
+    if verbose {
+        print_section("Persisting", &delta.persisting);
+    }
+    println!();
+}
+
+fn print_section(title: &str, findings: &[Finding]) {
+    if findings.is_empty() {
+        return;
+    }
+    println!("\n{}", format!("{title}:").dimmed());
+    for f in findings {
+        println!(
+            "  {}:{} [{}] {}",
+            f.file.display(),
+            f.row,
+            f.law.yellow(),
+            f.message
+        );
+    }
+}
@@ -4,36 +4,67 @@
 use crate::analysis::Engine;
 use crate::config::Config;
 use crate::discovery;
+use crate::events::{EventKind, EventLogger};
 use crate::exit::NetiExit;
-use crate::reporting;
+use crate::fsutil::atomic_write;
+use crate::notify::{self, Outcome};
+use crate::reporting::{self, porcelain};
+use crate::schema;
 use crate::spinner;
 use crate::types::CheckReport;
 use crate::verification;
 use anyhow::Result;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
 
 mod check_report;
+mod compare_report;
+mod output_mode;
+mod owner_report;
+mod ratchet_report;
+mod scan_options;
 pub mod scan_report;
 
+pub use output_mode::OutputMode;
+pub use scan_options::ScanOptions;
+
 #[must_use]
 pub fn get_repo_root() -> PathBuf {
     std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
 }
 
 /// Handles the scan command.
-pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<NetiExit> {
-    if locality {
-        return super::locality::handle_locality();
-    }
-
+///
+/// # Errors
+/// Returns error if discovery fails, `--group-by owner` finds no
+/// `CODEOWNERS`, the `--compare` report cannot be read, or a report cannot
+/// be written.
+pub fn handle_scan(opts: &ScanOptions) -> Result<NetiExit> {
+    let owners = opts.group_by.map(|_| owner_report::load()).transpose()?;
     let mut config = Config::load();
-    config.verbose = verbose;
+    config.verbose = opts.verbose;
+    config.categories = opts.categories.clone();
+    let discover = |config: &Config| match opts.package {
+        Some(name) => discovery::discover_package(config, name),
+        None => discovery::discover(config),
+    };
 
-    if json {
-        let files = discovery::discover(&config)?;
+    if let Some(old) = opts.compare {
+        let files = discover(&config)?;
         let report = Engine::scan(&config, &files);
-        reporting::print_json(&report)?;
+        return compare_report::run(old, &report, opts);
+    }
+
+    if opts.output != OutputMode::Human {
+        let files = discover(&config)?;
+        let report = Engine::scan(&config, &files);
+        match (opts.output, &owners) {
+            (OutputMode::Quiet, _) => println!("{}", porcelain::scan_summary(&report)),
+            (OutputMode::Porcelain, _) => print!("{}", porcelain::scan_rows(&report)),
+            (_, Some(owners)) => reporting::print_json(&owners.group(&report))?,
+            (_, None) => reporting::print_json(&schema::versioned(&report))?,
+        }
         return Ok(if report.has_errors() {
             NetiExit::CheckFailed
         } else {
@@ -42,7 +73,7 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<NetiExit
     }
 
     let (client, mut controller) = spinner::start("neti scan");
-    let files = discovery::discover(&config)?;
+    let files = discover(&config)?;
     let total = files.len();
     let counter = AtomicUsize::new(0);
 
@@ -73,6 +104,13 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<NetiExit
     if has_errors {
         reporting::print_report(&report)?;
     }
+    if let Some(owners) = &owners {
+        let groups = owners.group(&report);
+        owner_report::print(&groups);
+        if opts.team_reports {
+            owner_report::write_team_reports(&groups)?;
+        }
+    }
 
     Ok(if has_errors {
         NetiExit::CheckFailed
@@ -82,46 +120,91 @@ pub fn handle_scan(verbose: bool, locality: bool, json: bool) -> Result<NetiExit
 }
 
 /// Handles the check command. Master pipeline: Scan -> Locality -> Commands.
-pub fn handle_check(json: bool) -> Result<NetiExit> {
+/// With `upload`, the report and cache then go to the `[remote]` store.
+///
+/// # Errors
+/// Returns error if discovery, a command, the ratchet file, writing the
+/// report, or the upload fails.
+pub fn handle_check(output: OutputMode, upload: bool, ratchet: bool) -> Result<NetiExit> {
     let repo_root = get_repo_root();
     let config = Config::load();
+    let events = EventLogger::new(&repo_root);
+    events.log(EventKind::CheckStarted);
 
-    if json {
-        return handle_check_json(&repo_root, &config);
+    let start = Instant::now();
+    let result = if output == OutputMode::Human {
+        handle_check_interactive(&repo_root, &config, ratchet)
+    } else {
+        handle_check_headless(&repo_root, &config, output, ratchet)
+    };
+    let exit = match &result {
+        Ok(report) if report.passed => NetiExit::Success,
+        Ok(_) => NetiExit::CheckFailed,
+        Err(_) => NetiExit::Error,
+    };
+    events.log(match exit {
+        NetiExit::Success => EventKind::CheckPassed,
+        other => EventKind::CheckFailed {
+            exit_code: other.code(),
+        },
+    });
+    let report = result?;
+    notify::send(
+        &config.notifications,
+        &repo_root,
+        &Outcome {
+            command: "check",
+            passed: report.passed,
+            duration: start.elapsed(),
+            summary: check_report::notification_summary(&report),
+        },
+    );
+    if upload {
+        super::remote::upload_check(&config, &repo_root, &report)?;
     }
-
-    handle_check_interactive(&repo_root, &config)
+    Ok(exit)
 }
 
-/// JSON mode: emit `CheckReport` to stdout, write `neti-report.txt`.
-fn handle_check_json(repo_root: &Path, config: &Config) -> Result<NetiExit> {
+/// JSON, quiet and porcelain modes: no spinner, write `neti-report.txt`,
+/// print the `CheckReport` in the requested form.
+fn handle_check_headless(
+    repo_root: &Path,
+    config: &Config,
+    output: OutputMode,
+    ratchet: bool,
+) -> Result<CheckReport> {
     let files = discovery::discover(config)?;
     let scan_report = Engine::scan(config, &files);
     let locality_report = super::locality::check_locality_silent(repo_root, config)?;
     let verif_report = verification::run(repo_root, |_, _, _| {});
 
-    let passed = !scan_report.has_errors() && locality_report.passed && verif_report.passed;
+    let (scan_passed, ratchet) = ratchet_report::gate(repo_root, &scan_report, ratchet)?;
+    let passed = scan_passed && locality_report.passed && verif_report.passed;
 
     let text = check_report::build_report_text(&scan_report, &verif_report, Some(&locality_report));
-    std::fs::write("neti-report.txt", &text)?;
+    atomic_write("neti-report.txt", &text)?;
 
     let check_report = CheckReport {
         scan: scan_report,
         commands: verif_report.commands,
         locality: Some(locality_report),
+        ratchet,
         passed,
     };
-    reporting::print_json(&check_report)?;
-
-    Ok(if passed {
-        NetiExit::Success
-    } else {
-        NetiExit::CheckFailed
-    })
+    match output {
+        OutputMode::Quiet => println!("{}", porcelain::check_summary(&check_report)),
+        OutputMode::Porcelain => print!("{}", porcelain::check_rows(&check_report)),
+        _ => reporting::print_json(&schema::versioned(&check_report))?,
+    }
+    Ok(check_report)
 }
 
 /// Interactive mode: spinner UI, write `neti-report.txt`, print scorecard.
-fn handle_check_interactive(repo_root: &Path, config: &Config) -> Result<NetiExit> {
+fn handle_check_interactive(
+    repo_root: &Path,
+    config: &Config,
+    ratchet: bool,
+) -> Result<CheckReport> {
     let (client, mut controller) = spinner::start("neti check");
 
     client.set_macro_step(1, 3, "Static Analysis");
@@ -155,19 +238,25 @@ fn handle_check_interactive(repo_root: &Path, config: &Config) -> Result<NetiExi
         client.step_micro_progress(current, total, format!("Running: {cmd}"));
     });
 
-    let passed = !scan_report.has_errors() && locality_report.passed && verif_report.passed;
+    let (scan_passed, ratchet) = ratchet_report::gate(repo_root, &scan_report, ratchet)?;
+    let passed = scan_passed && locality_report.passed && verif_report.passed;
     controller.stop(passed);
 
     let text = check_report::build_report_text(&scan_report, &verif_report, Some(&locality_report));
-    std::fs::write("neti-report.txt", &text)?;
+    atomic_write("neti-report.txt", &text)?;
 
     scan_report::print(&scan_report);
     check_report::print_locality_scorecard(&locality_report);
+    if let Some(ratchet) = &ratchet {
+        ratchet_report::print(ratchet);
+    }
     check_report::print_commands_scorecard(&verif_report);
 
-    Ok(if passed {
-        NetiExit::Success
-    } else {
-        NetiExit::CheckFailed
+    Ok(CheckReport {
+        scan: scan_report,
+        commands: verif_report.commands,
+        locality: Some(locality_report),
+        ratchet,
+        passed,
     })
 }
@@ -0,0 +1,28 @@
+// src/cli/handlers/output_mode.rs
+//! Output mode selection for `scan` and `check`.
+
+/// How `scan` and `check` present their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Spinner, colored summary and report.
+    #[default]
+    Human,
+    Json,
+    /// The one-line summary only.
+    Quiet,
+    /// Stable tab-separated rows; see `reporting::porcelain`.
+    Porcelain,
+}
+
+impl OutputMode {
+    /// Picks the mode from mutually exclusive CLI flags.
+    #[must_use]
+    pub fn from_flags(json: bool, quiet: bool, porcelain: bool) -> Self {
+        match (json, quiet, porcelain) {
+            (true, _, _) => Self::Json,
+            (_, true, _) => Self::Quiet,
+            (_, _, true) => Self::Porcelain,
+            _ => Self::Human,
+        }
+    }
+}
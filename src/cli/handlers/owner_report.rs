@@ -0,0 +1,74 @@
+// src/cli/handlers/owner_report.rs
+//! Per-owner summaries and report files for `neti scan --group-by owner`.
+
+use crate::cli::handlers::scan_report;
+use crate::codeowners::{team_slug, CodeOwners};
+use crate::fsutil::atomic_write;
+use crate::reporting;
+use crate::types::ScanReport;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Loads `CODEOWNERS`, which grouping by owner cannot do without.
+///
+/// # Errors
+/// Returns an error if the project has no `CODEOWNERS` file.
+pub fn load() -> Result<CodeOwners> {
+    CodeOwners::load(Path::new(".")).ok_or_else(|| {
+        anyhow!("--group-by owner needs a CODEOWNERS file in .github/, the root or docs/")
+    })
+}
+
+/// Prints one line per owner, most violations first.
+pub fn print(groups: &BTreeMap<String, ScanReport>) {
+    let mut rows: Vec<_> = groups.iter().collect();
+    rows.sort_by_key(|(_, r)| std::cmp::Reverse(r.total_violations));
+    println!("{}", "By owner:".dimmed());
+    for (owner, report) in rows {
+        let count = format!("{:>4}", report.total_violations);
+        let count = if report.has_errors() {
+            count.yellow()
+        } else {
+            count.green()
+        };
+        println!(
+            "  {count} violations │ {:>4} files │ {}",
+            report.files.len(),
+            owner
+        );
+    }
+    println!();
+}
+
+/// Writes `neti-report-team-<team>.txt` for each owner. Owners whose names
+/// reduce to the same slug share a file.
+///
+/// # Errors
+/// Returns an error if a report cannot be written.
+pub fn write_team_reports(groups: &BTreeMap<String, ScanReport>) -> Result<()> {
+    let mut texts: BTreeMap<String, String> = BTreeMap::new();
+    for (owner, report) in groups {
+        let text = texts.entry(team_slug(owner)).or_default();
+        text.push_str(&team_text(owner, report));
+    }
+    for (slug, text) in &texts {
+        let path = format!("neti-report-team-{slug}.txt");
+        atomic_write(&path, text)?;
+        println!("  {} {}", "Wrote".dimmed(), path.yellow());
+    }
+    Ok(())
+}
+
+fn team_text(owner: &str, report: &ScanReport) -> String {
+    let mut out = format!("OWNER: {owner}\n");
+    out.push_str(&scan_report::build_summary_string(report));
+    out.push('\n');
+    if report.has_errors() {
+        if let Ok(rich) = reporting::build_rich_report(report) {
+            out.push_str(&rich);
+        }
+    }
+    out
+}
@@ -0,0 +1,69 @@
+// src/cli/handlers/ratchet_report.rs
+//! The scan gate of `neti check`, with and without `--ratchet`.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::last_check;
+use crate::ratchet::{self, RatchetReport, RATCHET_PATH};
+use crate::types::ScanReport;
+
+/// Whether the scan passes the check: no violations at all or, with
+/// `ratchet`, no rule over its recorded count. The counts are recorded for
+/// `{rules}` in promote commit messages either way.
+///
+/// # Errors
+/// Returns error if the ratchet file cannot be parsed or written.
+pub fn gate(
+    repo_root: &Path,
+    scan: &ScanReport,
+    ratchet: bool,
+) -> Result<(bool, Option<RatchetReport>)> {
+    last_check::record(repo_root, &ratchet::counts(scan));
+    if !ratchet {
+        return Ok((!scan.has_errors(), None));
+    }
+    let report = ratchet::check(repo_root, scan)?;
+    Ok((report.passed(), Some(report)))
+}
+
+/// Prints the ratchet section of the check scorecard.
+pub fn print(report: &RatchetReport) {
+    println!("{}", "RATCHET REPORT".cyan().bold());
+    println!("{}", "========================================".dimmed());
+    for c in &report.increased {
+        println!(
+            "  {} {}: {} violations, up from {}",
+            "✗".red().bold(),
+            c.rule,
+            c.current,
+            c.recorded
+        );
+    }
+    for c in &report.decreased {
+        println!(
+            "  {} {}: {} violations, down from {}",
+            "✓".green().bold(),
+            c.rule,
+            c.current,
+            c.recorded
+        );
+    }
+    if !report.passed() {
+        println!(
+            "{} Rules over their recorded count: {}.",
+            "✗".red().bold(),
+            report.increased.len()
+        );
+    } else if report.updated {
+        println!(
+            "{} Counts recorded in {}.",
+            "✓".green().bold(),
+            RATCHET_PATH
+        );
+    } else {
+        println!("{} No rule over its recorded count.", "✓".green().bold());
+    }
+    println!();
+}
@@ -0,0 +1,27 @@
+// src/cli/handlers/scan_options.rs
+//! Options for `neti scan`.
+
+use std::path::Path;
+
+use super::OutputMode;
+use crate::codeowners::GroupBy;
+use crate::types::CategoryFilter;
+
+/// Options for `neti scan`.
+#[derive(Debug, Default)]
+pub struct ScanOptions<'a> {
+    pub verbose: bool,
+    pub output: OutputMode,
+    /// Limit results to this workspace member.
+    pub package: Option<&'a str>,
+    /// Split results by owner.
+    pub group_by: Option<GroupBy>,
+    /// With `group_by`, write one report file per group.
+    pub team_reports: bool,
+    /// A previous `scan --json` report to show the delta against.
+    pub compare: Option<&'a Path>,
+    /// With `compare`, fail on new violations only.
+    pub fail_on_new: bool,
+    /// Rule categories to report.
+    pub categories: CategoryFilter,
+}
@@ -10,9 +10,15 @@ use crate::discovery;
 use crate::exit::NetiExit;
 use crate::graph::locality::analysis::analyze;
 use crate::graph::locality::coupling::compute_coupling;
-use crate::graph::locality::report::print_full_report;
-use crate::graph::locality::{collect_edges, validate_graph, Coupling};
-use crate::types::{LocalityReport, LocalityViolation};
+use crate::graph::locality::cycle_report::analyze_cycles;
+use crate::graph::locality::grandfathered::Grandfathered;
+use crate::graph::locality::report::{
+    print_cycle_report, print_expired_exemptions, print_full_report,
+};
+use crate::graph::locality::{collect_edges, validate_graph, Coupling, ValidatorConfig};
+use crate::profile;
+use crate::types::{ExpiredExemption, LocalityReport, LocalityViolation};
+use crate::workspace;
 
 /// Result of a locality check for use in verification pipeline.
 pub struct LocalityResult {
@@ -24,8 +30,8 @@ pub struct LocalityResult {
 ///
 /// # Errors
 /// Returns error if file discovery or import extraction fails.
-pub fn handle_locality() -> Result<NetiExit> {
-    let result = run_locality_check(Path::new("."))?;
+pub fn handle_locality(package: Option<&str>) -> Result<NetiExit> {
+    let result = run_scoped_check(Path::new("."), package)?;
 
     if result.passed {
         Ok(NetiExit::Success)
@@ -34,11 +40,49 @@ pub fn handle_locality() -> Result<NetiExit> {
     }
 }
 
+/// Reports dependency cycles with suggested break edges.
+///
+/// # Errors
+/// Returns error if file discovery or import extraction fails.
+pub fn handle_cycles(json: bool, package: Option<&str>) -> Result<NetiExit> {
+    let config = Config::load();
+    let project_root = std::env::current_dir()?;
+    let member = package
+        .map(|name| workspace::select_member(&project_root, name))
+        .transpose()?;
+    let files = discovery::discover(&config)?;
+    let edges = collect_edges(&project_root, &files)?;
+
+    let mut report = analyze_cycles(edges.iter().map(|(a, b)| (a.as_path(), b.as_path())));
+    if let Some(member) = &member {
+        report
+            .components
+            .retain(|c| c.files.iter().any(|f| member.contains(f)));
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_cycle_report(&report);
+    }
+
+    if report.is_empty() {
+        Ok(NetiExit::Success)
+    } else {
+        Ok(NetiExit::CheckFailed)
+    }
+}
+
 /// Runs locality check and returns result. Used by verification pipeline.
 ///
 /// # Errors
 /// Returns error if file discovery or graph construction fails.
 pub fn run_locality_check(cwd: &Path) -> Result<LocalityResult> {
+    run_scoped_check(cwd, None)
+}
+
+/// Runs the locality check over the whole graph, reporting only edges that
+/// leave workspace member `package` when one is given.
+fn run_scoped_check(cwd: &Path, package: Option<&str>) -> Result<LocalityResult> {
     let config = Config::load();
 
     if !config.rules.locality.is_enabled() {
@@ -48,29 +92,30 @@ pub fn run_locality_check(cwd: &Path) -> Result<LocalityResult> {
         });
     }
 
-    let locality_config = config.rules.locality.to_validator_config();
-    let project_root = if cwd == Path::new(".") {
-        std::env::current_dir()?
-    } else {
-        cwd.to_path_buf()
-    };
+    let project_root = resolve_root(cwd)?;
+    let (locality_config, grandfathered) = load_validator_config(&config, &project_root)?;
+    let member = package
+        .map(|name| workspace::select_member(&project_root, name))
+        .transpose()?;
 
     let files = discovery::discover(&config)?;
-    let edges = collect_edges(&project_root, &files)?;
-
-    let couplings: HashMap<PathBuf, Coupling> =
-        compute_coupling(edges.iter().map(|(a, b)| (a.as_path(), b.as_path())));
-
-    let report = validate_graph(
-        edges.iter().map(|(a, b)| (a.as_path(), b.as_path())),
-        &locality_config,
-    );
+    let edges = profile::time_stage("locality/edges", || collect_edges(&project_root, &files))?;
+
+    let (couplings, mut report) = profile::time_stage("locality/graph", || {
+        let pairs = || edges.iter().map(|(a, b)| (a.as_path(), b.as_path()));
+        let couplings: HashMap<PathBuf, Coupling> = compute_coupling(pairs());
+        (couplings, validate_graph(pairs(), &locality_config))
+    });
+    if let Some(member) = &member {
+        report = report.scoped_to(|path| member.contains(path));
+    }
 
-    let violations = report.failed().len();
-    let is_clean = report.is_clean();
+    let violations = report.failed().len() + grandfathered.expired().len();
+    let is_clean = report.is_clean() && grandfathered.expired().is_empty();
 
     let analysis = analyze(&report, &couplings);
     print_full_report(&report, &analysis);
+    print_expired_exemptions(grandfathered.expired());
 
     let passed = is_clean || !config.rules.locality.is_error_mode();
 
@@ -88,6 +133,7 @@ pub fn check_locality_silent(cwd: &Path, config: &Config) -> Result<LocalityRepo
         return Ok(LocalityReport {
             violation_count: 0,
             violations: Vec::new(),
+            expired_exemptions: Vec::new(),
             cycle_count: 0,
             cycles: Vec::new(),
             total_edges: 0,
@@ -96,12 +142,8 @@ pub fn check_locality_silent(cwd: &Path, config: &Config) -> Result<LocalityRepo
         });
     }
 
-    let locality_config = config.rules.locality.to_validator_config();
-    let project_root = if cwd == Path::new(".") {
-        std::env::current_dir()?
-    } else {
-        cwd.to_path_buf()
-    };
+    let project_root = resolve_root(cwd)?;
+    let (locality_config, grandfathered) = load_validator_config(config, &project_root)?;
 
     let files = discovery::discover(config)?;
     let edges = collect_edges(&project_root, &files)?;
@@ -122,15 +164,28 @@ pub fn check_locality_silent(cwd: &Path, config: &Config) -> Result<LocalityRepo
         })
         .collect();
 
-    let violation_count = violation_details.len();
+    let expired_exemptions: Vec<ExpiredExemption> = grandfathered
+        .expired()
+        .iter()
+        .map(|e| ExpiredExemption {
+            from: e.from.clone(),
+            to: e.to.clone(),
+            owner: e.owner.clone(),
+            expires: e.expires.clone(),
+        })
+        .collect();
+
+    let violation_count = violation_details.len() + expired_exemptions.len();
     let cycle_paths: Vec<Vec<PathBuf>> = report.cycles().to_vec();
     let cycle_count = cycle_paths.len();
     let total_edges = report.total_edges();
-    let passed = report.is_clean() || !config.rules.locality.is_error_mode();
+    let is_clean = report.is_clean() && expired_exemptions.is_empty();
+    let passed = is_clean || !config.rules.locality.is_error_mode();
 
     Ok(LocalityReport {
         violation_count,
         violations: violation_details,
+        expired_exemptions,
         cycle_count,
         cycles: cycle_paths,
         total_edges,
@@ -139,6 +194,23 @@ pub fn check_locality_silent(cwd: &Path, config: &Config) -> Result<LocalityRepo
     })
 }
 
+fn resolve_root(cwd: &Path) -> Result<PathBuf> {
+    if cwd == Path::new(".") {
+        Ok(std::env::current_dir()?)
+    } else {
+        Ok(cwd.to_path_buf())
+    }
+}
+
+/// Builds the validator config with grandfathered edges applied. Expired
+/// ones are skipped too, so each is counted once, as an expired exemption.
+fn load_validator_config(config: &Config, root: &Path) -> Result<(ValidatorConfig, Grandfathered)> {
+    let mut locality_config = config.rules.locality.to_validator_config()?;
+    let grandfathered = Grandfathered::load(root)?;
+    locality_config.grandfathered = grandfathered.exempt_edges();
+    Ok((locality_config, grandfathered))
+}
+
 /// Returns whether locality is in error mode (blocking).
 #[must_use]
 pub fn is_locality_blocking() -> bool {
@@ -0,0 +1,39 @@
+// src/cli/metrics.rs
+//! Handler for the dashboard metrics export.
+
+use anyhow::Result;
+use std::path::Path;
+
+use crate::analysis::Engine;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::metrics::{self, MetricsFormat};
+use crate::mutate::report::last_run;
+use crate::reporting;
+
+use super::handlers::get_repo_root;
+use super::locality::check_locality_silent;
+
+/// Handles the metrics command.
+///
+/// # Errors
+/// Returns error if file discovery or the locality graph fails.
+pub fn handle_metrics(format: MetricsFormat) -> Result<NetiExit> {
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let scan = Engine::scan(&config, &files);
+    let locality = if config.rules.locality.is_enabled() {
+        Some(check_locality_silent(Path::new("."), &config)?.violation_count)
+    } else {
+        None
+    };
+    let mutation = last_run::load(&get_repo_root());
+    let metrics = metrics::collect(&scan, locality, mutation.as_ref());
+
+    match format {
+        MetricsFormat::Prometheus => print!("{}", metrics::render_prometheus(&metrics)),
+        MetricsFormat::Json => reporting::print_json(&metrics)?,
+    }
+    Ok(NetiExit::Success)
+}
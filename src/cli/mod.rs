@@ -1,11 +1,25 @@
 //! CLI command handlers.
 
+pub mod actions;
+pub mod api;
 pub mod args;
+pub mod config_doctor;
 pub mod config_ui;
+pub mod debt;
 pub mod dispatch;
+pub mod doctor;
 pub mod git_ops;
+pub mod graph;
 pub mod handlers;
 pub mod locality;
+pub mod metrics;
 pub mod mutate_handler;
+pub mod options;
+pub mod profile;
+pub mod remote;
+pub mod report;
+pub mod secrets;
+pub mod status;
+pub mod symbols;
 
 pub use args::Cli;
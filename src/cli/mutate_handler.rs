@@ -1,32 +1,40 @@
 // src/cli/mutate_handler.rs
 use crate::cli::handlers::get_repo_root;
+use crate::config::Config;
 use crate::exit::NetiExit;
 use crate::mutate::{self, MutateOptions};
+use crate::notify::{self, Outcome};
 use anyhow::Result;
+use std::time::Instant;
 
 /// Handles the mutate command.
 ///
 /// # Errors
 /// Returns error if mutation testing fails.
-pub fn handle_mutate(
-    workers: Option<usize>,
-    timeout: u64,
-    json: bool,
-    filter: Option<String>,
-) -> Result<NetiExit> {
-    let opts = MutateOptions {
-        workers,
-        timeout_secs: timeout,
-        json,
-        filter,
-    };
-
+pub fn handle_mutate(opts: MutateOptions) -> Result<NetiExit> {
     let repo_root = get_repo_root();
+    let start = Instant::now();
     let report = mutate::run(&repo_root, &opts)?;
+    let summary = &report.summary;
+    let passed = summary.survived == 0;
 
-    if report.summary.survived > 0 {
-        Ok(NetiExit::CheckFailed)
-    } else {
+    notify::send(
+        &Config::load().notifications,
+        &repo_root,
+        &Outcome {
+            command: "mutate",
+            passed,
+            duration: start.elapsed(),
+            summary: format!(
+                "score {:.1}%: {} killed, {} survived of {}",
+                summary.score, summary.killed, summary.survived, summary.total
+            ),
+        },
+    );
+
+    if passed {
         Ok(NetiExit::Success)
+    } else {
+        Ok(NetiExit::CheckFailed)
     }
 }
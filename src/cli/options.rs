@@ -0,0 +1,45 @@
+// src/cli/options.rs
+//! The top-level `neti` parser and the options every command takes.
+
+use clap::Parser;
+use std::path::PathBuf;
+
+use super::args::Commands;
+
+#[derive(Parser)]
+#[command(name = "neti", version, about = "AI Code Quality Guardian")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+    /// Merge `[profile.<NAME>]` from neti.toml over the base config
+    /// (same as setting `NETI_PROFILE`)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Override a config key for this run, e.g. `--set rules.max_file_tokens=1500`
+    /// (beats neti.toml and `NETI_*` environment variables)
+    #[arg(
+        long = "set",
+        global = true,
+        value_name = "KEY=VALUE",
+        value_parser = crate::config::layers::parse_assignment
+    )]
+    pub overrides: Vec<(String, toml::Value)>,
+    /// Scan files excluded by .gitignore, git excludes and .netiignore
+    /// (same as `--set discovery.ignore_files=false`)
+    #[arg(long, global = true)]
+    pub no_ignore: bool,
+    /// Skip sorting reports by path, line and rule (faster, but order may vary)
+    #[arg(long, global = true)]
+    pub unsorted: bool,
+    /// Cap the memory of files analyzed at once at MB by analyzing in batches
+    /// (same as `--set discovery.max_memory_mb=MB`)
+    #[arg(long, global = true, value_name = "MB")]
+    pub max_mem: Option<u64>,
+    /// Diagnostic detail: error, warn, info, debug or trace
+    /// (default `NETI_LOG`, else warn; debug with `--log-file`)
+    #[arg(long, global = true, value_name = "LEVEL")]
+    pub log_level: Option<log::LevelFilter>,
+    /// Append diagnostics to this file, e.g. `.neti/neti.log`
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+}
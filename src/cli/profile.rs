@@ -0,0 +1,38 @@
+// src/cli/profile.rs
+//! `neti scan --profile-rules`: where the scan spent its time.
+
+use anyhow::Result;
+use std::path::Path;
+
+use crate::exit::NetiExit;
+use crate::fsutil::atomic_write;
+use crate::profile;
+
+/// Where the full profile is written as JSON.
+pub const PROFILE_PATH: &str = ".neti/profile.json";
+
+/// Rows shown per table.
+const TABLE_ROWS: usize = 15;
+
+/// Runs `scan` with rule timing on when `enabled`, then prints the slowest
+/// rules and files to stderr, so `--json` and `--porcelain` output stay
+/// clean, and writes every timing to [`PROFILE_PATH`].
+///
+/// # Errors
+/// Returns error if the scan fails or the profile cannot be written.
+pub fn wrap(enabled: bool, scan: impl FnOnce() -> Result<NetiExit>) -> Result<NetiExit> {
+    if !enabled {
+        return scan();
+    }
+    profile::enable();
+    let exit = scan()?;
+    let profile = profile::take();
+    let path = Path::new(PROFILE_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    atomic_write(path, serde_json::to_string_pretty(&profile)?)?;
+    eprint!("\n{}", profile.table(TABLE_ROWS));
+    eprintln!("\nFull profile: {PROFILE_PATH}");
+    Ok(exit)
+}
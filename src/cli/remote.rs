@@ -0,0 +1,56 @@
+// src/cli/remote.rs
+//! Handlers for the `[remote]` report and cache store.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::exit::NetiExit;
+use crate::fsutil::atomic_write;
+use crate::remote::{self, Remote};
+use crate::schema;
+use crate::types::CheckReport;
+
+use super::handlers::get_repo_root;
+
+/// Local copy of the report `neti check --upload` sends.
+pub const REPORT_PATH: &str = ".neti/check-report.json";
+
+/// Handles `neti remote pull`.
+///
+/// # Errors
+/// Returns error if no remote is configured or the cache cannot be fetched.
+pub fn handle_pull() -> Result<NetiExit> {
+    let remote = Remote::from_config(&Config::load().remote)?;
+    let count = remote::pull(&remote, &get_repo_root())?;
+    println!(
+        "{}",
+        format!("  Restored {count} cache files into {}", remote::CACHE_DIR).green()
+    );
+    Ok(NetiExit::Success)
+}
+
+/// Writes the check report to [`REPORT_PATH`] and uploads it with the
+/// cache. Progress goes to stderr so `--json` and `--porcelain` output stay
+/// clean.
+///
+/// # Errors
+/// Returns error if no remote is configured or an upload fails.
+pub fn upload_check(config: &Config, repo_root: &Path, report: &CheckReport) -> Result<()> {
+    let remote = Remote::from_config(&config.remote)?;
+    let path = repo_root.join(REPORT_PATH);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    atomic_write(
+        &path,
+        serde_json::to_string_pretty(&schema::versioned(report))?,
+    )?;
+    let count = remote::upload(&remote, repo_root, &path)?;
+    eprintln!(
+        "Uploaded check report and {count} cache files to {}",
+        remote.url("")
+    );
+    Ok(())
+}
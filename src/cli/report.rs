@@ -0,0 +1,33 @@
+// src/cli/report.rs
+//! Handler for spreadsheet export of scan results.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::analysis::Engine;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::fsutil;
+use crate::reporting::spreadsheet;
+use crate::xlsx;
+
+/// Handles `neti report --xlsx <FILE>`.
+///
+/// # Errors
+/// Returns error if file discovery fails or the workbook cannot be written.
+pub fn handle_report(xlsx_path: &Path) -> Result<NetiExit> {
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let scan = Engine::scan(&config, &files);
+    let bytes = xlsx::workbook(&spreadsheet::sheets(&scan));
+    fsutil::atomic_write(xlsx_path, bytes)
+        .with_context(|| format!("Failed to write {}", xlsx_path.display()))?;
+    let count: usize = scan.files.iter().map(|f| f.violations.len()).sum();
+    println!(
+        "Wrote {count} violations across {} files to {}",
+        scan.files.len(),
+        xlsx_path.display()
+    );
+    Ok(NetiExit::Success)
+}
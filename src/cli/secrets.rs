@@ -0,0 +1,30 @@
+// src/cli/secrets.rs
+//! Handler for accepting hardcoded-secret findings.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::secrets;
+
+use super::handlers::get_repo_root;
+
+/// Handles `neti secrets baseline`.
+///
+/// # Errors
+/// Returns error if discovery fails or the baseline cannot be written.
+pub fn handle_baseline() -> Result<NetiExit> {
+    let files = discovery::discover(&Config::load())?;
+    let count = secrets::write_baseline(&get_repo_root(), &files)?;
+    println!(
+        "{}",
+        format!(
+            "  Accepted {count} secret findings in {}",
+            secrets::BASELINE_PATH
+        )
+        .green()
+    );
+    Ok(NetiExit::Success)
+}
@@ -0,0 +1,77 @@
+// src/cli/status.rs
+//! Handler for the headless status snapshot.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::analysis::Engine;
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::reporting;
+use crate::status::{self, StatusReport};
+
+use super::handlers::get_repo_root;
+
+/// Handles the status command.
+///
+/// # Errors
+/// Returns error if file discovery fails.
+pub fn handle_status(json: bool) -> Result<NetiExit> {
+    let config = Config::load();
+    let files = discovery::discover(&config)?;
+    let scan = Engine::scan(&config, &files);
+    let report = status::collect(&get_repo_root(), &config, &scan);
+
+    if json {
+        reporting::print_json(&report)?;
+    } else {
+        print_text(&report);
+    }
+    Ok(NetiExit::Success)
+}
+
+fn print_text(report: &StatusReport) {
+    let scan = &report.scan;
+    let health = if scan.errors == 0 {
+        "clean".green().bold()
+    } else {
+        format!("{} errors", scan.errors).red().bold()
+    };
+    println!(
+        "  Scan:    {health}, {} warnings — {} files, {} tokens",
+        scan.warnings, scan.files, scan.total_tokens
+    );
+
+    let branch = &report.branch;
+    let current = branch.current.as_deref().unwrap_or("(not a git repo)");
+    let marker = if branch.on_work_branch {
+        " (work branch)".cyan()
+    } else {
+        "".normal()
+    };
+    println!(
+        "  Branch:  {current}{marker}, {} uncommitted files",
+        branch.uncommitted_files
+    );
+
+    if let Some(goal) = &branch.pending_goal {
+        println!("  Goal:    {goal}");
+    }
+
+    for event in &report.recent_events {
+        let kind = serde_json::to_value(&event.kind)
+            .ok()
+            .and_then(|v| match v {
+                serde_json::Value::String(s) => Some(s),
+                serde_json::Value::Object(map) => map.keys().next().cloned(),
+                _ => None,
+            })
+            .unwrap_or_default();
+        println!(
+            "  Event:   {} {}",
+            event.timestamp.to_string().dimmed(),
+            kind
+        );
+    }
+}
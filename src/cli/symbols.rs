@@ -0,0 +1,45 @@
+// src/cli/symbols.rs
+//! Handler for the symbol index search.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::discovery;
+use crate::exit::NetiExit;
+use crate::reporting;
+use crate::symbols::{Symbol, SymbolIndex};
+
+use super::handlers::get_repo_root;
+
+/// Lists symbols matching `query` (or all symbols when omitted).
+///
+/// # Errors
+/// Returns error if discovery fails or the index cannot be written.
+pub fn handle_symbols(query: Option<&str>, limit: usize, json: bool) -> Result<NetiExit> {
+    let files = discovery::discover(&Config::load())?;
+    let index = SymbolIndex::load_and_refresh(&get_repo_root(), &files)?;
+
+    let matches: Vec<&Symbol> = match query {
+        Some(q) => index.search(q, limit),
+        None => index.symbols().take(limit).collect(),
+    };
+
+    if json {
+        reporting::print_json(&matches)?;
+    } else if matches.is_empty() {
+        println!("{}", "No matching symbols.".yellow());
+    } else {
+        for symbol in &matches {
+            println!(
+                "{:<10} {} {}",
+                symbol.kind.dimmed(),
+                symbol.name.bold(),
+                format!("{}:{}", symbol.file.display(), symbol.line).cyan()
+            );
+            println!("           {}", symbol.signature.dimmed());
+        }
+    }
+
+    Ok(NetiExit::Success)
+}
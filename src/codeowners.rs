@@ -0,0 +1,204 @@
+// src/codeowners.rs
+//! `CODEOWNERS` parsing, for grouping scan results by owning team.
+//!
+//! The file is read from the first of `.github/CODEOWNERS`, `CODEOWNERS`
+//! and `docs/CODEOWNERS`, as GitHub does. Patterns use gitignore syntax and
+//! the last matching line wins; a pattern with no owners leaves its files
+//! unowned.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::discovery::gitignore::{gitignore_rule, Rule};
+use crate::types::{FileReport, ScanReport};
+
+/// Group name for files no `CODEOWNERS` line assigns.
+pub const UNOWNED: &str = "(unowned)";
+
+const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// How `neti scan --group-by` splits the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum GroupBy {
+    /// Owning team or user, from `CODEOWNERS`.
+    Owner,
+}
+
+#[derive(Debug)]
+struct Entry {
+    rule: Rule,
+    owners: Vec<String>,
+}
+
+/// Ownership rules from one `CODEOWNERS` file.
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    entries: Vec<Entry>,
+}
+
+impl CodeOwners {
+    /// Loads the project's `CODEOWNERS`, if it has one.
+    #[must_use]
+    pub fn load(root: &Path) -> Option<Self> {
+        LOCATIONS
+            .iter()
+            .find_map(|loc| fs::read_to_string(root.join(loc)).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    /// Parses `CODEOWNERS` content. Lines that are not valid patterns are
+    /// skipped.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let entries = content.lines().filter_map(entry).collect();
+        Self { entries }
+    }
+
+    /// Owners of `path` (relative to the root); empty when unowned.
+    #[must_use]
+    pub fn owners_of(&self, path: &Path) -> &[String] {
+        let rel = path.strip_prefix(".").unwrap_or(path);
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| covers(&e.rule, rel))
+            .map_or(&[], |e| e.owners.as_slice())
+    }
+
+    /// Splits `report` into one report per owner. A file with several
+    /// owners appears under each of them.
+    #[must_use]
+    pub fn group(&self, report: &ScanReport) -> BTreeMap<String, ScanReport> {
+        let mut groups: BTreeMap<String, Vec<FileReport>> = BTreeMap::new();
+        for file in &report.files {
+            for owner in self.group_names(&file.path) {
+                groups.entry(owner).or_default().push(file.clone());
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(owner, files)| (owner, sub_report(files, report.duration_ms)))
+            .collect()
+    }
+
+    fn group_names(&self, path: &Path) -> Vec<String> {
+        match self.owners_of(path) {
+            [] => vec![UNOWNED.to_string()],
+            owners => owners.to_vec(),
+        }
+    }
+}
+
+/// A file-name-safe form of an owner: `@acme/core` becomes `core`,
+/// `dev@acme.io` becomes `dev`.
+#[must_use]
+pub fn team_slug(owner: &str) -> String {
+    let name = owner.trim_start_matches('@');
+    let name = name.rsplit('/').next().unwrap_or(name);
+    let name = name.split('@').next().unwrap_or(name);
+    let slug: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    match slug.trim_matches('-') {
+        "" => "unowned".to_string(),
+        s => s.to_string(),
+    }
+}
+
+fn entry(line: &str) -> Option<Entry> {
+    let line = line.split_once(" #").map_or(line, |(before, _)| before);
+    let mut parts = line.split_whitespace();
+    let pattern = parts.next().filter(|p| !p.starts_with('#'))?;
+    let rule = gitignore_rule(pattern, Path::new(""))?;
+    let owners = parts.map(str::to_string).collect();
+    Some(Entry { rule, owners })
+}
+
+/// A pattern naming a directory owns everything below it.
+fn covers(rule: &Rule, rel: &Path) -> bool {
+    rule.matches(rel, false) == Some(true)
+        || rel
+            .ancestors()
+            .skip(1)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .any(|dir| rule.matches(dir, true) == Some(true))
+}
+
+fn sub_report(files: Vec<FileReport>, duration_ms: u128) -> ScanReport {
+    ScanReport {
+        total_tokens: files.iter().map(|f| f.token_count).sum(),
+        total_violations: files.iter().map(|f| f.violations.len()).sum(),
+        files,
+        duration_ms,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# Default owners
+*                   @acme/platform
+*.md                @acme/docs
+/src/analysis/      @acme/core dev@acme.io
+src/cli/args.rs
+docs/**             @acme/docs  # trailing comment
+";
+
+    fn owners(path: &str) -> Vec<String> {
+        CodeOwners::parse(SAMPLE)
+            .owners_of(Path::new(path))
+            .to_vec()
+    }
+
+    #[test]
+    fn test_last_matching_line_wins() {
+        assert_eq!(owners("src/lib.rs"), vec!["@acme/platform"]);
+        assert_eq!(owners("README.md"), vec!["@acme/docs"]);
+        assert_eq!(
+            owners("./src/analysis/worker.rs"),
+            vec!["@acme/core", "dev@acme.io"]
+        );
+        assert_eq!(
+            owners("src/analysis/patterns/mod.rs"),
+            vec!["@acme/core", "dev@acme.io"]
+        );
+        assert_eq!(owners("docs/guide/intro.txt"), vec!["@acme/docs"]);
+    }
+
+    #[test]
+    fn test_pattern_without_owners_unassigns() {
+        assert!(owners("src/cli/args.rs").is_empty());
+        let report = ScanReport {
+            files: vec![FileReport {
+                path: "src/cli/args.rs".into(),
+                token_count: 10,
+                complexity_score: 0,
+                violations: Vec::new(),
+                analysis: None,
+                suppressions: Default::default(),
+            }],
+            ..ScanReport::default()
+        };
+        let groups = CodeOwners::parse(SAMPLE).group(&report);
+        assert_eq!(groups.keys().collect::<Vec<_>>(), vec![UNOWNED]);
+    }
+
+    #[test]
+    fn test_team_slug() {
+        assert_eq!(team_slug("@acme/core"), "core");
+        assert_eq!(team_slug("@octocat"), "octocat");
+        assert_eq!(team_slug("dev@acme.io"), "dev");
+        assert_eq!(team_slug(UNOWNED), "unowned");
+    }
+}
@@ -0,0 +1,154 @@
+// src/commit_message.rs
+//! Commit message construction for `neti promote`.
+//!
+//! Expands the `[git] commit_template` placeholders and, when
+//! `conventional = true`, coerces the result into Conventional Commits form
+//! with a type inferred from the goal text.
+
+use crate::config::GitConfig;
+use crate::ratchet::Counts;
+
+#[cfg(test)]
+#[path = "commit_message_test.rs"]
+mod tests;
+
+/// Conventional Commit types Neti recognizes.
+const CONVENTIONAL_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// Values available to the commit template.
+pub struct MessageContext<'a> {
+    /// The pending goal, if one was recorded.
+    pub goal: Option<&'a str>,
+    /// Number of files changed by the promotion.
+    pub files: usize,
+    /// The branch being promoted.
+    pub branch: &'a str,
+    /// Violation counts by rule, for `{rules}`. `None` drops the placeholder.
+    pub rules: Option<&'a Counts>,
+}
+
+/// Builds the promote commit message from config and context.
+#[must_use]
+pub fn build(config: &GitConfig, ctx: &MessageContext) -> String {
+    let goal_text = ctx.goal.map(str::trim).filter(|g| !g.is_empty());
+    let raw_goal = goal_text.map_or_else(|| format!("promote {}", ctx.branch), ToString::to_string);
+
+    let (commit_type, goal) = match split_conventional(&raw_goal) {
+        Some((t, rest)) => (t.to_string(), rest.to_string()),
+        None if goal_text.is_none() => ("chore".to_string(), raw_goal),
+        None => (infer_type(&raw_goal).to_string(), raw_goal),
+    };
+
+    let files = ctx.files.to_string();
+    let rules = ctx.rules.map(rule_counts).unwrap_or_default();
+    let message = expand(&config.commit_template, |name| match name {
+        "goal" => Some(goal.as_str()),
+        "type" => Some(commit_type.as_str()),
+        "files" => Some(files.as_str()),
+        "branch" => Some(ctx.branch),
+        "rules" => Some(rules.as_str()),
+        _ => None,
+    });
+
+    if !config.conventional || is_conventional(&message) {
+        return message;
+    }
+    format!("{commit_type}: {message}")
+}
+
+/// Replaces each `{name}` in `template` with `value(name)` in a single pass,
+/// so braces inside a substituted goal are never expanded. Unknown
+/// placeholders are kept as written.
+fn expand<'v>(template: &str, value: impl Fn(&str) -> Option<&'v str>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let (before, tail) = rest.split_at(open);
+        out.push_str(before);
+        rest = tail;
+        let Some(close) = tail.find('}') else {
+            break;
+        };
+        if let Some(v) = value(&tail[1..close]) {
+            out.push_str(v);
+            rest = &tail[close + 1..];
+        } else {
+            out.push('{');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `{rules}` text: each rule with violations and its count, e.g.
+/// `P01: 2, X01: 1`.
+fn rule_counts(counts: &Counts) -> String {
+    if counts.is_empty() {
+        return "no violations".to_string();
+    }
+    let parts: Vec<String> = counts
+        .iter()
+        .map(|(rule, n)| format!("{rule}: {n}"))
+        .collect();
+    parts.join(", ")
+}
+
+/// Infers a Conventional Commit type from free-form goal text.
+#[must_use]
+pub fn infer_type(goal: &str) -> &'static str {
+    let lower = goal.to_lowercase();
+    let first = lower.split_whitespace().next().unwrap_or("");
+
+    let keyword_types: &[(&[&str], &str)] = &[
+        (
+            &["fix", "fixes", "fixed", "bug", "repair", "resolve"],
+            "fix",
+        ),
+        (
+            &["refactor", "restructure", "extract", "rename", "split"],
+            "refactor",
+        ),
+        (&["doc", "docs", "document", "readme"], "docs"),
+        (&["test", "tests", "cover"], "test"),
+        (&["perf", "optimize", "speed"], "perf"),
+        (&["remove", "bump", "update", "cleanup", "clean"], "chore"),
+    ];
+
+    for (words, commit_type) in keyword_types {
+        if words.contains(&first) {
+            return commit_type;
+        }
+    }
+
+    if lower.contains("bug") || lower.contains("crash") || lower.contains("regression") {
+        return "fix";
+    }
+    "feat"
+}
+
+/// Returns true if the subject line follows `type(scope)!: description`.
+#[must_use]
+pub fn is_conventional(message: &str) -> bool {
+    let subject = message.lines().next().unwrap_or("");
+    split_conventional(subject).is_some_and(|(_, rest)| !rest.is_empty())
+}
+
+/// Splits `type(scope): rest` into the full type head and the description.
+fn split_conventional(text: &str) -> Option<(&str, &str)> {
+    let (head, rest) = text.split_once(':')?;
+    let bare = head.trim_end_matches('!');
+    let commit_type = match bare.split_once('(') {
+        Some((t, scope)) if scope.ends_with(')') => t,
+        Some(_) => return None,
+        None => bare,
+    };
+
+    if CONVENTIONAL_TYPES.contains(&commit_type) {
+        Some((head, rest.trim()))
+    } else {
+        None
+    }
+}
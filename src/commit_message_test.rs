@@ -0,0 +1,99 @@
+use super::*;
+
+fn ctx(goal: Option<&str>) -> MessageContext<'_> {
+    MessageContext {
+        goal,
+        files: 3,
+        branch: "neti-work",
+        rules: None,
+    }
+}
+
+#[test]
+fn default_template_infers_type() {
+    let msg = build(&GitConfig::default(), &ctx(Some("Fix crash in parser")));
+    assert_eq!(msg, "fix: Fix crash in parser (promoted)");
+}
+
+#[test]
+fn existing_prefix_is_preserved() {
+    let msg = build(&GitConfig::default(), &ctx(Some("docs(readme): add usage")));
+    assert_eq!(msg, "docs(readme): add usage (promoted)");
+}
+
+#[test]
+fn missing_goal_falls_back_to_chore() {
+    let msg = build(&GitConfig::default(), &ctx(None));
+    assert_eq!(msg, "chore: promote neti-work (promoted)");
+}
+
+#[test]
+fn template_placeholders_expand() {
+    let config = GitConfig {
+        commit_template: "{goal}\n\n{files} files from {branch}".into(),
+        ..GitConfig::default()
+    };
+    let msg = build(&config, &ctx(Some("add locality layers")));
+    assert_eq!(msg, "feat: add locality layers\n\n3 files from neti-work");
+}
+
+#[test]
+fn placeholders_in_the_goal_stay_literal() {
+    let config = GitConfig {
+        commit_template: "{goal} on {branch} {unknown}".into(),
+        ..GitConfig::default()
+    };
+    let msg = build(&config, &ctx(Some("add {branch} and {files} {")));
+    assert_eq!(
+        msg,
+        "feat: add {branch} and {files} { on neti-work {unknown}"
+    );
+}
+
+#[test]
+fn rules_placeholder_lists_counts() {
+    let config = GitConfig {
+        commit_template: "{goal}\n\nViolations: {rules}".into(),
+        ..GitConfig::default()
+    };
+    let counts: Counts = [("P01".to_string(), 2), ("X01".to_string(), 1)].into();
+    let msg = build(
+        &config,
+        &MessageContext {
+            rules: Some(&counts),
+            ..ctx(Some("fix parser"))
+        },
+    );
+    assert_eq!(msg, "fix: fix parser\n\nViolations: P01: 2, X01: 1");
+    let clean = Counts::new();
+    let msg = build(
+        &config,
+        &MessageContext {
+            rules: Some(&clean),
+            ..ctx(Some("fix parser"))
+        },
+    );
+    assert!(msg.ends_with("Violations: no violations"));
+    let msg = build(&config, &ctx(Some("fix parser")));
+    assert_eq!(msg, "fix: fix parser\n\nViolations: ");
+}
+
+#[test]
+fn conventional_off_keeps_template_verbatim() {
+    let config = GitConfig {
+        commit_template: "{goal}".into(),
+        conventional: false,
+        ..GitConfig::default()
+    };
+    let msg = build(&config, &ctx(Some("Add locality layers")));
+    assert_eq!(msg, "Add locality layers");
+}
+
+#[test]
+fn validates_conventional_subjects() {
+    assert!(is_conventional("feat: add thing"));
+    assert!(is_conventional("fix(scan)!: handle empty"));
+    assert!(!is_conventional("feature: add thing"));
+    assert!(!is_conventional("feat:"));
+    assert!(!is_conventional("Add thing"));
+}
@@ -0,0 +1,149 @@
+// src/compare.rs
+//! Scan deltas (`neti scan --compare old.json`).
+//!
+//! A previous `neti scan --json` report is matched against the current scan
+//! violation by violation. Findings pair up when they share a file and rule:
+//! first by identical message at the nearest line, then by the nearest line
+//! within `LINE_TOLERANCE`, so edits above a violation do not make it look
+//! new. Unpaired current findings are new; unpaired old ones are fixed.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::normalize;
+use crate::types::ScanReport;
+
+#[cfg(test)]
+#[path = "compare_test.rs"]
+mod tests;
+
+/// How far a violation may move, in lines, and still match by position.
+pub const LINE_TOLERANCE: usize = 10;
+
+/// One violation, as written to or read back from a JSON report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub file: PathBuf,
+    pub row: usize,
+    pub law: String,
+    pub message: String,
+}
+
+/// The delta between two scans.
+#[derive(Debug, Default, Serialize)]
+pub struct ScanDiff {
+    pub new: Vec<Finding>,
+    pub fixed: Vec<Finding>,
+    pub persisting: Vec<Finding>,
+}
+
+#[derive(Deserialize)]
+struct PriorReport {
+    files: Vec<PriorFile>,
+}
+
+#[derive(Deserialize)]
+struct PriorFile {
+    path: PathBuf,
+    violations: Vec<PriorViolation>,
+}
+
+#[derive(Deserialize)]
+struct PriorViolation {
+    row: usize,
+    law: String,
+    message: String,
+}
+
+/// Reads the findings of a `neti scan --json` report.
+///
+/// # Errors
+/// Returns error if the file cannot be read or is not a scan report.
+pub fn load(path: &Path) -> Result<Vec<Finding>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let prior: PriorReport = serde_json::from_str(&content)
+        .with_context(|| format!("{} is not a `neti scan --json` report", path.display()))?;
+    let findings = prior.files.into_iter().flat_map(|f| {
+        let file = normalize(&f.path);
+        f.violations.into_iter().map(move |v| Finding {
+            file: file.clone(),
+            row: v.row,
+            law: v.law,
+            message: v.message,
+        })
+    });
+    Ok(findings.collect())
+}
+
+/// The findings of a scan just run.
+#[must_use]
+pub fn findings(report: &ScanReport) -> Vec<Finding> {
+    report
+        .files
+        .iter()
+        .flat_map(|f| {
+            let file = normalize(&f.path);
+            f.violations.iter().map(move |v| Finding {
+                file: file.clone(),
+                row: v.row,
+                law: v.law.into(),
+                message: v.message.clone(),
+            })
+        })
+        .collect()
+}
+
+/// `/`-separated and relative to the scan root, so reports written on
+/// Windows compare against scans run elsewhere.
+fn normalize(path: &Path) -> PathBuf {
+    let path = normalize::path_str(path);
+    PathBuf::from(path.strip_prefix("./").unwrap_or(&path))
+}
+
+/// Splits `current` into new and persisting findings, and `old` into fixed
+/// ones.
+#[must_use]
+pub fn diff(old: Vec<Finding>, current: Vec<Finding>) -> ScanDiff {
+    let mut old: Vec<Option<Finding>> = old.into_iter().map(Some).collect();
+    let mut matched = vec![false; current.len()];
+    for exact in [true, false] {
+        for (finding, done) in current.iter().zip(matched.iter_mut()) {
+            *done = *done || take_nearest(&mut old, finding, exact);
+        }
+    }
+    let mut out = ScanDiff {
+        fixed: old.into_iter().flatten().collect(),
+        ..ScanDiff::default()
+    };
+    for (finding, done) in current.into_iter().zip(matched) {
+        if done {
+            out.persisting.push(finding);
+        } else {
+            out.new.push(finding);
+        }
+    }
+    out
+}
+
+/// Removes the unmatched old finding closest to `finding`, if any
+/// qualifies, and reports whether one did.
+fn take_nearest(old: &mut [Option<Finding>], finding: &Finding, exact: bool) -> bool {
+    let nearest = old
+        .iter_mut()
+        .filter(|o| {
+            o.as_ref().is_some_and(|o| {
+                o.file == finding.file
+                    && o.law == finding.law
+                    && (!exact || o.message == finding.message)
+                    && (exact || o.row.abs_diff(finding.row) <= LINE_TOLERANCE)
+            })
+        })
+        .min_by_key(|o| {
+            o.as_ref()
+                .map_or(usize::MAX, |o| o.row.abs_diff(finding.row))
+        });
+    nearest.and_then(Option::take).is_some()
+}
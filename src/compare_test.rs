@@ -0,0 +1,48 @@
+use super::*;
+
+fn finding(file: &str, row: usize, law: &str, message: &str) -> Finding {
+    Finding {
+        file: PathBuf::from(file),
+        row,
+        law: law.into(),
+        message: message.into(),
+    }
+}
+
+fn rows(findings: &[Finding]) -> Vec<(&str, usize)> {
+    findings.iter().map(|f| (f.law.as_str(), f.row)).collect()
+}
+
+#[test]
+fn shifted_violations_persist() {
+    let old = vec![
+        finding("src/a.rs", 10, "P01", "clone in loop"),
+        finding("src/a.rs", 40, "E01", "Result of `save()` discarded"),
+    ];
+    let current = vec![
+        finding("src/a.rs", 16, "P01", "clone in loop"),
+        finding("src/a.rs", 95, "E01", "Result of `save()` discarded"),
+    ];
+    let d = diff(old, current);
+    assert!(d.new.is_empty());
+    assert!(d.fixed.is_empty());
+    assert_eq!(rows(&d.persisting), vec![("P01", 16), ("E01", 95)]);
+}
+
+#[test]
+fn new_and_fixed_are_split_by_rule_and_file() {
+    let old = vec![
+        finding("src/a.rs", 10, "P01", "clone in loop"),
+        finding("src/b.rs", 5, "L03", "index"),
+    ];
+    let current = vec![
+        finding("src/a.rs", 11, "P01", "clone of `items` in loop"),
+        finding("src/a.rs", 30, "P01", "clone of `rows` in loop"),
+        finding("src/c.rs", 5, "L03", "index"),
+    ];
+    let d = diff(old, current);
+    assert_eq!(rows(&d.persisting), vec![("P01", 11)]);
+    assert_eq!(rows(&d.new), vec![("P01", 30), ("L03", 5)]);
+    assert_eq!(rows(&d.fixed), vec![("L03", 5)]);
+    assert_eq!(d.fixed[0].file, PathBuf::from("src/b.rs"));
+}
@@ -0,0 +1,29 @@
+//! Configuration for intentional-debt tracking (D01) and `neti:off` overuse (D03).
+
+use serde::{Deserialize, Serialize};
+
+/// Debt-marker settings from neti.toml `[rules.debt]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebtConfig {
+    /// Report debt comments older than `max_age_days`. Default: true
+    pub enabled: bool,
+    /// Age, by `git blame` author date, past which a marker is reported.
+    /// Default: 180
+    pub max_age_days: u32,
+    /// Comment markers that record debt. Default: TODO, FIXME, HACK
+    pub markers: Vec<String>,
+    /// Share of a file `neti:off` regions may cover before D03. Default: 50
+    pub max_suppressed_percent: usize,
+}
+
+impl Default for DebtConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_age_days: 180,
+            markers: ["TODO", "FIXME", "HACK"].map(String::from).to_vec(),
+            max_suppressed_percent: 50,
+        }
+    }
+}
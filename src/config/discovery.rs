@@ -0,0 +1,47 @@
+//! Configuration for which files discovery skips as binary, minified or
+//! generated.
+
+use serde::{Deserialize, Serialize};
+
+/// File discovery heuristics from neti.toml `[discovery]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    /// Files above this byte entropy (bits per byte, 0-8) are treated as
+    /// binary or encoded blobs. Source code sits around 4.5-5.4; base64 is
+    /// about 6. Default: 5.8
+    pub max_entropy: f64,
+    /// Files whose average line length exceeds this are treated as
+    /// minified. Default: 300
+    pub max_avg_line_length: usize,
+    /// Skip files marked as generated (a `DO NOT EDIT` style header, or
+    /// protobuf output names). Default: true
+    pub skip_generated: bool,
+    /// Path regexes that are always scanned, whatever the heuristics say.
+    /// `.netiignore` still applies.
+    pub force_include: Vec<String>,
+    /// Honor `.gitignore` files, git's exclude files and `.netiignore`.
+    /// `--no-ignore` turns this off for one run. Default: true
+    pub ignore_files: bool,
+    /// Files larger than this many bytes are streamed for token counts and
+    /// secrets, and skip pattern and AST analysis. Default: 1 MiB
+    pub max_analysis_bytes: u64,
+    /// Ceiling, in MiB, on the estimated memory of the files being analyzed
+    /// at once. When set, files are analyzed in batches that fit it instead
+    /// of all at once. `--max-mem` sets it for one run. Default: 0 (no ceiling)
+    pub max_memory_mb: u64,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            max_entropy: 5.8,
+            max_avg_line_length: 300,
+            skip_generated: true,
+            force_include: Vec::new(),
+            ignore_files: true,
+            max_analysis_bytes: 1024 * 1024,
+            max_memory_mb: 0,
+        }
+    }
+}
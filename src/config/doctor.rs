@@ -0,0 +1,165 @@
+// src/config/doctor.rs
+//! Explains the effective configuration: every setting with its value and
+//! the file, line, and profile it came from.
+
+use std::path::{Path, PathBuf};
+
+use super::io;
+use super::layers::Pinned;
+use super::types::Config;
+use super::validate::locate;
+
+/// One effective setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    /// Dotted key, e.g. `rules.max_file_tokens`.
+    pub key: String,
+    pub value: String,
+    pub source: Source,
+}
+
+/// Where a setting's value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Built-in default.
+    Default,
+    /// Detected from the project type (`[commands]` only).
+    ProjectDefault,
+    /// Pinned by a `NETI_*` environment variable.
+    Env(String),
+    /// Pinned by `--set`.
+    Cli,
+    /// Set in a file, optionally inside `[profile.<name>]`.
+    File {
+        file: PathBuf,
+        line: Option<usize>,
+        profile: Option<String>,
+    },
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::ProjectDefault => write!(f, "project default"),
+            Self::Env(var) => write!(f, "env {var}"),
+            Self::Cli => write!(f, "--set"),
+            Self::File {
+                file,
+                line,
+                profile,
+            } => {
+                write!(f, "{}", file.display())?;
+                if let Some(line) = line {
+                    write!(f, ":{line}")?;
+                }
+                if let Some(profile) = profile {
+                    write!(f, " (profile {profile})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Settings of the root config, then those each nested `neti.toml` sets
+/// for its subtree (keyed by that file). `pinned` are the environment and
+/// CLI overrides in effect, which outrank every file.
+#[must_use]
+pub fn explain(
+    config: &Config,
+    root: &Path,
+    profile: Option<&str>,
+    pinned: &[Pinned],
+) -> Vec<Setting> {
+    let root_file = PathBuf::from("neti.toml");
+    let effective = toml::Value::try_from(io::to_document(config))
+        .unwrap_or_else(|_| toml::Value::Table(toml::Table::new()));
+    let mut settings = attribute(&effective, root, &root_file, profile, &|key| {
+        if key.starts_with("commands.") {
+            Source::ProjectDefault
+        } else {
+            Source::Default
+        }
+    });
+
+    for rule_override in &config.rule_overrides {
+        let file = rule_override.dir.join("neti.toml");
+        let mut section = toml::Table::new();
+        if let Ok(rules) = toml::Value::try_from(&rule_override.rules) {
+            section.insert("rules".into(), rules);
+        }
+        let nested = attribute(&toml::Value::Table(section), root, &file, profile, &|_| {
+            Source::Default
+        });
+        settings.extend(
+            nested
+                .into_iter()
+                .filter(|s| matches!(&s.source, Source::File { file: from, .. } if *from == file)),
+        );
+    }
+    for setting in &mut settings {
+        if let Some(p) = pinned.iter().rev().find(|p| p.key == setting.key) {
+            setting.source = p.env_var.clone().map_or(Source::Cli, Source::Env);
+        }
+    }
+    settings
+}
+
+/// Pairs each leaf of `effective` with the place in `file` that set it,
+/// or `fallback` when the file does not mention it.
+fn attribute(
+    effective: &toml::Value,
+    root: &Path,
+    file: &Path,
+    profile: Option<&str>,
+    fallback: &dyn Fn(&str) -> Source,
+) -> Vec<Setting> {
+    let content = std::fs::read_to_string(root.join(file)).unwrap_or_default();
+    let doc: toml::Value = content
+        .parse()
+        .unwrap_or_else(|_| toml::Value::Table(toml::Table::new()));
+    let profile_body = profile.and_then(|p| doc.get("profile")?.get(p));
+
+    let mut leaves = Vec::new();
+    flatten(effective, "", &mut leaves);
+    leaves
+        .into_iter()
+        .map(|(key, value)| {
+            let source = match (profile_body, profile) {
+                (Some(body), Some(name)) if lookup(body, &key).is_some() => Source::File {
+                    file: file.to_path_buf(),
+                    line: locate(&content, &format!("profile.{name}.{key}")),
+                    profile: Some(name.to_string()),
+                },
+                _ if lookup(&doc, &key).is_some() => Source::File {
+                    file: file.to_path_buf(),
+                    line: locate(&content, &key),
+                    profile: None,
+                },
+                _ => fallback(&key),
+            };
+            Setting { key, value, source }
+        })
+        .collect()
+}
+
+fn flatten(value: &toml::Value, prefix: &str, out: &mut Vec<(String, String)>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, child) in table {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(child, &path, out);
+            }
+        }
+        leaf => out.push((prefix.to_string(), leaf.to_string())),
+    }
+}
+
+fn lookup<'a>(value: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    key.split('.').try_fold(value, |v, part| v.get(part))
+}
@@ -0,0 +1,63 @@
+//! Configuration for Neti's git workflow (`neti promote`, `neti clean --commit`).
+
+use serde::{Deserialize, Serialize};
+
+/// Git workflow configuration from neti.toml `[git]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitConfig {
+    /// Template for the promote commit message.
+    /// Placeholders: `{goal}`, `{type}`, `{files}`, `{branch}`, and `{rules}`
+    /// (violation counts by rule, as recorded by the last `neti check`).
+    pub commit_template: String,
+    /// Format and validate messages as Conventional Commits. Default: true
+    pub conventional: bool,
+    /// Push the target branch after a successful promote. Default: false
+    pub push: bool,
+    /// Sign Neti's commits (`git commit -S`). Default: false
+    pub sign: bool,
+    /// Key for `-S<key>`. Falls back to git's `user.signingkey` when unset.
+    pub signing_key: Option<String>,
+    /// Signature format: "openpgp", "ssh", or "x509". Falls back to git's `gpg.format`.
+    pub signing_format: Option<String>,
+    /// Author identity for Neti's commits, e.g. "Neti Bot <bot@example.com>".
+    pub author: Option<String>,
+    /// Committer identity for Neti's commits. Defaults to `author` when unset.
+    pub committer: Option<String>,
+    /// "single" (shared `neti-work` branch, squash-merged into main) or
+    /// "per-goal" (`neti/<slug-of-goal>`, pushed for review). Default: "single"
+    pub branch_strategy: String,
+    /// Open a pull request with `gh` when promoting a per-goal branch. Default: false
+    pub open_pr: bool,
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            commit_template: "{type}: {goal} (promoted)".to_string(),
+            conventional: true,
+            push: false,
+            sign: false,
+            signing_key: None,
+            signing_format: None,
+            author: None,
+            committer: None,
+            branch_strategy: "single".to_string(),
+            open_pr: false,
+        }
+    }
+}
+
+impl GitConfig {
+    /// Returns the committer identity, falling back to the author override.
+    #[must_use]
+    pub fn effective_committer(&self) -> Option<&str> {
+        self.committer.as_deref().or(self.author.as_deref())
+    }
+
+    /// Returns true if each goal gets its own branch instead of `neti-work`.
+    #[must_use]
+    pub fn per_goal_branches(&self) -> bool {
+        self.branch_strategy == "per-goal"
+    }
+}
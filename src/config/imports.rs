@@ -0,0 +1,27 @@
+//! Configuration for import hygiene (I03, I04).
+
+use serde::{Deserialize, Serialize};
+
+/// Import settings from neti.toml `[rules.imports]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportsConfig {
+    /// Check imports. Default: true
+    pub enabled: bool,
+    /// Path segments under which a wildcard import is accepted.
+    /// Default: prelude
+    pub wildcard_allow: Vec<String>,
+    /// Most parent directories a relative import may climb (`../` in
+    /// TypeScript, the dots past the first in Python). Default: 2
+    pub max_relative_depth: usize,
+}
+
+impl Default for ImportsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            wildcard_allow: vec!["prelude".into()],
+            max_relative_depth: 2,
+        }
+    }
+}
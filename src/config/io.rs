@@ -1,7 +1,10 @@
 // src/config/io.rs
-use super::types::{CommandEntry, Config, NetiToml, Preferences, RuleConfig};
+use super::types::{CommandEntry, Config, NetiToml};
+use super::{layers, overrides};
+
+pub use super::save::{merge_edits, save_config, save_to_file, to_document};
+use crate::logging;
 use crate::project::{self, ProjectType};
-use anyhow::{anyhow, Result};
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
@@ -27,26 +30,79 @@ pub fn process_ignore_line(config: &mut Config, line: &str) {
 }
 
 pub fn load_toml_config(config: &mut Config) {
-    if !Path::new("neti.toml").exists() {
-        return;
-    }
-    let Ok(content) = fs::read_to_string("neti.toml") else {
+    let profile = overrides::active_profile();
+    let _span = logging::span("config");
+    let content = fs::read_to_string("neti.toml").unwrap_or_default();
+    log::debug!(
+        "neti.toml: {} bytes, profile {}",
+        content.len(),
+        profile.as_deref().unwrap_or("(none)")
+    );
+    let Some(mut doc) = resolve(&content, profile.as_deref()) else {
         return;
     };
-    parse_toml(config, &content);
+    let pinned = layers::current();
+    for p in &pinned {
+        log::debug!("override {} = {}", p.key, p.value);
+    }
+    layers::apply(&mut doc, &pinned);
+    apply_document(config, doc.clone());
+
+    let base_rules = doc
+        .get("rules")
+        .cloned()
+        .unwrap_or_else(|| toml::Value::Table(toml::Table::new()));
+    let pinned_rules = layers::rules_part(&pinned);
+    match overrides::collect(
+        Path::new("."),
+        &base_rules,
+        &pinned_rules,
+        profile.as_deref(),
+    ) {
+        Ok(found) => config.rule_overrides = found,
+        Err(e) => log::warn!("{e}"),
+    }
 }
 
 pub fn parse_toml(config: &mut Config, content: &str) {
-    let Ok(parsed) = toml::from_str::<NetiToml>(content) else {
+    let profile = overrides::active_profile();
+    if let Some(mut doc) = resolve(content, profile.as_deref()) {
+        layers::apply(&mut doc, &layers::current());
+        apply_document(config, doc);
+    }
+}
+
+fn resolve(content: &str, profile: Option<&str>) -> Option<toml::Value> {
+    match overrides::resolve_document(content, profile) {
+        Ok(doc) => Some(doc),
+        Err(e) => {
+            log::warn!("neti.toml: {e}");
+            None
+        }
+    }
+}
+
+fn apply_document(config: &mut Config, doc: toml::Value) {
+    let Ok(parsed) = doc.try_into::<NetiToml>() else {
         return;
     };
     config.rules = parsed.rules;
     config.preferences = parsed.preferences;
+    config.mutate_gate = match parsed.commands.get("mutate") {
+        Some(CommandEntry::Mutate(gate)) => Some(*gate),
+        _ => None,
+    };
     config.commands = parsed
         .commands
         .into_iter()
+        .filter(|(_, v)| !matches!(v, CommandEntry::Mutate(_)))
         .map(|(k, v)| (k, v.into_vec()))
         .collect();
+    config.git = parsed.git;
+    config.tui = parsed.tui;
+    config.discovery = parsed.discovery;
+    config.notifications = parsed.notifications;
+    config.remote = parsed.remote;
 }
 
 pub fn apply_project_defaults(config: &mut Config) {
@@ -59,34 +115,6 @@ pub fn apply_project_defaults(config: &mut Config) {
     }
 }
 
-/// Saves the configuration to the file system.
-///
-/// # Errors
-/// Returns an error if the config cannot be serialized or written to disk.
-#[allow(clippy::implicit_hasher)]
-pub fn save_to_file(
-    rules: &RuleConfig,
-    prefs: &Preferences,
-    commands: &HashMap<String, Vec<String>>,
-) -> Result<()> {
-    let cmd_entries: HashMap<String, CommandEntry> = commands
-        .iter()
-        .map(|(k, v)| (k.clone(), CommandEntry::List(v.clone())))
-        .collect();
-
-    let toml_struct = NetiToml {
-        rules: rules.clone(),
-        preferences: prefs.clone(),
-        commands: cmd_entries,
-    };
-
-    let content = toml::to_string_pretty(&toml_struct)
-        .map_err(|e| anyhow!("Failed to serialize config: {e}"))?;
-
-    fs::write("neti.toml", content)?;
-    Ok(())
-}
-
 fn project_defaults(project: ProjectType) -> HashMap<String, Vec<String>> {
     let mut m = HashMap::new();
     match project {
@@ -0,0 +1,225 @@
+// src/config/layers.rs
+//! Environment and command-line overrides, layered over `neti.toml`.
+//!
+//! Resolution order is defaults < file (with profile) < environment < CLI.
+//! Every setting can be pinned from the environment as `NETI_` plus its
+//! dotted key in upper snake case (`rules.max_file_tokens` becomes
+//! `NETI_RULES_MAX_FILE_TOKENS`), or from the CLI with
+//! `--set rules.max_file_tokens=1500`.
+
+use std::sync::{Once, OnceLock};
+
+use super::types::NetiToml;
+use super::validate::known_keys;
+
+/// `[commands]` keys that may be overridden; other command names are
+/// user-defined and have no environment variable.
+const COMMAND_KEYS: [&str; 2] = ["commands.check", "commands.fix"];
+
+/// Accepted in files for compatibility, but pinning it would clash with
+/// `rules.max_cognitive_complexity`.
+const ALIASES: [&str; 1] = ["rules.max_cyclomatic_complexity"];
+
+static CLI_OVERRIDES: OnceLock<Vec<(String, toml::Value)>> = OnceLock::new();
+static ENV_WARNINGS: Once = Once::new();
+
+/// One pinned setting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pinned {
+    /// Dotted key, e.g. `rules.max_file_tokens`.
+    pub key: String,
+    pub value: toml::Value,
+    /// The environment variable it came from; `None` for `--set`.
+    pub env_var: Option<String>,
+}
+
+/// Records `--set` assignments for the rest of the process. Later calls
+/// are ignored.
+pub fn set_cli_overrides(assignments: Vec<(String, toml::Value)>) {
+    let _ = CLI_OVERRIDES.set(assignments);
+}
+
+/// Every override in effect, environment first, then CLI, so applying
+/// them in order gives the CLI the last word.
+#[must_use]
+pub fn current() -> Vec<Pinned> {
+    let (mut pinned, rejected) = from_env(|var| std::env::var(var).ok());
+    ENV_WARNINGS.call_once(|| {
+        for message in &rejected {
+            log::warn!("{message}");
+        }
+    });
+    pinned.extend(
+        CLI_OVERRIDES
+            .get()
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| Pinned {
+                key: key.clone(),
+                value: value.clone(),
+                env_var: None,
+            }),
+    );
+    pinned
+}
+
+/// Collects overrides from the environment through `lookup`, plus a
+/// message for each variable whose value has the wrong type.
+#[must_use]
+pub fn from_env(lookup: impl Fn(&str) -> Option<String>) -> (Vec<Pinned>, Vec<String>) {
+    let mut pinned = Vec::new();
+    let mut rejected = Vec::new();
+    for key in overridable_keys() {
+        let var = env_var_for(&key);
+        let Some(text) = lookup(&var) else {
+            continue;
+        };
+        match typed(&key, &text) {
+            Ok(value) => pinned.push(Pinned {
+                key,
+                value,
+                env_var: Some(var),
+            }),
+            Err(e) => rejected.push(format!("ignoring {var}: {e}")),
+        }
+    }
+    (pinned, rejected)
+}
+
+/// Parses a `KEY=VALUE` assignment for `--set`.
+///
+/// # Errors
+/// Returns a message if the key is unknown or the value has the wrong type.
+pub fn parse_assignment(input: &str) -> Result<(String, toml::Value), String> {
+    let (key, raw) = input
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{input}'"))?;
+    let key = key.trim();
+    if !overridable_keys().iter().any(|k| k == key) {
+        return Err(format!("unknown config key '{key}'"));
+    }
+    Ok((key.to_string(), typed(key, raw.trim())?))
+}
+
+/// Applies pinned settings to a document, in order.
+pub fn apply(doc: &mut toml::Value, pinned: &[Pinned]) {
+    for p in pinned {
+        super::overrides::merge(doc, nest(&p.key, p.value.clone()));
+    }
+}
+
+/// The `[rules]` part of the pinned settings, for nested `neti.toml` files.
+#[must_use]
+pub fn rules_part(pinned: &[Pinned]) -> toml::Value {
+    let mut doc = toml::Value::Table(toml::Table::new());
+    let rules: Vec<Pinned> = pinned
+        .iter()
+        .filter(|p| p.key.starts_with("rules."))
+        .cloned()
+        .collect();
+    apply(&mut doc, &rules);
+    doc.get("rules")
+        .cloned()
+        .unwrap_or_else(|| toml::Value::Table(toml::Table::new()))
+}
+
+/// `NETI_` plus the dotted key in upper snake case.
+#[must_use]
+pub fn env_var_for(key: &str) -> String {
+    format!("NETI_{}", key.replace('.', "_").to_uppercase())
+}
+
+/// Parses `raw` as a TOML value and checks that it fits `key`. Text that
+/// is not TOML, or that only fits as a string, is taken as a string.
+fn typed(key: &str, raw: &str) -> Result<toml::Value, String> {
+    let as_string = toml::Value::String(raw.to_string());
+    let parsed = format!("v = {raw}")
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut t| t.remove("v"));
+    let candidates = parsed.into_iter().chain(std::iter::once(as_string));
+    let mut last_error = String::new();
+    for value in candidates {
+        match fits(key, &value) {
+            Ok(()) => return Ok(value),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(format!("invalid value '{raw}' for {key}: {last_error}"))
+}
+
+fn fits(key: &str, value: &toml::Value) -> Result<(), String> {
+    let mut probe = toml::Value::try_from(NetiToml::default()).map_err(|e| e.to_string())?;
+    super::overrides::merge(&mut probe, nest(key, value.clone()));
+    probe
+        .try_into::<NetiToml>()
+        .map(|_| ())
+        .map_err(|e| e.message().to_string())
+}
+
+fn nest(key: &str, value: toml::Value) -> toml::Value {
+    key.rsplit('.').fold(value, |inner, part| {
+        toml::Value::Table(toml::Table::from_iter([(part.to_string(), inner)]))
+    })
+}
+
+/// Dotted keys of every scalar or list setting.
+fn overridable_keys() -> Vec<String> {
+    let mut keys = Vec::new();
+    leaf_keys(&known_keys(), "", &mut keys);
+    keys.retain(|k| !ALIASES.contains(&k.as_str()));
+    keys.extend(COMMAND_KEYS.iter().map(|k| (*k).to_string()));
+    keys
+}
+
+fn leaf_keys(value: &toml::Value, prefix: &str, out: &mut Vec<String>) {
+    let Some(table) = value.as_table() else {
+        out.push(prefix.to_string());
+        return;
+    };
+    for (key, child) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        leaf_keys(child, &path, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_names_and_types() {
+        let env = |var: &str| match var {
+            "NETI_RULES_MAX_FILE_TOKENS" => Some("1500".to_string()),
+            "NETI_RULES_LOCALITY_MODE" => Some("error".to_string()),
+            "NETI_RULES_MAX_CBO" => Some("lots".to_string()),
+            _ => None,
+        };
+        let (pinned, rejected) = from_env(env);
+        assert_eq!(rejected.len(), 1, "{rejected:?}");
+        let keys: Vec<&str> = pinned.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(keys, vec!["rules.locality.mode", "rules.max_file_tokens"]);
+        assert_eq!(
+            pinned.get(1).map(|p| p.value.clone()),
+            Some(toml::Value::Integer(1500))
+        );
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("rules.max_nesting_depth=2"),
+            Ok((
+                "rules.max_nesting_depth".to_string(),
+                toml::Value::Integer(2)
+            ))
+        );
+        assert!(parse_assignment("rules.max_nesting=2").is_err());
+        assert!(parse_assignment("rules.max_nesting_depth=deep").is_err());
+        assert!(parse_assignment("commands.check=[\"cargo test\"]").is_ok());
+    }
+}
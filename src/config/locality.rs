@@ -1,9 +1,12 @@
 // src/config/locality.rs
 //! Configuration for the Law of Locality enforcement.
 
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::graph::locality::{ClassifierConfig, ValidatorConfig};
+use crate::graph::locality::{ClassifierConfig, DeclaredLayers, ValidatorConfig};
 
 /// Locality rules configuration from neti.toml.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +29,13 @@ pub struct LocalityConfig {
     /// Glob patterns to exempt from checks
     #[serde(default)]
     pub exempt_patterns: Vec<String>,
+    /// Declared architecture layers: name -> path globs
+    /// (`[rules.locality.layers] domain = ["src/core/**"]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub layers: BTreeMap<String, Vec<String>>,
+    /// Layers each declared layer may import (`adapters = ["domain"]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub layer_deps: BTreeMap<String, Vec<String>>,
 }
 
 impl Default for LocalityConfig {
@@ -39,15 +49,36 @@ impl Default for LocalityConfig {
             deadwood_threshold: 2,
             mode: "warn".to_string(),
             exempt_patterns: Vec::new(),
+            layers: BTreeMap::new(),
+            layer_deps: BTreeMap::new(),
         }
     }
 }
 
 impl LocalityConfig {
-    /// Converts to the validator's config format.
+    /// Layer names in `layer_deps` that `layers` does not declare.
     #[must_use]
-    pub fn to_validator_config(&self) -> ValidatorConfig {
-        ValidatorConfig {
+    pub fn undeclared_layers(&self) -> Vec<&str> {
+        self.layer_deps
+            .iter()
+            .flat_map(|(from, tos)| std::iter::once(from).chain(tos))
+            .filter(|name| !self.layers.contains_key(*name))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Converts to the validator's config format.
+    ///
+    /// # Errors
+    /// Returns error if `layer_deps` names an undeclared layer, which would
+    /// otherwise silently allow nothing.
+    pub fn to_validator_config(&self) -> Result<ValidatorConfig> {
+        if let Some(name) = self.undeclared_layers().first() {
+            bail!(
+                "rules.locality.layer_deps: layer '{name}' is not declared in [rules.locality.layers]"
+            );
+        }
+        Ok(ValidatorConfig {
             max_distance: self.max_distance,
             l1_threshold: self.l1_threshold,
             classifier: ClassifierConfig {
@@ -58,7 +89,9 @@ impl LocalityConfig {
                 volatile_leaf_efferent: 5,
             },
             exempt_patterns: self.exempt_patterns.clone(),
-        }
+            declared_layers: DeclaredLayers::new(&self.layers, &self.layer_deps),
+            grandfathered: HashSet::new(),
+        })
     }
 
     /// Returns true if enforcement is enabled.
@@ -73,3 +106,32 @@ impl LocalityConfig {
         self.mode == "error"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undeclared_layer_dep_is_rejected() {
+        let mut config = LocalityConfig::default();
+        config
+            .layers
+            .insert("domain".to_string(), vec!["src/core/**".to_string()]);
+        config
+            .layers
+            .insert("adapters".to_string(), vec!["src/io/**".to_string()]);
+        config
+            .layer_deps
+            .insert("adapters".to_string(), vec!["domian".to_string()]);
+
+        assert_eq!(config.undeclared_layers(), vec!["domian"]);
+        let err = config.to_validator_config().err().map(|e| e.to_string());
+        assert!(err.is_some_and(|e| e.contains("'domian' is not declared")));
+
+        config
+            .layer_deps
+            .insert("adapters".to_string(), vec!["domain".to_string()]);
+        assert!(config.undeclared_layers().is_empty());
+        assert!(config.to_validator_config().is_ok());
+    }
+}
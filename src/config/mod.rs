@@ -1,9 +1,35 @@
 // src/config/mod.rs
+pub mod debt;
+pub mod discovery;
+pub mod doctor;
+pub mod git;
+pub mod imports;
 pub mod io;
+pub mod layers;
 pub mod locality;
+pub mod mutate;
+pub mod naming;
+pub mod notifications;
+pub mod overrides;
+pub mod remote;
+mod save;
+pub mod testing;
+pub mod tui;
 pub mod types;
+pub mod validate;
 
+pub use self::debt::DebtConfig;
+pub use self::discovery::DiscoveryConfig;
+pub use self::git::GitConfig;
+pub use self::imports::ImportsConfig;
 pub use self::locality::LocalityConfig;
+pub use self::mutate::MutateGate;
+pub use self::naming::{NamingConfig, NamingPolicy};
+pub use self::notifications::NotificationsConfig;
+pub use self::overrides::RuleOverride;
+pub use self::remote::RemoteConfig;
+pub use self::testing::TestingConfig;
+pub use self::tui::TuiConfig;
 pub use self::types::{CommandEntry, Config, NetiToml, Preferences, RuleConfig};
 use anyhow::Result;
 
@@ -21,10 +47,22 @@ impl Config {
         config
     }
 
-    /// Validates configuration.
+    /// Returns the rules governing `path`: those of the closest nested
+    /// `neti.toml`, or the root rules.
+    #[must_use]
+    pub fn rules_for(&self, path: &std::path::Path) -> &RuleConfig {
+        let path = path.strip_prefix(".").unwrap_or(path);
+        self.rule_overrides
+            .iter()
+            .find(|o| path.starts_with(&o.dir))
+            .map_or(&self.rules, |o| &o.rules)
+    }
+
+    /// Validates `neti.toml` (root and nested) and `.netiignore` in the
+    /// current directory against this loaded config.
     ///
     /// # Errors
-    /// Returns Ok if validation passes.
+    /// Returns every problem found, one `file:line: message` per line.
     pub fn validate(&self) -> Result<()> {
         // Touch all fields for LCOM4 cohesion
         let _ = &self.rules;
@@ -34,7 +72,12 @@ impl Config {
         let _ = &self.exclude_patterns;
         let _ = self.verbose;
         let _ = self.code_only;
-        Ok(())
+        let diagnostics = validate::diagnose(self, std::path::Path::new("."));
+        if diagnostics.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+        anyhow::bail!("Invalid configuration:\n  {}", lines.join("\n  "))
     }
 
     pub fn load_local_config(&mut self) {
@@ -73,7 +116,7 @@ impl Config {
         let _ = &self.exclude_patterns;
         let _ = self.verbose;
         let _ = self.code_only;
-        io::save_to_file(&self.rules, &self.preferences, &self.commands)
+        io::save_config(self)
     }
 }
 
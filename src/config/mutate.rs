@@ -0,0 +1,35 @@
+//! Configuration for the mutation testing stage of `neti check`.
+
+use serde::{Deserialize, Serialize};
+
+/// Time-boxed mutation gate from neti.toml
+/// `[commands] mutate = { budget_secs = 300, min_score = 60 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MutateGate {
+    /// Stop the sample after this many seconds, including running mutants.
+    /// Default: 300
+    #[serde(default = "default_budget_secs")]
+    pub budget_secs: u64,
+    /// Fail the check when the sampled mutation score is below this percentage.
+    /// Default: 60
+    #[serde(default = "default_min_score")]
+    pub min_score: f64,
+}
+
+impl Default for MutateGate {
+    fn default() -> Self {
+        Self {
+            budget_secs: default_budget_secs(),
+            min_score: default_min_score(),
+        }
+    }
+}
+
+fn default_budget_secs() -> u64 {
+    300
+}
+
+fn default_min_score() -> f64 {
+    60.0
+}
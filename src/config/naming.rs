@@ -0,0 +1,148 @@
+//! Configuration for naming conventions (N01).
+
+use serde::{Deserialize, Serialize};
+
+use crate::lang::Lang;
+
+/// Naming policies from neti.toml `[rules.naming]`, one table per language
+/// (`[rules.naming.rust]`, `.python`, `.typescript`, `.swift`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamingConfig {
+    /// Check names against the policies. Default: true
+    pub enabled: bool,
+    pub rust: NamingPolicy,
+    pub python: NamingPolicy,
+    pub typescript: NamingPolicy,
+    pub swift: NamingPolicy,
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rust: NamingPolicy::default(),
+            python: NamingPolicy::default(),
+            typescript: NamingPolicy::default(),
+            swift: NamingPolicy::default(),
+        }
+    }
+}
+
+impl NamingConfig {
+    /// The policy for `lang`: configured patterns over the language defaults.
+    #[must_use]
+    pub fn policy(&self, lang: Lang) -> NamingPolicy {
+        let configured = match lang {
+            Lang::Rust => &self.rust,
+            Lang::Python => &self.python,
+            Lang::TypeScript => &self.typescript,
+            Lang::Swift => &self.swift,
+        };
+        configured.over(defaults(lang))
+    }
+
+    /// Every configured pattern with its dotted key, for validation.
+    #[must_use]
+    pub fn patterns(&self) -> Vec<(String, &str)> {
+        let langs = [
+            ("rust", &self.rust),
+            ("python", &self.python),
+            ("typescript", &self.typescript),
+            ("swift", &self.swift),
+        ];
+        langs
+            .into_iter()
+            .flat_map(|(lang, policy)| {
+                policy
+                    .entries()
+                    .into_iter()
+                    .filter(|(_, p)| !p.is_empty())
+                    .map(move |(kind, p)| (format!("rules.naming.{lang}.{kind}"), p))
+            })
+            .collect()
+    }
+}
+
+/// Regexes names must match, by kind. An empty entry falls back to the
+/// language default (or no check, where there is none); `".*"` accepts
+/// any name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamingPolicy {
+    pub function: String,
+    /// Structs, classes, enums, traits, interfaces and type aliases.
+    #[serde(rename = "type")]
+    pub types: String,
+    /// Rust `const` and `static` items.
+    pub constant: String,
+    /// Rust inline `mod` items.
+    pub module: String,
+    /// The file name, extension included.
+    pub file: String,
+}
+
+impl NamingPolicy {
+    /// `(kind, pattern)` pairs, kinds named as in `neti.toml`.
+    #[must_use]
+    pub fn entries(&self) -> [(&'static str, &str); 5] {
+        [
+            ("function", &self.function),
+            ("type", &self.types),
+            ("constant", &self.constant),
+            ("module", &self.module),
+            ("file", &self.file),
+        ]
+    }
+
+    fn over(&self, defaults: Self) -> Self {
+        let pick = |mine: &String, default: String| {
+            if mine.is_empty() {
+                default
+            } else {
+                mine.clone()
+            }
+        };
+        Self {
+            function: pick(&self.function, defaults.function),
+            types: pick(&self.types, defaults.types),
+            constant: pick(&self.constant, defaults.constant),
+            module: pick(&self.module, defaults.module),
+            file: pick(&self.file, defaults.file),
+        }
+    }
+}
+
+fn defaults(lang: Lang) -> NamingPolicy {
+    let [function, types, constant, module, file] = match lang {
+        Lang::Rust => [
+            r"^_*[a-z][a-z0-9_]*$",
+            r"^[A-Z][A-Za-z0-9]*$",
+            r"^_*[A-Z][A-Z0-9_]*$",
+            r"^[a-z][a-z0-9_]*$",
+            r"^[a-z][a-z0-9_]*\.rs$",
+        ],
+        Lang::Python => [
+            r"^_{0,2}[a-z][a-z0-9_]*$",
+            r"^_?[A-Z][A-Za-z0-9]*$",
+            "",
+            "",
+            r"^_{0,2}[a-z][a-z0-9_]*\.pyi?$",
+        ],
+        Lang::TypeScript => [
+            r"^_?[A-Za-z$][A-Za-z0-9$]*$",
+            r"^[A-Z][A-Za-z0-9]*$",
+            "",
+            "",
+            "",
+        ],
+        Lang::Swift => [r"^_?[a-z][A-Za-z0-9]*$", r"^[A-Z][A-Za-z0-9]*$", "", "", ""],
+    };
+    NamingPolicy {
+        function: function.into(),
+        types: types.into(),
+        constant: constant.into(),
+        module: module.into(),
+        file: file.into(),
+    }
+}
@@ -0,0 +1,54 @@
+//! Configuration for completion notifications (`neti check`, `neti mutate`).
+
+use serde::{Deserialize, Serialize};
+
+/// Notification settings from neti.toml `[notifications]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// "always", "failure" or "success". Default: "always"
+    pub when: String,
+    /// Skip runs shorter than this. Default: 0 (notify for every run)
+    pub min_duration_secs: u64,
+    /// Command to run when a run finishes, split like `[commands]` entries.
+    /// Gets `NETI_COMMAND`, `NETI_STATUS`, `NETI_DURATION_SECS` and
+    /// `NETI_SUMMARY` in its environment.
+    pub command: Option<String>,
+    /// Environment variable holding a Slack incoming-webhook URL to post the
+    /// result to (needs `curl`). The URL is a secret, so it stays out of
+    /// neti.toml.
+    pub slack_webhook_env: Option<String>,
+    /// Show a desktop notification (`notify-send` or `osascript`). Default: false
+    pub desktop: bool,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            when: "always".to_string(),
+            min_duration_secs: 0,
+            command: None,
+            slack_webhook_env: None,
+            desktop: false,
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// Returns true if any notification channel is configured.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.command.is_some() || self.slack_webhook_env.is_some() || self.desktop
+    }
+
+    /// Returns true if a run with this result and length should notify.
+    #[must_use]
+    pub fn wants(&self, passed: bool, duration_secs: u64) -> bool {
+        let status = match self.when.as_str() {
+            "failure" => !passed,
+            "success" => passed,
+            _ => true,
+        };
+        self.is_enabled() && status && duration_secs >= self.min_duration_secs
+    }
+}
@@ -0,0 +1,248 @@
+// src/config/overrides.rs
+//! Profiles and per-directory rule overrides.
+//!
+//! `[profile.<name>]` mirrors the top-level layout of `neti.toml` and is
+//! merged over it when selected with `--profile` (or `NETI_PROFILE`).
+//! A `neti.toml` in a subdirectory overrides `[rules]` for that subtree;
+//! nested files stack, so the deepest one wins key by key.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+#[cfg(feature = "fs")]
+use walkdir::WalkDir;
+
+use super::types::RuleConfig;
+#[cfg(feature = "fs")]
+use crate::constants::should_prune;
+
+/// Environment variable holding the selected profile.
+pub const PROFILE_ENV: &str = "NETI_PROFILE";
+
+const CONFIG_FILE: &str = "neti.toml";
+
+/// Rules that apply to every file under `dir`.
+#[derive(Debug, Clone)]
+pub struct RuleOverride {
+    /// Directory relative to the project root.
+    pub dir: PathBuf,
+    pub rules: RuleConfig,
+}
+
+/// Returns the profile selected via `NETI_PROFILE`, if any.
+#[must_use]
+pub fn active_profile() -> Option<String> {
+    std::env::var(PROFILE_ENV).ok().filter(|p| !p.is_empty())
+}
+
+/// Parses a config document and merges the selected profile over it.
+/// The `[profile]` table itself is removed from the result.
+///
+/// # Errors
+/// Returns error on malformed TOML or when `profile` is not defined.
+pub fn resolve_document(content: &str, profile: Option<&str>) -> Result<toml::Value> {
+    let (mut doc, profiles) = split_profiles(content)?;
+    let Some(name) = profile else {
+        return Ok(doc);
+    };
+    let Some(selected) = profiles.get(name) else {
+        let available: Vec<&str> = profiles.keys().map(String::as_str).collect();
+        if available.is_empty() {
+            bail!("Unknown profile '{name}': no [profile.*] sections defined");
+        }
+        bail!(
+            "Unknown profile '{name}'. Available: {}",
+            available.join(", ")
+        );
+    };
+    merge(&mut doc, selected.clone());
+    Ok(doc)
+}
+
+/// Like [`resolve_document`], but a nested file need not define the profile.
+fn resolve_nested(content: &str, profile: Option<&str>) -> Result<toml::Value> {
+    let (mut doc, profiles) = split_profiles(content)?;
+    if let Some(selected) = profile.and_then(|name| profiles.get(name)) {
+        merge(&mut doc, selected.clone());
+    }
+    Ok(doc)
+}
+
+fn split_profiles(content: &str) -> Result<(toml::Value, toml::Table)> {
+    let mut doc: toml::Value = content.parse()?;
+    let profiles = match doc.as_table_mut().and_then(|t| t.remove("profile")) {
+        Some(toml::Value::Table(profiles)) => profiles,
+        Some(_) => bail!("`profile` must be a table of [profile.<name>] sections"),
+        None => toml::Table::new(),
+    };
+    Ok((doc, profiles))
+}
+
+/// Finds nested `neti.toml` files below `root` and resolves the `[rules]`
+/// each subtree sees. `base_rules` is the root file's `[rules]` table;
+/// `pinned_rules` (from the environment or CLI) win over every file.
+/// Results are ordered deepest first, so the first match is the closest.
+///
+/// # Errors
+/// Returns error if a nested file is malformed or its rules do not
+/// deserialize.
+pub fn collect(
+    root: &Path,
+    base_rules: &toml::Value,
+    pinned_rules: &toml::Value,
+    profile: Option<&str>,
+) -> Result<Vec<RuleOverride>> {
+    let mut dirs = nested_config_dirs(root);
+    dirs.sort_by_key(|d| d.components().count());
+
+    let mut resolved: Vec<(PathBuf, toml::Value)> = Vec::new();
+    for dir in dirs {
+        let rules = resolve_dir(root, &dir, &resolved, base_rules, profile)?;
+        resolved.push((dir, rules));
+    }
+
+    let mut overrides = resolved
+        .into_iter()
+        .map(|(dir, mut rules)| {
+            merge(&mut rules, pinned_rules.clone());
+            to_override(&root.join(CONFIG_FILE), dir, rules)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    overrides.reverse();
+    Ok(overrides)
+}
+
+/// Merges the `[rules]` of `dir/neti.toml` over those of its closest
+/// already-resolved ancestor (or the root).
+fn resolve_dir(
+    root: &Path,
+    dir: &Path,
+    resolved: &[(PathBuf, toml::Value)],
+    base_rules: &toml::Value,
+    profile: Option<&str>,
+) -> Result<toml::Value> {
+    let path = root.join(dir).join(CONFIG_FILE);
+    let content = std::fs::read_to_string(&path)?;
+    let doc = resolve_nested(&content, profile)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+    let mut rules = resolved
+        .iter()
+        .rev()
+        .find(|(parent, _)| dir.starts_with(parent))
+        .map_or(base_rules, |(_, rules)| rules)
+        .clone();
+    if let Some(local) = doc.get("rules") {
+        merge(&mut rules, local.clone());
+    }
+    Ok(rules)
+}
+
+fn to_override(root_file: &Path, dir: PathBuf, rules: toml::Value) -> Result<RuleOverride> {
+    let rules: RuleConfig = rules.try_into().map_err(|e| {
+        anyhow::anyhow!(
+            "{} (merged over {}): {e}",
+            dir.join(CONFIG_FILE).display(),
+            root_file.display()
+        )
+    })?;
+    Ok(RuleOverride { dir, rules })
+}
+
+#[cfg(feature = "fs")]
+fn nested_config_dirs(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .min_depth(2)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| !should_prune(&e.file_name().to_string_lossy()))
+        .flatten()
+        .filter(|e| e.file_type().is_file() && e.file_name() == CONFIG_FILE)
+        .filter_map(|e| {
+            let parent = e.path().parent()?;
+            parent.strip_prefix(root).ok().map(Path::to_path_buf)
+        })
+        .collect()
+}
+
+/// Without `fs` there is no project tree to search.
+#[cfg(not(feature = "fs"))]
+fn nested_config_dirs(_root: &Path) -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Deep-merges `overlay` into `base`: tables merge key by key, anything
+/// else replaces.
+pub fn merge(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base), toml::Value::Table(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = r#"
+[rules]
+max_file_tokens = 2000
+max_nesting_depth = 3
+
+[profile.strict.rules]
+max_file_tokens = 1500
+
+[profile.ci.commands]
+check = ["cargo test"]
+"#;
+
+    #[test]
+    fn test_profile_merges_over_base() {
+        let doc = resolve_document(DOC, Some("strict")).expect("valid");
+        let rules = doc.get("rules").expect("rules");
+        assert_eq!(
+            rules
+                .get("max_file_tokens")
+                .and_then(toml::Value::as_integer),
+            Some(1500)
+        );
+        assert_eq!(
+            rules
+                .get("max_nesting_depth")
+                .and_then(toml::Value::as_integer),
+            Some(3)
+        );
+        assert!(doc.get("profile").is_none());
+    }
+
+    #[test]
+    fn test_unknown_profile_lists_available() {
+        let err = resolve_document(DOC, Some("nope")).expect_err("unknown");
+        let message = err.to_string();
+        assert!(
+            message.contains("ci") && message.contains("strict"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_no_profile_drops_profile_table() {
+        let doc = resolve_document(DOC, None).expect("valid");
+        let rules = doc.get("rules").expect("rules");
+        assert_eq!(
+            rules
+                .get("max_file_tokens")
+                .and_then(toml::Value::as_integer),
+            Some(2000)
+        );
+        assert!(doc.get("profile").is_none());
+    }
+}
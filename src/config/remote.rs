@@ -0,0 +1,25 @@
+//! Configuration for the shared report and cache store (`neti check --upload`).
+
+use serde::{Deserialize, Serialize};
+
+/// Remote store from neti.toml `[remote]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    /// Base URL artifacts are PUT under and fetched from: an S3 bucket
+    /// (or presigned prefix) or any HTTP server that accepts PUT. Unset
+    /// disables the remote.
+    pub url: Option<String>,
+    /// Environment variable holding a bearer token for the store.
+    /// Default: "NETI_REMOTE_TOKEN"
+    pub token_env: String,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            token_env: "NETI_REMOTE_TOKEN".to_string(),
+        }
+    }
+}
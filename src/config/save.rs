@@ -0,0 +1,138 @@
+// src/config/save.rs
+//! Writing `neti.toml` back from a loaded config.
+
+use super::types::{CommandEntry, Config, NetiToml, Preferences, RuleConfig};
+use crate::fsutil::atomic_write;
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::fs;
+
+/// Saves the configuration to the file system.
+///
+/// # Errors
+/// Returns an error if the config cannot be serialized or written to disk.
+#[allow(clippy::implicit_hasher)]
+pub fn save_to_file(
+    rules: &RuleConfig,
+    prefs: &Preferences,
+    commands: &HashMap<String, Vec<String>>,
+) -> Result<()> {
+    let toml_struct = NetiToml {
+        rules: rules.clone(),
+        preferences: prefs.clone(),
+        commands: command_entries(commands),
+        ..NetiToml::default()
+    };
+    write_toml(&toml_struct)
+}
+
+/// Saves the edits made to a loaded config into `neti.toml`.
+///
+/// The loaded config is the effective one: profile, `NETI_*` variables,
+/// `--set` and project defaults included. Only the settings that differ
+/// from a fresh load are written, onto the file as it is, so transient
+/// overrides are never pinned to disk.
+///
+/// # Errors
+/// Returns an error if the config cannot be serialized or written to disk.
+pub fn save_config(config: &Config) -> Result<()> {
+    let serialize = |c: &Config| {
+        toml::Value::try_from(to_document(c))
+            .map_err(|e| anyhow!("Failed to serialize config: {e}"))
+    };
+    let before = serialize(&Config::load())?;
+    let after = serialize(config)?;
+    let mut file = fs::read_to_string("neti.toml")
+        .ok()
+        .and_then(|c| c.parse::<toml::Value>().ok())
+        .unwrap_or_else(|| toml::Value::Table(toml::Table::new()));
+    merge_edits(&mut file, &before, &after);
+    let content =
+        toml::to_string_pretty(&file).map_err(|e| anyhow!("Failed to serialize config: {e}"))?;
+    atomic_write("neti.toml", content)?;
+    Ok(())
+}
+
+/// Copies into `file` every value that differs between `before` and
+/// `after`, and drops keys `after` no longer has. Values equal in both are
+/// left as `file` has them, or absent.
+pub fn merge_edits(file: &mut toml::Value, before: &toml::Value, after: &toml::Value) {
+    let (Some(file), Some(before), Some(after)) =
+        (file.as_table_mut(), before.as_table(), after.as_table())
+    else {
+        return;
+    };
+    for key in before.keys().filter(|k| !after.contains_key(*k)) {
+        file.remove(key);
+    }
+    for (key, new) in after {
+        let old = before.get(key);
+        if old == Some(new) {
+            continue;
+        }
+        match (old, new) {
+            (Some(old @ toml::Value::Table(_)), toml::Value::Table(_)) => {
+                let entry = file
+                    .entry(key.clone())
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                if !entry.is_table() {
+                    *entry = toml::Value::Table(toml::Table::new());
+                }
+                merge_edits(entry, old, new);
+            }
+            _ => {
+                file.insert(key.clone(), new.clone());
+            }
+        }
+    }
+}
+
+/// Every section of a loaded config in `neti.toml` shape.
+#[must_use]
+pub fn to_document(config: &Config) -> NetiToml {
+    NetiToml {
+        rules: config.rules.clone(),
+        preferences: config.preferences.clone(),
+        commands: command_entries(&config.commands)
+            .into_iter()
+            .chain(
+                config
+                    .mutate_gate
+                    .map(|g| ("mutate".into(), CommandEntry::Mutate(g))),
+            )
+            .collect(),
+        git: config.git.clone(),
+        tui: config.tui.clone(),
+        discovery: config.discovery.clone(),
+        notifications: config.notifications.clone(),
+        remote: config.remote.clone(),
+    }
+}
+
+fn command_entries(commands: &HashMap<String, Vec<String>>) -> HashMap<String, CommandEntry> {
+    commands
+        .iter()
+        .map(|(k, v)| (k.clone(), CommandEntry::List(v.clone())))
+        .collect()
+}
+
+/// Writes `neti.toml`, carrying over the existing `[profile.*]` sections,
+/// which are not part of the loaded config.
+fn write_toml(toml_struct: &NetiToml) -> Result<()> {
+    let mut doc = toml::Value::try_from(toml_struct)
+        .map_err(|e| anyhow!("Failed to serialize config: {e}"))?;
+    if let (Some(table), Some(profiles)) = (doc.as_table_mut(), existing_profiles()) {
+        table.insert("profile".into(), profiles);
+    }
+    let content =
+        toml::to_string_pretty(&doc).map_err(|e| anyhow!("Failed to serialize config: {e}"))?;
+
+    atomic_write("neti.toml", content)?;
+    Ok(())
+}
+
+fn existing_profiles() -> Option<toml::Value> {
+    let content = fs::read_to_string("neti.toml").ok()?;
+    let mut doc: toml::Value = content.parse().ok()?;
+    doc.as_table_mut()?.remove("profile")
+}
@@ -0,0 +1,28 @@
+//! Configuration for the test-coverage heuristic (T01).
+
+use serde::{Deserialize, Serialize};
+
+/// Test expectations from neti.toml `[rules.testing]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TestingConfig {
+    /// Check that complex modules have tests. Default: true
+    pub enabled: bool,
+    /// Modules whose most complex function scores at least this are
+    /// expected to have tests. Default: 15
+    pub min_complexity: usize,
+    /// Lines of test code per line of implementation below which a tested
+    /// module is still reported, as a suggestion. 0 turns this off.
+    /// Default: 0.0
+    pub min_test_ratio: f64,
+}
+
+impl Default for TestingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_complexity: 15,
+            min_test_ratio: 0.0,
+        }
+    }
+}
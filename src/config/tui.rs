@@ -0,0 +1,41 @@
+//! Configuration for Neti's interactive screens (`neti config`).
+
+use serde::{Deserialize, Serialize};
+
+/// Terminal UI configuration from neti.toml `[tui]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiConfig {
+    /// "dark", "light", "high-contrast", "none", or "custom". Default: "dark"
+    pub theme: String,
+    /// Colors used when `theme = "custom"`.
+    pub palette: PaletteConfig,
+}
+
+/// Named or `#rrggbb` colors for the custom theme, from `[tui.palette]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaletteConfig {
+    /// Borders and headings.
+    pub accent: String,
+    /// The selected row.
+    pub highlight: String,
+}
+
+impl Default for TuiConfig {
+    fn default() -> Self {
+        Self {
+            theme: "dark".to_string(),
+            palette: PaletteConfig::default(),
+        }
+    }
+}
+
+impl Default for PaletteConfig {
+    fn default() -> Self {
+        Self {
+            accent: "cyan".to_string(),
+            highlight: "yellow".to_string(),
+        }
+    }
+}
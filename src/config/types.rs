@@ -22,6 +22,12 @@ pub struct Preferences {
     pub fix_packet_path: String,
     #[serde(default)]
     pub auto_promote: bool,
+    #[serde(default)]
+    pub tokenizer: crate::tokens::TokenizerKind,
+    /// Sort reports by path, line and rule so runs compare cleanly.
+    /// `--unsorted` turns this off for one run.
+    #[serde(default = "default_stable_order")]
+    pub stable_order: bool,
 }
 
 impl Default for Preferences {
@@ -36,6 +42,8 @@ impl Default for Preferences {
             write_fix_packet: false,
             fix_packet_path: default_fix_packet_path(),
             auto_promote: false,
+            tokenizer: crate::tokens::TokenizerKind::default(),
+            stable_order: default_stable_order(),
         }
     }
 }
@@ -46,6 +54,9 @@ fn default_auto_copy() -> bool {
 fn default_progress_bars() -> bool {
     true
 }
+fn default_stable_order() -> bool {
+    true
+}
 fn default_backup_retention() -> usize {
     5
 }
@@ -68,6 +79,12 @@ pub struct RuleConfig {
     pub max_function_args: usize,
     #[serde(default = "default_max_words")]
     pub max_function_words: usize,
+    #[serde(default = "default_max_function_lines")]
+    pub max_function_lines: usize,
+    #[serde(default = "default_max_function_tokens")]
+    pub max_function_tokens: usize,
+    #[serde(default = "default_max_struct_fields")]
+    pub max_struct_fields: usize,
 
     // Scan v2 Metrics
     #[serde(default = "default_lcom4")]
@@ -87,6 +104,14 @@ pub struct RuleConfig {
     pub safety: SafetyConfig,
     #[serde(default)]
     pub locality: super::locality::LocalityConfig,
+    #[serde(default)]
+    pub testing: super::testing::TestingConfig,
+    #[serde(default)]
+    pub debt: super::debt::DebtConfig,
+    #[serde(default)]
+    pub naming: super::naming::NamingConfig,
+    #[serde(default)]
+    pub imports: super::imports::ImportsConfig,
 }
 
 impl Default for RuleConfig {
@@ -97,6 +122,9 @@ impl Default for RuleConfig {
             max_nesting_depth: default_max_depth(),
             max_function_args: default_max_args(),
             max_function_words: default_max_words(),
+            max_function_lines: default_max_function_lines(),
+            max_function_tokens: default_max_function_tokens(),
+            max_struct_fields: default_max_struct_fields(),
 
             max_lcom4: default_lcom4(),
             min_ahf: default_ahf(),
@@ -107,6 +135,10 @@ impl Default for RuleConfig {
             ignore_tokens_on: default_ignore_tokens(),
             safety: SafetyConfig::default(),
             locality: super::locality::LocalityConfig::default(),
+            testing: super::testing::TestingConfig::default(),
+            debt: super::debt::DebtConfig::default(),
+            naming: super::naming::NamingConfig::default(),
+            imports: super::imports::ImportsConfig::default(),
         }
     }
 }
@@ -146,6 +178,15 @@ const fn default_max_args() -> usize {
 const fn default_max_words() -> usize {
     10
 }
+const fn default_max_function_lines() -> usize {
+    100
+}
+const fn default_max_function_tokens() -> usize {
+    1000
+}
+const fn default_max_struct_fields() -> usize {
+    20
+}
 
 // V2 Defaults
 const fn default_lcom4() -> usize {
@@ -160,7 +201,6 @@ const fn default_cbo() -> usize {
 const fn default_sfout() -> usize {
     7
 }
-
 fn default_ignore_tokens() -> Vec<String> {
     vec![
         "Cargo.lock".into(),
@@ -177,6 +217,8 @@ fn default_ignore_tokens() -> Vec<String> {
 pub enum CommandEntry {
     Single(String),
     List(Vec<String>),
+    /// `mutate = { budget_secs, min_score }`: a mutation stage, not a shell command.
+    Mutate(super::mutate::MutateGate),
 }
 
 impl CommandEntry {
@@ -185,6 +227,7 @@ impl CommandEntry {
         match self {
             Self::Single(s) => vec![s],
             Self::List(l) => l,
+            Self::Mutate(_) => Vec::new(),
         }
     }
 }
@@ -197,6 +240,16 @@ pub struct NetiToml {
     pub preferences: Preferences,
     #[serde(default)]
     pub commands: HashMap<String, CommandEntry>,
+    #[serde(default)]
+    pub git: super::git::GitConfig,
+    #[serde(default)]
+    pub tui: super::tui::TuiConfig,
+    #[serde(default)]
+    pub discovery: super::discovery::DiscoveryConfig,
+    #[serde(default)]
+    pub notifications: super::notifications::NotificationsConfig,
+    #[serde(default)]
+    pub remote: super::remote::RemoteConfig,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -208,4 +261,15 @@ pub struct Config {
     pub rules: RuleConfig,
     pub preferences: Preferences,
     pub commands: HashMap<String, Vec<String>>,
+    /// The `[commands] mutate` stage of `neti check`, when configured.
+    pub mutate_gate: Option<super::mutate::MutateGate>,
+    pub git: super::git::GitConfig,
+    pub tui: super::tui::TuiConfig,
+    pub discovery: super::discovery::DiscoveryConfig,
+    pub notifications: super::notifications::NotificationsConfig,
+    pub remote: super::remote::RemoteConfig,
+    /// `[rules]` from nested `neti.toml` files, deepest directory first.
+    pub rule_overrides: Vec<super::overrides::RuleOverride>,
+    /// Rule categories to report, from `scan --only` / `--skip`.
+    pub categories: crate::types::CategoryFilter,
 }
@@ -0,0 +1,239 @@
+// src/config/validate/mod.rs
+//! Checks `neti.toml` (root and nested) and `.netiignore` for unknown keys,
+//! bad types, out-of-range thresholds, and patterns that contradict each
+//! other. Each problem points at the file and line that caused it.
+
+mod ranges;
+mod schema;
+
+pub use schema::{known_keys, locate};
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::types::{Config, NetiToml};
+
+/// One configuration problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.file.display(), self.message),
+            None => write!(f, "{}: {}", self.file.display(), self.message),
+        }
+    }
+}
+
+/// Checks every config source of a loaded `config` rooted at `root`.
+#[must_use]
+pub fn diagnose(config: &Config, root: &Path) -> Vec<Diagnostic> {
+    let mut found = Vec::new();
+    let legacy = root.join("slopchop.toml");
+    if legacy.exists() && !root.join("neti.toml").exists() {
+        found.push(Diagnostic {
+            file: PathBuf::from("slopchop.toml"),
+            line: None,
+            message: "ignored: rename it to neti.toml".to_string(),
+        });
+    }
+    found.extend(read_and_check(root, Path::new("neti.toml"), false));
+    for dir in config
+        .rule_overrides
+        .iter()
+        .map(|o| o.dir.join("neti.toml"))
+    {
+        found.extend(read_and_check(root, &dir, true));
+    }
+    if let Ok(content) = std::fs::read_to_string(root.join(".netiignore")) {
+        found.extend(check_ignore_file(Path::new(".netiignore"), &content));
+    }
+    found.extend(check_patterns(config));
+    found
+}
+
+fn read_and_check(root: &Path, file: &Path, nested: bool) -> Vec<Diagnostic> {
+    std::fs::read_to_string(root.join(file))
+        .map(|content| check_file(file, &content, nested))
+        .unwrap_or_default()
+}
+
+/// Checks one `neti.toml`. Nested files may only hold `[rules]` and
+/// `[profile.<name>.rules]`.
+#[must_use]
+pub fn check_file(file: &Path, content: &str, nested: bool) -> Vec<Diagnostic> {
+    let at = |path: &str, message: String| Diagnostic {
+        file: file.to_path_buf(),
+        line: locate(content, path),
+        message: format!("{path}: {message}"),
+    };
+    let doc: toml::Value = match content.parse() {
+        Ok(doc) => doc,
+        Err(e) => return vec![syntax_error(file, content, &e)],
+    };
+
+    let mut found: Vec<Diagnostic> = key_problems(&doc, nested)
+        .into_iter()
+        .map(|(path, message)| at(&path, message))
+        .collect();
+    if let Err(e) = toml::from_str::<NetiToml>(content) {
+        found.push(syntax_error(file, content, &e));
+        return found;
+    }
+
+    // A problem inherited from the base is reported once, at the base.
+    let mut seen = std::collections::HashSet::new();
+    for (prefix, parsed) in ranges::variants(&doc) {
+        found.extend(
+            ranges::problems(&parsed)
+                .into_iter()
+                .filter(|problem| seen.insert(problem.clone()))
+                .map(|(key, message)| at(&schema::join(&prefix, &key), message)),
+        );
+    }
+    found
+}
+
+fn syntax_error(file: &Path, content: &str, error: &toml::de::Error) -> Diagnostic {
+    let line = error.span().map(|span| {
+        content
+            .get(..span.start)
+            .unwrap_or("")
+            .lines()
+            .count()
+            .max(1)
+    });
+    Diagnostic {
+        file: file.to_path_buf(),
+        line,
+        message: error.message().to_string(),
+    }
+}
+
+/// Unknown keys in the document and in every profile body.
+fn key_problems(doc: &toml::Value, nested: bool) -> Vec<(String, String)> {
+    let known = schema::known_keys();
+    let mut base = doc.clone();
+    let profiles = base.as_table_mut().and_then(|t| t.remove("profile"));
+    let mut bodies = vec![(String::new(), base)];
+    match profiles {
+        Some(toml::Value::Table(profiles)) => bodies.extend(
+            profiles
+                .into_iter()
+                .map(|(name, body)| (format!("profile.{name}"), body)),
+        ),
+        Some(_) => bodies.push(("profile".into(), toml::Value::Boolean(false))),
+        None => {}
+    }
+
+    bodies
+        .into_iter()
+        .flat_map(|(prefix, body)| body_problems(&prefix, body, nested, &known))
+        .collect()
+}
+
+fn body_problems(
+    prefix: &str,
+    mut body: toml::Value,
+    nested: bool,
+    known: &toml::Value,
+) -> Vec<(String, String)> {
+    let Some(table) = body.as_table_mut() else {
+        return vec![(prefix.to_string(), "expected a table".to_string())];
+    };
+    let mut found = if nested {
+        non_rule_sections(table, prefix)
+    } else {
+        Vec::new()
+    };
+    found.extend(
+        schema::unknown_keys(&body, known, prefix)
+            .into_iter()
+            .map(|(path, hint)| (path, unknown_message(hint))),
+    );
+    found
+}
+
+/// Removes and reports everything but `rules` from a nested file's table.
+fn non_rule_sections(table: &mut toml::Table, prefix: &str) -> Vec<(String, String)> {
+    let others: Vec<String> = table.keys().filter(|k| *k != "rules").cloned().collect();
+    others
+        .into_iter()
+        .map(|key| {
+            table.remove(&key);
+            (
+                schema::join(prefix, &key),
+                "only [rules] is read from a nested neti.toml".to_string(),
+            )
+        })
+        .collect()
+}
+
+fn unknown_message(hint: Option<String>) -> String {
+    match hint {
+        Some(hint) => format!("unknown key (did you mean `{hint}`?)"),
+        None => "unknown key".to_string(),
+    }
+}
+
+/// Lines of `.netiignore` that are not valid regular expressions.
+#[must_use]
+pub fn check_ignore_file(file: &Path, content: &str) -> Vec<Diagnostic> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        })
+        .filter_map(|(index, line)| {
+            regex::Regex::new(line.trim()).err().map(|e| Diagnostic {
+                file: file.to_path_buf(),
+                line: Some(index + 1),
+                message: format!("invalid pattern: {}", regex_reason(&e)),
+            })
+        })
+        .collect()
+}
+
+/// The last line of a regex error, which names the problem without the
+/// caret diagram.
+fn regex_reason(error: &regex::Error) -> String {
+    let text = error.to_string();
+    let reason = text.lines().last().unwrap_or_default();
+    reason.trim_start_matches("error: ").to_string()
+}
+
+/// Include patterns that an exclude pattern cancels, and excludes that
+/// match everything.
+#[must_use]
+pub fn check_patterns(config: &Config) -> Vec<Diagnostic> {
+    let excludes: Vec<&str> = config
+        .exclude_patterns
+        .iter()
+        .map(regex::Regex::as_str)
+        .collect();
+    let cancelled = config
+        .include_patterns
+        .iter()
+        .filter(|re| excludes.contains(&re.as_str()))
+        .map(|re| format!("pattern '{}' is both included and excluded", re.as_str()));
+    let total = config
+        .exclude_patterns
+        .iter()
+        .filter(|re| re.is_match("") && re.is_match("src/main.rs"))
+        .map(|re| format!("pattern '{}' excludes every file", re.as_str()));
+    cancelled
+        .chain(total)
+        .map(|message| Diagnostic {
+            file: PathBuf::from(".netiignore"),
+            line: None,
+            message,
+        })
+        .collect()
+}
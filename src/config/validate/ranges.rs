@@ -0,0 +1,238 @@
+// src/config/validate/ranges.rs
+//! Range and enumeration checks on parsed config values.
+
+use crate::config::discovery::DiscoveryConfig;
+use crate::config::types::{CommandEntry, NetiToml, RuleConfig};
+
+/// The base document and each profile merged over it, parsed.
+pub(super) fn variants(doc: &toml::Value) -> Vec<(String, NetiToml)> {
+    let mut base = doc.clone();
+    let profiles = base
+        .as_table_mut()
+        .and_then(|t| t.remove("profile"))
+        .and_then(|p| p.as_table().cloned())
+        .unwrap_or_default();
+
+    let mut found: Vec<(String, NetiToml)> = base
+        .clone()
+        .try_into()
+        .map(|parsed| vec![(String::new(), parsed)])
+        .unwrap_or_default();
+    for (name, body) in profiles {
+        let mut merged = base.clone();
+        crate::config::overrides::merge(&mut merged, body);
+        if let Ok(parsed) = merged.try_into() {
+            found.push((format!("profile.{name}"), parsed));
+        }
+    }
+    found
+}
+
+/// Thresholds and enumerations outside their valid range.
+pub(super) fn problems(parsed: &NetiToml) -> Vec<(String, String)> {
+    let mut found = rule_problems(&parsed.rules);
+    found.extend(discovery_problems(&parsed.discovery));
+    let mut expect_one_of = |key: &str, value: &str, allowed: &[&str]| {
+        if !allowed.contains(&value) {
+            found.push((
+                key.to_string(),
+                format!("'{value}' is not one of: {}", allowed.join(", ")),
+            ));
+        }
+    };
+    expect_one_of(
+        "git.branch_strategy",
+        &parsed.git.branch_strategy,
+        &["single", "per-goal"],
+    );
+    if let Some(format) = &parsed.git.signing_format {
+        expect_one_of("git.signing_format", format, &["openpgp", "ssh", "x509"]);
+    }
+    expect_one_of(
+        "tui.theme",
+        &parsed.tui.theme,
+        &["dark", "light", "high-contrast", "none", "custom"],
+    );
+    expect_one_of(
+        "notifications.when",
+        &parsed.notifications.when,
+        &["always", "failure", "success"],
+    );
+    if let Some(CommandEntry::Mutate(gate)) = parsed.commands.get("mutate") {
+        if !(0.0..=100.0).contains(&gate.min_score) {
+            found.push(("commands.mutate".into(), "min_score must be 0-100".into()));
+        }
+        if gate.budget_secs == 0 {
+            found.push((
+                "commands.mutate".into(),
+                "budget_secs must be positive".into(),
+            ));
+        }
+    }
+    found
+}
+
+fn rule_problems(rules: &RuleConfig) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    let minimums = [
+        ("max_file_tokens", rules.max_file_tokens),
+        ("max_cognitive_complexity", rules.max_cognitive_complexity),
+        ("max_nesting_depth", rules.max_nesting_depth),
+        ("max_function_args", rules.max_function_args),
+        ("max_function_words", rules.max_function_words),
+        ("max_function_lines", rules.max_function_lines),
+        ("max_function_tokens", rules.max_function_tokens),
+        ("max_struct_fields", rules.max_struct_fields),
+        ("max_lcom4", rules.max_lcom4),
+        ("max_cbo", rules.max_cbo),
+        ("max_sfout", rules.max_sfout),
+    ];
+    found.extend(
+        minimums
+            .iter()
+            .filter(|(_, value)| *value == 0)
+            .map(|(key, _)| (format!("rules.{key}"), "must be at least 1".to_string())),
+    );
+    if !(0.0..=100.0).contains(&rules.min_ahf) {
+        found.push((
+            "rules.min_ahf".into(),
+            "must be a percentage (0-100)".into(),
+        ));
+    }
+    if rules.debt.max_suppressed_percent > 100 {
+        found.push((
+            "rules.debt.max_suppressed_percent".into(),
+            "must be a percentage (0-100)".into(),
+        ));
+    }
+
+    let locality = &rules.locality;
+    if !["error", "warn", "off"].contains(&locality.mode.as_str()) {
+        found.push((
+            "rules.locality.mode".into(),
+            format!("'{}' is not one of: error, warn, off", locality.mode),
+        ));
+    }
+    if locality.l1_threshold > locality.max_distance {
+        found.push((
+            "rules.locality.l1_threshold".into(),
+            format!("exceeds max_distance ({})", locality.max_distance),
+        ));
+    }
+    if locality.hub_threshold < 0.0 {
+        found.push((
+            "rules.locality.hub_threshold".into(),
+            "must not be negative".into(),
+        ));
+    }
+    found.extend(heuristic_problems(rules));
+    found.extend(naming_problems(rules));
+    found.extend(undeclared_layers(rules));
+    found
+}
+
+/// Thresholds for the T01 and D01 heuristics and import hygiene.
+fn heuristic_problems(rules: &RuleConfig) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    if rules.testing.min_complexity == 0 {
+        found.push((
+            "rules.testing.min_complexity".into(),
+            "must be at least 1".into(),
+        ));
+    }
+    if rules.testing.min_test_ratio < 0.0 {
+        found.push((
+            "rules.testing.min_test_ratio".into(),
+            "must not be negative".into(),
+        ));
+    }
+    if rules.debt.max_age_days == 0 {
+        found.push((
+            "rules.debt.max_age_days".into(),
+            "must be at least 1".into(),
+        ));
+    }
+    if rules.debt.markers.iter().any(|m| !is_word(m)) {
+        found.push((
+            "rules.debt.markers".into(),
+            "each marker must be a single word of letters, digits or '_'".into(),
+        ));
+    }
+    if rules.imports.wildcard_allow.iter().any(|s| !is_word(s)) {
+        found.push((
+            "rules.imports.wildcard_allow".into(),
+            "each entry must be a single path segment".into(),
+        ));
+    }
+    found
+}
+
+/// `[rules.naming]` patterns that are not valid regexes.
+fn naming_problems(rules: &RuleConfig) -> Vec<(String, String)> {
+    rules
+        .naming
+        .patterns()
+        .into_iter()
+        .filter_map(|(key, pattern)| {
+            let error = regex::Regex::new(pattern).err()?;
+            Some((
+                key,
+                format!("invalid regex: {}", super::regex_reason(&error)),
+            ))
+        })
+        .collect()
+}
+
+fn is_word(marker: &str) -> bool {
+    !marker.is_empty() && marker.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn discovery_problems(discovery: &DiscoveryConfig) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    if !(0.0..=8.0).contains(&discovery.max_entropy) {
+        found.push((
+            "discovery.max_entropy".into(),
+            "must be 0-8 bits per byte".into(),
+        ));
+    }
+    if discovery.max_avg_line_length == 0 {
+        found.push((
+            "discovery.max_avg_line_length".into(),
+            "must be at least 1".into(),
+        ));
+    }
+    if discovery.max_analysis_bytes == 0 {
+        found.push((
+            "discovery.max_analysis_bytes".into(),
+            "must be at least 1".into(),
+        ));
+    }
+    found.extend(
+        discovery
+            .force_include
+            .iter()
+            .filter_map(|p| regex::Regex::new(p).err().map(|e| (p, e)))
+            .map(|(p, e)| {
+                (
+                    "discovery.force_include".to_string(),
+                    format!("invalid pattern '{p}': {}", super::regex_reason(&e)),
+                )
+            }),
+    );
+    found
+}
+
+/// `layer_deps` entries naming layers that `layers` does not declare.
+fn undeclared_layers(rules: &RuleConfig) -> Vec<(String, String)> {
+    rules
+        .locality
+        .undeclared_layers()
+        .into_iter()
+        .map(|name| {
+            (
+                "rules.locality.layer_deps".to_string(),
+                format!("layer '{name}' is not declared in [rules.locality.layers]"),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,182 @@
+// src/config/validate/schema.rs
+//! Known keys of `neti.toml` and where keys live in the source text.
+
+use crate::config::types::NetiToml;
+
+/// Tables whose keys are user-chosen names rather than settings.
+const FREE_FORM: [&str; 3] = [
+    "commands",
+    "rules.locality.layers",
+    "rules.locality.layer_deps",
+];
+
+/// Keys that are valid but absent from a serialized default config.
+const OPTIONAL: [(&str, &str); 8] = [
+    ("git", "signing_key"),
+    ("git", "signing_format"),
+    ("git", "author"),
+    ("git", "committer"),
+    ("notifications", "command"),
+    ("notifications", "slack_webhook_env"),
+    ("remote", "url"),
+    ("rules", "max_cyclomatic_complexity"),
+];
+
+/// The key tree accepted by `neti.toml`, built from the defaults.
+#[must_use]
+pub fn known_keys() -> toml::Value {
+    let mut schema = toml::Value::try_from(NetiToml::default())
+        .unwrap_or_else(|_| toml::Value::Table(toml::Table::new()));
+    for (table, key) in OPTIONAL {
+        mark_known(&mut schema, table, key);
+    }
+    schema
+}
+
+fn mark_known(schema: &mut toml::Value, table: &str, key: &str) {
+    if let Some(t) = schema.get_mut(table).and_then(toml::Value::as_table_mut) {
+        t.insert(key.to_string(), toml::Value::Boolean(true));
+    }
+}
+
+/// Dotted paths of keys in `value` that `schema` does not define, each with
+/// the closest known sibling when one is near enough to be a typo.
+pub(super) fn unknown_keys(
+    value: &toml::Value,
+    schema: &toml::Value,
+    prefix: &str,
+) -> Vec<(String, Option<String>)> {
+    let (Some(table), Some(known)) = (value.as_table(), schema.as_table()) else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+    for (key, child) in table {
+        let path = join(prefix, key);
+        if FREE_FORM.contains(&without_profile(&path)) {
+            continue;
+        }
+        match known.get(key) {
+            Some(known_child) => found.extend(unknown_keys(child, known_child, &path)),
+            None => found.push((path, closest(key, known.keys()))),
+        }
+    }
+    found
+}
+
+fn closest<'a>(key: &str, candidates: impl Iterator<Item = &'a String>) -> Option<String> {
+    candidates
+        .map(|c| (edit_distance(key, c), c))
+        .filter(|(d, c)| *d <= 2.max(c.len() / 4))
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c.clone())
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row.first().copied().unwrap_or(0);
+        if let Some(first) = row.first_mut() {
+            *first = i + 1;
+        }
+        for (j, cb) in b.iter().enumerate() {
+            let above = row.get(j + 1).copied().unwrap_or(0);
+            let left = row.get(j).copied().unwrap_or(0);
+            let cost = usize::from(ca != *cb);
+            let best = (diagonal + cost).min(above + 1).min(left + 1);
+            diagonal = above;
+            if let Some(cell) = row.get_mut(j + 1) {
+                *cell = best;
+            }
+        }
+    }
+    row.last().copied().unwrap_or(0)
+}
+
+/// Strips a leading `profile.<name>.` so profile bodies match the schema.
+fn without_profile(path: &str) -> &str {
+    path.strip_prefix("profile.")
+        .and_then(|rest| rest.split_once('.'))
+        .map_or(path, |(_, key)| key)
+}
+
+pub(super) fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+/// Returns the 1-based line defining the dotted `path`, falling back to
+/// the closest enclosing table header.
+#[must_use]
+pub fn locate(content: &str, path: &str) -> Option<usize> {
+    let mut header = String::new();
+    let mut best: Option<(usize, usize)> = None;
+    for (index, line) in content.lines().enumerate() {
+        let full = match line_key(line.trim(), &mut header) {
+            Some(full) => full,
+            None => continue,
+        };
+        if full == path {
+            return Some(index + 1);
+        }
+        let is_ancestor = path
+            .strip_prefix(full.as_str())
+            .is_some_and(|rest| rest.starts_with('.'));
+        if is_ancestor && best.is_none_or(|(len, _)| full.len() > len) {
+            best = Some((full.len(), index + 1));
+        }
+    }
+    best.map(|(_, line)| line)
+}
+
+/// The dotted path a line defines: a `[table]` header or a `key = value`.
+fn line_key(line: &str, header: &mut String) -> Option<String> {
+    if line.starts_with('[') {
+        *header = line
+            .trim_start_matches('[')
+            .split(']')
+            .next()
+            .unwrap_or("")
+            .trim()
+            .replace([' ', '"'], "");
+        return Some(header.clone());
+    }
+    if line.starts_with('#') {
+        return None;
+    }
+    let (key, _) = line.split_once('=')?;
+    let key = key.trim().replace([' ', '"'], "");
+    (!key.is_empty()).then(|| join(header, &key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate_keys_and_headers() {
+        let content = "[rules]\nmax_file_tokens = 1\n\n[rules.locality]\nmode = \"x\"\n";
+        assert_eq!(locate(content, "rules.max_file_tokens"), Some(2));
+        assert_eq!(locate(content, "rules.locality.mode"), Some(5));
+        assert_eq!(locate(content, "rules.locality.max_distance"), Some(4));
+        assert_eq!(locate(content, "git.push"), None);
+    }
+
+    #[test]
+    fn test_unknown_keys_suggest_typos() {
+        let value: toml::Value = "[rules]\nmax_file_token = 1\n[commands]\nlint = \"x\"\n"
+            .parse()
+            .unwrap_or_else(|_| toml::Value::Table(toml::Table::new()));
+        let found = unknown_keys(&value, &known_keys(), "");
+        assert_eq!(
+            found,
+            vec![(
+                "rules.max_file_token".to_string(),
+                Some("max_file_tokens".to_string())
+            )]
+        );
+    }
+}
@@ -26,8 +26,14 @@ pub const PRUNE_FILES: &[&str] = &[
     "pnpm-lock.yaml",
     "yarn.lock",
     "bun.lockb",
+    "bun.lock",
     "go.sum",
     "Gemfile.lock",
+    "Pipfile.lock",
+    "poetry.lock",
+    "uv.lock",
+    "composer.lock",
+    "flake.lock",
 ];
 
 pub const SKIP_DIRS: &[&str] = &["spec", "examples", "fixtures"];
@@ -35,12 +41,12 @@ pub const SKIP_DIRS: &[&str] = &["spec", "examples", "fixtures"];
 pub const BIN_EXT_PATTERN: &str =
     r"(?i)\.(png|jpg|gif|svg|ico|webp|woff2?|ttf|pdf|mp4|zip|gz|tar|exe|dll|so|dylib|class|pyc)$";
 
-pub const SECRET_PATTERN: &str =
-    r"(?i)(^\.?env(\..*)?$|/\.?env(\..*)?$|(^|/)(id_rsa|id_ed25519|.*\.(pem|p12|key|pfx))$)";
+pub const SECRET_PATTERN: &str = r"(?i)(^|/)(id_rsa|id_ed25519|.*\.(pem|p12|key|pfx))$";
 
-pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|java|c|cpp|h|hpp|cs|php|rb|sh|sql|html|css|scss|json|toml|yaml|md)$";
+pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|java|c|cpp|h|hpp|cs|php|rb|sh|sql|html|css|scss|json|toml|yaml|yml|ini|cfg|properties|env|md)$";
 
-pub const CODE_BARE_PATTERN: &str = r"(?i)(Makefile|Dockerfile|CMakeLists\.txt)$";
+/// Extensionless files worth scanning; dotenv files are included for secrets.
+pub const CODE_BARE_PATTERN: &str = r"(?i)(Makefile|Dockerfile|CMakeLists\.txt|^\.env(\..+)?)$";
 
 /// Checks if a directory name should be pruned during traversal.
 #[must_use]
@@ -0,0 +1,238 @@
+// src/debt.rs
+//! Intentional-debt tracking (D01): `TODO`, `FIXME` and `HACK` comments.
+//!
+//! A marker counts only where a comment starts, so prose and string
+//! literals that mention one are left alone. Each item is dated by the
+//! author time `git blame` gives its line; lines git does not know yet
+//! (untracked files, uncommitted edits) are undated and never overdue.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{DateTime, NaiveDate};
+use rayon::prelude::*;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::config::{Config, DebtConfig};
+use crate::types::{Confidence, FileReport, Violation, ViolationDetails};
+
+#[cfg(test)]
+#[path = "debt_test.rs"]
+mod tests;
+
+/// Rule code for aged-debt findings.
+pub const LAW: &str = "D01";
+
+/// One debt comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DebtItem {
+    pub file: PathBuf,
+    pub line: usize,
+    /// The marker as written, e.g. `FIXME`.
+    pub marker: String,
+    pub text: String,
+    /// Author date of the line as `YYYY-MM-DD`, from `git blame`.
+    pub date: Option<String>,
+    pub age_days: Option<i64>,
+    /// Older than `[rules.debt] max_age_days`.
+    pub overdue: bool,
+}
+
+/// Debt items in every file, oldest first; undated items come last.
+#[must_use]
+pub fn collect_all(config: &Config, files: &[PathBuf], today: NaiveDate) -> Vec<DebtItem> {
+    let mut items: Vec<DebtItem> = files
+        .par_iter()
+        .flat_map(|path| collect(path, &config.rules_for(path).debt, today))
+        .collect();
+    items.sort_by(|a, b| {
+        b.age_days
+            .cmp(&a.age_days)
+            .then_with(|| a.file.cmp(&b.file))
+            .then(a.line.cmp(&b.line))
+    });
+    items
+}
+
+/// Debt items in one file, dated when it has any.
+#[must_use]
+pub fn collect(path: &Path, rules: &DebtConfig, today: NaiveDate) -> Vec<DebtItem> {
+    let Ok(content) = crate::normalize::read_text(path) else {
+        return Vec::new();
+    };
+    let mut items = find(path, &content, &rules.markers);
+    if items.is_empty() {
+        return items;
+    }
+    let lines: Vec<usize> = items.iter().map(|i| i.line).collect();
+    let dates = blame_dates(path, &lines);
+    for item in &mut items {
+        if let Some(date) = dates.get(&item.line) {
+            set_date(item, *date, today, rules);
+        }
+    }
+    items
+}
+
+fn set_date(item: &mut DebtItem, date: NaiveDate, today: NaiveDate, rules: &DebtConfig) {
+    let age = (today - date).num_days();
+    item.date = Some(date.to_string());
+    item.age_days = Some(age);
+    item.overdue = age > i64::from(rules.max_age_days);
+}
+
+/// D01 findings, keyed by file.
+#[must_use]
+pub fn check(config: &Config, results: &[FileReport]) -> HashMap<PathBuf, Vec<Violation>> {
+    let today = chrono::Local::now().date_naive();
+    results
+        .par_iter()
+        .filter_map(|report| {
+            let rules = &config.rules_for(&report.path).debt;
+            if !rules.enabled {
+                return None;
+            }
+            let found: Vec<Violation> = collect(&report.path, rules, today)
+                .iter()
+                .filter(|item| item.overdue)
+                .map(|item| overdue(item, rules))
+                .collect();
+            (!found.is_empty()).then(|| (report.path.clone(), found))
+        })
+        .collect()
+}
+
+/// Undated debt comments in `content`.
+#[must_use]
+pub fn find(path: &Path, content: &str, markers: &[String]) -> Vec<DebtItem> {
+    let Some(pattern) = marker_pattern(markers) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let caps = pattern.captures(line)?;
+            Some(DebtItem {
+                file: path.to_path_buf(),
+                line: i + 1,
+                marker: caps.get(1)?.as_str().to_string(),
+                text: clean_text(caps.get(2).map_or("", |m| m.as_str())),
+                date: None,
+                age_days: None,
+                overdue: false,
+            })
+        })
+        .collect()
+}
+
+/// A marker directly after a comment opener: `//`, `///`, `//!`, `#`,
+/// `/*`, a block comment's leading `*`, `--` or `<!--`.
+fn marker_pattern(markers: &[String]) -> Option<Regex> {
+    if markers.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = markers.iter().map(|m| regex::escape(m)).collect();
+    Regex::new(&format!(
+        r"(?:^|\s)(?://+!?|#+|/\*+!?|\*|--|<!--)\s*({})\b[\s:]*(.*)",
+        names.join("|")
+    ))
+    .ok()
+}
+
+fn clean_text(text: &str) -> String {
+    let text = text.trim_end();
+    let text = text
+        .strip_suffix("*/")
+        .or_else(|| text.strip_suffix("-->"))
+        .unwrap_or(text);
+    text.trim().to_string()
+}
+
+/// Author date of each of `lines` that `git blame` knows, by line number.
+fn blame_dates(path: &Path, lines: &[usize]) -> HashMap<usize, NaiveDate> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let Some(name) = path.file_name() else {
+        return HashMap::new();
+    };
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["blame", "--line-porcelain"])
+        .args(line_ranges(lines))
+        .arg("--")
+        .arg(name)
+        .output();
+    match output {
+        Ok(out) if out.status.success() => parse_porcelain(&String::from_utf8_lossy(&out.stdout)),
+        _ => {
+            log::debug!("git blame unavailable for {}", path.display());
+            HashMap::new()
+        }
+    }
+}
+
+fn line_ranges(lines: &[usize]) -> Vec<String> {
+    lines.iter().map(|n| format!("-L{n},{n}")).collect()
+}
+
+/// Reads `git blame --line-porcelain` output. Uncommitted lines carry the
+/// all-zero hash and are skipped.
+fn parse_porcelain(porcelain: &str) -> HashMap<usize, NaiveDate> {
+    let mut dates = HashMap::new();
+    let mut current: Option<usize> = None;
+    for line in porcelain.lines() {
+        if let Some(row) = header_row(line) {
+            current = row;
+        } else if let Some(secs) = line.strip_prefix("author-time ") {
+            let date = secs
+                .parse()
+                .ok()
+                .and_then(|s| DateTime::from_timestamp(s, 0))
+                .map(|d| d.date_naive());
+            if let (Some(row), Some(date)) = (current, date) {
+                dates.insert(row, date);
+            }
+        }
+    }
+    dates
+}
+
+/// For a `<hash> <orig-line> <final-line> [<count>]` header, the final
+/// line when the hash is a commit.
+fn header_row(line: &str) -> Option<Option<usize>> {
+    let mut parts = line.split(' ');
+    let hash = parts.next()?;
+    if hash.len() != 40 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let row = parts.nth(1)?.parse().ok()?;
+    Some((!hash.chars().all(|c| c == '0')).then_some(row))
+}
+
+fn overdue(item: &DebtItem, rules: &DebtConfig) -> Violation {
+    let age = item.age_days.unwrap_or_default();
+    let mut v = Violation::with_details(
+        item.line,
+        format!(
+            "{} is {age} days old (Max: {}): {}",
+            item.marker, rules.max_age_days, item.text
+        ),
+        LAW,
+        ViolationDetails {
+            function_name: None,
+            analysis: vec![format!(
+                "Last changed {}, per git blame.",
+                item.date.as_deref().unwrap_or("?")
+            )],
+            suggestion: Some("Do it, track it in an issue, or delete the comment.".into()),
+        },
+    );
+    v.confidence = Confidence::Medium;
+    v.confidence_reason = Some("age is the line's last change, from git blame".into());
+    v
+}
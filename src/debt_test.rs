@@ -0,0 +1,61 @@
+use super::*;
+
+// Custom markers, so these fixtures are not themselves debt.
+fn markers() -> Vec<String> {
+    vec!["LATER".into(), "XXX".into()]
+}
+
+fn found(content: &str) -> Vec<(usize, String, String)> {
+    find(Path::new("a.rs"), content, &markers())
+        .into_iter()
+        .map(|i| (i.line, i.marker, i.text))
+        .collect()
+}
+
+#[test]
+fn test_markers_only_at_comment_start() {
+    let code = [
+        "// LATER: split this up",
+        "let x = 1; // XXX handle overflow",
+        "/* LATER until upstream fixes it */",
+        "# XXX(ops): rotate",
+        "// Collects LATER comments",
+        "let s = \"// LATER not a comment\";",
+        "// LATERS are plural",
+    ]
+    .join("\n");
+    assert_eq!(
+        found(&code),
+        vec![
+            (1, "LATER".into(), "split this up".into()),
+            (2, "XXX".into(), "handle overflow".into()),
+            (3, "LATER".into(), "until upstream fixes it".into()),
+            (4, "XXX".into(), "(ops): rotate".into()),
+        ]
+    );
+    assert!(find(Path::new("a.rs"), "// LATER: x", &[]).is_empty());
+}
+
+#[test]
+fn test_parse_porcelain_skips_uncommitted_lines() {
+    let committed = "a".repeat(40);
+    let uncommitted = "0".repeat(40);
+    let porcelain = format!(
+        "{committed} 1 1 1\nauthor A\nauthor-time 1700000000\n\tfn a() {{}}\n\
+         {uncommitted} 2 2 1\nauthor Not Committed Yet\nauthor-time 1800000000\n\t// LATER\n\
+         {committed} 3 3\nauthor-time 0\n\tfn b() {{}}\n"
+    );
+    let dates = parse_porcelain(&porcelain);
+    assert_eq!(dates.len(), 2);
+    assert_eq!(
+        dates.get(&1),
+        NaiveDate::from_ymd_opt(2023, 11, 14).as_ref()
+    );
+    assert!(!dates.contains_key(&2));
+    assert_eq!(dates.get(&3), NaiveDate::from_ymd_opt(1970, 1, 1).as_ref());
+}
+
+#[test]
+fn test_blame_covers_only_marker_lines() {
+    assert_eq!(line_ranges(&[2, 9]), ["-L2,2", "-L9,9"]);
+}
@@ -0,0 +1,135 @@
+// src/discovery/gitignore.rs
+//! `.gitignore` pattern syntax, translated to regexes.
+
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// One ignore pattern, relative to the directory that declared it.
+#[derive(Debug, Clone)]
+pub(crate) struct Rule {
+    pub base: PathBuf,
+    pub regex: Regex,
+    pub negated: bool,
+    pub dir_only: bool,
+    /// Directories are matched as `dir/`, for `.netiignore` regexes such
+    /// as `^fixtures/`. Gitignore globs see the bare name, so that `dir/*`
+    /// matches the children of `dir` and not `dir` itself.
+    pub slash_dirs: bool,
+}
+
+impl Rule {
+    /// Whether the rule matches; `None` when `rel` is outside its base.
+    pub fn matches(&self, rel: &Path, is_dir: bool) -> Option<bool> {
+        let local = rel.strip_prefix(&self.base).ok()?;
+        let mut text = crate::normalize::path_str(local);
+        if is_dir && self.slash_dirs {
+            text.push('/');
+        }
+        Some((is_dir || !self.dir_only) && self.regex.is_match(&text))
+    }
+}
+
+/// Parses one `.gitignore` line.
+pub(crate) fn gitignore_rule(line: &str, base: &Path) -> Option<Rule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negated, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let dir_only = pattern.ends_with('/');
+    let pattern = pattern.trim_end_matches('/');
+    let anchored = pattern.contains('/');
+    let pattern = pattern.trim_start_matches('/');
+    let prefix = if anchored { "^" } else { "^(?:.*/)?" };
+    let regex = Regex::new(&format!("{prefix}{}$", glob_to_regex(pattern))).ok()?;
+    Some(Rule {
+        base: base.to_path_buf(),
+        regex,
+        negated,
+        dir_only,
+        slash_dirs: false,
+    })
+}
+
+/// Translates gitignore glob syntax (`*`, `?`, `[..]`, `**`) to a regex.
+fn glob_to_regex(glob: &str) -> String {
+    let mut out = String::new();
+    let mut rest = glob;
+    while !rest.is_empty() {
+        let (piece, len) = next_piece(rest);
+        out.push_str(&piece);
+        rest = rest.get(len..).unwrap_or("");
+    }
+    out
+}
+
+/// The regex for the glob token at the start of `rest`, and its length.
+fn next_piece(rest: &str) -> (String, usize) {
+    let c = rest.chars().next().unwrap_or_default();
+    match c {
+        '*' if rest.starts_with("**/") => ("(?:.*/)?".to_string(), 3),
+        '*' if rest == "**" => (".*".to_string(), 2),
+        '*' => ("[^/]*".to_string(), 1),
+        '?' => ("[^/]".to_string(), 1),
+        '[' => class(rest).unwrap_or_else(|| (regex::escape("["), 1)),
+        '\\' => escaped(rest),
+        _ => (regex::escape(&c.to_string()), c.len_utf8().max(1)),
+    }
+}
+
+fn class(rest: &str) -> Option<(String, usize)> {
+    let end = rest.get(1..)?.find(']')? + 1;
+    let body = rest.get(1..end)?;
+    let body = body.strip_prefix('!').map_or_else(
+        || body.replace('\\', "\\\\"),
+        |negated| format!("^{}", negated.replace('\\', "\\\\")),
+    );
+    Some((format!("[{body}]"), end + 1))
+}
+
+fn escaped(rest: &str) -> (String, usize) {
+    match rest.chars().nth(1) {
+        Some(next) => (regex::escape(&next.to_string()), 1 + next.len_utf8()),
+        None => (String::new(), 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, path: &str, is_dir: bool) -> bool {
+        gitignore_rule(pattern, Path::new(""))
+            .and_then(|r| r.matches(Path::new(path), is_dir))
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_gitignore_globs() {
+        assert!(matches("*.log", "a/b/debug.log", false));
+        assert!(matches("vendor/", "vendor", true));
+        assert!(!matches("vendor/", "vendor", false));
+        assert!(matches("/build", "build", true));
+        assert!(!matches("/build", "src/build", true));
+        assert!(matches("docs/*.md", "docs/a.md", false));
+        assert!(!matches("docs/*.md", "docs/x/a.md", false));
+        assert!(matches("**/gen", "a/b/gen", true));
+        assert!(matches("third_party/**", "third_party/x/y.rs", false));
+        assert!(matches("a/**/z", "a/z", true));
+        assert!(matches("a/**/z", "a/b/c/z", true));
+        assert!(matches("file[0-9].rs", "src/file7.rs", false));
+        assert!(matches("\\#notes", "#notes", false));
+    }
+
+    #[test]
+    fn test_dir_star_matches_children_only() {
+        assert!(!matches("third/*", "third", true));
+        assert!(matches("third/*", "third/x", true));
+        assert!(matches("third/*", "third/a.rs", false));
+        assert!(!matches("third/**", "third", true));
+        assert!(matches("!third/x/", "third/x", true));
+    }
+}
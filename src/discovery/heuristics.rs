@@ -0,0 +1,202 @@
+// src/discovery/heuristics.rs
+//! Decides which walked files are worth scanning. Binaries, key material,
+//! lockfiles, minified bundles and generated code are skipped unless a
+//! `[discovery] force_include` pattern names them.
+
+use regex::Regex;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use crate::config::{
+    DiscoveryConfig, BIN_EXT_PATTERN, CODE_BARE_PATTERN, CODE_EXT_PATTERN, SECRET_PATTERN,
+};
+use crate::constants::PRUNE_FILES;
+
+#[cfg(test)]
+#[path = "heuristics_test.rs"]
+mod tests;
+
+/// Bytes read from each file for content checks.
+const SNIFF_BYTES: u64 = 64 * 1024;
+
+/// Content checks need enough text to be meaningful.
+const MIN_SNIFF_LEN: usize = 1024;
+
+/// Lines searched for a generated-code header.
+const HEADER_LINES: usize = 5;
+
+const GENERATED_MARKERS: [&str; 6] = [
+    "@generated",
+    "do not edit",
+    "code generated by",
+    "<auto-generated",
+    "autogenerated by",
+    "automatically generated",
+];
+
+static BIN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(BIN_EXT_PATTERN).unwrap_or_else(|_| panic!("Invalid Regex")));
+static SECRET_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(SECRET_PATTERN).unwrap_or_else(|_| panic!("Invalid Regex")));
+static CODE_EXT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(CODE_EXT_PATTERN).unwrap_or_else(|_| panic!("Invalid Regex")));
+static CODE_BARE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(CODE_BARE_PATTERN).unwrap_or_else(|_| panic!("Invalid Regex")));
+static MINIFIED_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(\.min\.|[.-]bundle\.(js|ts)$)").unwrap_or_else(|_| panic!("Invalid Regex"))
+});
+static GENERATED_NAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(_pb2(_grpc)?\.pyi?$|\.pb\.(go|cc|h|rs)$|_pb\.(js|d\.ts)$|_grpc\.pb\.go$|\.generated\.)")
+        .unwrap_or_else(|_| panic!("Invalid Regex"))
+});
+
+/// Why a file was left out of the scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Skip {
+    Binary,
+    KeyMaterial,
+    Lockfile,
+    Minified,
+    Generated,
+    NotCode,
+}
+
+impl Skip {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Binary => "binary",
+            Self::KeyMaterial => "key material",
+            Self::Lockfile => "lockfile",
+            Self::Minified => "minified",
+            Self::Generated => "generated",
+            Self::NotCode => "not code",
+        }
+    }
+}
+
+/// File filter built from `[discovery]`.
+#[derive(Debug, Clone)]
+pub struct HeuristicFilter {
+    max_entropy: f64,
+    max_avg_line_length: usize,
+    skip_generated: bool,
+    force_include: Vec<Regex>,
+}
+
+impl HeuristicFilter {
+    /// Builds the filter. Invalid `force_include` patterns are dropped;
+    /// config validation reports them.
+    #[must_use]
+    pub fn new(config: &DiscoveryConfig) -> Self {
+        Self {
+            max_entropy: config.max_entropy,
+            max_avg_line_length: config.max_avg_line_length,
+            skip_generated: config.skip_generated,
+            force_include: config
+                .force_include
+                .iter()
+                .filter_map(|p| Regex::new(p).ok())
+                .collect(),
+        }
+    }
+
+    /// Why `path` should be skipped, or `None` to scan it.
+    #[must_use]
+    pub fn skip_reason(&self, path: &Path) -> Option<Skip> {
+        let normalized = crate::normalize::path_str(path);
+        if self.force_include.iter().any(|re| re.is_match(&normalized)) {
+            return None;
+        }
+        let name = path.file_name().map_or("", |f| f.to_str().unwrap_or(""));
+        let known = match self.by_name(name) {
+            Ok(known) => known,
+            Err(skip) => return Some(skip),
+        };
+        match sniff(path) {
+            Some(head) => self.by_content(&head, known),
+            None if known => None,
+            None => Some(Skip::NotCode),
+        }
+    }
+
+    /// Name-only verdict: a skip, or whether the name looks like code.
+    fn by_name(&self, name: &str) -> Result<bool, Skip> {
+        if BIN_RE.is_match(name) {
+            return Err(Skip::Binary);
+        }
+        if SECRET_RE.is_match(name) {
+            return Err(Skip::KeyMaterial);
+        }
+        if PRUNE_FILES.contains(&name) {
+            return Err(Skip::Lockfile);
+        }
+        if MINIFIED_NAME_RE.is_match(name) {
+            return Err(Skip::Minified);
+        }
+        if self.skip_generated && GENERATED_NAME_RE.is_match(name) {
+            return Err(Skip::Generated);
+        }
+        Ok(CODE_EXT_RE.is_match(name) || CODE_BARE_RE.is_match(name))
+    }
+
+    fn by_content(&self, head: &[u8], known: bool) -> Option<Skip> {
+        if !known && !head.starts_with(b"#!") {
+            return Some(Skip::NotCode);
+        }
+        if head.contains(&0) {
+            return Some(Skip::Binary);
+        }
+        let text = String::from_utf8_lossy(head);
+        if self.skip_generated && has_generated_header(&text) {
+            return Some(Skip::Generated);
+        }
+        if head.len() < MIN_SNIFF_LEN {
+            return None;
+        }
+        if byte_entropy(head) > self.max_entropy {
+            return Some(Skip::Binary);
+        }
+        let lines = text.lines().count().max(1);
+        (text.len() / lines > self.max_avg_line_length).then_some(Skip::Minified)
+    }
+}
+
+fn sniff(path: &Path) -> Option<Vec<u8>> {
+    let mut head = Vec::new();
+    fs::File::open(path)
+        .ok()?
+        .take(SNIFF_BYTES)
+        .read_to_end(&mut head)
+        .ok()?;
+    Some(head)
+}
+
+fn has_generated_header(text: &str) -> bool {
+    text.lines().take(HEADER_LINES).any(|line| {
+        let lower = line.to_ascii_lowercase();
+        GENERATED_MARKERS.iter().any(|m| lower.contains(m))
+    })
+}
+
+/// Shannon entropy of `bytes` in bits per byte.
+#[must_use]
+pub fn byte_entropy(bytes: &[u8]) -> f64 {
+    let mut counts = [0_usize; 256];
+    for &b in bytes {
+        if let Some(count) = counts.get_mut(usize::from(b)) {
+            *count += 1;
+        }
+    }
+    let len = bytes.len() as f64;
+    counts
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
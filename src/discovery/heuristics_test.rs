@@ -0,0 +1,50 @@
+// src/discovery/heuristics_test.rs
+
+use super::*;
+
+fn filter() -> HeuristicFilter {
+    HeuristicFilter::new(&DiscoveryConfig::default())
+}
+
+#[test]
+fn test_names() {
+    let f = filter();
+    assert_eq!(f.by_name("logo.png"), Err(Skip::Binary));
+    assert_eq!(f.by_name("id_rsa"), Err(Skip::KeyMaterial));
+    assert_eq!(f.by_name("Cargo.lock"), Err(Skip::Lockfile));
+    assert_eq!(f.by_name("app.min.js"), Err(Skip::Minified));
+    assert_eq!(f.by_name("user_pb2.py"), Err(Skip::Generated));
+    assert_eq!(f.by_name("api.pb.go"), Err(Skip::Generated));
+    assert_eq!(f.by_name("main.rs"), Ok(true));
+    assert_eq!(f.by_name("notes.bin"), Ok(false));
+}
+
+#[test]
+fn test_content() {
+    let f = filter();
+    let minified = format!("var a=1;{}\n", "function b(c){return c+1};".repeat(60));
+    assert_eq!(
+        f.by_content(minified.as_bytes(), true),
+        Some(Skip::Minified)
+    );
+
+    let header = "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage api\n";
+    assert_eq!(f.by_content(header.as_bytes(), true), Some(Skip::Generated));
+
+    let code = "fn main() {\n    println!(\"hi\");\n}\n".repeat(50);
+    assert_eq!(f.by_content(code.as_bytes(), true), None);
+    assert_eq!(f.by_content(b"#!/bin/sh\necho hi\n", false), None);
+    assert_eq!(f.by_content(b"plain text\n", false), Some(Skip::NotCode));
+
+    let blob: Vec<u8> = (0..4096_u32).map(|i| (i * 7 % 251) as u8 + 1).collect();
+    assert_eq!(f.by_content(&blob, true), Some(Skip::Binary));
+}
+
+#[test]
+fn test_generated_can_be_kept() {
+    let f = HeuristicFilter::new(&DiscoveryConfig {
+        skip_generated: false,
+        ..DiscoveryConfig::default()
+    });
+    assert_eq!(f.by_name("user_pb2.py"), Ok(true));
+}
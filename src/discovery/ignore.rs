@@ -0,0 +1,134 @@
+// src/discovery/ignore.rs
+//! Ignore files honored while walking: the global git excludes file,
+//! `.git/info/exclude`, a `.gitignore` in any directory, and `.netiignore`
+//! files below the root (the root one is loaded into the config).
+//!
+//! Precedence follows git: later sources win, in the order global excludes,
+//! `info/exclude`, then each directory from the root down, where a
+//! directory's `.netiignore` comes after its `.gitignore`. The last matching
+//! pattern decides, so a deeper `!pattern` can re-include a file.
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::gitignore::{gitignore_rule, Rule};
+
+const GITIGNORE: &str = ".gitignore";
+const NETIIGNORE: &str = ".netiignore";
+
+/// Ignore rules for a walk rooted at `root`, loaded per directory on demand.
+#[derive(Debug)]
+pub struct IgnoreStack {
+    root: PathBuf,
+    base: Vec<Rule>,
+    dirs: HashMap<PathBuf, Vec<Rule>>,
+}
+
+impl IgnoreStack {
+    /// Rules for `root`, including the user's global git excludes.
+    #[must_use]
+    pub fn new(root: &Path) -> Self {
+        Self::with_excludes_file(root, global_excludes_file().as_deref())
+    }
+
+    /// Rules for `root` with an explicit global excludes file.
+    #[must_use]
+    pub fn with_excludes_file(root: &Path, excludes: Option<&Path>) -> Self {
+        let mut base = Vec::new();
+        if root.join(".git").exists() {
+            let sources = excludes
+                .into_iter()
+                .map(Path::to_path_buf)
+                .chain(std::iter::once(root.join(".git/info/exclude")));
+            for file in sources {
+                base.extend(read_gitignore(&file, Path::new("")));
+            }
+        }
+        Self {
+            root: root.to_path_buf(),
+            base,
+            dirs: HashMap::new(),
+        }
+    }
+
+    /// Whether `path` (as produced by walking `root`) is ignored.
+    pub fn excludes(&mut self, path: &Path, is_dir: bool) -> bool {
+        let rel = path.strip_prefix(&self.root).unwrap_or(path);
+        if rel.as_os_str().is_empty() {
+            return false;
+        }
+        let mut ancestors: Vec<PathBuf> = rel.ancestors().skip(1).map(Path::to_path_buf).collect();
+        ancestors.reverse();
+        for dir in &ancestors {
+            self.load_dir(dir);
+        }
+        let mut ignored = false;
+        let layered = ancestors.iter().filter_map(|d| self.dirs.get(d)).flatten();
+        for rule in self.base.iter().chain(layered) {
+            if rule.matches(rel, is_dir) == Some(true) {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+
+    fn load_dir(&mut self, dir: &Path) {
+        if self.dirs.contains_key(dir) {
+            return;
+        }
+        let abs = self.root.join(dir);
+        let mut rules = read_gitignore(&abs.join(GITIGNORE), dir);
+        if !dir.as_os_str().is_empty() {
+            rules.extend(read_netiignore(&abs.join(NETIIGNORE), dir));
+        }
+        self.dirs.insert(dir.to_path_buf(), rules);
+    }
+}
+
+fn read_gitignore(file: &Path, base: &Path) -> Vec<Rule> {
+    fs::read_to_string(file)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| gitignore_rule(line, base))
+        .collect()
+}
+
+/// Nested `.netiignore` lines are regexes, like the root file, matched
+/// against paths relative to their directory.
+fn read_netiignore(file: &Path, base: &Path) -> Vec<Rule> {
+    fs::read_to_string(file)
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| Regex::new(l).ok())
+        .map(|regex| Rule {
+            base: base.to_path_buf(),
+            regex,
+            negated: false,
+            dir_only: false,
+            slash_dirs: true,
+        })
+        .collect()
+}
+
+/// `core.excludesFile`, or git's default `$XDG_CONFIG_HOME/git/ignore`.
+fn global_excludes_file() -> Option<PathBuf> {
+    let configured = Command::new("git")
+        .args(["config", "--path", "--get", "core.excludesFile"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .filter(|p| !p.is_empty());
+    if let Some(path) = configured {
+        return Some(PathBuf::from(path));
+    }
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .map(|config| config.join("git/ignore"))
+}
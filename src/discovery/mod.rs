@@ -0,0 +1,143 @@
+// src/discovery/mod.rs
+pub(crate) mod gitignore;
+mod heuristics;
+mod ignore;
+
+pub use heuristics::{byte_entropy, HeuristicFilter, Skip};
+pub use ignore::IgnoreStack;
+
+use crate::config::Config;
+use crate::constants::should_prune;
+use crate::logging;
+use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Runs the file discovery pipeline.
+///
+/// # Errors
+/// Returns error if regexes are invalid or file system walk fails.
+pub fn discover(config: &Config) -> Result<Vec<PathBuf>> {
+    let _span = logging::span("discovery");
+    let raw_files = walk_filesystem(config);
+    log::debug!("walk found {} files", raw_files.len());
+    let filter = HeuristicFilter::new(&config.discovery);
+    let heuristic_files = filter_heuristics(raw_files, &filter, config.verbose);
+    let mut final_files = filter_config(heuristic_files, config);
+    // Walk order depends on the filesystem; everything downstream sees
+    // files in path order instead.
+    final_files.sort();
+    log::debug!("{} files left after filters", final_files.len());
+    Ok(final_files)
+}
+
+/// Runs discovery and keeps only files belonging to workspace member `name`.
+///
+/// # Errors
+/// Returns error if discovery fails or `name` is not a workspace member.
+pub fn discover_package(config: &Config, name: &str) -> Result<Vec<PathBuf>> {
+    let member = crate::workspace::select_member(Path::new("."), name)?;
+    let files = discover(config)?;
+    Ok(files.into_iter().filter(|f| member.contains(f)).collect())
+}
+
+fn walk_filesystem(config: &Config) -> Vec<PathBuf> {
+    let root = Path::new(".");
+    let mut ignores = config
+        .discovery
+        .ignore_files
+        .then(|| IgnoreStack::new(root));
+    let walker = WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| {
+            let is_dir = e.file_type().is_dir();
+            if is_dir && should_prune(&e.file_name().to_string_lossy()) {
+                return false;
+            }
+            ignores
+                .as_mut()
+                .is_none_or(|stack| !stack.excludes(e.path(), is_dir))
+        });
+
+    let (paths, error_count) = accumulate_walker(walker);
+    if error_count > 0 && config.verbose {
+        log::warn!("Encountered {error_count} errors during file walk");
+    }
+    paths
+}
+
+fn accumulate_walker<I>(walker: I) -> (Vec<PathBuf>, usize)
+where
+    I: Iterator<Item = walkdir::Result<walkdir::DirEntry>>,
+{
+    let mut paths = Vec::new();
+    let mut errors = 0;
+    for item in walker {
+        match item {
+            Ok(entry) => {
+                if entry.file_type().is_file() {
+                    let p = entry.path().strip_prefix(".").unwrap_or(entry.path());
+                    paths.push(p.to_path_buf());
+                }
+            }
+            Err(_) => errors += 1,
+        }
+    }
+    (paths, errors)
+}
+
+fn filter_heuristics(paths: Vec<PathBuf>, filter: &HeuristicFilter, verbose: bool) -> Vec<PathBuf> {
+    let mut skipped: BTreeMap<Skip, usize> = BTreeMap::new();
+    let kept = paths
+        .into_iter()
+        .filter(|path| match filter.skip_reason(path) {
+            Some(reason) => {
+                log::trace!("skip {} ({})", path.display(), reason.label());
+                *skipped.entry(reason).or_default() += 1;
+                false
+            }
+            None => true,
+        })
+        .collect();
+    if verbose && !skipped.is_empty() {
+        let counts: Vec<String> = skipped
+            .iter()
+            .map(|(reason, n)| format!("{n} {}", reason.label()))
+            .collect();
+        eprintln!("Skipped files: {}", counts.join(", "));
+    }
+    kept
+}
+
+fn filter_config(mut paths: Vec<PathBuf>, config: &Config) -> Vec<PathBuf> {
+    if !config.include_patterns.is_empty() {
+        paths.retain(|p| {
+            let s = crate::normalize::path_str(p);
+            config.include_patterns.iter().any(|re| re.is_match(&s))
+        });
+    }
+
+    if config.discovery.ignore_files && !config.exclude_patterns.is_empty() {
+        paths.retain(|p| {
+            let s = crate::normalize::path_str(p);
+            !config.exclude_patterns.iter().any(|re| re.is_match(&s))
+        });
+    }
+
+    paths
+}
+
+/// Groups files by their parent directory.
+#[must_use]
+pub fn group_by_directory(files: &[PathBuf]) -> HashMap<PathBuf, Vec<PathBuf>> {
+    let mut groups: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+
+    for file in files {
+        let dir = file.parent().unwrap_or(Path::new(".")).to_path_buf();
+        groups.entry(dir).or_default().push(file.clone());
+    }
+
+    groups
+}
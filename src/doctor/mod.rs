@@ -0,0 +1,143 @@
+// src/doctor/mod.rs
+//! Environment diagnostics for `neti doctor`.
+//!
+//! Each check looks at one thing neti depends on (grammars, the tokenizer,
+//! the clipboard tool, git, the config, the work branch, the terminal and
+//! `.neti/`) and, when it is not healthy, says how to fix it.
+
+mod project;
+mod tools;
+
+use serde::Serialize;
+use std::path::Path;
+use tree_sitter::{Parser, Query};
+
+use crate::config::Config;
+use crate::lang::{Lang, QueryKind};
+use crate::tokens::{Tokenizer, TokenizerKind};
+
+/// How a check came out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+/// The result of one check.
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// What to do about a warning or failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            status: Status::Warn,
+            fix: Some(fix.into()),
+            ..Self::ok(name, detail)
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            status: Status::Fail,
+            ..Self::warn(name, detail, fix)
+        }
+    }
+}
+
+/// Runs every check for the project at `root`.
+#[must_use]
+pub fn run(root: &Path, config: &Config) -> Vec<Check> {
+    vec![
+        grammars(),
+        tokenizer(config.preferences.tokenizer),
+        tools::clipboard(config.preferences.auto_copy),
+        tools::git(),
+        project::config_file(config, root),
+        project::work_branch(root, config),
+        tools::terminal(),
+        project::cache(root),
+    ]
+}
+
+/// True if any check failed outright.
+#[must_use]
+pub fn has_failures(checks: &[Check]) -> bool {
+    checks.iter().any(|c| c.status == Status::Fail)
+}
+
+const QUERY_KINDS: [QueryKind; 6] = [
+    QueryKind::Naming,
+    QueryKind::Complexity,
+    QueryKind::Imports,
+    QueryKind::Defs,
+    QueryKind::Exports,
+    QueryKind::Skeleton,
+];
+
+fn grammars() -> Check {
+    let broken: Vec<String> = Lang::ALL
+        .into_iter()
+        .filter_map(|lang| grammar_error(lang).map(|e| format!("{}: {e}", name(lang))))
+        .collect();
+    if broken.is_empty() {
+        let names: Vec<String> = Lang::ALL.into_iter().map(name).collect();
+        return Check::ok(
+            "grammars",
+            format!("{} load and compile their queries", names.join(", ")),
+        );
+    }
+    Check::fail(
+        "grammars",
+        broken.join("; "),
+        "install a neti build whose tree-sitter runtime supports these grammars (`cargo install neti --force`)",
+    )
+}
+
+fn name(lang: Lang) -> String {
+    format!("{lang:?}").to_lowercase()
+}
+
+/// Why `lang` cannot be analyzed, if it cannot.
+fn grammar_error(lang: Lang) -> Option<String> {
+    let grammar = lang.grammar();
+    if let Err(e) = Parser::new().set_language(&grammar) {
+        return Some(e.to_string());
+    }
+    QUERY_KINDS.into_iter().find_map(|kind| {
+        Query::new(&grammar, lang.query(kind))
+            .err()
+            .map(|e| format!("{kind:?} query: {e}"))
+    })
+}
+
+fn tokenizer(kind: TokenizerKind) -> Check {
+    if Tokenizer::count_as(kind, "neti doctor") > 0 {
+        return Check::ok("tokenizer", format!("{kind:?} is available").to_lowercase());
+    }
+    Check::fail(
+        "tokenizer",
+        format!("{kind:?} failed to initialize").to_lowercase(),
+        "set [preferences] tokenizer = \"claude\" or \"whitespace\", which need no data files",
+    )
+}
+
+#[cfg(test)]
+mod tests;
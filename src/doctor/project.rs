@@ -0,0 +1,95 @@
+// src/doctor/project.rs
+//! Checks on the project: its config, work branch and `.neti/` state.
+
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+use super::Check;
+use crate::branch;
+use crate::config::{validate, Config};
+use crate::status;
+
+pub(super) fn config_file(config: &Config, root: &Path) -> Check {
+    let problems = validate::diagnose(config, root);
+    let Some(first) = problems.first() else {
+        let detail = if root.join("neti.toml").exists() {
+            "neti.toml is valid"
+        } else {
+            "no neti.toml; using defaults"
+        };
+        return Check::ok("config", detail);
+    };
+    Check::fail(
+        "config",
+        format!("{} problem(s), first: {first}", problems.len()),
+        "run `neti config doctor` to list every problem with where it was set",
+    )
+}
+
+pub(super) fn work_branch(root: &Path, config: &Config) -> Check {
+    if !branch::in_git_repo() {
+        return Check::ok("work branch", "not a git repository; nothing to check");
+    }
+    let goal = status::pending_goal(root);
+    let work = branch::resolve_work_branch(&config.git, goal.as_deref());
+    let current = branch::current_branch().unwrap_or_default();
+    if let Some(goal) = goal.filter(|_| !branch::branch_exists(&work)) {
+        return Check::warn(
+            "work branch",
+            format!("pending goal \"{goal}\" but branch {work} does not exist"),
+            "run `neti branch` to create it, or delete .neti/pending_goal",
+        );
+    }
+    if current == work && !branch::branch_exists("main") {
+        return Check::fail(
+            "work branch",
+            format!("on {work}, but there is no main branch to promote into"),
+            "create main (`git branch main <base>`) before `neti promote`",
+        );
+    }
+    Check::ok("work branch", format!("on {current}"))
+}
+
+pub(super) fn cache(root: &Path) -> Check {
+    let dir = root.join(".neti");
+    if !dir.is_dir() {
+        return Check::ok("cache", "no .neti directory yet");
+    }
+    let probe = dir.join(".doctor-probe");
+    if let Err(e) = fs::write(&probe, b"").and_then(|()| fs::remove_file(&probe)) {
+        return Check::fail(
+            "cache",
+            format!(".neti is not writable: {e}"),
+            "fix the permissions on .neti; reports and caches are written there",
+        );
+    }
+    let json: Vec<_> = WalkDir::new(&dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && e.path().extension().is_some_and(|x| x == "json"))
+        .map(walkdir::DirEntry::into_path)
+        .collect();
+    let corrupt: Vec<String> = json
+        .iter()
+        .filter(|path| !parses(path))
+        .map(|path| crate::normalize::path_str(path.strip_prefix(root).unwrap_or(path)))
+        .collect();
+    if corrupt.is_empty() {
+        return Check::ok(
+            "cache",
+            format!("{} JSON file(s) in .neti read cleanly", json.len()),
+        );
+    }
+    Check::warn(
+        "cache",
+        format!("unreadable: {}", corrupt.join(", ")),
+        "delete the listed files; caches rebuild on the next run and baselines with their snapshot command",
+    )
+}
+
+fn parses(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .is_some_and(|text| serde_json::from_str::<serde_json::Value>(&text).is_ok())
+}
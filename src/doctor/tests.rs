@@ -0,0 +1,41 @@
+// src/doctor/tests.rs
+use super::project::cache;
+use super::tools::{clipboard, clipboard_tool};
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn grammar_problems_are_explained() {
+    assert_eq!(grammar_error(Lang::Rust), None);
+    let check = grammars();
+    assert_eq!(check.status == Status::Ok, check.fix.is_none());
+}
+
+#[test]
+fn corrupt_cache_files_are_named() {
+    let dir = TempDir::new().expect("temp dir");
+    let cache_dir = dir.path().join(".neti/cache/mutants");
+    fs::create_dir_all(&cache_dir).expect("mkdir");
+    fs::write(cache_dir.join("good.json"), "{}").expect("write");
+    fs::write(cache_dir.join("bad.json"), "{trunc").expect("write");
+
+    let check = cache(dir.path());
+    assert_eq!(check.status, Status::Warn);
+    assert_eq!(check.detail, "unreadable: .neti/cache/mutants/bad.json");
+    assert!(!dir.path().join(".neti/.doctor-probe").exists());
+}
+
+#[test]
+fn clipboard_is_only_a_problem_when_auto_copy_is_on() {
+    assert_eq!(clipboard(false).status, Status::Ok);
+    let on = clipboard(true);
+    assert_eq!(on.status == Status::Warn, clipboard_tool().is_none());
+}
+
+#[test]
+fn failures_are_detected() {
+    let checks = vec![Check::ok("a", ""), Check::warn("b", "", "")];
+    assert!(!has_failures(&checks));
+    assert!(has_failures(&[Check::fail("c", "", "")]));
+}
@@ -0,0 +1,82 @@
+// src/doctor/tools.rs
+//! Checks on the programs and terminal neti runs alongside.
+
+use std::env;
+use std::io::IsTerminal;
+use std::process::Command;
+
+use super::Check;
+use crate::branch;
+
+pub(super) fn clipboard(auto_copy: bool) -> Check {
+    match (clipboard_tool(), auto_copy) {
+        (Some(tool), _) => Check::ok("clipboard", format!("using {tool}")),
+        (None, false) => Check::ok("clipboard", "no clipboard tool found; auto_copy is off"),
+        (None, true) => Check::warn(
+            "clipboard",
+            "auto_copy is on but no clipboard tool was found on PATH",
+            "install wl-clipboard, xclip or xsel, or set [preferences] auto_copy = false",
+        ),
+    }
+}
+
+/// The first clipboard tool on `PATH` for this platform.
+pub(super) fn clipboard_tool() -> Option<&'static str> {
+    let candidates: &[&'static str] = if cfg!(target_os = "macos") {
+        &["pbcopy"]
+    } else if cfg!(windows) {
+        &["clip.exe"]
+    } else if env::var_os("WAYLAND_DISPLAY").is_some() {
+        &["wl-copy", "xclip", "xsel"]
+    } else {
+        &["xclip", "xsel", "wl-copy"]
+    };
+    candidates.iter().copied().find(|tool| on_path(tool))
+}
+
+fn on_path(program: &str) -> bool {
+    env::var_os("PATH")
+        .is_some_and(|paths| env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+}
+
+pub(super) fn git() -> Check {
+    let version = Command::new("git")
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+    match version {
+        None => Check::fail(
+            "git",
+            "git was not found on PATH",
+            "install git; branch, promote, debt ages and mutate --since need it",
+        ),
+        Some(v) if !branch::in_git_repo() => Check::warn(
+            "git",
+            format!("{v}, but this is not a git repository"),
+            "run `git init` to use neti branch and promote",
+        ),
+        Some(v) => Check::ok("git", v),
+    }
+}
+
+pub(super) fn terminal() -> Check {
+    let tty = std::io::stdout().is_terminal();
+    let term = env::var("TERM").unwrap_or_default();
+    let colors = colored::control::SHOULD_COLORIZE.should_colorize();
+    let detail = format!(
+        "stdout {} a terminal, TERM={}, colors {}",
+        if tty { "is" } else { "is not" },
+        if term.is_empty() { "unset" } else { &term },
+        if colors { "on" } else { "off" }
+    );
+    if tty && term == "dumb" {
+        return Check::warn(
+            "terminal",
+            detail,
+            "progress bars and `neti config` need cursor control; set TERM (e.g. xterm-256color) or use --porcelain",
+        );
+    }
+    Check::ok("terminal", detail)
+}
@@ -76,6 +76,20 @@ impl EventLogger {
         }
     }
 
+    /// Returns the last `limit` events, oldest first. Unparseable lines are skipped.
+    #[must_use]
+    pub fn recent(&self, limit: usize) -> Vec<NetiEvent> {
+        let Ok(content) = fs::read_to_string(&self.log_path) else {
+            return Vec::new();
+        };
+        let events: Vec<NetiEvent> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        let skip = events.len().saturating_sub(limit);
+        events.into_iter().skip(skip).collect()
+    }
+
     fn serialize_event(kind: EventKind) -> Result<String> {
         let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
         let event = NetiEvent { timestamp, kind };
@@ -63,6 +63,9 @@ pub fn classify(path: &Path) -> FileKind {
     if is_minified_artifact(&name) {
         return FileKind::Asset;
     }
+    if is_env_file(&name) {
+        return FileKind::Config;
+    }
 
     classify_by_ext(&ext)
 }
@@ -76,6 +79,11 @@ fn is_minified_artifact(name: &str) -> bool {
         || name.ends_with("-bundle.js")
 }
 
+/// Returns `true` for dotenv files: `.env`, `.env.local`, `.env.production`.
+fn is_env_file(name: &str) -> bool {
+    name == ".env" || name.starts_with(".env.")
+}
+
 fn classify_by_ext(ext: &str) -> FileKind {
     match ext {
         // Source code — governed by all structural laws
@@ -131,6 +139,13 @@ mod tests {
         assert!(!kind.is_governed());
     }
 
+    #[test]
+    fn env_files_are_config() {
+        for name in [".env", "app/.env.local", "settings.env"] {
+            assert_eq!(classify(Path::new(name)), FileKind::Config, "{name}");
+        }
+    }
+
     #[test]
     fn toml_is_config() {
         assert_eq!(classify(Path::new("neti.toml")), FileKind::Config);
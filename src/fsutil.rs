@@ -0,0 +1,129 @@
+// src/fsutil.rs
+//! Crash-safe file writes.
+//!
+//! Content goes to a temporary file beside the target, is flushed to disk,
+//! and is then renamed over the target. A rename within one directory is
+//! atomic, so an interrupted run leaves either the old file or the new one,
+//! never a truncated mix. Append-only logs (`events.jsonl`, `--log-file`)
+//! do not need this and write in place.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_TEMP: AtomicUsize = AtomicUsize::new(0);
+
+/// Replaces `path` with `contents` atomically. Drop-in for `fs::write`.
+///
+/// # Errors
+/// Returns an error if the temporary file cannot be written or renamed;
+/// the target is left untouched.
+pub fn atomic_write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let mut file = AtomicFile::create(path.as_ref())?;
+    file.write_all(contents.as_ref())?;
+    file.commit()
+}
+
+/// A file being written beside its target. Nothing is visible at the target
+/// until [`commit`](Self::commit); dropping it uncommitted removes the
+/// temporary file and leaves the target as it was.
+#[derive(Debug)]
+pub struct AtomicFile {
+    target: PathBuf,
+    temp: PathBuf,
+    file: Option<File>,
+}
+
+impl AtomicFile {
+    /// Starts writing a replacement for `target`.
+    ///
+    /// # Errors
+    /// Returns an error if the temporary file cannot be created.
+    pub fn create(target: &Path) -> io::Result<Self> {
+        let temp = temp_path(target);
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp)?;
+        Ok(Self {
+            target: target.to_path_buf(),
+            temp,
+            file: Some(file),
+        })
+    }
+
+    /// Flushes the content to disk and renames it over the target, keeping
+    /// the target's permissions if it already existed.
+    ///
+    /// # Errors
+    /// Returns an error if syncing or renaming fails; the target is left
+    /// untouched and the temporary file is removed.
+    pub fn commit(mut self) -> io::Result<()> {
+        let Some(file) = self.file.take() else {
+            return Ok(());
+        };
+        let result = finish(&file, &self.temp, &self.target);
+        if result.is_err() {
+            let _ = fs::remove_file(&self.temp);
+        }
+        result
+    }
+}
+
+impl Write for AtomicFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.file {
+            Some(file) => file.write(buf),
+            None => Err(io::Error::other("write after commit")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.as_mut().map_or(Ok(()), Write::flush)
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if self.file.take().is_some() {
+            let _ = fs::remove_file(&self.temp);
+        }
+    }
+}
+
+fn finish(file: &File, temp: &Path, target: &Path) -> io::Result<()> {
+    if let Ok(meta) = fs::metadata(target) {
+        file.set_permissions(meta.permissions())?;
+    }
+    file.sync_all()?;
+    fs::rename(temp, target)?;
+    sync_dir(target);
+    Ok(())
+}
+
+/// Makes the rename itself durable. Best effort: not every platform can
+/// open a directory for syncing.
+fn sync_dir(target: &Path) {
+    let dir = parent_dir(target);
+    if let Ok(handle) = File::open(dir) {
+        let _ = handle.sync_all();
+    }
+}
+
+fn parent_dir(target: &Path) -> &Path {
+    target
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+/// `.<name>.<pid>.<n>.tmp` in the target's directory, so the rename never
+/// crosses a filesystem.
+fn temp_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .map_or_else(|| "neti".into(), |n| n.to_string_lossy());
+    let n = NEXT_TEMP.fetch_add(1, Ordering::Relaxed);
+    parent_dir(target).join(format!(".{name}.{}.{n}.tmp", std::process::id()))
+}
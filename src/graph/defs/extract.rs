@@ -12,8 +12,20 @@ pub struct Definition {
     pub kind: DefKind,
     pub line: usize,
     pub signature: String,
+    /// The enclosing impl, trait or class, e.g. `Parser` or `Display for Id`.
+    pub owner: Option<String>,
 }
 
+/// Node kinds whose members are qualified by the container's name.
+const OWNER_KINDS: [&str; 6] = [
+    "impl_item",
+    "trait_item",
+    "class_definition",
+    "class_declaration",
+    "interface_declaration",
+    "protocol_declaration",
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DefKind {
     Function,
@@ -60,7 +72,7 @@ pub fn extract(path: &Path, content: &str) -> Vec<Definition> {
         return Vec::new();
     };
 
-    run_extraction(content, &grammar, &query)
+    run_extraction(content, &grammar, query)
 }
 
 fn run_extraction(source: &str, lang: &Language, query: &Query) -> Vec<Definition> {
@@ -103,9 +115,28 @@ fn build_def(
             .unwrap_or(DefKind::Function),
         line: row + 1,
         signature: get_signature(lines, row),
+        owner: owner_of(sig, source),
     })
 }
 
+fn owner_of(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let mut owner = node.parent();
+    while let Some(p) = owner.filter(|p| !OWNER_KINDS.contains(&p.kind())) {
+        owner = p.parent();
+    }
+    let owner = owner?;
+    let text = |field: &str| {
+        owner
+            .child_by_field_name(field)
+            .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+    };
+    match (text("trait"), text("type")) {
+        (Some(t), Some(ty)) => Some(format!("{t} for {ty}")),
+        (None, Some(ty)) => Some(ty.to_string()),
+        _ => text("name").map(String::from),
+    }
+}
+
 fn find_captures<'a>(
     m: &'a tree_sitter::QueryMatch,
     name_idx: u32,
@@ -145,6 +176,21 @@ mod tests {
         assert!(defs.iter().any(|d| d.name == "helper"));
     }
 
+    #[test]
+    fn test_methods_record_their_impl() {
+        let code = "struct A;\nimpl A {\n    pub fn new() -> Self { A }\n}\n\
+                    impl Default for A {\n    fn default() -> Self { A }\n}\nfn free() {}";
+        let defs = extract(Path::new("lib.rs"), code);
+        let owner = |name: &str| {
+            defs.iter()
+                .find(|d| d.name == name)
+                .and_then(|d| d.owner.clone())
+        };
+        assert_eq!(owner("new").as_deref(), Some("A"));
+        assert_eq!(owner("default").as_deref(), Some("Default for A"));
+        assert_eq!(owner("free"), None);
+    }
+
     #[test]
     fn test_python_defs() {
         let code = "class UserService:\n    pass\n\ndef helper():\n    return True";
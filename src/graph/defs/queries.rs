@@ -1,13 +1,13 @@
 use crate::lang::Lang;
+use crate::query_cache;
 use tree_sitter::{Language, Query};
 
 pub struct DefExtractor;
 
 impl DefExtractor {
     #[must_use]
-    pub fn get_config(lang: Lang) -> Option<(Language, Query)> {
-        let grammar = lang.grammar();
-        let query = Query::new(&grammar, lang.q_defs()).ok()?;
-        Some((grammar, query))
+    pub fn get_config(lang: Lang) -> Option<(Language, &'static Query)> {
+        let query = query_cache::get(lang, lang.q_defs())?;
+        Some((lang.grammar(), query))
     }
 }
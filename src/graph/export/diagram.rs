@@ -0,0 +1,73 @@
+// src/graph/export/diagram.rs
+//! Graphviz DOT and Mermaid output.
+
+use std::fmt::Write;
+
+use super::{Edge, GraphNode};
+
+/// Tokens per extra point of DOT font size.
+const TOKENS_PER_FONT_STEP: usize = 200;
+/// Largest font size increase, so huge files don't swamp the layout.
+const MAX_FONT_GROWTH: usize = 20;
+const BASE_FONT_SIZE: usize = 10;
+
+fn label(node: &GraphNode, separator: &str) -> String {
+    let mut text = format!("{}{separator}{} tok", node.path.display(), node.tokens);
+    if node.violations > 0 {
+        let _ = write!(text, "{separator}{} violations", node.violations);
+    }
+    text.replace('"', "'")
+}
+
+pub(super) fn render_dot(nodes: &[GraphNode], edges: &[Edge]) -> String {
+    let mut out = String::from("digraph neti {\n    rankdir=LR;\n    node [shape=box];\n");
+
+    for (i, node) in nodes.iter().enumerate() {
+        let size = BASE_FONT_SIZE + (node.tokens / TOKENS_PER_FONT_STEP).min(MAX_FONT_GROWTH);
+        let mut attrs = format!("label=\"{}\", fontsize={size}", label(node, "\\n"));
+        if node.is_hub {
+            attrs.push_str(", style=filled, fillcolor=gold");
+        }
+        if node.violations > 0 {
+            attrs.push_str(", color=red, penwidth=2");
+        }
+        let _ = writeln!(out, "    n{i} [{attrs}];");
+    }
+
+    for edge in edges {
+        let style = if edge.failed { " [color=red]" } else { "" };
+        let _ = writeln!(out, "    n{} -> n{}{style};", edge.from, edge.to);
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+pub(super) fn render_mermaid(nodes: &[GraphNode], edges: &[Edge]) -> String {
+    let mut out = String::from("graph LR\n");
+
+    for (i, node) in nodes.iter().enumerate() {
+        let _ = writeln!(out, "    n{i}[\"{}\"]", label(node, "<br/>"));
+    }
+
+    for edge in edges {
+        let _ = writeln!(out, "    n{} --> n{}", edge.from, edge.to);
+    }
+    for (i, edge) in edges.iter().enumerate() {
+        if edge.failed {
+            let _ = writeln!(out, "    linkStyle {i} stroke:#d00");
+        }
+    }
+
+    out.push_str("    classDef hub fill:#f9d71c,stroke:#b8860b\n");
+    out.push_str("    classDef violated stroke:#d00,stroke-width:2px\n");
+    for (i, node) in nodes.iter().enumerate() {
+        if node.is_hub {
+            let _ = writeln!(out, "    class n{i} hub");
+        }
+        if node.violations > 0 {
+            let _ = writeln!(out, "    class n{i} violated");
+        }
+    }
+    out
+}
@@ -0,0 +1,76 @@
+// src/graph/export/graphml.rs
+//! GraphML output for Gephi, yEd, and other graph tools.
+
+use std::fmt::Write;
+
+use super::{Edge, GraphNode};
+
+/// Attribute keys: (id, domain, type).
+const KEYS: [(&str, &str, &str); 8] = [
+    ("tokens", "node", "int"),
+    ("violations", "node", "int"),
+    ("hub", "node", "boolean"),
+    ("rank", "node", "double"),
+    ("afferent", "node", "int"),
+    ("efferent", "node", "int"),
+    ("label", "node", "string"),
+    ("locality_violation", "edge", "boolean"),
+];
+
+pub(super) fn render(nodes: &[GraphNode], edges: &[Edge]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+    for (id, domain, kind) in KEYS {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{domain}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"neti\" edgedefault=\"directed\">\n");
+
+    for node in nodes {
+        write_node(&mut out, node);
+    }
+    for edge in edges {
+        let (Some(from), Some(to)) = (nodes.get(edge.from), nodes.get(edge.to)) else {
+            continue;
+        };
+        let _ = writeln!(
+            out,
+            "    <edge source=\"{}\" target=\"{}\"><data key=\"locality_violation\">{}</data></edge>",
+            escape(&from.path.display().to_string()),
+            escape(&to.path.display().to_string()),
+            edge.failed
+        );
+    }
+
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn write_node(out: &mut String, node: &GraphNode) {
+    let path = escape(&node.path.display().to_string());
+    let _ = writeln!(out, "    <node id=\"{path}\">");
+    let values = [
+        ("label", path.clone()),
+        ("tokens", node.tokens.to_string()),
+        ("violations", node.violations.to_string()),
+        ("hub", node.is_hub.to_string()),
+        ("rank", node.rank.to_string()),
+        ("afferent", node.afferent.to_string()),
+        ("efferent", node.efferent.to_string()),
+    ];
+    for (key, value) in values {
+        let _ = writeln!(out, "      <data key=\"{key}\">{value}</data>");
+    }
+    out.push_str("    </node>\n");
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,61 @@
+// src/graph/export/json.rs
+//! JSON output for custom dashboards.
+
+use serde::Serialize;
+use std::path::Path;
+
+use super::{Edge, GraphNode};
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    nodes: Vec<JsonNode<'a>>,
+    edges: Vec<JsonEdge<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonNode<'a> {
+    path: &'a Path,
+    tokens: usize,
+    violations: usize,
+    is_hub: bool,
+    rank: f64,
+    afferent: usize,
+    efferent: usize,
+}
+
+#[derive(Serialize)]
+struct JsonEdge<'a> {
+    from: &'a Path,
+    to: &'a Path,
+    locality_violation: bool,
+}
+
+pub(super) fn render(nodes: &[GraphNode], edges: &[Edge]) -> String {
+    let graph = JsonGraph {
+        nodes: nodes
+            .iter()
+            .map(|n| JsonNode {
+                path: &n.path,
+                tokens: n.tokens,
+                violations: n.violations,
+                is_hub: n.is_hub,
+                rank: n.rank,
+                afferent: n.afferent,
+                efferent: n.efferent,
+            })
+            .collect(),
+        edges: edges
+            .iter()
+            .filter_map(|e| {
+                Some(JsonEdge {
+                    from: &nodes.get(e.from)?.path,
+                    to: &nodes.get(e.to)?.path,
+                    locality_violation: e.failed,
+                })
+            })
+            .collect(),
+    };
+    let mut out = serde_json::to_string_pretty(&graph).unwrap_or_default();
+    out.push('\n');
+    out
+}
@@ -0,0 +1,134 @@
+// src/graph/export/mod.rs
+//! Renders the dependency graph as Graphviz DOT, Mermaid, GraphML, or JSON.
+//!
+//! Nodes carry their token and violation counts, `PageRank`, and coupling;
+//! edges that fail locality validation are flagged. DOT nodes grow with
+//! token count, and stable hubs are highlighted in the diagram formats.
+
+mod diagram;
+mod graphml;
+mod json;
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Output format for `neti graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    /// For Gephi, yEd, and other graph tools.
+    Graphml,
+    /// For custom dashboards.
+    Json,
+}
+
+/// A file in the rendered graph.
+#[derive(Debug, Clone, Default)]
+pub struct GraphNode {
+    pub path: PathBuf,
+    pub tokens: usize,
+    pub violations: usize,
+    pub is_hub: bool,
+    /// `PageRank` over import edges (personalized when focused).
+    pub rank: f64,
+    /// Files importing this one (Cₐ).
+    pub afferent: usize,
+    /// Files this one imports (Cₑ).
+    pub efferent: usize,
+}
+
+/// Files and their dependency edges, ready to render.
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<(PathBuf, PathBuf)>,
+    /// Edges that failed locality validation.
+    pub failed_edges: HashSet<(PathBuf, PathBuf)>,
+}
+
+impl DependencyGraph {
+    /// Keeps files under `focus` plus their direct dependencies and dependents.
+    #[must_use]
+    pub fn focused(self, focus: &Path) -> Self {
+        let focus = focus.strip_prefix(".").unwrap_or(focus);
+        let edges: Vec<(PathBuf, PathBuf)> = self
+            .edges
+            .into_iter()
+            .filter(|(from, to)| from.starts_with(focus) || to.starts_with(focus))
+            .collect();
+        let kept: HashSet<&PathBuf> = edges.iter().flat_map(|(from, to)| [from, to]).collect();
+        let nodes = self
+            .nodes
+            .into_iter()
+            .filter(|n| n.path.starts_with(focus) || kept.contains(&n.path))
+            .collect();
+        Self {
+            nodes,
+            edges,
+            failed_edges: self.failed_edges,
+        }
+    }
+}
+
+/// An edge between positions in the rendered node list.
+struct Edge {
+    from: usize,
+    to: usize,
+    failed: bool,
+}
+
+/// Renders the graph in the requested format.
+#[must_use]
+pub fn render(graph: &DependencyGraph, format: GraphFormat) -> String {
+    let nodes = with_edge_endpoints(graph);
+    let edges = index_edges(graph, &nodes);
+
+    match format {
+        GraphFormat::Dot => diagram::render_dot(&nodes, &edges),
+        GraphFormat::Mermaid => diagram::render_mermaid(&nodes, &edges),
+        GraphFormat::Graphml => graphml::render(&nodes, &edges),
+        GraphFormat::Json => json::render(&nodes, &edges),
+    }
+}
+
+/// Adds bare nodes for edge endpoints that were not scanned (e.g. non-source files).
+fn with_edge_endpoints(graph: &DependencyGraph) -> Vec<GraphNode> {
+    let mut nodes = graph.nodes.clone();
+    let mut seen: HashSet<PathBuf> = nodes.iter().map(|n| n.path.clone()).collect();
+    for path in graph.edges.iter().flat_map(|(from, to)| [from, to]) {
+        if seen.insert(path.clone()) {
+            nodes.push(GraphNode {
+                path: path.clone(),
+                ..GraphNode::default()
+            });
+        }
+    }
+    nodes
+}
+
+/// Maps edges to node positions, dropping duplicate imports.
+fn index_edges(graph: &DependencyGraph, nodes: &[GraphNode]) -> Vec<Edge> {
+    let ids: HashMap<&PathBuf, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (&n.path, i))
+        .collect();
+    let mut seen = HashSet::new();
+    graph
+        .edges
+        .iter()
+        .filter(|edge| seen.insert(*edge))
+        .filter_map(|edge| {
+            Some(Edge {
+                from: *ids.get(&edge.0)?,
+                to: *ids.get(&edge.1)?,
+                failed: graph.failed_edges.contains(edge),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+// src/graph/export/tests.rs
+//! Rendering tests for every export format.
+
+#![allow(clippy::indexing_slicing)]
+
+use super::*;
+
+fn sample() -> DependencyGraph {
+    DependencyGraph {
+        nodes: vec![
+            GraphNode {
+                path: PathBuf::from("src/a.rs"),
+                tokens: 1000,
+                violations: 2,
+                rank: 0.25,
+                efferent: 2,
+                ..GraphNode::default()
+            },
+            GraphNode {
+                path: PathBuf::from("src/hub.rs"),
+                tokens: 100,
+                is_hub: true,
+                rank: 0.5,
+                afferent: 1,
+                ..GraphNode::default()
+            },
+        ],
+        edges: vec![
+            (PathBuf::from("src/a.rs"), PathBuf::from("src/hub.rs")),
+            (PathBuf::from("src/a.rs"), PathBuf::from("build.rs")),
+            (PathBuf::from("src/a.rs"), PathBuf::from("src/hub.rs")),
+        ],
+        failed_edges: [(PathBuf::from("src/a.rs"), PathBuf::from("build.rs"))]
+            .into_iter()
+            .collect(),
+    }
+}
+
+#[test]
+fn dot_sizes_labels_and_highlights() {
+    let dot = render(&sample(), GraphFormat::Dot);
+    assert!(dot.starts_with("digraph neti {"));
+    assert!(dot.contains(
+        "n0 [label=\"src/a.rs\\n1000 tok\\n2 violations\", fontsize=15, color=red, penwidth=2];"
+    ));
+    assert!(dot.contains("fillcolor=gold"));
+    assert!(dot.contains("n0 -> n1;"));
+    assert!(
+        dot.contains("n0 -> n2 [color=red];"),
+        "unscanned endpoints become nodes, failed edges are red"
+    );
+    assert_eq!(
+        dot.matches("n0 -> n1").count(),
+        1,
+        "duplicate imports merge"
+    );
+}
+
+#[test]
+fn mermaid_emits_nodes_edges_and_classes() {
+    let mermaid = render(&sample(), GraphFormat::Mermaid);
+    assert!(mermaid.starts_with("graph LR\n"));
+    assert!(mermaid.contains("n0[\"src/a.rs<br/>1000 tok<br/>2 violations\"]"));
+    assert!(mermaid.contains("n0 --> n1"));
+    assert!(mermaid.contains("class n1 hub"));
+    assert!(mermaid.contains("class n0 violated"));
+    assert!(mermaid.contains("linkStyle 1 stroke:#d00"));
+}
+
+#[test]
+fn graphml_declares_keys_and_attributes() {
+    let graphml = render(&sample(), GraphFormat::Graphml);
+    assert!(
+        graphml.contains("<key id=\"rank\" for=\"node\" attr.name=\"rank\" attr.type=\"double\"/>")
+    );
+    assert!(graphml.contains("<node id=\"src/hub.rs\">"));
+    assert!(graphml.contains("<data key=\"hub\">true</data>"));
+    assert!(graphml.contains("<edge source=\"src/a.rs\" target=\"build.rs\"><data key=\"locality_violation\">true</data></edge>"));
+}
+
+#[test]
+fn json_lists_nodes_and_edges() {
+    let json: serde_json::Value =
+        serde_json::from_str(&render(&sample(), GraphFormat::Json)).unwrap_or_default();
+    assert_eq!(json["nodes"][1]["rank"], 0.5);
+    assert_eq!(json["nodes"][1]["afferent"], 1);
+    assert_eq!(json["edges"].as_array().map(Vec::len), Some(2));
+    assert_eq!(json["edges"][1]["locality_violation"], true);
+}
+
+#[test]
+fn focus_keeps_direct_neighbors() {
+    let graph = DependencyGraph {
+        nodes: ["src/cli/a.rs", "src/core/b.rs", "src/core/c.rs", "src/x.rs"]
+            .iter()
+            .map(|p| GraphNode {
+                path: PathBuf::from(p),
+                ..GraphNode::default()
+            })
+            .collect(),
+        edges: vec![
+            (
+                PathBuf::from("src/cli/a.rs"),
+                PathBuf::from("src/core/b.rs"),
+            ),
+            (
+                PathBuf::from("src/core/b.rs"),
+                PathBuf::from("src/core/c.rs"),
+            ),
+            (PathBuf::from("src/x.rs"), PathBuf::from("src/core/c.rs")),
+        ],
+        failed_edges: HashSet::new(),
+    }
+    .focused(Path::new("./src/cli"));
+
+    let paths: Vec<_> = graph.nodes.iter().map(|n| n.path.clone()).collect();
+    assert_eq!(
+        paths,
+        [
+            PathBuf::from("src/cli/a.rs"),
+            PathBuf::from("src/core/b.rs")
+        ]
+    );
+    assert_eq!(graph.edges.len(), 1);
+}
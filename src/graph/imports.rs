@@ -1,5 +1,6 @@
 // src/graph/imports.rs
 use crate::lang::Lang;
+use crate::query_cache;
 use omni_ast::language::extract_import_strings;
 use std::path::Path;
 use tree_sitter::{Language, Parser, Query, QueryCursor};
@@ -24,8 +25,8 @@ pub fn extract(path: &Path, content: &str) -> Vec<String> {
 
     let mut imports = extract_import_strings(path, content, None);
     let grammar = lang.grammar();
-    let query = compile_query(&grammar, lang.q_imports());
-    imports.extend(run_query(content, &grammar, &query));
+    let query = compile_query(lang, lang.q_imports());
+    imports.extend(run_query(content, &grammar, query));
     imports.sort();
     imports.dedup();
 
@@ -61,10 +62,10 @@ fn clean_text(text: &str) -> String {
         .to_string()
 }
 
-fn compile_query(lang: &Language, pattern: &str) -> Query {
-    match Query::new(lang, pattern) {
-        Ok(q) => q,
-        Err(e) => panic!("Invalid import query: {e}"),
+fn compile_query(lang: Lang, pattern: &'static str) -> &'static Query {
+    match query_cache::get(lang, pattern) {
+        Some(q) => q,
+        None => panic!("Invalid import query for {lang:?}"),
     }
 }
 
@@ -34,9 +34,14 @@ pub fn analyze(
     let mut analysis = TopologyAnalysis::default();
 
     for edge in report.failed() {
-        let kind = categorize_violation(edge, couplings, report.layers());
         let fan_in = couplings.get(&edge.to).map_or(0, Coupling::afferent);
-        let suggestion = kind.suggest(edge, fan_in);
+        let (kind, suggestion) = if let Some(breach) = report.layer_breach(edge) {
+            (ViolationKind::LayerBreach, breach.suggestion())
+        } else {
+            let kind = categorize_violation(edge, couplings, report.layers());
+            let suggestion = kind.suggest(edge, fan_in);
+            (kind, suggestion)
+        };
         analysis.violations.push(CategorizedViolation {
             edge: edge.clone(),
             kind,
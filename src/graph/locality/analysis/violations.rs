@@ -16,6 +16,8 @@ pub enum ViolationKind {
     SidewaysDep,
     /// Dependency flows upwards (Lower Layer -> Higher Layer).
     UpwardDep,
+    /// Dependency between declared layers not allowed by `layer_deps`.
+    LayerBreach,
 }
 
 impl ViolationKind {
@@ -27,6 +29,7 @@ impl ViolationKind {
             Self::MissingHub => "MISSING_HUB",
             Self::SidewaysDep => "SIDEWAYS_DEP",
             Self::UpwardDep => "UPWARD_DEP",
+            Self::LayerBreach => "LAYER_BREACH",
         }
     }
 
@@ -38,6 +41,7 @@ impl ViolationKind {
             Self::MissingHub => "Frequently imported file should be a Hub",
             Self::SidewaysDep => "Cross-module dependency without Hub routing",
             Self::UpwardDep => "Dependency violates architectural layering (Upward)",
+            Self::LayerBreach => "Dependency not allowed by declared [rules.locality.layers]",
         }
     }
 
@@ -48,7 +52,7 @@ impl ViolationKind {
             Self::GodModule => suggest_god_module(edge),
             Self::MissingHub => suggest_missing_hub(edge, fan_in),
             Self::SidewaysDep => suggest_sideways(edge),
-            Self::UpwardDep => suggest_upward(edge),
+            Self::UpwardDep | Self::LayerBreach => suggest_upward(edge),
         }
     }
 }
@@ -0,0 +1,104 @@
+// src/graph/locality/cycle_report/breaking.rs
+//! Choosing which edges to remove so a cyclic component becomes a DAG.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use super::BreakEdge;
+
+/// Components with at most this many internal edges get an exact minimum
+/// break set; larger ones fall back to DFS back edges.
+const EXACT_EDGE_LIMIT: usize = 16;
+
+/// Smallest set of internal edges whose removal leaves the component acyclic.
+pub(super) fn break_edges(files: &BTreeSet<PathBuf>, internal: &[BreakEdge]) -> Vec<BreakEdge> {
+    if internal.len() <= EXACT_EDGE_LIMIT {
+        for size in 1..=internal.len() {
+            if let Some(found) = first_breaking_subset(files, internal, size) {
+                return found;
+            }
+        }
+    }
+    back_edges(files, internal)
+}
+
+/// Tries every subset of `size` edges in lexicographic order.
+fn first_breaking_subset(
+    files: &BTreeSet<PathBuf>,
+    internal: &[BreakEdge],
+    size: usize,
+) -> Option<Vec<BreakEdge>> {
+    let mut picks: Vec<usize> = (0..size).collect();
+    loop {
+        let removed: Vec<&BreakEdge> = picks.iter().filter_map(|&i| internal.get(i)).collect();
+        let remaining = internal.iter().filter(|e| !removed.contains(e));
+        if back_edges(files, &remaining.cloned().collect::<Vec<_>>()).is_empty() {
+            return Some(removed.into_iter().cloned().collect());
+        }
+        if !advance(&mut picks, internal.len()) {
+            return None;
+        }
+    }
+}
+
+/// Steps `picks` to the next combination of indices below `n`.
+fn advance(picks: &mut [usize], n: usize) -> bool {
+    let k = picks.len();
+    let Some(pos) = (0..k)
+        .rev()
+        .find(|&i| picks.get(i).is_some_and(|&p| p < n - k + i))
+    else {
+        return false;
+    };
+    let start = picks.get(pos).copied().unwrap_or(0) + 1;
+    for (offset, slot) in picks.iter_mut().skip(pos).enumerate() {
+        *slot = start + offset;
+    }
+    true
+}
+
+/// Edges that close a cycle during a DFS; removing them all yields a DAG.
+fn back_edges(files: &BTreeSet<PathBuf>, edges: &[BreakEdge]) -> Vec<BreakEdge> {
+    let mut adjacency: BTreeMap<&Path, Vec<&Path>> = BTreeMap::new();
+    for edge in edges {
+        adjacency.entry(&edge.from).or_default().push(&edge.to);
+    }
+    let mut search = BackEdgeSearch {
+        adjacency: &adjacency,
+        done: BTreeSet::new(),
+        active: BTreeSet::new(),
+        found: Vec::new(),
+    };
+    for file in files {
+        if !search.done.contains(file.as_path()) {
+            search.visit(file);
+        }
+    }
+    search.found
+}
+
+struct BackEdgeSearch<'a> {
+    adjacency: &'a BTreeMap<&'a Path, Vec<&'a Path>>,
+    done: BTreeSet<&'a Path>,
+    active: BTreeSet<&'a Path>,
+    found: Vec<BreakEdge>,
+}
+
+impl<'a> BackEdgeSearch<'a> {
+    fn visit(&mut self, node: &'a Path) {
+        self.active.insert(node);
+        let adjacency = self.adjacency;
+        for &dep in adjacency.get(node).into_iter().flatten() {
+            if self.active.contains(dep) {
+                self.found.push(BreakEdge {
+                    from: node.to_path_buf(),
+                    to: dep.to_path_buf(),
+                });
+            } else if !self.done.contains(dep) {
+                self.visit(dep);
+            }
+        }
+        self.active.remove(node);
+        self.done.insert(node);
+    }
+}
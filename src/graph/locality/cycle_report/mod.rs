@@ -0,0 +1,249 @@
+// src/graph/locality/cycle_report/mod.rs
+//! Actionable cycle report: strongly connected components, the fewest edges
+//! whose removal breaks each one, and how many files depend on it.
+
+mod breaking;
+
+use self::breaking::break_edges;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+type Adjacency = BTreeMap<PathBuf, BTreeSet<PathBuf>>;
+
+/// A dependency edge suggested for removal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BreakEdge {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// One strongly connected component of the dependency graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleComponent {
+    /// Files in the component, sorted.
+    pub files: Vec<PathBuf>,
+    /// Edges inside the component whose removal makes it acyclic.
+    pub break_edges: Vec<BreakEdge>,
+    /// Files outside the component that transitively depend on it.
+    pub blast_radius: usize,
+}
+
+/// All cyclic components, largest blast radius first.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CycleReport {
+    pub components: Vec<CycleComponent>,
+}
+
+impl CycleReport {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+/// Builds the cycle report for a dependency graph.
+pub fn analyze_cycles<'a, I>(edges: I) -> CycleReport
+where
+    I: Iterator<Item = (&'a Path, &'a Path)>,
+{
+    let mut adjacency: Adjacency = BTreeMap::new();
+    let mut reverse: Adjacency = BTreeMap::new();
+    for (from, to) in edges {
+        adjacency
+            .entry(from.to_path_buf())
+            .or_default()
+            .insert(to.to_path_buf());
+        adjacency.entry(to.to_path_buf()).or_default();
+        reverse
+            .entry(to.to_path_buf())
+            .or_default()
+            .insert(from.to_path_buf());
+    }
+
+    let mut components: Vec<CycleComponent> = strongly_connected(&adjacency)
+        .into_iter()
+        .filter(|scc| is_cyclic(scc, &adjacency))
+        .map(|files| {
+            let internal = internal_edges(&files, &adjacency);
+            CycleComponent {
+                break_edges: break_edges(&files, &internal),
+                blast_radius: blast_radius(&files, &reverse),
+                files: files.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    components.sort_by(|a, b| {
+        b.blast_radius
+            .cmp(&a.blast_radius)
+            .then_with(|| a.files.cmp(&b.files))
+    });
+    CycleReport { components }
+}
+
+/// A component is cyclic if it has several files or a self-import.
+fn is_cyclic(scc: &BTreeSet<PathBuf>, adjacency: &Adjacency) -> bool {
+    scc.len() > 1
+        || scc
+            .iter()
+            .any(|node| adjacency.get(node).is_some_and(|deps| deps.contains(node)))
+}
+
+/// Tarjan's algorithm over the adjacency map.
+fn strongly_connected(adjacency: &Adjacency) -> Vec<BTreeSet<PathBuf>> {
+    let mut tarjan = Tarjan {
+        adjacency,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: BTreeSet::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for node in adjacency.keys() {
+        if !tarjan.index.contains_key(node.as_path()) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+struct Tarjan<'a> {
+    adjacency: &'a Adjacency,
+    index: HashMap<&'a Path, usize>,
+    low: HashMap<&'a Path, usize>,
+    stack: Vec<&'a Path>,
+    on_stack: BTreeSet<&'a Path>,
+    next: usize,
+    components: Vec<BTreeSet<PathBuf>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn visit(&mut self, node: &'a Path) {
+        self.index.insert(node, self.next);
+        self.low.insert(node, self.next);
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack.insert(node);
+
+        let adjacency = self.adjacency;
+        for dep in adjacency.get(node).into_iter().flatten() {
+            self.relax(node, dep);
+        }
+
+        if self.low.get(node) == self.index.get(node) {
+            self.pop_component(node);
+        }
+    }
+
+    fn relax(&mut self, node: &'a Path, dep: &'a Path) {
+        let candidate = if self.index.contains_key(dep) {
+            if !self.on_stack.contains(dep) {
+                return;
+            }
+            self.index.get(dep).copied()
+        } else {
+            self.visit(dep);
+            self.low.get(dep).copied()
+        };
+        if let (Some(candidate), Some(low)) = (candidate, self.low.get_mut(node)) {
+            *low = (*low).min(candidate);
+        }
+    }
+
+    fn pop_component(&mut self, root: &Path) {
+        let mut component = BTreeSet::new();
+        while let Some(member) = self.stack.pop() {
+            self.on_stack.remove(member);
+            component.insert(member.to_path_buf());
+            if member == root {
+                break;
+            }
+        }
+        self.components.push(component);
+    }
+}
+
+fn internal_edges(files: &BTreeSet<PathBuf>, adjacency: &Adjacency) -> Vec<BreakEdge> {
+    files
+        .iter()
+        .flat_map(|from| {
+            adjacency
+                .get(from)
+                .into_iter()
+                .flatten()
+                .filter(|to| files.contains(*to))
+                .map(move |to| BreakEdge {
+                    from: from.clone(),
+                    to: to.clone(),
+                })
+        })
+        .collect()
+}
+
+/// Counts files outside the component that reach it through imports.
+fn blast_radius(files: &BTreeSet<PathBuf>, reverse: &Adjacency) -> usize {
+    let mut seen: BTreeSet<&Path> = files.iter().map(PathBuf::as_path).collect();
+    let mut queue: Vec<&Path> = seen.iter().copied().collect();
+    let mut count = 0;
+    while let Some(node) = queue.pop() {
+        let dependents = reverse.get(node).into_iter().flatten();
+        for dependent in dependents {
+            if seen.insert(dependent) {
+                count += 1;
+                queue.push(dependent);
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    fn report(list: &[(&str, &str)]) -> CycleReport {
+        let edges: Vec<(PathBuf, PathBuf)> = list
+            .iter()
+            .map(|(a, b)| (PathBuf::from(a), PathBuf::from(b)))
+            .collect();
+        analyze_cycles(edges.iter().map(|(a, b)| (a.as_path(), b.as_path())))
+    }
+
+    fn edge(from: &str, to: &str) -> BreakEdge {
+        BreakEdge {
+            from: PathBuf::from(from),
+            to: PathBuf::from(to),
+        }
+    }
+
+    #[test]
+    fn test_dag_has_no_components() {
+        assert!(report(&[("a", "b"), ("b", "c"), ("a", "c")]).is_empty());
+    }
+
+    #[test]
+    fn test_figure_eight_is_one_component_with_two_breaks() {
+        let result = report(&[("a", "b"), ("b", "a"), ("b", "c"), ("c", "b"), ("x", "a")]);
+        assert_eq!(result.components.len(), 1);
+        let component = &result.components[0];
+        assert_eq!(component.files.len(), 3);
+        assert_eq!(component.break_edges.len(), 2);
+        assert_eq!(component.blast_radius, 1);
+    }
+
+    #[test]
+    fn test_shared_edge_breaks_two_cycles() {
+        // a->b is on both a->b->a and a->b->c->a.
+        let result = report(&[("a", "b"), ("b", "a"), ("b", "c"), ("c", "a")]);
+        assert_eq!(result.components[0].break_edges, vec![edge("a", "b")]);
+    }
+
+    #[test]
+    fn test_self_import_is_reported() {
+        let result = report(&[("a", "a")]);
+        assert_eq!(result.components[0].break_edges, vec![edge("a", "a")]);
+    }
+}
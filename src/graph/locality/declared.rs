@@ -0,0 +1,170 @@
+// src/graph/locality/declared.rs
+//! Declared architecture layers from `[rules.locality.layers]`.
+//!
+//! Unlike the inferred layers in `layers.rs`, these encode the intended
+//! architecture: each layer owns a set of path globs, and `layer_deps`
+//! lists which other layers it may import. Files outside every declared
+//! layer are unconstrained.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Declared layers and the dependencies allowed between them.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredLayers {
+    layers: Vec<(String, Vec<String>)>,
+    allowed: HashSet<(String, String)>,
+}
+
+/// An edge between two declared layers that is not listed in `layer_deps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerBreach {
+    pub from_layer: String,
+    pub to_layer: String,
+}
+
+impl LayerBreach {
+    #[must_use]
+    pub fn suggestion(&self) -> String {
+        format!(
+            "Layer '{}' may not depend on '{}'. Invert the dependency or add it to [rules.locality.layer_deps]",
+            self.from_layer, self.to_layer
+        )
+    }
+}
+
+impl DeclaredLayers {
+    /// Builds the layer set from config maps of layer -> globs and
+    /// layer -> layers it may depend on.
+    #[must_use]
+    pub fn new(
+        layers: &BTreeMap<String, Vec<String>>,
+        deps: &BTreeMap<String, Vec<String>>,
+    ) -> Self {
+        let allowed = deps
+            .iter()
+            .flat_map(|(from, tos)| tos.iter().map(move |to| (from.clone(), to.clone())))
+            .collect();
+        Self {
+            layers: layers
+                .iter()
+                .map(|(name, globs)| (name.clone(), globs.clone()))
+                .collect(),
+            allowed,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns the first declared layer whose globs match `path`.
+    #[must_use]
+    pub fn layer_of(&self, path: &Path) -> Option<&str> {
+        let path_str = crate::normalize::path_str(path);
+        self.layers
+            .iter()
+            .find(|(_, globs)| globs.iter().any(|g| glob_match(g, &path_str)))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns a breach if `from -> to` crosses declared layers without
+    /// an allowing `layer_deps` entry.
+    #[must_use]
+    pub fn check(&self, from: &Path, to: &Path) -> Option<LayerBreach> {
+        let from_layer = self.layer_of(from)?;
+        let to_layer = self.layer_of(to)?;
+        if from_layer == to_layer
+            || self
+                .allowed
+                .contains(&(from_layer.to_string(), to_layer.to_string()))
+        {
+            return None;
+        }
+        Some(LayerBreach {
+            from_layer: from_layer.to_string(),
+            to_layer: to_layer.to_string(),
+        })
+    }
+}
+
+/// Matches a `/`-separated path against a glob where `**` spans any number
+/// of directories and `*` matches within a single segment.
+#[must_use]
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| {
+            path.get(skip..)
+                .is_some_and(|tail| match_segments(rest, tail))
+        }),
+        Some((segment, rest)) => path
+            .split_first()
+            .is_some_and(|(head, tail)| match_segment(segment, head) && match_segments(rest, tail)),
+    }
+}
+
+fn match_segment(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(remaining) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=remaining.len()).any(|i| {
+                remaining
+                    .get(i..)
+                    .is_some_and(|tail| match_segment(rest, tail))
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layers() -> DeclaredLayers {
+        let mut layers = BTreeMap::new();
+        layers.insert("domain".to_string(), vec!["src/core/**".to_string()]);
+        layers.insert("adapters".to_string(), vec!["src/io/**".to_string()]);
+        let mut deps = BTreeMap::new();
+        deps.insert("adapters".to_string(), vec!["domain".to_string()]);
+        DeclaredLayers::new(&layers, &deps)
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("src/core/**", "src/core/model.rs"));
+        assert!(glob_match("src/core/**", "src/core/a/b.rs"));
+        assert!(glob_match("src/*/mod.rs", "src/io/mod.rs"));
+        assert!(glob_match("src/**/*_test.rs", "src/a/b/x_test.rs"));
+        assert!(!glob_match("src/core/**", "src/corex/a.rs"));
+        assert!(!glob_match("src/*.rs", "src/io/a.rs"));
+    }
+
+    #[test]
+    fn test_check_respects_layer_deps() {
+        let layers = layers();
+        let io = Path::new("src/io/file.rs");
+        let core = Path::new("src/core/model.rs");
+
+        assert_eq!(layers.check(io, core), None);
+        assert_eq!(layers.check(core, Path::new("src/core/other.rs")), None);
+        assert_eq!(layers.check(core, Path::new("src/cli/mod.rs")), None);
+        assert_eq!(
+            layers.check(core, io),
+            Some(LayerBreach {
+                from_layer: "domain".to_string(),
+                to_layer: "adapters".to_string(),
+            })
+        );
+    }
+}
@@ -33,7 +33,7 @@ pub fn collect(root: &Path, files: &[PathBuf]) -> Result<Vec<(PathBuf, PathBuf)>
 }
 
 fn collect_file_edges(root: &Path, file: &Path) -> Result<Vec<(PathBuf, PathBuf)>> {
-    let content = std::fs::read_to_string(file)?;
+    let content = crate::normalize::read_text(file)?;
     let raw_imports = imports::extract(file, &content);
 
     let edges = raw_imports
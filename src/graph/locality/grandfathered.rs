@@ -0,0 +1,162 @@
+// src/graph/locality/grandfathered.rs
+//! Grandfathered edges from `.neti/locality-exemptions.toml`.
+//!
+//! Each entry names one edge, an owner, and an expiry date. Until it expires
+//! the edge passes validation; afterwards the exemption itself is reported
+//! so the cleanup cannot be forgotten. An expired edge is reported only as
+//! its exemption, never also as a failed edge.
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Location of the exemptions file, relative to the project root.
+pub const EXEMPTIONS_PATH: &str = ".neti/locality-exemptions.toml";
+
+/// One grandfathered dependency edge.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Exemption {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub owner: String,
+    /// Last day the exemption applies, as `YYYY-MM-DD`.
+    pub expires: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExemptionFile {
+    #[serde(default)]
+    exemption: Vec<Exemption>,
+}
+
+/// Exemptions split by whether they still apply.
+#[derive(Debug, Default)]
+pub struct Grandfathered {
+    active: HashSet<(PathBuf, PathBuf)>,
+    expired: Vec<Exemption>,
+}
+
+impl Grandfathered {
+    /// Loads the exemptions file under `root`, judged against today's date.
+    /// A missing file means no exemptions.
+    ///
+    /// # Errors
+    /// Returns error if the file exists but cannot be read or parsed.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(EXEMPTIONS_PATH);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::parse(&content, chrono::Local::now().date_naive())
+            .with_context(|| format!("Invalid {EXEMPTIONS_PATH}"))
+    }
+
+    /// Parses exemptions, treating those whose expiry is before `today` as expired.
+    ///
+    /// # Errors
+    /// Returns error on malformed TOML or an unparseable `expires` date.
+    pub fn parse(content: &str, today: NaiveDate) -> Result<Self> {
+        let file: ExemptionFile = toml::from_str(content)?;
+        let mut result = Self::default();
+        for exemption in file.exemption {
+            let expires =
+                NaiveDate::parse_from_str(&exemption.expires, "%Y-%m-%d").with_context(|| {
+                    format!(
+                        "Bad expires '{}' for {} -> {} (expected YYYY-MM-DD)",
+                        exemption.expires,
+                        exemption.from.display(),
+                        exemption.to.display()
+                    )
+                })?;
+            if expires < today {
+                result.expired.push(exemption);
+            } else {
+                result
+                    .active
+                    .insert((exemption.from.clone(), exemption.to.clone()));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Edges that are currently exempt.
+    #[must_use]
+    pub fn active(&self) -> &HashSet<(PathBuf, PathBuf)> {
+        &self.active
+    }
+
+    /// Edges kept out of edge validation: active ones pass, and expired
+    /// ones are reported through [`Self::expired`] instead.
+    #[must_use]
+    pub fn exempt_edges(&self) -> HashSet<(PathBuf, PathBuf)> {
+        let expired = self.expired.iter().map(|e| (e.from.clone(), e.to.clone()));
+        self.active.iter().cloned().chain(expired).collect()
+    }
+
+    /// Exemptions past their expiry date.
+    #[must_use]
+    pub fn expired(&self) -> &[Exemption] {
+        &self.expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r#"
+[[exemption]]
+from = "src/cli/a.rs"
+to = "src/graph/b.rs"
+owner = "team-core"
+expires = "2026-03-31"
+
+[[exemption]]
+from = "src/cli/c.rs"
+to = "src/graph/d.rs"
+owner = "team-io"
+expires = "2026-01-31"
+reason = "legacy import, tracked for cleanup"
+"#;
+
+    #[test]
+    fn test_splits_active_and_expired() {
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap_or_default();
+        let grandfathered = Grandfathered::parse(FILE, today).expect("valid file");
+
+        assert!(grandfathered.active().contains(&(
+            PathBuf::from("src/cli/a.rs"),
+            PathBuf::from("src/graph/b.rs")
+        )));
+        assert_eq!(grandfathered.expired().len(), 1);
+        assert_eq!(grandfathered.expired()[0].owner, "team-io");
+    }
+
+    #[test]
+    fn test_expired_edges_are_exempt_from_validation() {
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap_or_default();
+        let grandfathered = Grandfathered::parse(FILE, today).expect("valid file");
+        let exempt = grandfathered.exempt_edges();
+
+        assert_eq!(exempt.len(), 2);
+        assert!(exempt.contains(&(
+            PathBuf::from("src/cli/c.rs"),
+            PathBuf::from("src/graph/d.rs")
+        )));
+    }
+
+    #[test]
+    fn test_rejects_bad_date() {
+        let file = "[[exemption]]\nfrom = \"a\"\nto = \"b\"\nowner = \"x\"\nexpires = \"soon\"\n";
+        assert!(Grandfathered::parse(file, NaiveDate::default()).is_err());
+    }
+}
@@ -7,10 +7,13 @@
 pub mod analysis;
 pub mod classifier;
 pub mod coupling;
+pub mod cycle_report;
 pub mod cycles;
+pub mod declared;
 pub mod distance;
 pub mod edges;
 pub mod exemptions;
+pub mod grandfathered;
 pub mod layers;
 pub mod report;
 pub mod types;
@@ -18,6 +21,7 @@ pub mod validator;
 
 pub use classifier::{classify, ClassifierConfig};
 pub use coupling::compute_coupling;
+pub use declared::DeclaredLayers;
 pub use distance::compute_distance;
 pub use edges::collect as collect_edges;
 pub use exemptions::is_structural_pattern;
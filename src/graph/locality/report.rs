@@ -4,6 +4,8 @@
 use colored::Colorize;
 
 use super::analysis::{TopologyAnalysis, ViolationKind};
+use super::cycle_report::{CycleComponent, CycleReport};
+use super::grandfathered::{Exemption, EXEMPTIONS_PATH};
 use super::ValidationReport;
 
 /// Prints a comprehensive locality report.
@@ -23,6 +25,61 @@ pub fn print_full_report(report: &ValidationReport, analysis: &TopologyAnalysis)
     print_entropy(report);
 }
 
+/// Prints each dependency cycle with the edges that would break it.
+pub fn print_cycle_report(report: &CycleReport) {
+    println!("\n{}", "DEPENDENCY CYCLES".cyan().bold());
+    if report.is_empty() {
+        println!("{}", "  ✓ No dependency cycles.".green());
+        return;
+    }
+
+    for (i, component) in report.components.iter().enumerate() {
+        print_cycle_component(i + 1, component);
+    }
+}
+
+fn print_cycle_component(number: usize, component: &CycleComponent) {
+    println!(
+        "\n{} {} ({} files, blast radius: {} dependents)",
+        ">>".yellow(),
+        format!("Cycle {number}").yellow().bold(),
+        component.files.len(),
+        component.blast_radius
+    );
+    for file in &component.files {
+        println!("    {}", file.display());
+    }
+    println!("  {}", "Break by removing:".dimmed());
+    for edge in &component.break_edges {
+        println!(
+            "    {} -> {}",
+            edge.from.display(),
+            edge.to.display().to_string().red()
+        );
+    }
+}
+
+/// Prints grandfathered edges whose exemption has expired.
+pub fn print_expired_exemptions(expired: &[Exemption]) {
+    if expired.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{} ({})",
+        "EXPIRED EXEMPTIONS".red().bold(),
+        EXEMPTIONS_PATH
+    );
+    for e in expired {
+        println!(
+            "  {} -> {}  {}",
+            e.from.display(),
+            e.to.display().to_string().red(),
+            format!("owner: {}, expired: {}", e.owner, e.expires).dimmed()
+        );
+    }
+}
+
 #[allow(clippy::cast_precision_loss)]
 fn print_summary(report: &ValidationReport) {
     let health = if report.total_edges() > 0 {
@@ -59,6 +116,7 @@ fn print_violations_by_category(analysis: &TopologyAnalysis) {
     }
 
     let order = [
+        ViolationKind::LayerBreach,
         ViolationKind::EncapsulationBreach,
         ViolationKind::GodModule,
         ViolationKind::MissingHub,
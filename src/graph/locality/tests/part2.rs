@@ -2,16 +2,20 @@
 //! Integration tests for locality analysis — part 2.
 //!
 //! Covers: upward dependency categorization, cycle detection,
-//! lib.rs exemption, vertical routing exemption.
+//! lib.rs exemption, vertical routing exemption, declared layers,
+//! grandfathered edges.
 
 #[allow(clippy::indexing_slicing)]
 #[allow(clippy::useless_vec)]
 #[allow(clippy::uninlined_format_args)]
 mod integration2 {
+    use super::super::super::analysis::analyze;
     use super::super::super::analysis::violations::{categorize_violation, ViolationKind};
+    use super::super::super::coupling::compute_coupling;
+    use super::super::super::declared::DeclaredLayers;
     use super::super::super::types::{LocalityEdge, NodeIdentity};
     use super::super::super::validator::{validate_graph, ValidatorConfig};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::path::{Path, PathBuf};
 
     #[test]
@@ -108,4 +112,52 @@ mod integration2 {
             report.failed().len()
         );
     }
+
+    #[test]
+    fn test_declared_layer_breach_overrides_distance() {
+        let edges = vec![(Path::new("src/core/model.rs"), Path::new("src/io/file.rs"))];
+
+        let mut layers = BTreeMap::new();
+        layers.insert("domain".to_string(), vec!["src/core/**".to_string()]);
+        layers.insert("adapters".to_string(), vec!["src/io/**".to_string()]);
+        let mut deps = BTreeMap::new();
+        deps.insert("adapters".to_string(), vec!["domain".to_string()]);
+
+        let config = ValidatorConfig {
+            declared_layers: DeclaredLayers::new(&layers, &deps),
+            ..Default::default()
+        };
+        let iter = || edges.iter().map(|(a, b)| (*a, *b));
+        let report = validate_graph(iter(), &config);
+
+        assert_eq!(
+            report.failed().len(),
+            1,
+            "domain -> adapters should fail even within max_distance"
+        );
+        let analysis = analyze(&report, &compute_coupling(iter()));
+        assert_eq!(analysis.violations[0].kind, ViolationKind::LayerBreach);
+    }
+
+    #[test]
+    fn test_grandfathered_edge_passes() {
+        let edges = vec![(
+            Path::new("src/cli/handlers.rs"),
+            Path::new("src/graph/deep/nested/internal.rs"),
+        )];
+
+        let mut config = ValidatorConfig {
+            max_distance: 1,
+            ..Default::default()
+        };
+        let failing = validate_graph(edges.iter().map(|(a, b)| (*a, *b)), &config);
+        assert_eq!(failing.failed().len(), 1);
+
+        config.grandfathered.insert((
+            PathBuf::from("src/cli/handlers.rs"),
+            PathBuf::from("src/graph/deep/nested/internal.rs"),
+        ));
+        let report = validate_graph(edges.iter().map(|(a, b)| (*a, *b)), &config);
+        assert!(report.failed().is_empty(), "grandfathered edge should pass");
+    }
 }
@@ -1,5 +1,6 @@
 // src/graph/mod.rs
 pub mod defs;
+pub mod export;
 pub mod imports;
 pub mod locality;
 pub mod rank;
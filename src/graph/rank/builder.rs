@@ -7,6 +7,8 @@ use std::path::{Path, PathBuf};
 use super::tags::{Tag, TagKind};
 use crate::graph::defs;
 use crate::graph::imports;
+use crate::graph::resolver;
+use crate::normalize::relative_to;
 
 /// Container for the raw data needed to construct the graph.
 pub struct GraphData {
@@ -17,10 +19,11 @@ pub struct GraphData {
     pub all_files: HashSet<PathBuf>,
 }
 
-/// Extracts tags from all files and builds the initial edge set.
+/// Extracts tags from all files and builds the initial edge set. Imports
+/// are resolved against the project `root`.
 #[must_use]
-pub fn build_data(files: &[(PathBuf, String)]) -> GraphData {
-    let extracted = extract_all_tags(files);
+pub fn build_data(root: &Path, files: &[(PathBuf, String)]) -> GraphData {
+    let extracted = extract_all_tags(root, files);
     let edges = build_edges(&extracted.defines, &extracted.references);
     let all_files = collect_all_files(&edges);
 
@@ -52,14 +55,16 @@ struct ExtractedTags {
     references: HashMap<String, HashSet<PathBuf>>,
 }
 
-fn extract_all_tags(files: &[(PathBuf, String)]) -> ExtractedTags {
+fn extract_all_tags(root: &Path, files: &[(PathBuf, String)]) -> ExtractedTags {
     let mut tags = Vec::new();
     let mut defines: HashMap<String, HashSet<PathBuf>> = HashMap::new();
     let mut references: HashMap<String, HashSet<PathBuf>> = HashMap::new();
+    let modules = ModuleResolver::new(root, files);
 
     for (path, content) in files {
         extract_defs(path, content, &mut tags, &mut defines);
         extract_refs(path, content, &mut tags, &mut references);
+        extract_module_refs(&modules, path, content, &mut defines, &mut references);
     }
 
     ExtractedTags {
@@ -120,6 +125,60 @@ fn extract_refs(
     }
 }
 
+/// Resolves path-style imports to the keys the graph uses for files.
+struct ModuleResolver<'a> {
+    root: &'a Path,
+    /// Each file's path relative to `root`, mapped to the path it was given
+    /// as, so `./src/b.ts`, `src/b.ts` and `/repo/src/b.ts` meet.
+    nodes: HashMap<PathBuf, &'a Path>,
+}
+
+impl<'a> ModuleResolver<'a> {
+    fn new(root: &'a Path, files: &'a [(PathBuf, String)]) -> Self {
+        let nodes = files
+            .iter()
+            .map(|(path, _)| (relative_to(root, path), path.as_path()))
+            .collect();
+        Self { root, nodes }
+    }
+
+    /// The file `import` in `from` names, keyed like the graph's own nodes.
+    fn resolve(&self, from: &Path, import: &str) -> Option<PathBuf> {
+        let target = relative_to(self.root, &resolver::resolve(self.root, from, import)?);
+        Some(self.nodes.get(&target).map_or(target, |p| p.to_path_buf()))
+    }
+}
+
+/// Path-style imports (TypeScript, Python) name modules, not symbols, so
+/// they never match a definition. Resolve them to files and key both sides
+/// by the target path; `build_edges` then links importer to module.
+fn extract_module_refs(
+    modules: &ModuleResolver,
+    path: &Path,
+    content: &str,
+    defines: &mut HashMap<String, HashSet<PathBuf>>,
+    references: &mut HashMap<String, HashSet<PathBuf>>,
+) {
+    if path.extension().is_some_and(|ext| ext == "rs") {
+        return;
+    }
+    let targets = imports::extract(path, content)
+        .into_iter()
+        .filter_map(|import| modules.resolve(path, &import))
+        .map(module_key);
+    for (key, target) in targets {
+        defines.entry(key.clone()).or_default().insert(target);
+        references
+            .entry(key)
+            .or_default()
+            .insert(path.to_path_buf());
+    }
+}
+
+fn module_key(target: PathBuf) -> (String, PathBuf) {
+    (target.display().to_string(), target)
+}
+
 fn build_edges(
     defines: &HashMap<String, HashSet<PathBuf>>,
     references: &HashMap<String, HashSet<PathBuf>>,
@@ -170,3 +229,30 @@ fn collect_all_files(edges: &HashMap<PathBuf, HashMap<PathBuf, usize>>) -> HashS
     }
     files
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn module_imports_resolve_under_the_root_to_the_given_keys() {
+        let dir = TempDir::new().expect("temp dir");
+        std::fs::create_dir(dir.path().join("pkg")).expect("create pkg");
+        std::fs::write(dir.path().join("pkg/util.py"), "def helper():\n    pass\n")
+            .expect("write util.py");
+        let main = PathBuf::from("./app/main.py");
+        let util = PathBuf::from("./pkg/util.py");
+        let files = vec![
+            (main.clone(), "import pkg.util\n".to_string()),
+            (util.clone(), "def helper():\n    pass\n".to_string()),
+        ];
+
+        let data = build_data(dir.path(), &files);
+        assert!(data
+            .edges
+            .get(&main)
+            .is_some_and(|targets| targets.contains_key(&util)));
+        assert_eq!(data.all_files, HashSet::from([main, util]));
+    }
+}
@@ -12,9 +12,10 @@ use std::path::Path;
 pub struct GraphEngine;
 
 impl GraphEngine {
+    /// Builds the graph of `files`, resolving imports against `root`.
     #[must_use]
-    pub fn build(files: &[(std::path::PathBuf, String)]) -> RepoGraph {
-        let data = builder::build_data(files);
+    pub fn build(root: &Path, files: &[(std::path::PathBuf, String)]) -> RepoGraph {
+        let data = builder::build_data(root, files);
         let ranks = pagerank::compute(&data.edges, &data.all_files, None);
         RepoGraph::new(data.tags, data.defines, data.references, ranks)
     }
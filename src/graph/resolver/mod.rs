@@ -1,6 +1,10 @@
-// src/graph/resolver.rs
-use crate::graph::tsconfig::TsConfig;
-use std::path::{Path, PathBuf};
+// src/graph/resolver/mod.rs
+//! Resolves import strings to files on disk, per ecosystem.
+
+mod python;
+mod typescript;
+
+use std::path::{Component, Path, PathBuf};
 
 /// Resolves an import string to a likely file path on disk.
 #[must_use]
@@ -9,8 +13,10 @@ pub fn resolve(project_root: &Path, current_file: &Path, import_str: &str) -> Op
 
     match ext {
         "rs" => resolve_rust(project_root, current_file, import_str),
-        "ts" | "tsx" | "js" | "jsx" => resolve_ts(project_root, current_file, import_str),
-        "py" => resolve_python(project_root, import_str),
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" => {
+            typescript::resolve(project_root, current_file, import_str)
+        }
+        "py" => python::resolve(project_root, current_file, import_str),
         _ => None,
     }
 }
@@ -79,78 +85,43 @@ fn check_rust_variations(base: &Path, parts: &[&str]) -> Option<PathBuf> {
     None
 }
 
-fn resolve_ts(root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
-    if import.starts_with('.') {
-        return resolve_relative_ts(current, import);
-    }
-    if is_node_module(import) {
-        return None;
-    }
-    TsConfig::load(root).and_then(|cfg| cfg.resolve(import))
-}
-
-fn resolve_relative_ts(current: &Path, import: &str) -> Option<PathBuf> {
-    let parent = current.parent()?;
-    let path = parent.join(import);
-    check_ts_file(&path).or_else(|| check_ts_index(&path))
-}
-
-/// Heuristic: bare specifiers without path separators are likely `node_modules`.
-fn is_node_module(import: &str) -> bool {
-    if import.starts_with('@') {
-        return import.splitn(3, '/').count() <= 2;
-    }
-    !import.contains('/')
-}
-
-fn check_ts_file(path: &Path) -> Option<PathBuf> {
-    if path.is_file() {
-        return Some(path.to_path_buf());
-    }
-
-    for ext in &["ts", "tsx", "js", "jsx", "json"] {
-        let p = path.with_extension(ext);
-        if p.exists() {
-            return Some(p);
-        }
-    }
-    None
-}
-
-fn check_ts_index(path: &Path) -> Option<PathBuf> {
-    if !path.is_dir() {
-        return None;
-    }
-
-    for ext in &["ts", "tsx", "js", "jsx"] {
-        let p = path.join(format!("index.{ext}"));
-        if p.exists() {
-            return Some(p);
+/// Removes `.` segments and folds `..` into their parent, without touching
+/// the filesystem, so `src/a/../b.ts` and `src/b.ts` name the same node.
+fn clean(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(out.components().next_back(), Some(Component::Normal(_))) {
+                    out.pop();
+                } else {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
         }
     }
-    None
+    out
 }
 
-fn resolve_python(root: &Path, import: &str) -> Option<PathBuf> {
-    if import.starts_with('.') {
-        return None;
-    }
-
-    let parts: Vec<&str> = import.split('.').collect();
-    let mut current = root.to_path_buf();
-    for part in &parts {
-        current.push(part);
-    }
-
-    let file_path = current.with_extension("py");
-    if file_path.exists() {
-        return Some(file_path);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_folds_dot_segments() {
+        assert_eq!(
+            clean(Path::new("./src/a/../b.ts")),
+            PathBuf::from("src/b.ts")
+        );
+        assert_eq!(
+            clean(Path::new("../lib/x.py")),
+            PathBuf::from("../lib/x.py")
+        );
+        assert_eq!(
+            clean(Path::new("/repo/./pkg/mod.py")),
+            PathBuf::from("/repo/pkg/mod.py")
+        );
     }
-
-    let init_path = current.join("__init__.py");
-    if init_path.exists() {
-        return Some(init_path);
-    }
-
-    None
 }
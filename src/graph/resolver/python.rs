@@ -0,0 +1,54 @@
+// src/graph/resolver/python.rs
+//! Python: absolute and relative module imports with package semantics.
+//!
+//! `import pkg.mod` loads `pkg/__init__.py` on the way to `pkg/mod.py`, and
+//! `from pkg import name` may name an attribute rather than a submodule, so
+//! an unresolved dotted path falls back to its deepest existing package.
+
+use std::path::{Path, PathBuf};
+
+use super::clean;
+
+/// Directories searched for absolute imports, relative to the project root.
+const SOURCE_ROOTS: [&str; 2] = ["", "src"];
+
+pub(super) fn resolve(root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
+    let found = if import.starts_with('.') {
+        resolve_relative(current, import)
+    } else {
+        SOURCE_ROOTS
+            .iter()
+            .find_map(|dir| resolve_module(&root.join(dir), import))
+    };
+    found.map(|path| clean(&path))
+}
+
+/// `from ..pkg.mod import x`: one dot is the current package, each extra
+/// dot climbs one level.
+fn resolve_relative(current: &Path, import: &str) -> Option<PathBuf> {
+    let rest = import.trim_start_matches('.');
+    let levels = import.len() - rest.len();
+
+    let mut base = current.parent()?;
+    for _ in 1..levels {
+        base = base.parent()?;
+    }
+    if rest.is_empty() {
+        return existing(base.join("__init__.py"));
+    }
+    resolve_module(base, rest)
+}
+
+/// Tries the full dotted path, then each enclosing package.
+fn resolve_module(base: &Path, dotted: &str) -> Option<PathBuf> {
+    let parts: Vec<&str> = dotted.split('.').collect();
+    (1..=parts.len()).rev().find_map(|len| {
+        let module: PathBuf = parts.iter().take(len).collect();
+        let path = base.join(module);
+        existing(path.with_extension("py")).or_else(|| existing(path.join("__init__.py")))
+    })
+}
+
+fn existing(path: PathBuf) -> Option<PathBuf> {
+    path.is_file().then_some(path)
+}
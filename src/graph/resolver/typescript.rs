@@ -0,0 +1,71 @@
+// src/graph/resolver/typescript.rs
+//! TypeScript/JavaScript: relative paths, tsconfig `paths`/`baseUrl`
+//! aliases, and package.json `imports` subpaths (`#internal/*`).
+
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use super::clean;
+use crate::graph::tsconfig::{find_ts_file, TsConfig};
+
+/// Condition keys tried, in order, for conditional `imports` targets.
+const CONDITIONS: [&str; 5] = ["types", "import", "default", "require", "node"];
+
+pub(super) fn resolve(root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
+    let found = if import.starts_with('.') {
+        find_ts_file(&current.parent()?.join(import))
+    } else if import.starts_with('#') {
+        resolve_package_import(root, current, import)
+    } else {
+        // Aliases before the node_modules assumption: `@app/utils` or a bare
+        // `utils` may be a tsconfig path. Packages simply don't resolve.
+        TsConfig::load(root).and_then(|cfg| cfg.resolve(import))
+    };
+    found.map(|path| clean(&path))
+}
+
+/// Resolves `#name` through the nearest package.json `imports` map.
+fn resolve_package_import(root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
+    let (dir, manifest) = nearest_package_json(root, current)?;
+    let imports = manifest.get("imports")?.as_object()?;
+
+    if let Some(target) = imports.get(import).and_then(pick_target) {
+        return find_ts_file(&dir.join(target));
+    }
+    imports.iter().find_map(|(pattern, target)| {
+        let matched = match_subpath(pattern, import)?;
+        let target = pick_target(target)?;
+        find_ts_file(&dir.join(target.replace('*', matched)))
+    })
+}
+
+fn nearest_package_json(root: &Path, current: &Path) -> Option<(PathBuf, Value)> {
+    for dir in current.parent()?.ancestors() {
+        let manifest = dir.join("package.json");
+        if let Ok(content) = std::fs::read_to_string(&manifest) {
+            let value = serde_json::from_str(&content).ok()?;
+            return Some((dir.to_path_buf(), value));
+        }
+        if dir == root {
+            break;
+        }
+    }
+    None
+}
+
+/// Picks a file target from a string, condition object, or fallback array.
+fn pick_target(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(target) => Some(target),
+        Value::Object(conditions) => CONDITIONS
+            .iter()
+            .find_map(|key| conditions.get(*key).and_then(pick_target)),
+        Value::Array(options) => options.iter().find_map(pick_target),
+        _ => None,
+    }
+}
+
+fn match_subpath<'a>(pattern: &str, import: &'a str) -> Option<&'a str> {
+    let (prefix, suffix) = pattern.split_once('*')?;
+    import.strip_prefix(prefix)?.strip_suffix(suffix)
+}
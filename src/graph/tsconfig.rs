@@ -104,21 +104,48 @@ fn expand_and_find(target: &Path, matched: &str) -> Option<PathBuf> {
     find_ts_file(&resolved)
 }
 
-fn find_ts_file(path: &Path) -> Option<PathBuf> {
+/// Extensions tried when an import omits one, in TypeScript's order.
+const TS_EXTENSIONS: [&str; 8] = ["ts", "tsx", "d.ts", "js", "jsx", "mjs", "cjs", "json"];
+
+/// Finds the file an extensionless or ESM-style (`./x.js` for `x.ts`)
+/// specifier refers to, falling back to a directory index.
+pub(crate) fn find_ts_file(path: &Path) -> Option<PathBuf> {
     if path.is_file() {
         return Some(path.to_path_buf());
     }
 
-    for ext in &["ts", "tsx", "js", "jsx", "json", "d.ts"] {
-        let with_ext = path.with_extension(ext);
-        if with_ext.is_file() {
-            return Some(with_ext);
-        }
+    // Append rather than replace, so `./user.service` finds `user.service.ts`.
+    let appended = TS_EXTENSIONS.iter().map(|ext| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(".");
+        name.push(ext);
+        PathBuf::from(name)
+    });
+    if let Some(found) = appended.into_iter().find(|p| p.is_file()) {
+        return Some(found);
+    }
+
+    if let Some(found) = swap_js_extension(path) {
+        return Some(found);
     }
 
     find_ts_index(path)
 }
 
+/// `./x.js` in TypeScript source names `x.ts` (or `x.tsx`) on disk.
+fn swap_js_extension(path: &Path) -> Option<PathBuf> {
+    let sources: &[&str] = match path.extension()?.to_str()? {
+        "js" | "jsx" => &["ts", "tsx"],
+        "mjs" => &["mts"],
+        "cjs" => &["cts"],
+        _ => return None,
+    };
+    sources
+        .iter()
+        .map(|ext| path.with_extension(ext))
+        .find(|p| p.is_file())
+}
+
 fn find_ts_index(path: &Path) -> Option<PathBuf> {
     if !path.is_dir() {
         return None;
@@ -0,0 +1,218 @@
+// src/init.rs
+//! `neti init`: writes a starter `neti.toml` and `.netiignore` for the
+//! detected ecosystem and optionally installs a pre-commit hook.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::fsutil::atomic_write;
+use crate::project::{self, ProjectType, Strictness};
+
+const CONFIG_FILE: &str = "neti.toml";
+const IGNORE_FILE: &str = ".netiignore";
+const HOOK_MARKER: &str = "# installed by neti init";
+
+/// Options for `neti init`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InitOptions {
+    /// Accept defaults instead of prompting.
+    pub yes: bool,
+    /// Overwrite an existing `neti.toml`.
+    pub force: bool,
+    /// Rule levels; prompted for (or Standard) when `None`.
+    pub strictness: Option<Strictness>,
+    /// Install the pre-commit hook without asking.
+    pub hooks: bool,
+}
+
+/// What happened to the pre-commit hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookOutcome {
+    Installed(PathBuf),
+    /// A hook not written by neti is already there; it was left alone.
+    Foreign(PathBuf),
+}
+
+/// Runs `neti init` in the current directory.
+///
+/// # Errors
+/// Returns error if `neti.toml` exists without `--force`, or a write fails.
+pub fn run(opts: InitOptions) -> Result<()> {
+    let root = Path::new(".");
+    let interactive = !opts.yes && std::io::stdin().is_terminal();
+    let project = ProjectType::detect_in(root);
+    println!("Detected project: {}", describe(project).bold());
+
+    if root.join(CONFIG_FILE).exists()
+        && !opts.force
+        && !(interactive && confirm("neti.toml exists. Overwrite?", false)?)
+    {
+        bail!("{CONFIG_FILE} already exists (use --force to overwrite)");
+    }
+
+    let strictness = match opts.strictness {
+        Some(s) => s,
+        None if interactive => ask_strictness()?,
+        None => Strictness::Standard,
+    };
+    write_config(root, project, strictness)?;
+    println!("{} Wrote {CONFIG_FILE}", "✓".green());
+
+    if write_ignore(root, project)? {
+        println!("{} Wrote {IGNORE_FILE}", "✓".green());
+    }
+
+    let hooks = opts.hooks
+        || (interactive && confirm("Install a pre-commit hook running `neti check`?", false)?);
+    if hooks {
+        report_hook(&install_hook(&hooks_dir()?)?);
+    }
+
+    println!(
+        "\nNext: run {} to see where the project stands.",
+        "neti check".bold()
+    );
+    Ok(())
+}
+
+/// Writes the starter `neti.toml` for `project` under `root`.
+///
+/// # Errors
+/// Returns error if the file cannot be written.
+pub fn write_config(root: &Path, project: ProjectType, strictness: Strictness) -> Result<()> {
+    let content = project::generate_toml(project, strictness);
+    atomic_write(root.join(CONFIG_FILE), content).context("Failed to write neti.toml")
+}
+
+/// Writes a starter `.netiignore` unless one exists. Returns true if written.
+///
+/// # Errors
+/// Returns error if the file cannot be written.
+pub fn write_ignore(root: &Path, project: ProjectType) -> Result<bool> {
+    let path = root.join(IGNORE_FILE);
+    if path.exists() {
+        return Ok(false);
+    }
+    atomic_write(&path, ignore_patterns(project)).context("Failed to write .netiignore")?;
+    Ok(true)
+}
+
+/// Starter `.netiignore` content: one regex per line, matched against paths.
+#[must_use]
+pub fn ignore_patterns(project: ProjectType) -> String {
+    let specific: &[&str] = match project {
+        ProjectType::Node => &[r"^(dist|build|coverage)/", r"\.min\.js$", r"\.d\.ts$"],
+        ProjectType::Python => &[r"/migrations/", r"_pb2\.py$"],
+        ProjectType::Go => &[r"\.pb\.go$", r"_gen\.go$"],
+        ProjectType::Rust | ProjectType::Unknown => &[],
+    };
+    let mut lines = vec![
+        "# Regexes for paths Neti should skip (one per line).".to_string(),
+        r"(^|/)fixtures/".to_string(),
+        r"(^|/)generated/".to_string(),
+    ];
+    lines.extend(specific.iter().map(|p| (*p).to_string()));
+    lines.join("\n") + "\n"
+}
+
+/// Writes a pre-commit hook running `neti check` into `hooks_dir`. A hook
+/// neti did not write is left in place.
+///
+/// # Errors
+/// Returns error if the hook cannot be written.
+pub fn install_hook(hooks_dir: &Path) -> Result<HookOutcome> {
+    let path = hooks_dir.join("pre-commit");
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !existing.contains(HOOK_MARKER) {
+            return Ok(HookOutcome::Foreign(path));
+        }
+    }
+    fs::create_dir_all(hooks_dir).context("Failed to create hooks directory")?;
+    atomic_write(
+        &path,
+        format!("#!/bin/sh\n{HOOK_MARKER}\nexec neti check\n"),
+    )
+    .context("Failed to write pre-commit hook")?;
+    make_executable(&path)?;
+    Ok(HookOutcome::Installed(path))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .context("Failed to make hook executable")
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// Resolves the hooks directory, honoring `core.hooksPath` and worktrees.
+fn hooks_dir() -> Result<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!("Not a git repository; cannot install hooks");
+    }
+    Ok(PathBuf::from(
+        String::from_utf8_lossy(&output.stdout).trim(),
+    ))
+}
+
+fn report_hook(outcome: &HookOutcome) {
+    match outcome {
+        HookOutcome::Installed(path) => {
+            println!("{} Installed {}", "✓".green(), path.display());
+        }
+        HookOutcome::Foreign(path) => println!(
+            "{} {} exists and was not written by neti; left unchanged",
+            "!".yellow(),
+            path.display()
+        ),
+    }
+}
+
+fn describe(project: ProjectType) -> &'static str {
+    match project {
+        ProjectType::Rust => "Rust",
+        ProjectType::Node => "Node",
+        ProjectType::Python => "Python",
+        ProjectType::Go => "Go",
+        ProjectType::Unknown => "unknown (commands left for you to fill in)",
+    }
+}
+
+fn ask_strictness() -> Result<Strictness> {
+    let answer = prompt("Rule level: [s]trict, s[t]andard, [r]elaxed? (standard)")?;
+    Ok(match answer.as_str() {
+        "s" | "strict" => Strictness::Strict,
+        "r" | "relaxed" => Strictness::Relaxed,
+        _ => Strictness::Standard,
+    })
+}
+
+fn confirm(question: &str, default: bool) -> Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    let answer = prompt(&format!("{question} {hint}"))?;
+    Ok(match answer.as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    })
+}
+
+fn prompt(question: &str) -> Result<String> {
+    print!("{question} ");
+    std::io::stdout().flush()?;
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_lowercase())
+}
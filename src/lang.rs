@@ -1,6 +1,8 @@
 // src/lang.rs
 use omni_ast::SemanticLanguage;
-use tree_sitter::Language;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use tree_sitter::{Language, Parser, Tree};
 
 #[path = "lang_queries.rs"]
 mod lang_queries;
@@ -14,6 +16,12 @@ pub enum Lang {
     Swift,
 }
 
+thread_local! {
+    /// One parser per language per thread, reused across files so its
+    /// internal buffers are allocated once rather than per parse.
+    static PARSERS: RefCell<HashMap<Lang, Parser>> = RefCell::new(HashMap::new());
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum QueryKind {
     Naming,
@@ -25,11 +33,54 @@ pub enum QueryKind {
 }
 
 impl Lang {
+    /// Every supported language.
+    pub const ALL: [Self; 4] = [Self::Rust, Self::Python, Self::TypeScript, Self::Swift];
+
     #[must_use]
     pub fn from_ext(ext: &str) -> Option<Self> {
         SemanticLanguage::from_ext(ext).and_then(Self::from_semantic_language)
     }
 
+    /// Parses a language name (`rust`, `python`, `typescript`, `swift`) or a
+    /// file extension.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "rust" => Some(Self::Rust),
+            "python" => Some(Self::Python),
+            "typescript" | "javascript" => Some(Self::TypeScript),
+            "swift" => Some(Self::Swift),
+            other => Self::from_ext(other),
+        }
+    }
+
+    /// The canonical file extension.
+    #[must_use]
+    pub fn ext(self) -> &'static str {
+        match self {
+            Self::Rust => "rs",
+            Self::Python => "py",
+            Self::TypeScript => "ts",
+            Self::Swift => "swift",
+        }
+    }
+
+    /// Parses `source` with this thread's parser for the language.
+    #[must_use]
+    pub fn parse(self, source: &str) -> Option<Tree> {
+        PARSERS.with_borrow_mut(|parsers| {
+            let parser = match parsers.entry(self) {
+                std::collections::hash_map::Entry::Occupied(e) => e.into_mut(),
+                std::collections::hash_map::Entry::Vacant(e) => {
+                    let mut parser = Parser::new();
+                    parser.set_language(&self.grammar()).ok()?;
+                    e.insert(parser)
+                }
+            };
+            parser.parse(source, None)
+        })
+    }
+
     #[must_use]
     pub fn grammar(self) -> Language {
         match self {
@@ -116,6 +167,15 @@ mod tests {
         assert_eq!(Lang::from_ext("xyz"), None);
     }
 
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Lang::from_name("Rust"), Some(Lang::Rust));
+        assert_eq!(Lang::from_name("javascript"), Some(Lang::TypeScript));
+        assert_eq!(Lang::from_name("py"), Some(Lang::Python));
+        assert_eq!(Lang::from_name("cobol"), None);
+        assert_eq!(Lang::from_name(Lang::Swift.ext()), Some(Lang::Swift));
+    }
+
     #[test]
     fn test_semantic_language_mapping() {
         assert_eq!(Lang::Rust.semantic_language(), SemanticLanguage::Rust);
@@ -52,6 +52,7 @@ pub const QUERIES: [[&str; 6]; 4] = [
             (import_statement name: (dotted_name) @import)
             (aliased_import name: (dotted_name) @import)
             (import_from_statement module_name: (dotted_name) @import)
+            (import_from_statement module_name: (relative_import) @import)
         ",
         r"
             (function_definition name: (identifier) @name) @sig
@@ -0,0 +1,55 @@
+// src/last_check.rs
+//! Violation counts by rule from the most recent `neti check`, kept in
+//! `.neti/last-check.json` so `neti promote` can fill `{rules}` in the
+//! commit message without scanning again.
+
+use crate::fsutil::atomic_write;
+use crate::ratchet::{self, Counts};
+use std::fs;
+use std::path::Path;
+
+/// Where the last check's counts are kept, relative to the repo root.
+pub const PATH: &str = ".neti/last-check.json";
+
+/// Records `counts` as the last check's. A failed write only leaves
+/// `{rules}` empty at the next promote, so it is not an error.
+pub fn record(root: &Path, counts: &Counts) {
+    let path = root.join(PATH);
+    let Some(dir) = path.parent() else { return };
+    if fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(counts) {
+        // Best effort, as above.
+        let _ = atomic_write(&path, json);
+    }
+}
+
+/// The last check's counts, or else the ratchet file's, if either exists.
+#[must_use]
+pub fn load(root: &Path) -> Option<Counts> {
+    fs::read_to_string(root.join(PATH))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .or_else(|| ratchet::load(root).ok().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_last_check_wins_over_the_ratchet_file() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        assert_eq!(load(dir.path()), None);
+
+        let ratchet = Counts::from([("P01".to_string(), 3)]);
+        ratchet::save(dir.path(), &ratchet)?;
+        assert_eq!(load(dir.path()), Some(ratchet));
+
+        let last = Counts::from([("X01".to_string(), 1)]);
+        record(dir.path(), &last);
+        assert_eq!(load(dir.path()), Some(last));
+        Ok(())
+    }
+}
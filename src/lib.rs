@@ -1,24 +1,150 @@
+//! Neti's analysis engine as a library.
+//!
+//! The `neti` binary is a thin layer over this crate. Everything it reports
+//! comes from the modules here: language detection ([`Lang`]), file
+//! discovery, the rule checks in [`analysis`], the dependency graph in
+//! [`graph`] and the report types in [`types`].
+//!
+//! [`scan`] is the stable entry point. It runs the same pipeline as
+//! `neti scan` and returns the report instead of printing it:
+//!
+//! ```no_run
+//! use std::path::PathBuf;
+//!
+//! # #[cfg(feature = "fs")]
+//! # fn main() -> anyhow::Result<()> {
+//! let config = neti_core::Config::load();
+//! let report = neti_core::scan(&[PathBuf::from("src")], &config)?;
+//! for file in &report.files {
+//!     for v in &file.violations {
+//!         println!("{}:{} {}", file.path.display(), v.row, v.message);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "fs"))]
+//! # fn main() {}
+//! ```
+//!
+//! [`scan_source`] analyzes a single in-memory buffer without touching the
+//! filesystem; the C and WASM bindings in `bindings/ffi` are built on it.
+//!
+//! The command-line interface lives behind the default `cli` feature.
+//! Depend on the crate with `default-features = false` to get the analysis
+//! API without clap or the terminal UI. [`scan`] and the modules that walk
+//! the project or run git need the `fs` feature (implied by `cli`); without
+//! it the crate builds for `wasm32-unknown-unknown`.
+
 pub mod analysis;
+pub mod api;
+#[cfg(feature = "fs")]
 pub mod branch;
+#[cfg(feature = "fs")]
 pub mod clean;
+#[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(feature = "fs")]
+pub mod codeowners;
+pub mod commit_message;
+pub mod compare;
 pub mod config;
 pub mod constants;
+#[cfg(feature = "fs")]
+pub mod debt;
 pub mod detection;
+#[cfg(feature = "fs")]
 pub mod discovery;
+#[cfg(feature = "fs")]
+pub mod doctor;
 pub mod events;
 pub mod exit;
 pub mod file_class;
+pub mod fsutil;
 pub mod graph;
+#[cfg(feature = "fs")]
+pub mod init;
 pub mod lang;
+#[cfg(feature = "fs")]
+pub mod last_check;
+pub mod logging;
+#[cfg(feature = "fs")]
+pub mod metrics;
+#[cfg(feature = "fs")]
 pub mod mutate;
+pub mod normalize;
+#[cfg(feature = "fs")]
+pub mod notify;
+pub mod profile;
 pub mod project;
+pub mod query_cache;
+pub mod ratchet;
+#[cfg(feature = "fs")]
+pub mod remote;
 pub mod reporting;
+pub mod schema;
+pub mod secrets;
 pub mod skeleton;
+#[cfg(feature = "cli")]
 pub mod spinner;
+#[cfg(feature = "fs")]
+pub mod status;
+pub mod symbols;
 pub mod tokens;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "fs")]
 pub mod verification;
+pub mod workspace;
+pub mod xlsx;
 
 pub use omni_ast;
+
+#[cfg(feature = "fs")]
+pub use analysis::Engine;
+pub use config::Config;
+pub use lang::Lang;
+pub use types::{Confidence, FileReport, ScanReport, Violation};
+
+#[cfg(feature = "fs")]
+use anyhow::Result;
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Scans the source files under `paths` and returns what `neti scan` would
+/// report for them.
+///
+/// Files are discovered from the current directory with `config`'s
+/// discovery and include/exclude settings, then narrowed to those equal to
+/// or under one of `paths`. An empty `paths` scans the whole project.
+/// Paths are relative to the current directory.
+///
+/// # Errors
+/// Returns error if file discovery fails.
+#[cfg(feature = "fs")]
+pub fn scan(paths: &[PathBuf], config: &Config) -> Result<ScanReport> {
+    let mut files = discovery::discover(config)?;
+    if !paths.is_empty() {
+        files.retain(|file| paths.iter().any(|p| file.starts_with(relative(p))));
+    }
+    Ok(Engine::scan(config, &files))
+}
+
+/// Analyzes one buffer of `lang` source and returns its violations, without
+/// reading or writing any files. Line endings are normalized first, as they
+/// are for files on disk.
+#[must_use]
+pub fn scan_source(lang: Lang, source: &str, config: &Config) -> Vec<Violation> {
+    let path = PathBuf::from(format!("input.{}", lang.ext()));
+    let source = normalize::line_endings(source.to_owned());
+    let mut violations = analysis::worker::scan_text(&path, &source, config).violations;
+    if config.preferences.stable_order {
+        types::sort_violations(&mut violations);
+    }
+    violations
+}
+
+#[cfg(feature = "fs")]
+fn relative(path: &Path) -> &Path {
+    path.strip_prefix(".").unwrap_or(path)
+}
@@ -0,0 +1,128 @@
+// src/logging.rs
+//! Diagnostic logging behind the `log` facade.
+//!
+//! Warnings reach stderr as `WARN: ...`, as they always have. `--log-level`
+//! (or `NETI_LOG`) turns on more detail, and `--log-file` sends every
+//! record to a file instead, stamped with the time and the module that
+//! emitted it, so a field report can come with a log rather than a rebuild.
+
+use anyhow::{Context, Result};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Environment variable read when `--log-level` is not given.
+pub const LOG_ENV: &str = "NETI_LOG";
+
+struct Logger {
+    level: LevelFilter,
+    stderr_level: LevelFilter,
+    file: Option<Mutex<File>>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if record.level() <= self.stderr_level {
+            eprintln!("{}: {}", record.level(), record.args());
+        }
+        if let Some(file) = &self.file {
+            let line = format!(
+                "{} {:<5} {}: {}\n",
+                chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f"),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+            if let Ok(mut f) = file.lock() {
+                let _ = f.write_all(line.as_bytes());
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(Ok(mut f)) = self.file.as_ref().map(Mutex::lock) {
+            let _ = f.flush();
+        }
+    }
+}
+
+/// Installs the process logger. `level` defaults to `NETI_LOG`, then to
+/// `debug` when logging to a file and `warn` otherwise. With a file, stderr
+/// still only shows warnings and errors.
+///
+/// # Errors
+/// Returns an error if the log file cannot be opened.
+pub fn init(level: Option<LevelFilter>, file: Option<&Path>) -> Result<()> {
+    let level = level.or_else(env_level).unwrap_or(if file.is_some() {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Warn
+    });
+    let file = file.map(open_log).transpose()?;
+    let stderr_level = if file.is_some() {
+        level.min(LevelFilter::Warn)
+    } else {
+        level
+    };
+    let logger = Logger {
+        level,
+        stderr_level,
+        file: file.map(Mutex::new),
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(level);
+    }
+    Ok(())
+}
+
+fn env_level() -> Option<LevelFilter> {
+    std::env::var(LOG_ENV).ok()?.parse().ok()
+}
+
+fn open_log(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open log file {}", path.display()))
+}
+
+/// A timed unit of work in one subsystem. Logs at debug level when opened
+/// and again, with the elapsed time, when dropped.
+#[must_use = "the span ends when dropped"]
+pub struct Span {
+    name: &'static str,
+    start: Instant,
+}
+
+/// Opens a span named after the subsystem doing the work, e.g. `scan`.
+pub fn span(name: &'static str) -> Span {
+    log::debug!(target: "neti::span", "{name}: start");
+    Span {
+        name,
+        start: Instant::now(),
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if log::log_enabled!(target: "neti::span", Level::Debug) {
+            let ms = self.start.elapsed().as_millis();
+            log::debug!(target: "neti::span", "{}: done in {ms} ms", self.name);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// src/metrics.rs
+//! Dashboard metrics (`neti metrics`).
+//!
+//! Gauges from a full scan — violations by rule, tokens, a histogram of
+//! per-file complexity, per-file totals — plus the locality violation count
+//! and the last mutation score when those are available. Rendered as
+//! Prometheus text exposition (for a textfile collector or pushgateway) or
+//! as JSON.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::mutate::runner::MutationSummary;
+use crate::types::{Confidence, ScanReport};
+
+#[cfg(test)]
+#[path = "metrics_test.rs"]
+mod tests;
+
+/// Upper bounds of the complexity histogram buckets.
+const COMPLEXITY_BOUNDS: [usize; 7] = [1, 2, 5, 10, 15, 25, 50];
+
+/// Output format for `neti metrics`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum MetricsFormat {
+    /// Prometheus text exposition format.
+    Prometheus,
+    Json,
+}
+
+/// Everything `neti metrics` exports.
+#[derive(Debug, Serialize)]
+pub struct Metrics {
+    pub files: usize,
+    pub total_tokens: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub violations_by_rule: BTreeMap<&'static str, usize>,
+    /// Cumulative file counts by complexity score, Prometheus-style.
+    pub complexity_histogram: Vec<Bucket>,
+    pub complexity_sum: usize,
+    pub per_file: Vec<FileMetrics>,
+    /// `None` when locality checking is off.
+    pub locality_violations: Option<usize>,
+    /// Score of the last `neti mutate` run, if one was recorded.
+    pub mutation_score: Option<f64>,
+}
+
+/// Files whose complexity is at most `le` (`+Inf` for the last bucket).
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Bucket {
+    pub le: String,
+    pub count: usize,
+}
+
+/// Totals for one scanned file.
+#[derive(Debug, Serialize)]
+pub struct FileMetrics {
+    pub path: String,
+    pub tokens: usize,
+    pub complexity: usize,
+    pub violations: usize,
+}
+
+/// Builds the metrics from a scan and the optional extras.
+#[must_use]
+pub fn collect(
+    scan: &ScanReport,
+    locality_violations: Option<usize>,
+    mutation: Option<&MutationSummary>,
+) -> Metrics {
+    let violations = scan.files.iter().flat_map(|f| &f.violations);
+    let mut violations_by_rule = BTreeMap::new();
+    for v in violations.clone() {
+        *violations_by_rule.entry(v.law).or_insert(0) += 1;
+    }
+    let errors = violations
+        .clone()
+        .filter(|v| v.confidence == Confidence::High)
+        .count();
+    let scores: Vec<usize> = scan.files.iter().map(|f| f.complexity_score).collect();
+
+    Metrics {
+        files: scan.files.len(),
+        total_tokens: scan.total_tokens,
+        errors,
+        warnings: violations.count() - errors,
+        violations_by_rule,
+        complexity_histogram: histogram(&scores),
+        complexity_sum: scores.iter().sum(),
+        per_file: scan
+            .files
+            .iter()
+            .map(|f| FileMetrics {
+                path: crate::normalize::path_str(&f.path),
+                tokens: f.token_count,
+                complexity: f.complexity_score,
+                violations: f.violations.len(),
+            })
+            .collect(),
+        locality_violations,
+        mutation_score: mutation.map(|m| m.score),
+    }
+}
+
+fn histogram(scores: &[usize]) -> Vec<Bucket> {
+    let bounded = COMPLEXITY_BOUNDS.iter().map(|&bound| Bucket {
+        le: bound.to_string(),
+        count: scores.iter().filter(|&&s| s <= bound).count(),
+    });
+    bounded
+        .chain(std::iter::once(Bucket {
+            le: "+Inf".into(),
+            count: scores.len(),
+        }))
+        .collect()
+}
+
+/// Renders the metrics in the Prometheus text exposition format.
+#[must_use]
+pub fn render_prometheus(m: &Metrics) -> String {
+    let mut out = String::new();
+    gauge(&mut out, "neti_files", "Files scanned.", &[("", m.files)]);
+    gauge(
+        &mut out,
+        "neti_tokens",
+        "Tokens across all scanned files.",
+        &[("", m.total_tokens)],
+    );
+    gauge(
+        &mut out,
+        "neti_errors",
+        "High-confidence violations.",
+        &[("", m.errors)],
+    );
+    gauge(
+        &mut out,
+        "neti_warnings",
+        "Violations to review.",
+        &[("", m.warnings)],
+    );
+
+    let by_rule: Vec<(String, usize)> = m
+        .violations_by_rule
+        .iter()
+        .map(|(rule, n)| (labels("rule", rule), *n))
+        .collect();
+    gauge(
+        &mut out,
+        "neti_violations",
+        "Violations by rule.",
+        &borrowed(&by_rule),
+    );
+
+    let _ = writeln!(
+        out,
+        "# HELP neti_file_complexity Highest function complexity per file."
+    );
+    let _ = writeln!(out, "# TYPE neti_file_complexity histogram");
+    for bucket in &m.complexity_histogram {
+        let _ = writeln!(
+            out,
+            "neti_file_complexity_bucket{} {}",
+            labels("le", &bucket.le),
+            bucket.count
+        );
+    }
+    let _ = writeln!(out, "neti_file_complexity_sum {}", m.complexity_sum);
+    let _ = writeln!(out, "neti_file_complexity_count {}", m.files);
+
+    render_files(&mut out, &m.per_file);
+    if let Some(n) = m.locality_violations {
+        gauge(
+            &mut out,
+            "neti_locality_violations",
+            "Locality violations.",
+            &[("", n)],
+        );
+    }
+    if let Some(score) = m.mutation_score {
+        let _ = writeln!(
+            out,
+            "# HELP neti_mutation_score Mutation score of the last run, in percent."
+        );
+        let _ = writeln!(out, "# TYPE neti_mutation_score gauge");
+        let _ = writeln!(out, "neti_mutation_score {score:.1}");
+    }
+    out
+}
+
+fn render_files(out: &mut String, files: &[FileMetrics]) {
+    let series = |value: fn(&FileMetrics) -> usize| -> Vec<(String, usize)> {
+        files
+            .iter()
+            .map(|f| (labels("file", &f.path), value(f)))
+            .collect()
+    };
+    let tokens = series(|f| f.tokens);
+    let violations = series(|f| f.violations);
+    gauge(
+        out,
+        "neti_file_tokens",
+        "Tokens per file.",
+        &borrowed(&tokens),
+    );
+    gauge(
+        out,
+        "neti_file_violations",
+        "Violations per file.",
+        &borrowed(&violations),
+    );
+}
+
+fn borrowed(series: &[(String, usize)]) -> Vec<(&str, usize)> {
+    series.iter().map(|(l, n)| (l.as_str(), *n)).collect()
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, series: &[(&str, usize)]) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} gauge");
+    for (labels, value) in series {
+        let _ = writeln!(out, "{name}{labels} {value}");
+    }
+}
+
+/// A single-label set, with the value escaped as the format requires.
+fn labels(key: &str, value: &str) -> String {
+    let escaped = value
+        .replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("{{{key}=\"{escaped}\"}}")
+}
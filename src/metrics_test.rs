@@ -0,0 +1,55 @@
+use super::*;
+use crate::types::{FileReport, Violation};
+use std::path::PathBuf;
+
+fn file(path: &str, complexity: usize, laws: &[&'static str]) -> FileReport {
+    FileReport {
+        path: PathBuf::from(path),
+        token_count: 100,
+        complexity_score: complexity,
+        violations: laws
+            .iter()
+            .map(|l| Violation::simple(1, "m".into(), l))
+            .collect(),
+        analysis: None,
+        suppressions: Default::default(),
+    }
+}
+
+fn scan() -> ScanReport {
+    ScanReport {
+        files: vec![
+            file("src/a.rs", 3, &["P01", "P01"]),
+            file("src/b.rs", 30, &["E01"]),
+        ],
+        total_tokens: 200,
+        total_violations: 3,
+        duration_ms: 0,
+    }
+}
+
+#[test]
+fn collect_counts_rules_and_buckets() {
+    let m = collect(&scan(), Some(2), None);
+    assert_eq!(m.violations_by_rule.get("P01"), Some(&2));
+    assert_eq!(m.errors, 3);
+    let counts: Vec<usize> = m.complexity_histogram.iter().map(|b| b.count).collect();
+    assert_eq!(counts, vec![0, 0, 1, 1, 1, 1, 2, 2]);
+    assert_eq!(m.complexity_sum, 33);
+}
+
+#[test]
+fn prometheus_output_has_typed_series() {
+    let text = render_prometheus(&collect(&scan(), Some(2), None));
+    assert!(text.contains("# TYPE neti_violations gauge\n"));
+    assert!(text.contains("neti_violations{rule=\"P01\"} 2\n"));
+    assert!(text.contains("neti_file_complexity_bucket{le=\"+Inf\"} 2\n"));
+    assert!(text.contains("neti_file_tokens{file=\"src/a.rs\"} 100\n"));
+    assert!(text.contains("neti_locality_violations 2\n"));
+    assert!(!text.contains("neti_mutation_score"));
+}
+
+#[test]
+fn label_values_are_escaped() {
+    assert_eq!(labels("file", "a\"b\\c"), r#"{file="a\"b\\c"}"#);
+}
@@ -0,0 +1,110 @@
+// src/mutate/coverage/formats.rs
+//! Parsers for each supported coverage report, yielding executed lines per file.
+
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+pub type LineSets = Vec<(String, HashSet<usize>)>;
+
+/// llvm-cov export: segments are `[line, col, count, has_count, is_entry, ...]`
+/// and each one's count holds until the next segment starts.
+pub fn parse_llvm(data: &[Value]) -> LineSets {
+    let files = data
+        .iter()
+        .filter_map(|d| d.get("files").and_then(Value::as_array))
+        .flatten();
+
+    files
+        .filter_map(|file| {
+            let name = file.get("filename")?.as_str()?.to_string();
+            let segments: Vec<(usize, u64, bool)> = file
+                .get("segments")?
+                .as_array()?
+                .iter()
+                .filter_map(parse_segment)
+                .collect();
+            Some((name, covered_from_segments(&segments)))
+        })
+        .collect()
+}
+
+fn parse_segment(segment: &Value) -> Option<(usize, u64, bool)> {
+    let fields = segment.as_array()?;
+    let line = usize::try_from(fields.first()?.as_u64()?).ok()?;
+    let count = fields.get(2)?.as_u64()?;
+    let has_count = fields.get(3)?.as_bool()?;
+    Some((line, count, has_count))
+}
+
+fn covered_from_segments(segments: &[(usize, u64, bool)]) -> HashSet<usize> {
+    let mut covered = HashSet::new();
+    for (idx, &(line, count, has_count)) in segments.iter().enumerate() {
+        if !has_count || count == 0 {
+            continue;
+        }
+        let end = segments.get(idx + 1).map_or(line, |next| next.0);
+        covered.extend(line..=end);
+    }
+    covered
+}
+
+/// coverage.py: `{"files": {"pkg/mod.py": {"executed_lines": [..]}}}`.
+pub fn parse_coverage_py(files: &Map<String, Value>) -> LineSets {
+    files
+        .iter()
+        .map(|(name, entry)| {
+            let lines = entry
+                .get("executed_lines")
+                .and_then(Value::as_array)
+                .map(|l| line_numbers(l))
+                .unwrap_or_default();
+            (name.clone(), lines)
+        })
+        .collect()
+}
+
+fn line_numbers(values: &[Value]) -> HashSet<usize> {
+    values
+        .iter()
+        .filter_map(Value::as_u64)
+        .filter_map(|n| usize::try_from(n).ok())
+        .collect()
+}
+
+pub fn is_istanbul(files: &Map<String, Value>) -> bool {
+    files.values().all(|f| f.get("statementMap").is_some())
+}
+
+/// istanbul: statements with a hit count in `s` cover their whole line span.
+pub fn parse_istanbul(files: &Map<String, Value>) -> LineSets {
+    files
+        .iter()
+        .map(|(name, entry)| (name.clone(), istanbul_lines(entry)))
+        .collect()
+}
+
+fn istanbul_lines(entry: &Value) -> HashSet<usize> {
+    let (Some(map), Some(hits)) = (
+        entry.get("statementMap").and_then(Value::as_object),
+        entry.get("s").and_then(Value::as_object),
+    ) else {
+        return HashSet::new();
+    };
+
+    let mut covered = HashSet::new();
+    for (id, stmt) in map {
+        if hits.get(id).and_then(Value::as_u64).unwrap_or(0) == 0 {
+            continue;
+        }
+        let line = |key: &str| {
+            stmt.get(key)
+                .and_then(|p| p.get("line"))
+                .and_then(Value::as_u64)
+                .and_then(|n| usize::try_from(n).ok())
+        };
+        if let (Some(start), Some(end)) = (line("start"), line("end")) {
+            covered.extend(start..=end);
+        }
+    }
+    covered
+}
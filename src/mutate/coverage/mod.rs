@@ -0,0 +1,158 @@
+// src/mutate/coverage/mod.rs
+//! Line coverage import for mutant filtering.
+//!
+//! Reads the JSON reports of `cargo llvm-cov --json`, coverage.py
+//! (`coverage json`), and istanbul (`coverage-final.json`). Mutation points
+//! on lines no test executes cannot be killed, so they are reported as
+//! "not covered" instead of spending a full test run on them.
+
+mod formats;
+
+use crate::mutate::mutations::MutationPoint;
+use crate::mutate::runner::MutationResult;
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Executed lines per source file, keyed by project-relative path.
+#[derive(Debug, Default)]
+pub struct CoverageMap {
+    files: HashMap<PathBuf, HashSet<usize>>,
+}
+
+impl CoverageMap {
+    /// Loads a coverage report, detecting its format from the JSON shape.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read or is not a known format.
+    pub fn load(path: &Path, root: &Path) -> Result<Self> {
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read coverage report {}", path.display()))?;
+        let json: Value = serde_json::from_str(&raw)
+            .with_context(|| format!("Invalid JSON in {}", path.display()))?;
+        Self::from_json(&json, root)
+    }
+
+    /// Parses an already-decoded coverage report.
+    ///
+    /// # Errors
+    /// Returns error if the JSON matches none of the supported formats.
+    pub fn from_json(json: &Value, root: &Path) -> Result<Self> {
+        let files = if let Some(data) = json.get("data").and_then(Value::as_array) {
+            formats::parse_llvm(data)
+        } else if let Some(files) = json.get("files").and_then(Value::as_object) {
+            formats::parse_coverage_py(files)
+        } else if let Some(files) = json.as_object().filter(|m| formats::is_istanbul(m)) {
+            formats::parse_istanbul(files)
+        } else {
+            return Err(anyhow!(
+                "Unrecognized coverage format (expected llvm-cov, coverage.py, or istanbul JSON)"
+            ));
+        };
+
+        Ok(Self {
+            files: files
+                .into_iter()
+                .map(|(path, lines)| (relative(Path::new(&path), root), lines))
+                .collect(),
+        })
+    }
+
+    /// Whether `line` of `file` was executed. Files absent from the report
+    /// return `None` so they are tested rather than silently skipped.
+    #[must_use]
+    pub fn line_coverage(&self, file: &Path, line: usize) -> Option<bool> {
+        self.files
+            .get(&relative(file, Path::new("")))
+            .map(|lines| lines.contains(&line))
+    }
+}
+
+/// Splits points into those to test and uncovered ones reported without a
+/// run. With `only_covered`, uncovered points are dropped entirely.
+///
+/// # Errors
+/// Returns error if the coverage report cannot be loaded.
+pub fn split(
+    points: Vec<MutationPoint>,
+    report: &Path,
+    workdir: &Path,
+    only_covered: bool,
+) -> Result<(Vec<MutationPoint>, Vec<MutationResult>)> {
+    let root = workdir
+        .canonicalize()
+        .unwrap_or_else(|_| workdir.to_path_buf());
+    let map = CoverageMap::load(report, &root)?;
+
+    let (tested, skipped): (Vec<_>, Vec<_>) = points
+        .into_iter()
+        .partition(|p| map.line_coverage(&p.file, p.line) != Some(false));
+
+    let uncovered = if only_covered {
+        Vec::new()
+    } else {
+        skipped
+            .into_iter()
+            .map(MutationResult::not_covered)
+            .collect()
+    };
+    Ok((tested, uncovered))
+}
+
+/// Normalizes a report or discovery path to a project-relative one.
+fn relative(path: &Path, root: &Path) -> PathBuf {
+    let path = path.strip_prefix(root).unwrap_or(path);
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn llvm_segments_cover_until_next_segment() {
+        let report = json!({"data": [{"files": [{
+            "filename": "/repo/src/lib.rs",
+            "segments": [[2, 1, 5, true, true], [4, 1, 0, true, false], [6, 1, 0, false, false]]
+        }]}]});
+        let map = CoverageMap::from_json(&report, Path::new("/repo")).unwrap();
+        let file = Path::new("./src/lib.rs");
+
+        assert_eq!(map.line_coverage(file, 3), Some(true));
+        assert_eq!(map.line_coverage(file, 5), Some(false));
+        assert_eq!(map.line_coverage(Path::new("src/other.rs"), 3), None);
+    }
+
+    #[test]
+    fn coverage_py_executed_lines() {
+        let report = json!({"files": {"pkg/mod.py": {"executed_lines": [1, 3]}}});
+        let map = CoverageMap::from_json(&report, Path::new("/repo")).unwrap();
+
+        assert_eq!(map.line_coverage(Path::new("pkg/mod.py"), 3), Some(true));
+        assert_eq!(map.line_coverage(Path::new("pkg/mod.py"), 2), Some(false));
+    }
+
+    #[test]
+    fn istanbul_statement_hits() {
+        let report = json!({"/repo/src/a.js": {
+            "statementMap": {
+                "0": {"start": {"line": 1}, "end": {"line": 2}},
+                "1": {"start": {"line": 4}, "end": {"line": 4}}
+            },
+            "s": {"0": 3, "1": 0}
+        }});
+        let map = CoverageMap::from_json(&report, Path::new("/repo")).unwrap();
+
+        assert_eq!(map.line_coverage(Path::new("./src/a.js"), 2), Some(true));
+        assert_eq!(map.line_coverage(Path::new("./src/a.js"), 4), Some(false));
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        let report = json!({"lines": []});
+        assert!(CoverageMap::from_json(&report, Path::new("/repo")).is_err());
+    }
+}
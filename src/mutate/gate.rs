@@ -0,0 +1,166 @@
+// src/mutate/gate.rs
+//! Time-boxed mutation sample for `neti check` (`[commands] mutate`).
+//!
+//! Mutants are visited round-robin across files so a budget that expires
+//! early still samples the whole codebase rather than the first few files.
+//! A sample in which no mutant finished is inconclusive and fails the gate.
+
+use super::{config_for_project, discover_all_mutations, runner, targets};
+use crate::config::{Config, MutateGate};
+use crate::discovery::discover;
+use crate::mutate::mutations::MutationPoint;
+use crate::mutate::runner::MutationSummary;
+use crate::project::ProjectType;
+use crate::types::CommandResult;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Display name of the stage in check reports.
+const STAGE: &str = "neti mutate (sampled)";
+
+/// Runs the sample and reports it as a verification command result that
+/// fails when the score is below `gate.min_score`.
+#[must_use]
+pub fn check(workdir: &Path, gate: &MutateGate) -> CommandResult {
+    let start = Instant::now();
+    let (exit_code, stdout, stderr) = match run_sample(workdir, gate, start) {
+        Ok((passed, summary)) => (i32::from(!passed), summary, String::new()),
+        Err(e) => (1, String::new(), format!("{e:#}")),
+    };
+    let duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+    CommandResult::new(STAGE.to_string(), exit_code, stdout, stderr, duration_ms)
+}
+
+/// Returns whether the sample met the minimum score, and a text summary.
+fn run_sample(workdir: &Path, gate: &MutateGate, start: Instant) -> Result<(bool, String)> {
+    let config = Config::load();
+    let files = discover(&config)?;
+
+    let project_type = ProjectType::detect_in(workdir);
+    let mut runner_config = config_for_project(project_type);
+    runner_config.targeting = targets::for_project(project_type, &files);
+    runner_config.deadline = Some(start + Duration::from_secs(gate.budget_secs));
+
+    let points = round_robin(discover_all_mutations(&files));
+    let results = runner::run_mutations(&points, &runner_config, workdir, |_, _, _| {})?;
+
+    let summary = runner::summarize(&results);
+    let mut out = format!(
+        "Sampled {} of {} mutants in {}s: score {:.1}% (minimum {:.0}%)\n",
+        summary.total,
+        points.len(),
+        start.elapsed().as_secs(),
+        summary.score,
+        gate.min_score
+    );
+    for result in results.iter().filter(|r| r.survived) {
+        let p = &result.point;
+        let _ = writeln!(
+            out,
+            "  survived: {}:{} '{}' -> '{}'",
+            p.file.display(),
+            p.line,
+            p.original,
+            p.mutated
+        );
+    }
+    if tested(&summary) == 0 {
+        out.push_str("No mutant finished within the budget; the sample is inconclusive.\n");
+    }
+    Ok((passes(&summary, gate.min_score), out))
+}
+
+/// Mutants whose tests ran to a verdict.
+fn tested(summary: &MutationSummary) -> usize {
+    summary.killed + summary.survived
+}
+
+/// Whether the sample meets `min_score`. With nothing tested the score is
+/// vacuous, so the gate fails.
+fn passes(summary: &MutationSummary, min_score: f64) -> bool {
+    tested(summary) > 0 && summary.score >= min_score
+}
+
+/// Interleaves points file by file, keeping each file's own order.
+fn round_robin(points: Vec<MutationPoint>) -> Vec<MutationPoint> {
+    let mut file_order: HashMap<PathBuf, usize> = HashMap::new();
+    let mut seen: HashMap<usize, usize> = HashMap::new();
+
+    let mut keyed: Vec<((usize, usize), MutationPoint)> = points
+        .into_iter()
+        .map(|point| {
+            let next = file_order.len();
+            let file = *file_order.entry(point.file.clone()).or_insert(next);
+            let rank = seen.entry(file).or_insert(0);
+            *rank += 1;
+            ((*rank, file), point)
+        })
+        .collect();
+
+    keyed.sort_by_key(|(key, _)| *key);
+    keyed.into_iter().map(|(_, point)| point).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutate::mutations::MutationKind;
+    use crate::mutate::runner::{summarize, MutationResult};
+
+    fn point(file: &str, line: usize) -> MutationPoint {
+        MutationPoint {
+            file: PathBuf::from(file),
+            line,
+            column: 1,
+            byte_start: 0,
+            byte_end: 1,
+            original: "<".into(),
+            mutated: ">=".into(),
+            kind: MutationKind::Comparison,
+        }
+    }
+
+    #[test]
+    fn round_robin_interleaves_files() {
+        let points = vec![
+            point("a.rs", 1),
+            point("a.rs", 2),
+            point("a.rs", 3),
+            point("b.rs", 1),
+        ];
+        let order: Vec<_> = round_robin(points)
+            .iter()
+            .map(|p| format!("{}:{}", p.file.display(), p.line))
+            .collect();
+        assert_eq!(order, ["a.rs:1", "b.rs:1", "a.rs:2", "a.rs:3"]);
+    }
+
+    #[test]
+    fn an_empty_sample_fails_the_gate() {
+        assert!(!passes(&summarize(&[]), 0.0));
+        let uncovered = [MutationResult::not_covered(point("a.rs", 1))];
+        assert!(!passes(&summarize(&uncovered), 0.0));
+    }
+
+    #[test]
+    fn a_sample_passes_at_the_minimum_score() {
+        let killed = MutationResult {
+            point: point("a.rs", 1),
+            survived: false,
+            covered: true,
+            killed_by: Some("full suite".into()),
+            duration_ms: 0,
+        };
+        let survived = MutationResult {
+            survived: true,
+            killed_by: None,
+            ..killed.clone()
+        };
+        let summary = summarize(&[killed, survived]);
+        assert!(passes(&summary, 50.0));
+        assert!(!passes(&summary, 51.0));
+    }
+}
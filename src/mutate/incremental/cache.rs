@@ -0,0 +1,235 @@
+// src/mutate/incremental/cache.rs
+//! Per-file mutation result cache under `.neti/cache/mutants/`.
+//!
+//! An entry is valid while its key — the SHA-256 of the source file combined
+//! with the test fingerprint — is unchanged; a mismatch simply re-runs the
+//! file's mutants.
+
+use crate::fsutil::atomic_write;
+use crate::mutate::mutations::MutationPoint;
+use crate::mutate::runner::{MutationResult, RunnerConfig};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CACHE_DIR: &str = ".neti/cache/mutants";
+
+/// Cached outcome of one mutant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedMutant {
+    line: usize,
+    column: usize,
+    original: String,
+    mutated: String,
+    survived: bool,
+    /// What killed the mutant, restored with the cached result.
+    #[serde(default)]
+    killed_by: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    file: PathBuf,
+    key: String,
+    mutants: Vec<CachedMutant>,
+}
+
+/// Result cache for one run, bound to the current test fingerprint.
+#[derive(Debug)]
+pub struct MutantCache {
+    dir: PathBuf,
+    fingerprint: String,
+}
+
+impl MutantCache {
+    /// Opens the cache under `root`. `tests` are the files whose contents
+    /// invalidate every entry when they change.
+    #[must_use]
+    pub fn open(root: &Path, config: &RunnerConfig, tests: &[PathBuf]) -> Self {
+        let mut hasher = Sha256::new();
+        update(&mut hasher, config.test_command.as_bytes());
+        for arg in &config.test_args {
+            update(&mut hasher, arg.as_bytes());
+        }
+        for test in tests {
+            update(&mut hasher, test.as_os_str().as_encoded_bytes());
+            update(&mut hasher, &fs::read(test).unwrap_or_default());
+        }
+        Self {
+            dir: root.join(CACHE_DIR),
+            fingerprint: format!("{:x}", hasher.finalize()),
+        }
+    }
+
+    /// Splits points into cached results and points that still need a run.
+    #[must_use]
+    pub fn partition(
+        &self,
+        points: Vec<MutationPoint>,
+    ) -> (Vec<MutationResult>, Vec<MutationPoint>) {
+        let files: HashSet<&Path> = points.iter().map(|p| p.file.as_path()).collect();
+        let entries: Entries = files
+            .into_iter()
+            .filter_map(|f| Some((f.to_path_buf(), self.load(f)?)))
+            .collect();
+
+        let mut hits = Vec::new();
+        let mut misses = Vec::new();
+        for point in points {
+            match cached_outcome(&entries, &point) {
+                Some(cached) => hits.push(MutationResult {
+                    point,
+                    survived: cached.survived,
+                    covered: true,
+                    killed_by: cached.killed_by.clone(),
+                    duration_ms: 0,
+                }),
+                None => misses.push(point),
+            }
+        }
+        (hits, misses)
+    }
+
+    /// Writes results back, one entry per source file. Uncovered mutants
+    /// were never run and are not cached.
+    pub fn store(&self, results: &[MutationResult]) {
+        let mut by_file: HashMap<&Path, Vec<CachedMutant>> = HashMap::new();
+        for result in results.iter().filter(|r| r.covered) {
+            let point = &result.point;
+            by_file.entry(&point.file).or_default().push(CachedMutant {
+                line: point.line,
+                column: point.column,
+                original: point.original.clone(),
+                mutated: point.mutated.clone(),
+                survived: result.survived,
+                killed_by: result.killed_by.clone(),
+            });
+        }
+
+        if fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        for (file, mutants) in by_file {
+            let Some(key) = self.key(file) else { continue };
+            let entry = CacheEntry {
+                file: file.to_path_buf(),
+                key,
+                mutants,
+            };
+            if let Ok(json) = serde_json::to_string(&entry) {
+                let _ = atomic_write(self.entry_path(file), json);
+            }
+        }
+    }
+
+    /// Cached outcomes for `file`, or `None` if absent or stale.
+    fn load(&self, file: &Path) -> Option<HashMap<MutantId, CachedMutant>> {
+        let contents = fs::read_to_string(self.entry_path(file)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+        if entry.file != file || Some(entry.key) != self.key(file) {
+            return None;
+        }
+        Some(
+            entry
+                .mutants
+                .into_iter()
+                .map(|m| (MutantId::cached(&m), m))
+                .collect(),
+        )
+    }
+
+    fn key(&self, file: &Path) -> Option<String> {
+        let mut hasher = Sha256::new();
+        update(&mut hasher, &fs::read(file).ok()?);
+        update(&mut hasher, self.fingerprint.as_bytes());
+        Some(format!("{:x}", hasher.finalize()))
+    }
+
+    fn entry_path(&self, file: &Path) -> PathBuf {
+        let hash = Sha256::digest(file.as_os_str().as_encoded_bytes());
+        self.dir.join(format!("{hash:x}.json"))
+    }
+}
+
+/// Feeds `bytes` to `hasher` behind their length, so adjacent fields
+/// cannot run together.
+fn update(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+type Entries = HashMap<PathBuf, HashMap<MutantId, CachedMutant>>;
+
+fn cached_outcome<'a>(entries: &'a Entries, point: &MutationPoint) -> Option<&'a CachedMutant> {
+    entries.get(&point.file)?.get(&MutantId::of(point))
+}
+
+/// Identifies a mutant within a file.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct MutantId {
+    line: usize,
+    column: usize,
+    mutated: String,
+}
+
+impl MutantId {
+    fn of(point: &MutationPoint) -> Self {
+        Self {
+            line: point.line,
+            column: point.column,
+            mutated: point.mutated.clone(),
+        }
+    }
+
+    fn cached(m: &CachedMutant) -> Self {
+        Self {
+            line: m.line,
+            column: m.column,
+            mutated: m.mutated.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutate::mutations::MutationKind;
+    use tempfile::TempDir;
+
+    #[test]
+    fn hits_restore_outcome_and_killer() {
+        let dir = TempDir::new().expect("temp dir");
+        let file = dir.path().join("lib.rs");
+        fs::write(&file, "a < b\n").expect("write lib.rs");
+        let point = MutationPoint {
+            file: file.clone(),
+            line: 1,
+            column: 3,
+            byte_start: 2,
+            byte_end: 3,
+            original: "<".into(),
+            mutated: ">=".into(),
+            kind: MutationKind::Comparison,
+        };
+        let cache = MutantCache::open(dir.path(), &RunnerConfig::default(), &[]);
+        cache.store(&[MutationResult {
+            point: point.clone(),
+            survived: false,
+            covered: true,
+            killed_by: Some("tests/lib_test.rs".into()),
+            duration_ms: 5,
+        }]);
+
+        let (hits, misses) = cache.partition(vec![point.clone()]);
+        assert!(misses.is_empty());
+        assert_eq!(hits[0].killed_by.as_deref(), Some("tests/lib_test.rs"));
+        assert!(!hits[0].survived);
+
+        fs::write(&file, "a <= b\n").expect("rewrite lib.rs");
+        let (hits, misses) = cache.partition(vec![point]);
+        assert!(hits.is_empty());
+        assert_eq!(misses.len(), 1);
+    }
+}
@@ -0,0 +1,220 @@
+// src/mutate/incremental/mod.rs
+//! Incremental mutation testing (`neti mutate --since <ref>`).
+//!
+//! Restricts mutation to functions touched since a git ref and reuses cached
+//! results for files whose contents and tests have not changed.
+
+pub mod cache;
+
+use crate::lang::Lang;
+use crate::mutate::mutations::MutationPoint;
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tree_sitter::{Node, Parser};
+
+/// Node kinds that delimit a function body across supported grammars.
+const FUNCTION_KINDS: &[&str] = &[
+    "function_item",
+    "function_definition",
+    "function_declaration",
+    "function_expression",
+    "method_definition",
+    "arrow_function",
+    "init_declaration",
+];
+
+/// Inclusive 1-based line range.
+pub type LineRange = (usize, usize);
+
+/// Changed line ranges per project-relative file, from `git diff <since>`.
+///
+/// # Errors
+/// Returns error if git fails or `since` is not a valid ref.
+pub fn changed_lines(root: &Path, since: &str) -> Result<HashMap<PathBuf, Vec<LineRange>>> {
+    let output = Command::new("git")
+        .args([
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            since,
+            "--",
+        ])
+        .current_dir(root)
+        .output()
+        .context("Failed to run git diff")?;
+
+    if !output.status.success() {
+        bail!(
+            "git diff {since} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_diff(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parses `--unified=0` output into new-side line ranges. A pure deletion
+/// is recorded as the line it was removed before.
+fn parse_diff(diff: &str) -> HashMap<PathBuf, Vec<LineRange>> {
+    let mut changes: HashMap<PathBuf, Vec<LineRange>> = HashMap::new();
+    let mut current: Option<PathBuf> = None;
+
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = path.strip_prefix("b/").map(PathBuf::from);
+        } else if let (Some(file), Some(range)) = (&current, parse_hunk(line)) {
+            changes.entry(file.clone()).or_default().push(range);
+        }
+    }
+    changes
+}
+
+/// Extracts the new-side range from `@@ -a,b +c,d @@`.
+fn parse_hunk(line: &str) -> Option<LineRange> {
+    let new_side = line
+        .strip_prefix("@@ ")?
+        .split(' ')
+        .nth(1)?
+        .strip_prefix('+')?;
+    let (start, count) = match new_side.split_once(',') {
+        Some((s, c)) => (s.parse::<usize>().ok()?, c.parse::<usize>().ok()?),
+        None => (new_side.parse::<usize>().ok()?, 1),
+    };
+    Some((start.max(1), (start + count.saturating_sub(1)).max(1)))
+}
+
+/// Keeps points inside a function the diff touches. Points outside any
+/// function are kept only when their own line changed.
+#[must_use]
+pub fn touched(
+    points: Vec<MutationPoint>,
+    changes: &HashMap<PathBuf, Vec<LineRange>>,
+) -> Vec<MutationPoint> {
+    let mut spans: HashMap<PathBuf, Vec<LineRange>> = HashMap::new();
+
+    points
+        .into_iter()
+        .filter(|point| {
+            let file = relative(&point.file);
+            let Some(ranges) = changes.get(&file) else {
+                return false;
+            };
+            let functions = spans
+                .entry(file)
+                .or_insert_with(|| function_spans(&point.file));
+            let scope = innermost(functions, point.line).unwrap_or((point.line, point.line));
+            ranges.iter().any(|&r| overlaps(r, scope))
+        })
+        .collect()
+}
+
+/// Line spans of every function in `path`; empty if it cannot be parsed.
+fn function_spans(path: &Path) -> Vec<LineRange> {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let (Some(lang), Ok(source)) = (Lang::from_ext(ext), fs::read_to_string(path)) else {
+        return Vec::new();
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&lang.grammar()).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(&source, None) else {
+        return Vec::new();
+    };
+
+    let mut spans = Vec::new();
+    collect_functions(tree.root_node(), &mut spans);
+    spans
+}
+
+fn collect_functions(node: Node, out: &mut Vec<LineRange>) {
+    if FUNCTION_KINDS.contains(&node.kind()) {
+        out.push((node.start_position().row + 1, node.end_position().row + 1));
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_functions(child, out);
+    }
+}
+
+/// The smallest span containing `line`, so nested closures scope tightly.
+fn innermost(spans: &[LineRange], line: usize) -> Option<LineRange> {
+    spans
+        .iter()
+        .filter(|(start, end)| (*start..=*end).contains(&line))
+        .min_by_key(|(start, end)| end - start)
+        .copied()
+}
+
+fn overlaps(a: LineRange, b: LineRange) -> bool {
+    a.0 <= b.1 && b.0 <= a.1
+}
+
+/// Files whose changes invalidate every cached result: anything under a
+/// test directory or named like a test or spec.
+#[must_use]
+pub fn test_files(files: &[PathBuf]) -> Vec<PathBuf> {
+    files.iter().filter(|f| is_test_file(f)).cloned().collect()
+}
+
+fn is_test_file(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let stem = path.file_stem().and_then(|n| n.to_str()).unwrap_or("");
+    path.components().any(|c| c.as_os_str() == "tests")
+        || name.starts_with("test_")
+        || stem.ends_with("_test")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+}
+
+/// Strips the `./` prefix discovery adds so paths match diff output.
+fn relative(path: &Path) -> PathBuf {
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hunks_per_file() {
+        let diff = "\
+diff --git a/src/a.rs b/src/a.rs
+--- a/src/a.rs
++++ b/src/a.rs
+@@ -3 +3 @@ fn a() {
+@@ -10,2 +11,4 @@
+@@ -20,3 +24,0 @@
+--- a/src/old.rs
++++ /dev/null
+@@ -1,5 +0,0 @@
+";
+        let changes = parse_diff(diff);
+        assert_eq!(
+            changes.get(Path::new("src/a.rs")),
+            Some(&vec![(3, 3), (11, 14), (24, 24)])
+        );
+        assert_eq!(changes.len(), 1);
+    }
+
+    #[test]
+    fn innermost_span_wins() {
+        let spans = [(1, 20), (5, 8)];
+        assert_eq!(innermost(&spans, 6), Some((5, 8)));
+        assert_eq!(innermost(&spans, 12), Some((1, 20)));
+        assert_eq!(innermost(&spans, 30), None);
+        assert!(overlaps((8, 9), (5, 8)));
+        assert!(!overlaps((9, 9), (5, 8)));
+    }
+
+    #[test]
+    fn recognizes_test_files() {
+        assert!(is_test_file(Path::new("./tests/cli_test.rs")));
+        assert!(is_test_file(Path::new("pkg/test_mod.py")));
+        assert!(is_test_file(Path::new("src/app.spec.ts")));
+        assert!(!is_test_file(Path::new("src/latest_release.rs")));
+    }
+}
@@ -7,10 +7,16 @@
 //!
 //! # Architecture
 //!
+//! - `coverage`: Imports line coverage to skip mutants no test can reach
 //! - `discovery`: Finds mutation points using tree-sitter AST analysis
+//! - `gate`: Time-boxed sample run as a `neti check` stage
+//! - `incremental`: Diff-scoped runs and the per-file result cache
 //! - `mutations`: Defines mutation types and application logic
-//! - `runner`: Executes tests against mutated code (serial, v1)
-//! - `report`: Formats results for terminal and JSON output
+//! - `targets`: Picks the tests to run first for each mutated file
+//! - `runner`: Executes tests against mutated code, in parallel across workers
+//! - `workspace`: Isolated project copies, one per worker
+//! - `report`: Formats results for terminal, JSON, HTML, and SVG badge output,
+//!   and keeps the last run's summary for `neti metrics`
 //!
 //! # Example
 //!
@@ -18,16 +24,23 @@
 //! neti mutate --filter src/tokens.rs --timeout 30
 //! ```
 
+pub mod coverage;
 pub mod discovery;
+pub mod gate;
+pub mod incremental;
 pub mod mutations;
 pub mod report;
 pub mod runner;
+pub mod targets;
+pub mod workspace;
 
 use crate::config::Config;
 use crate::discovery::discover;
 use crate::project::ProjectType;
 use anyhow::Result;
 use colored::Colorize;
+use incremental::cache::MutantCache;
+use mutations::MutationPoint;
 use runner::{MutationSummary, RunnerConfig};
 use std::path::{Path, PathBuf};
 
@@ -38,6 +51,16 @@ pub struct MutateOptions {
     pub timeout_secs: u64,
     pub json: bool,
     pub filter: Option<String>,
+    /// Coverage report (llvm-cov, coverage.py, or istanbul JSON).
+    pub coverage: Option<PathBuf>,
+    /// Drop uncovered mutants from the report instead of listing them.
+    pub only_covered: bool,
+    /// Only mutate functions changed since this git ref, reusing cached results.
+    pub since: Option<String>,
+    /// Write a browsable HTML report of survivors here.
+    pub html: Option<PathBuf>,
+    /// Write an SVG score badge here.
+    pub badge: Option<PathBuf>,
 }
 
 impl Default for MutateOptions {
@@ -47,6 +70,11 @@ impl Default for MutateOptions {
             timeout_secs: 30,
             json: false,
             filter: None,
+            coverage: None,
+            only_covered: false,
+            since: None,
+            html: None,
+            badge: None,
         }
     }
 }
@@ -63,6 +91,7 @@ pub struct MutateReport {
 /// # Errors
 /// Returns error if discovery or test execution fails.
 pub fn run(workdir: &Path, opts: &MutateOptions) -> Result<MutateReport> {
+    let _span = crate::logging::span("mutate");
     let config = Config::load();
 
     // Discover source files
@@ -77,44 +106,70 @@ pub fn run(workdir: &Path, opts: &MutateOptions) -> Result<MutateReport> {
         runner_config.workers = workers;
     }
     runner_config.timeout_secs = opts.timeout_secs;
+    runner_config.targeting = targets::for_project(project_type, &files);
 
-    // Filter files if specified
-    let target_files = filter_files(&files, opts.filter.as_deref());
+    // Filter files if specified, then to those changed since the ref
+    let mut target_files = filter_files(&files, opts.filter.as_deref());
+    let changes = match &opts.since {
+        Some(since) => Some(incremental::changed_lines(workdir, since)?),
+        None => None,
+    };
+    if let Some(changes) = &changes {
+        target_files.retain(|f| changes.contains_key(f.strip_prefix(".").unwrap_or(f)));
+    }
 
     if !opts.json {
         print_header(&target_files, &runner_config);
     }
 
-    // Discover mutation points
-    let points = discover_all_mutations(&target_files);
+    // Discover mutation points, setting aside those no test reaches
+    let mut points = discover_all_mutations(&target_files);
+    if let Some(changes) = &changes {
+        points = incremental::touched(points, changes);
+    }
+    let (points, uncovered) = match &opts.coverage {
+        Some(path) => coverage::split(points, path, workdir, opts.only_covered)?,
+        None => (points, Vec::new()),
+    };
+
+    // Reuse results for files whose source and tests are unchanged
+    let cache = opts
+        .since
+        .as_ref()
+        .map(|_| MutantCache::open(workdir, &runner_config, &incremental::test_files(&files)));
+    let (cached, points) = match &cache {
+        Some(cache) => cache.partition(points),
+        None => (Vec::new(), points),
+    };
 
-    if points.is_empty() {
+    if points.is_empty() && uncovered.is_empty() && cached.is_empty() {
         return Ok(MutateReport {
-            summary: MutationSummary {
-                total: 0,
-                killed: 0,
-                survived: 0,
-                score: 100.0,
-                total_duration_ms: 0,
-            },
+            summary: runner::summarize(&[]),
             results: Vec::new(),
         });
     }
 
     if !opts.json {
-        println!(
-            "Found {} mutation points across {} files\n",
-            points.len().to_string().cyan(),
-            target_files.len()
+        print_plan(
+            points.len(),
+            target_files.len(),
+            uncovered.len(),
+            cached.len(),
         );
     }
 
     // Run mutations with progress reporting
-    let results = runner::run_mutations(&points, &runner_config, workdir, |cur, total, result| {
-        if !opts.json {
-            println!("{}", report::format_progress(cur, total, result));
-        }
-    })?;
+    let mut results =
+        runner::run_mutations(&points, &runner_config, workdir, |cur, total, result| {
+            if !opts.json {
+                println!("{}", report::format_progress(cur, total, result));
+            }
+        })?;
+    if let Some(cache) = &cache {
+        cache.store(&results);
+    }
+    results.extend(cached);
+    results.extend(uncovered);
 
     let summary = runner::summarize(&results);
 
@@ -125,10 +180,36 @@ pub fn run(workdir: &Path, opts: &MutateOptions) -> Result<MutateReport> {
         println!("{}", report::format_summary(&summary));
         println!("{}", report::format_survivors(&results));
     }
+    report::last_run::record(workdir, &summary);
+    report::write_files(
+        opts.html.as_deref(),
+        opts.badge.as_deref(),
+        &results,
+        &summary,
+    )?;
 
     Ok(MutateReport { summary, results })
 }
 
+fn print_plan(points: usize, files: usize, uncovered: usize, cached: usize) {
+    println!(
+        "Found {} mutation points across {files} files\n",
+        points.to_string().cyan()
+    );
+    if uncovered > 0 {
+        println!(
+            "Skipping {} mutation points on uncovered lines\n",
+            uncovered.to_string().yellow()
+        );
+    }
+    if cached > 0 {
+        println!(
+            "Reusing {} cached results for unchanged files\n",
+            cached.to_string().cyan()
+        );
+    }
+}
+
 /// Returns appropriate runner config for the project type.
 fn config_for_project(project_type: ProjectType) -> RunnerConfig {
     match project_type {
@@ -147,7 +228,7 @@ fn filter_files(files: &[PathBuf], filter: Option<&str>) -> Vec<PathBuf> {
             files
                 .iter()
                 .filter(|f| {
-                    let s = f.to_string_lossy().replace('\\', "/");
+                    let s = crate::normalize::path_str(f);
                     s.contains(&pattern)
                 })
                 .cloned()
@@ -158,14 +239,14 @@ fn filter_files(files: &[PathBuf], filter: Option<&str>) -> Vec<PathBuf> {
 }
 
 /// Discovers mutations in all target files.
-fn discover_all_mutations(files: &[PathBuf]) -> Vec<mutations::MutationPoint> {
+fn discover_all_mutations(files: &[PathBuf]) -> Vec<MutationPoint> {
     let mut all_points = Vec::new();
 
     for file in files {
         match discovery::discover_mutations(file) {
             Ok(points) => all_points.extend(points),
             Err(e) => {
-                eprintln!("Warning: Could not scan {}: {e}", file.display());
+                log::warn!("Could not scan {}: {e}", file.display());
             }
         }
     }
@@ -180,6 +261,7 @@ fn print_header(files: &[PathBuf], config: &RunnerConfig) {
     println!("{}", "═".repeat(60));
     println!("  Files:    {}", files.len().to_string().cyan());
     println!("  Timeout:  {}s", config.timeout_secs.to_string().cyan());
+    println!("  Workers:  {}", config.workers.to_string().cyan());
     println!(
         "  Command:  {} {}",
         config.test_command.cyan(),
@@ -0,0 +1,62 @@
+// src/mutate/report/badge.rs
+//! Flat SVG score badge for READMEs, in the shields.io style.
+
+use crate::mutate::runner::MutationSummary;
+
+const LABEL: &str = "mutation";
+
+/// Approximate width of one character at 11px Verdana.
+const CHAR_WIDTH: usize = 7;
+const PADDING: usize = 10;
+
+/// Renders the mutation score as an SVG badge.
+#[must_use]
+pub fn format_badge(summary: &MutationSummary) -> String {
+    let value = format!("{:.0}%", summary.score);
+    let color = badge_color(summary.score);
+    let label_w = LABEL.len() * CHAR_WIDTH + PADDING;
+    let value_w = value.len() * CHAR_WIDTH + PADDING;
+    let total_w = label_w + value_w;
+    let label_x = label_w / 2;
+    let value_x = label_w + value_w / 2;
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_w}" height="20" role="img" aria-label="{LABEL}: {value}">
+<title>{LABEL}: {value}</title>
+<linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient>
+<clipPath id="r"><rect width="{total_w}" height="20" rx="3" fill="#fff"/></clipPath>
+<g clip-path="url(#r)"><rect width="{label_w}" height="20" fill="#555"/><rect x="{label_w}" width="{value_w}" height="20" fill="{color}"/><rect width="{total_w}" height="20" fill="url(#s)"/></g>
+<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+<text x="{label_x}" y="14">{LABEL}</text><text x="{value_x}" y="14">{value}</text>
+</g>
+</svg>
+"##
+    )
+}
+
+/// Same thresholds as the terminal summary: green at 80%, yellow at 60%.
+fn badge_color(score: f64) -> &'static str {
+    if score >= 80.0 {
+        "#4c1"
+    } else if score >= 60.0 {
+        "#dfb317"
+    } else {
+        "#e05d44"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutate::runner::summarize;
+
+    #[test]
+    fn badge_shows_rounded_score() {
+        let svg = format_badge(&summarize(&[]));
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">100%</text>"));
+        assert!(svg.contains("#4c1"));
+        assert_eq!(badge_color(65.0), "#dfb317");
+        assert_eq!(badge_color(10.0), "#e05d44");
+    }
+}
@@ -0,0 +1,132 @@
+// src/mutate/report/html.rs
+//! Self-contained HTML report: the summary plus every surviving mutant with
+//! the surrounding source lines.
+
+use crate::mutate::runner::{MutationResult, MutationSummary};
+use std::fmt::Write;
+use std::fs;
+
+/// Source lines shown above and below a survivor.
+const CONTEXT_LINES: usize = 2;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2rem;color:#222}\
+table{border-collapse:collapse}td{padding:.2rem .8rem}\
+.mutant{border:1px solid #ddd;border-radius:4px;margin:1rem 0}\
+.mutant h3{margin:0;padding:.5rem;background:#f6f6f6;font-size:1rem}\
+pre{margin:0;padding:.5rem;overflow-x:auto}.hit{background:#fff3bf}\
+.kind{color:#0b7285}del{color:#c92a2a}ins{color:#2b8a3e}";
+
+/// Renders the report as a standalone HTML page.
+#[must_use]
+pub fn format_html(results: &[MutationResult], summary: &MutationSummary) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\
+         <title>Mutation report</title><style>{STYLE}</style></head><body>"
+    );
+    let _ = writeln!(out, "<h1>Mutation report</h1>");
+    write_summary(&mut out, summary);
+
+    let survivors: Vec<_> = results.iter().filter(|r| r.survived).collect();
+    let _ = writeln!(out, "<h2>Surviving mutants ({})</h2>", survivors.len());
+    if survivors.is_empty() {
+        let _ = writeln!(out, "<p>All mutants killed.</p>");
+    }
+    for result in survivors {
+        write_survivor(&mut out, result);
+    }
+
+    let _ = writeln!(out, "</body></html>");
+    out
+}
+
+fn write_summary(out: &mut String, summary: &MutationSummary) {
+    let rows = [
+        ("Total mutations", summary.total.to_string()),
+        ("Killed", summary.killed.to_string()),
+        ("Survived", summary.survived.to_string()),
+        ("Not covered", summary.not_covered.to_string()),
+        ("Mutation score", format!("{:.1}%", summary.score)),
+        ("Duration", format!("{}s", summary.total_duration_ms / 1000)),
+    ];
+    let _ = writeln!(out, "<table>");
+    for (label, value) in rows {
+        let _ = writeln!(out, "<tr><td>{label}</td><td><b>{value}</b></td></tr>");
+    }
+    let _ = writeln!(out, "</table>");
+}
+
+fn write_survivor(out: &mut String, result: &MutationResult) {
+    let point = &result.point;
+    let _ = writeln!(
+        out,
+        "<div class=\"mutant\"><h3><span class=\"kind\">[{}]</span> {}:{} \
+         <del>{}</del> &rarr; <ins>{}</ins></h3>",
+        point.kind.symbol(),
+        escape(&point.file.display().to_string()),
+        point.line,
+        escape(&point.original),
+        escape(&point.mutated)
+    );
+
+    let source = fs::read_to_string(&point.file).unwrap_or_default();
+    let first = point.line.saturating_sub(CONTEXT_LINES).max(1);
+    let _ = write!(out, "<pre>");
+    for (idx, text) in source
+        .lines()
+        .enumerate()
+        .skip(first - 1)
+        .take(CONTEXT_LINES * 2 + 1)
+    {
+        let number = idx + 1;
+        let class = if number == point.line {
+            " class=\"hit\""
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "<span{class}>{number:>5} | {}</span>", escape(text));
+    }
+    let _ = writeln!(out, "</pre></div>");
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutate::mutations::{MutationKind, MutationPoint};
+    use crate::mutate::runner::summarize;
+    use std::path::PathBuf;
+
+    #[test]
+    fn lists_survivors_with_escaped_operators() {
+        let result = MutationResult {
+            point: MutationPoint {
+                file: PathBuf::from("missing/file.rs"),
+                line: 3,
+                column: 5,
+                byte_start: 0,
+                byte_end: 1,
+                original: "<".into(),
+                mutated: ">=".into(),
+                kind: MutationKind::Comparison,
+            },
+            survived: true,
+            covered: true,
+            killed_by: None,
+            duration_ms: 10,
+        };
+        let results = [result];
+        let html = format_html(&results, &summarize(&results));
+
+        assert!(html.contains("Surviving mutants (1)"));
+        assert!(html.contains("<del>&lt;</del> &rarr; <ins>&gt;=</ins>"));
+        assert!(html.contains("0.0%"));
+    }
+}
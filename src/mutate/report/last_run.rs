@@ -0,0 +1,33 @@
+// src/mutate/report/last_run.rs
+//! The summary of the most recent mutation run, kept in
+//! `.neti/mutation-score.json` so `neti metrics` can report the score
+//! without re-running the mutants.
+
+use crate::fsutil::atomic_write;
+use crate::mutate::runner::MutationSummary;
+use std::fs;
+use std::path::Path;
+
+/// Where the last run's summary is kept, relative to the repo root.
+pub const PATH: &str = ".neti/mutation-score.json";
+
+/// Records `summary` as the last run. A failed write only costs the
+/// metrics export its score, so it is not an error.
+pub fn record(root: &Path, summary: &MutationSummary) {
+    let path = root.join(PATH);
+    let Some(dir) = path.parent() else { return };
+    if fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(summary) {
+        // Best effort, as above.
+        let _ = atomic_write(&path, json);
+    }
+}
+
+/// The last recorded run, if any.
+#[must_use]
+pub fn load(root: &Path) -> Option<MutationSummary> {
+    let content = fs::read_to_string(root.join(PATH)).ok()?;
+    serde_json::from_str(&content).ok()
+}
@@ -1,17 +1,27 @@
-// src/mutate/report.rs
+// src/mutate/report/mod.rs
 //! Report formatting for mutation test results.
 
+pub mod badge;
+pub mod html;
+pub mod last_run;
+
+use crate::fsutil::atomic_write;
 use crate::mutate::runner::{MutationResult, MutationSummary};
+use anyhow::{Context, Result};
 use colored::Colorize;
 use std::fmt::Write;
+use std::path::Path;
 
 /// Formats a progress update for terminal output.
 #[must_use]
 pub fn format_progress(current: usize, total: usize, result: &MutationResult) -> String {
     let status = if result.survived {
-        "SURVIVED".yellow().bold()
+        "SURVIVED".yellow().bold().to_string()
     } else {
-        "KILLED".green()
+        match &result.killed_by {
+            Some(by) => format!("{} {}", "KILLED".green(), format!("by {by}").dimmed()),
+            None => "KILLED".green().to_string(),
+        }
     };
 
     let file = result.point.file.display();
@@ -43,6 +53,13 @@ pub fn format_summary(summary: &MutationSummary) -> String {
         "  Survived:         {} ⚠",
         format_survived(summary.survived)
     );
+    if summary.not_covered > 0 {
+        let _ = writeln!(
+            out,
+            "  Not covered:      {}",
+            summary.not_covered.to_string().dimmed()
+        );
+    }
     let _ = writeln!(out);
 
     let score_str = format!("{:.1}%", summary.score);
@@ -118,31 +135,63 @@ pub fn format_survivors(results: &[MutationResult]) -> String {
 /// Formats results as JSON for machine consumption.
 #[must_use]
 pub fn format_json(results: &[MutationResult], summary: &MutationSummary) -> String {
-    let survivors: Vec<_> = results
-        .iter()
-        .filter(|r| r.survived)
-        .map(|r| {
-            serde_json::json!({
-                "file": r.point.file.display().to_string(),
-                "line": r.point.line,
-                "column": r.point.column,
-                "original": r.point.original,
-                "mutated": r.point.mutated,
-                "kind": r.point.kind.symbol(),
-            })
-        })
-        .collect();
+    let survivors = mutants_json(results, |r| r.survived);
+    let not_covered = mutants_json(results, |r| !r.covered);
 
     let output = serde_json::json!({
+        "schema_version": crate::schema::SCHEMA_VERSION,
         "summary": {
             "total": summary.total,
             "killed": summary.killed,
             "survived": summary.survived,
+            "not_covered": summary.not_covered,
             "score": summary.score,
             "duration_ms": summary.total_duration_ms,
         },
         "survivors": survivors,
+        "not_covered": not_covered,
     });
 
     serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
 }
+
+fn mutants_json(
+    results: &[MutationResult],
+    keep: impl Fn(&MutationResult) -> bool,
+) -> Vec<serde_json::Value> {
+    results
+        .iter()
+        .filter(|r| keep(r))
+        .map(|r| {
+            serde_json::json!({
+                "file": r.point.file.display().to_string(),
+                "line": r.point.line,
+                "column": r.point.column,
+                "original": r.point.original,
+                "mutated": r.point.mutated,
+                "kind": r.point.kind.symbol(),
+            })
+        })
+        .collect()
+}
+
+/// Writes the optional HTML report and SVG badge.
+///
+/// # Errors
+/// Returns error if either file cannot be written.
+pub fn write_files(
+    html: Option<&Path>,
+    badge: Option<&Path>,
+    results: &[MutationResult],
+    summary: &MutationSummary,
+) -> Result<()> {
+    if let Some(path) = html {
+        atomic_write(path, html::format_html(results, summary))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    if let Some(path) = badge {
+        atomic_write(path, badge::format_badge(summary))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// src/mutate/runner/config.rs
+//! Runner settings and the per-language defaults.
+
+use crate::mutate::targets::Targeting;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Configuration for the mutation runner.
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    pub test_command: String,
+    pub test_args: Vec<String>,
+    pub timeout_secs: u64,
+    pub workers: usize,
+    /// Narrows the suite per mutated file before falling back to a full run.
+    pub targeting: Option<Targeting>,
+    /// Runs still going at this instant are stopped and no new mutant is
+    /// started; results cover only the mutants that finished.
+    pub deadline: Option<Instant>,
+    /// `CARGO_TARGET_DIR` for the test command; each parallel worker gets
+    /// its own so builds in different copies never share artifacts.
+    pub target_dir: Option<PathBuf>,
+}
+
+impl RunnerConfig {
+    /// Whether the time budget is spent.
+    #[must_use]
+    pub fn out_of_time(&self) -> bool {
+        self.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            test_command: "cargo".to_string(),
+            test_args: vec!["test".to_string(), "--lib".to_string()],
+            timeout_secs: 30,
+            workers: get_worker_count(),
+            targeting: None,
+            deadline: None,
+            target_dir: None,
+        }
+    }
+}
+
+/// Gets a reasonable worker count based on available CPUs.
+fn get_worker_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get().min(8))
+        .unwrap_or(4)
+}
+
+impl RunnerConfig {
+    /// Creates config for Rust projects.
+    #[must_use]
+    pub fn rust() -> Self {
+        Self::default()
+    }
+
+    /// Creates config for TypeScript/Node projects.
+    #[must_use]
+    pub fn typescript() -> Self {
+        Self {
+            test_command: "npm".to_string(),
+            test_args: vec!["test".to_string()],
+            timeout_secs: 60,
+            workers: get_worker_count(),
+            targeting: None,
+            deadline: None,
+            target_dir: None,
+        }
+    }
+
+    /// Creates config for Python projects.
+    #[must_use]
+    pub fn python() -> Self {
+        Self {
+            test_command: "pytest".to_string(),
+            test_args: vec!["-x".to_string(), "-q".to_string()],
+            timeout_secs: 60,
+            workers: get_worker_count(),
+            targeting: None,
+            deadline: None,
+            target_dir: None,
+        }
+    }
+}
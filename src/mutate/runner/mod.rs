@@ -0,0 +1,245 @@
+// src/mutate/runner/mod.rs
+//! Parallel mutation test runner.
+//!
+//! Executes tests against mutated code to identify surviving mutants.
+//! Parallel runs give every worker its own copy of the project.
+
+mod config;
+mod parallel;
+mod process;
+
+#[cfg(test)]
+#[path = "runner_test.rs"]
+mod tests;
+
+pub use config::RunnerConfig;
+
+use crate::fsutil::atomic_write;
+use crate::mutate::mutations::{apply_mutation, MutationPoint};
+use anyhow::{bail, Result};
+use process::{run_test_command, run_untimed, TestRun};
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+/// Result of testing a single mutation.
+#[derive(Debug, Clone)]
+pub struct MutationResult {
+    pub point: MutationPoint,
+    pub survived: bool,
+    /// False when coverage data shows no test executes the mutated line.
+    pub covered: bool,
+    /// The targeted tests that killed the mutant, or "full suite".
+    pub killed_by: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl MutationResult {
+    /// A mutant on a line no test executes; never run.
+    #[must_use]
+    pub fn not_covered(point: MutationPoint) -> Self {
+        Self {
+            point,
+            survived: false,
+            covered: false,
+            killed_by: None,
+            duration_ms: 0,
+        }
+    }
+}
+
+/// Runs all mutations and collects results.
+///
+/// With more than one worker, each worker mutates its own temporary copy of
+/// the project so mutations never interfere. Results are returned in the
+/// order of `points`; `on_progress` fires as each mutation completes.
+///
+/// Before any mutant is timed, the unmutated suite runs once in every
+/// directory that will be mutated, without the per-mutant timeout, so
+/// builds are warm and a broken suite is caught.
+///
+/// # Errors
+/// Returns error if a workspace copy cannot be created or the suite fails
+/// on unmutated code.
+pub fn run_mutations(
+    points: &[MutationPoint],
+    config: &RunnerConfig,
+    workdir: &Path,
+    on_progress: impl Fn(usize, usize, &MutationResult) + Sync,
+) -> Result<Vec<MutationResult>> {
+    if points.is_empty() {
+        return Ok(Vec::new());
+    }
+    if config.workers <= 1 || points.len() <= 1 {
+        if !baseline(config, workdir)? {
+            return Ok(Vec::new());
+        }
+        return Ok(run_serial(points, config, workdir, on_progress));
+    }
+    parallel::run_parallel(points, config, workdir, on_progress)
+}
+
+/// Runs the unmutated suite in `workdir`. Returns `false` if the deadline
+/// stopped it.
+///
+/// # Errors
+/// Returns error if the suite fails: every mutant would count as killed.
+fn baseline(config: &RunnerConfig, workdir: &Path) -> Result<bool> {
+    if config.out_of_time() {
+        return Ok(false);
+    }
+    match run_untimed(config, workdir) {
+        TestRun::Passed => Ok(true),
+        TestRun::Cancelled => Ok(false),
+        TestRun::Failed | TestRun::TimedOut => bail!(
+            "`{} {}` fails on unmutated code in {}; fix the tests before mutating",
+            config.test_command,
+            config.test_args.join(" "),
+            workdir.display()
+        ),
+    }
+}
+
+/// Mutates `workdir` in place, one mutation at a time.
+fn run_serial(
+    points: &[MutationPoint],
+    config: &RunnerConfig,
+    workdir: &Path,
+    on_progress: impl Fn(usize, usize, &MutationResult),
+) -> Vec<MutationResult> {
+    let total = points.len();
+    let mut results = Vec::with_capacity(total);
+
+    for (idx, point) in points.iter().enumerate() {
+        if config.out_of_time() {
+            break;
+        }
+        let Some(result) = test_mutation(point, config, workdir) else {
+            break;
+        };
+        on_progress(idx + 1, total, &result);
+        results.push(result);
+    }
+
+    results
+}
+
+/// Tests a single mutation point, or returns `None` if the deadline stopped
+/// its tests before they finished.
+fn test_mutation(
+    point: &MutationPoint,
+    config: &RunnerConfig,
+    workdir: &Path,
+) -> Option<MutationResult> {
+    let start = Instant::now();
+
+    // Read original file
+    let file_path = workdir.join(&point.file);
+    let Ok(original) = fs::read_to_string(&file_path) else {
+        return Some(MutationResult {
+            point: point.clone(),
+            survived: false,
+            covered: true,
+            killed_by: None,
+            duration_ms: 0,
+        });
+    };
+
+    // Apply mutation
+    let mutated = apply_mutation(&original, point);
+    if atomic_write(&file_path, &mutated).is_err() {
+        let _ = atomic_write(&file_path, &original); // Restore
+        #[allow(clippy::cast_possible_truncation)]
+        return Some(MutationResult {
+            point: point.clone(),
+            survived: false,
+            covered: true,
+            killed_by: None,
+            duration_ms: start.elapsed().as_millis() as u64,
+        });
+    }
+
+    // Run the targeted tests first, then the full suite if the mutant survives
+    let killed_by = run_tests(config, &point.file, workdir);
+
+    // Restore original
+    let _ = atomic_write(&file_path, &original);
+
+    let killed_by = killed_by.ok()?;
+    #[allow(clippy::cast_possible_truncation)]
+    Some(MutationResult {
+        point: point.clone(),
+        survived: killed_by.is_none(),
+        covered: true,
+        killed_by,
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// The deadline stopped a test run before it finished.
+struct Cancelled;
+
+/// Runs the tests for a mutated `file`, returning what killed the mutant.
+fn run_tests(
+    config: &RunnerConfig,
+    file: &Path,
+    workdir: &Path,
+) -> Result<Option<String>, Cancelled> {
+    let targeted = config.targeting.as_ref().and_then(|t| t.run_for(file));
+    if let Some(run) = targeted {
+        let killer = killer(run_test_command(config, &run.args, workdir), &run.label)?;
+        if killer.is_some() {
+            return Ok(killer);
+        }
+    }
+    killer(run_test_command(config, &[], workdir), "full suite")
+}
+
+/// What killed the mutant, given how its test run labelled `label` ended.
+fn killer(run: TestRun, label: &str) -> Result<Option<String>, Cancelled> {
+    match run {
+        TestRun::Passed => Ok(None),
+        TestRun::Failed => Ok(Some(label.to_string())),
+        TestRun::TimedOut => Ok(Some(format!("{label} (timeout)"))),
+        TestRun::Cancelled => Err(Cancelled),
+    }
+}
+
+/// Calculates summary statistics from results.
+///
+/// Mutants on uncovered lines are counted separately and excluded from the score.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn summarize(results: &[MutationResult]) -> MutationSummary {
+    let total = results.len();
+    let survived = results.iter().filter(|r| r.survived).count();
+    let not_covered = results.iter().filter(|r| !r.covered).count();
+    let killed = total - survived - not_covered;
+    let total_ms: u64 = results.iter().map(|r| r.duration_ms).sum();
+    let tested = killed + survived;
+
+    MutationSummary {
+        total,
+        killed,
+        survived,
+        not_covered,
+        score: if tested > 0 {
+            (killed as f64 / tested as f64) * 100.0
+        } else {
+            100.0
+        },
+        total_duration_ms: total_ms,
+    }
+}
+
+/// Summary statistics for a mutation run.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MutationSummary {
+    pub total: usize,
+    pub killed: usize,
+    pub survived: usize,
+    /// Mutants skipped because no test executes their line.
+    pub not_covered: usize,
+    pub score: f64,
+    pub total_duration_ms: u64,
+}
@@ -0,0 +1,105 @@
+// src/mutate/runner/parallel.rs
+//! Parallel execution: workers pull mutations from a shared queue, each
+//! testing in its own workspace copy.
+
+use super::{baseline, test_mutation, MutationResult, RunnerConfig};
+use crate::mutate::mutations::MutationPoint;
+use crate::mutate::workspace::WorkspaceCopy;
+use anyhow::{anyhow, Result};
+use std::iter::Enumerate;
+use std::path::Path;
+use std::slice::Iter;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// Fans mutations out to workers, each owning an isolated workspace copy
+/// and, for Cargo projects, its own target directory.
+///
+/// # Errors
+/// Returns error if a workspace copy cannot be created or the baseline
+/// fails in one.
+pub fn run_parallel(
+    points: &[MutationPoint],
+    config: &RunnerConfig,
+    workdir: &Path,
+    on_progress: impl Fn(usize, usize, &MutationResult),
+) -> Result<Vec<MutationResult>> {
+    let total = points.len();
+    let copies = (0..config.workers.min(total))
+        .map(|id| WorkspaceCopy::create(workdir, id))
+        .collect::<Result<Vec<_>>>()?;
+    let configs: Vec<RunnerConfig> = copies
+        .iter()
+        .map(|copy| RunnerConfig {
+            target_dir: copy.target_dir().map(Path::to_path_buf),
+            ..config.clone()
+        })
+        .collect();
+    if !baselines(&configs, &copies)? {
+        return Ok(Vec::new());
+    }
+
+    let queue = Mutex::new(points.iter().enumerate());
+    let mut slots: Vec<Option<MutationResult>> = vec![None; total];
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for (config, copy) in configs.iter().zip(&copies) {
+            let tx = tx.clone();
+            let queue = &queue;
+            scope.spawn(move || work_queue(config, copy.root(), queue, &tx));
+        }
+        drop(tx);
+
+        // Progress is aggregated here so the callback sees a single, ordered count.
+        for (done, (idx, result)) in rx.iter().enumerate() {
+            on_progress(done + 1, total, &result);
+            if let Some(slot) = slots.get_mut(idx) {
+                *slot = Some(result);
+            }
+        }
+    });
+
+    Ok(slots.into_iter().flatten().collect())
+}
+
+/// Runs the baseline in every copy at once. Returns `false` if the
+/// deadline stopped any of them.
+fn baselines(configs: &[RunnerConfig], copies: &[WorkspaceCopy]) -> Result<bool> {
+    thread::scope(|scope| {
+        let runs: Vec<_> = configs
+            .iter()
+            .zip(copies)
+            .map(|(config, copy)| scope.spawn(move || baseline(config, copy.root())))
+            .collect();
+        let mut ready = true;
+        for run in runs {
+            ready &= run
+                .join()
+                .map_err(|_| anyhow!("baseline worker panicked"))??;
+        }
+        Ok(ready)
+    })
+}
+
+/// Mutations not yet claimed by a worker, with their index in the input.
+type Queue<'a> = Mutex<Enumerate<Iter<'a, MutationPoint>>>;
+
+/// Claims mutations from the shared queue until it is empty.
+fn work_queue(
+    config: &RunnerConfig,
+    root: &Path,
+    queue: &Queue<'_>,
+    tx: &mpsc::Sender<(usize, MutationResult)>,
+) {
+    while !config.out_of_time() {
+        let claimed = queue.lock().ok().and_then(|mut q| q.next());
+        let Some((idx, point)) = claimed else { return };
+        let Some(result) = test_mutation(point, config, root) else {
+            return;
+        };
+        if tx.send((idx, result)).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// src/mutate/runner/process.rs
+//! Runs the test command under the per-mutant timeout and the run deadline.
+
+use super::RunnerConfig;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a running test command is checked for completion.
+const POLL: Duration = Duration::from_millis(50);
+
+/// How a test command run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestRun {
+    /// The tests passed: the mutant survived.
+    Passed,
+    /// The tests failed or could not start: the mutant was killed.
+    Failed,
+    /// The tests ran longer than `timeout_secs`, which counts as killed.
+    TimedOut,
+    /// The run deadline passed first; the mutant has no result.
+    Cancelled,
+}
+
+/// Runs the test command, stopping it at the timeout or the deadline.
+pub fn run_test_command(config: &RunnerConfig, extra_args: &[String], workdir: &Path) -> TestRun {
+    let timeout = Instant::now() + Duration::from_secs(config.timeout_secs);
+    run(config, extra_args, workdir, Some(timeout))
+}
+
+/// Runs the unmutated suite with no per-mutant timeout; only the deadline
+/// stops it.
+pub fn run_untimed(config: &RunnerConfig, workdir: &Path) -> TestRun {
+    run(config, &[], workdir, None)
+}
+
+fn run(
+    config: &RunnerConfig,
+    extra_args: &[String],
+    workdir: &Path,
+    timeout: Option<Instant>,
+) -> TestRun {
+    let mut command = Command::new(&config.test_command);
+    command
+        .args(&config.test_args)
+        .args(extra_args)
+        .current_dir(workdir)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Some(dir) = &config.target_dir {
+        command.env("CARGO_TARGET_DIR", dir);
+    }
+    // Its own group, so stopping it also stops the test binaries it spawned.
+    #[cfg(unix)]
+    command.process_group(0);
+    let Ok(mut child) = command.spawn() else {
+        return TestRun::Failed;
+    };
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return TestRun::Passed,
+            Ok(Some(_)) => return TestRun::Failed,
+            Ok(None) => {}
+            Err(_) => return stop(&mut child, TestRun::Failed),
+        }
+        if config.out_of_time() {
+            return stop(&mut child, TestRun::Cancelled);
+        }
+        if timeout.is_some_and(|t| Instant::now() >= t) {
+            return stop(&mut child, TestRun::TimedOut);
+        }
+        thread::sleep(POLL);
+    }
+}
+
+/// Kills `child` and everything in its process group, reaps it, and returns
+/// `outcome`.
+fn stop(child: &mut Child, outcome: TestRun) -> TestRun {
+    kill_group(child);
+    let _ = child.kill();
+    let _ = child.wait();
+    outcome
+}
+
+#[cfg(unix)]
+fn kill_group(child: &Child) {
+    if let Ok(pid) = libc::pid_t::try_from(child.id()) {
+        // SAFETY: kill(2) takes no pointers. The negative pid names the group
+        // `process_group(0)` created with the child as its leader; the child
+        // is not reaped yet, so the id cannot have been reused.
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+    }
+}
+
+/// Without process groups only the direct child is killed.
+#[cfg(not(unix))]
+fn kill_group(_child: &Child) {}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::mutate::mutations::MutationKind;
+use std::path::PathBuf;
+use std::time::Duration;
+use tempfile::TempDir;
+
+fn point() -> MutationPoint {
+    MutationPoint {
+        file: PathBuf::from("lib.rs"),
+        line: 1,
+        column: 3,
+        byte_start: 2,
+        byte_end: 3,
+        original: "<".into(),
+        mutated: ">=".into(),
+        kind: MutationKind::Comparison,
+    }
+}
+
+fn project() -> TempDir {
+    let dir = TempDir::new().expect("temp dir");
+    fs::write(dir.path().join("lib.rs"), "a < b\n").expect("write lib.rs");
+    dir
+}
+
+/// Tests that pass on the original source and sleep `secs` on the mutant.
+fn sleeping(secs: &str) -> RunnerConfig {
+    RunnerConfig {
+        test_command: "sh".to_string(),
+        test_args: vec![
+            "-c".to_string(),
+            format!("if grep -q '>=' lib.rs; then sleep {secs}; fi"),
+        ],
+        timeout_secs: 1,
+        workers: 1,
+        targeting: None,
+        deadline: None,
+        target_dir: None,
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn hanging_tests_are_stopped_at_the_timeout() {
+    let dir = project();
+    let start = Instant::now();
+    let results = run_mutations(&[point()], &sleeping("10"), dir.path(), |_, _, _| {})
+        .expect("run mutations");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].killed_by.as_deref(),
+        Some("full suite (timeout)")
+    );
+    let restored = fs::read_to_string(dir.path().join("lib.rs")).expect("read lib.rs");
+    assert_eq!(restored, "a < b\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn the_deadline_stops_a_running_mutant_without_a_result() {
+    let dir = project();
+    let mut config = sleeping("10");
+    config.timeout_secs = 60;
+    config.deadline = Some(Instant::now() + Duration::from_millis(200));
+    let start = Instant::now();
+    let results =
+        run_mutations(&[point()], &config, dir.path(), |_, _, _| {}).expect("run mutations");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(results.is_empty());
+}
+
+#[test]
+fn an_expired_budget_runs_nothing() {
+    let dir = project();
+    let mut config = sleeping("0");
+    config.deadline = Some(Instant::now());
+    let results =
+        run_mutations(&[point()], &config, dir.path(), |_, _, _| {}).expect("run mutations");
+    assert!(results.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn a_failing_baseline_fails_the_run() {
+    let dir = project();
+    let mut config = sleeping("0");
+    config.test_args = vec!["-c".to_string(), "exit 1".to_string()];
+    let run = run_mutations(&[point()], &config, dir.path(), |_, _, _| {});
+    let err = run.err().map(|e| e.to_string()).unwrap_or_default();
+    assert!(err.contains("fails on unmutated code"), "{err}");
+}
+
+/// Whether `pid` is running; zombies waiting to be reaped count as stopped.
+#[cfg(target_os = "linux")]
+fn running(pid: &str) -> bool {
+    fs::read_to_string(format!("/proc/{pid}/stat")).is_ok_and(|stat| {
+        !stat
+            .rsplit(')')
+            .next()
+            .unwrap_or("")
+            .trim_start()
+            .starts_with('Z')
+    })
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn the_timeout_stops_processes_the_tests_spawned() {
+    let dir = project();
+    let mut config = sleeping("0");
+    config.test_args = vec![
+        "-c".to_string(),
+        "if grep -q '>=' lib.rs; then sleep 30 & echo $! > spawned.pid; wait; fi".to_string(),
+    ];
+    run_mutations(&[point()], &config, dir.path(), |_, _, _| {}).expect("run mutations");
+
+    let pid = fs::read_to_string(dir.path().join("spawned.pid")).expect("read pid");
+    let pid = pid.trim();
+    let stopped = Instant::now() + Duration::from_secs(2);
+    while running(pid) && Instant::now() < stopped {
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    assert!(!running(pid), "spawned process {pid} outlived the timeout");
+}
@@ -0,0 +1,145 @@
+// src/mutate/targets.rs
+//! Targeted test selection.
+//!
+//! Maps a mutated file to the tests most likely to kill it, by naming
+//! convention: cargo runs the file's module path as a test filter, pytest runs
+//! the `test_<stem>.py` / `<stem>_test.py` files. The runner tries these first
+//! and only falls back to the full suite when the mutant survives them.
+
+use crate::project::ProjectType;
+use std::path::{Component, Path, PathBuf};
+
+/// How to narrow the test command to a single source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Targeting {
+    /// `cargo test <module::path::>`.
+    Cargo,
+    /// `pytest <matching test files>`, chosen from the project's test files.
+    Pytest { test_files: Vec<PathBuf> },
+}
+
+/// A narrowed test run for one source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetedRun {
+    /// Extra arguments appended to the configured test arguments.
+    pub args: Vec<String>,
+    /// Human-readable description, reported as "killed by".
+    pub label: String,
+}
+
+/// Targeting for a project, if its test runner supports it.
+#[must_use]
+pub fn for_project(project: ProjectType, files: &[PathBuf]) -> Option<Targeting> {
+    match project {
+        ProjectType::Rust => Some(Targeting::Cargo),
+        ProjectType::Python => Some(Targeting::Pytest {
+            test_files: files
+                .iter()
+                .filter(|f| is_pytest_file(f))
+                .cloned()
+                .collect(),
+        }),
+        _ => None,
+    }
+}
+
+impl Targeting {
+    /// The narrowed run for `file`, or `None` when no subset applies.
+    #[must_use]
+    pub fn run_for(&self, file: &Path) -> Option<TargetedRun> {
+        match self {
+            Self::Cargo => {
+                let filter = rust_module_filter(file)?;
+                Some(TargetedRun {
+                    label: filter.clone(),
+                    args: vec![filter],
+                })
+            }
+            Self::Pytest { test_files } => {
+                let stem = file.file_stem()?.to_str()?;
+                let matching: Vec<String> = test_files
+                    .iter()
+                    .filter(|t| tests_module(t, stem))
+                    .map(|t| t.to_string_lossy().into_owned())
+                    .collect();
+                if matching.is_empty() {
+                    return None;
+                }
+                Some(TargetedRun {
+                    label: matching.join(" "),
+                    args: matching,
+                })
+            }
+        }
+    }
+}
+
+/// `src/graph/rank/mod.rs` → `graph::rank::`. Crate roots and files outside
+/// `src/` have no narrower filter than the full suite.
+fn rust_module_filter(file: &Path) -> Option<String> {
+    let parts: Vec<&str> = file
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(s) => s.to_str(),
+            _ => None,
+        })
+        .collect();
+    let (first, rest) = parts.split_first()?;
+    if *first != "src" {
+        return None;
+    }
+
+    let mut modules: Vec<&str> = rest.iter().map(|p| p.trim_end_matches(".rs")).collect();
+    if modules.last() == Some(&"mod") {
+        modules.pop();
+    }
+    match modules.as_slice() {
+        [] | ["lib" | "main"] | ["bin", ..] => None,
+        _ => Some(format!("{}::", modules.join("::"))),
+    }
+}
+
+fn is_pytest_file(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name.ends_with(".py") && (name.starts_with("test_") || name.ends_with("_test.py"))
+}
+
+fn tests_module(test_file: &Path, stem: &str) -> bool {
+    let name = test_file.file_stem().and_then(|n| n.to_str()).unwrap_or("");
+    name.strip_prefix("test_") == Some(stem) || name.strip_suffix("_test") == Some(stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cargo_filter_from_module_path() {
+        let run = |p: &str| Targeting::Cargo.run_for(Path::new(p)).map(|r| r.label);
+        assert_eq!(run("./src/graph/rank/mod.rs"), Some("graph::rank::".into()));
+        assert_eq!(run("src/tokens.rs"), Some("tokens::".into()));
+        assert_eq!(run("src/lib.rs"), None);
+        assert_eq!(run("src/bin/tool.rs"), None);
+        assert_eq!(run("tests/cli_test.rs"), None);
+    }
+
+    #[test]
+    fn pytest_matches_test_files_by_stem() {
+        let files = vec![
+            PathBuf::from("pkg/parser.py"),
+            PathBuf::from("tests/test_parser.py"),
+            PathBuf::from("tests/lexer_test.py"),
+        ];
+        let Some(targeting) = for_project(ProjectType::Python, &files) else {
+            panic!("python projects support targeting");
+        };
+
+        let run = targeting.run_for(Path::new("pkg/parser.py"));
+        assert_eq!(
+            run.map(|r| r.args),
+            Some(vec!["tests/test_parser.py".into()])
+        );
+        assert!(targeting.run_for(Path::new("pkg/lexer.py")).is_some());
+        assert!(targeting.run_for(Path::new("pkg/other.py")).is_none());
+    }
+}
@@ -0,0 +1,152 @@
+// src/mutate/workspace.rs
+//! Isolated project copies for parallel mutation workers.
+//!
+//! Each worker mutates and tests its own copy, so concurrent mutations never
+//! see each other's edits. Copies live in the system temp directory and are
+//! removed when dropped. Installed dependencies are linked, not copied, and
+//! Cargo projects build into a per-worker target directory that outlives
+//! the copy, so later runs start warm.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directories never copied: build output, VCS metadata, caches.
+const SKIP_DIRS: &[&str] = &["target", ".git", ".neti", "__pycache__"];
+
+/// Installed dependencies, symlinked into the copy so its tests can run.
+const SHARED_DIRS: &[&str] = &["node_modules", ".venv"];
+
+/// A temporary copy of the project, deleted on drop.
+#[derive(Debug)]
+pub struct WorkspaceCopy {
+    root: PathBuf,
+    target_dir: Option<PathBuf>,
+}
+
+impl WorkspaceCopy {
+    /// Copies `source` into a fresh temp directory for worker `id`.
+    ///
+    /// # Errors
+    /// Returns error if the directory cannot be created or a file cannot be copied.
+    pub fn create(source: &Path, id: usize) -> Result<Self> {
+        let root = std::env::temp_dir().join(format!("neti-mutate-{}-{id}", std::process::id()));
+        if root.exists() {
+            fs::remove_dir_all(&root)?;
+        }
+        let source = fs::canonicalize(source)?;
+        let copy = Self {
+            root,
+            target_dir: worker_target_dir(&source, id),
+        };
+        copy_tree(&source, &copy.root)
+            .with_context(|| format!("Failed to copy workspace for worker {id}"))?;
+        Ok(copy)
+    }
+
+    /// Root of the copy; mutation point paths resolve against it.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// `CARGO_TARGET_DIR` for this worker, if the project uses Cargo.
+    #[must_use]
+    pub fn target_dir(&self) -> Option<&Path> {
+        self.target_dir.as_deref()
+    }
+}
+
+/// `<target>/neti-mutate/worker-<id>` under the project's target directory,
+/// kept between runs so only changed crates rebuild.
+fn worker_target_dir(source: &Path, id: usize) -> Option<PathBuf> {
+    if !source.join("Cargo.toml").exists() {
+        return None;
+    }
+    let base = std::env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| source.join("target"), |dir| source.join(dir));
+    Some(base.join("neti-mutate").join(format!("worker-{id}")))
+}
+
+impl Drop for WorkspaceCopy {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn copy_tree(source: &Path, dest: &Path) -> Result<()> {
+    let mut walker = WalkDir::new(source).follow_links(false).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(source)?;
+        let target = dest.join(relative);
+        let name = entry.file_name().to_str().unwrap_or("");
+
+        if entry.depth() > 0 && entry.file_type().is_dir() && is_shared(name) {
+            link_dir(entry.path(), &target)?;
+            walker.skip_current_dir();
+        } else if entry.depth() > 0 && is_skipped(name) {
+            walker.skip_current_dir();
+        } else if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_file() {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn link_dir(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(windows)]
+fn link_dir(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_dir(original, link)
+}
+
+fn is_skipped(name: &str) -> bool {
+    SKIP_DIRS.contains(&name)
+}
+
+fn is_shared(name: &str) -> bool {
+    SHARED_DIRS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_build_and_vcs_dirs() {
+        assert!(is_skipped("target"));
+        assert!(is_skipped(".git"));
+        assert!(!is_skipped("node_modules"));
+        assert!(!is_skipped("src"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn links_dependencies_and_skips_build_output() -> Result<()> {
+        let source = tempfile::TempDir::new()?;
+        fs::create_dir_all(source.path().join("node_modules/dep"))?;
+        fs::write(source.path().join("node_modules/dep/index.js"), "")?;
+        fs::create_dir_all(source.path().join("target/debug"))?;
+        fs::write(source.path().join("Cargo.toml"), "")?;
+
+        let copy = WorkspaceCopy::create(source.path(), 0)?;
+        let modules = copy.root().join("node_modules");
+        assert!(fs::symlink_metadata(&modules)?.file_type().is_symlink());
+        assert!(modules.join("dep/index.js").exists());
+        assert!(!copy.root().join("target").exists());
+        assert!(copy.root().join("Cargo.toml").exists());
+
+        let target = copy.target_dir().map(Path::to_path_buf);
+        let expected = fs::canonicalize(source.path())?.join("target/neti-mutate/worker-0");
+        assert_eq!(target, Some(expected));
+        Ok(())
+    }
+}
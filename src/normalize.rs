@@ -0,0 +1,193 @@
+// src/normalize.rs
+//! Platform normalization: `/`-separated path text, LF line endings and
+//! lossy UTF-8 decoding.
+//!
+//! Detectors match paths as text and split sources into lines. On Windows
+//! paths arrive with `\` separators and checkouts often have CRLF endings,
+//! so anything that compares a path as a string, or reads a source file for
+//! analysis, goes through here to give the same results on every platform.
+//! Legacy files with stray Latin-1 bytes are decoded lossily rather than
+//! skipped, so they are still scanned.
+
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// A source file decoded for analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// The text, with LF endings. Invalid UTF-8 reads as U+FFFD.
+    pub text: String,
+    /// 1-based line of the first invalid UTF-8 sequence, if there was one.
+    pub invalid_utf8_line: Option<usize>,
+}
+
+/// Reads `path` for analysis; see [`decode`].
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn read_source(path: &Path) -> io::Result<Source> {
+    fs::read(path).map(decode)
+}
+
+/// Reads `path` for analysis into `buf`, which the caller reuses across
+/// files. Clean UTF-8 with LF endings is borrowed from `buf` rather than
+/// copied; anything else is decoded as by [`decode`].
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn read_source_into<'b>(
+    path: &Path,
+    buf: &'b mut Vec<u8>,
+) -> io::Result<(Cow<'b, str>, Option<usize>)> {
+    buf.clear();
+    fs::File::open(path)?.read_to_end(buf)?;
+    if std::str::from_utf8(buf).is_ok_and(|text| !text.contains('\r')) {
+        return Ok((String::from_utf8_lossy(buf), None));
+    }
+    let source = decode(std::mem::take(buf));
+    Ok((Cow::Owned(source.text), source.invalid_utf8_line))
+}
+
+/// Reads `path` as LF text, replacing invalid UTF-8. For callers that only
+/// need the text.
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn read_text(path: &Path) -> io::Result<String> {
+    read_source(path).map(|s| s.text)
+}
+
+/// Decodes file bytes as UTF-8 with LF endings. Invalid sequences become
+/// U+FFFD instead of failing the whole file.
+#[must_use]
+pub fn decode(bytes: Vec<u8>) -> Source {
+    match String::from_utf8(bytes) {
+        Ok(text) => Source {
+            text: line_endings(text),
+            invalid_utf8_line: None,
+        },
+        Err(e) => {
+            let bytes = e.as_bytes();
+            let valid = e.utf8_error().valid_up_to();
+            let newlines = bytes.iter().take(valid).filter(|&&b| b == b'\n').count();
+            Source {
+                text: line_endings(String::from_utf8_lossy(bytes).into_owned()),
+                invalid_utf8_line: Some(newlines + 1),
+            }
+        }
+    }
+}
+
+/// `path` as text with `/` separators.
+#[must_use]
+pub fn path_str(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// `path` relative to `root`, without a leading `./`: discovery yields
+/// `./src/x.rs` while baselines and graph keys store `src/x.rs`. Paths
+/// outside `root` are returned as they are.
+#[must_use]
+pub fn relative_to(root: &Path, path: &Path) -> PathBuf {
+    let path = path.strip_prefix(root).unwrap_or(path);
+    path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+/// The directory and file names in a path string, split on either
+/// separator. Empty and `.` segments are skipped.
+pub fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['/', '\\'])
+        .filter(|s| !s.is_empty() && *s != ".")
+}
+
+/// The last segment of a path string, split on either separator.
+#[must_use]
+pub fn file_name(path: &str) -> &str {
+    segments(path).last().unwrap_or("")
+}
+
+/// Whether some directory in `path` (not the file name) is named `dir`.
+#[must_use]
+pub fn has_dir(path: &str, dir: &str) -> bool {
+    let parts: Vec<&str> = segments(path).collect();
+    parts
+        .split_last()
+        .is_some_and(|(_, dirs)| dirs.contains(&dir))
+}
+
+/// `content` with CRLF and lone CR line endings turned into LF. Returns the
+/// string unchanged (without copying) when it has no `\r`.
+#[must_use]
+pub fn line_endings(content: String) -> String {
+    if content.contains('\r') {
+        content.replace("\r\n", "\n").replace('\r', "\n")
+    } else {
+        content
+    }
+}
+
+/// Indent and width, in characters, of the caret underline beneath a
+/// snippet line: everything after the leading whitespace.
+#[must_use]
+pub fn underline(line: &str) -> (usize, usize) {
+    let line = line.trim_end_matches('\r');
+    let trimmed = line.trim_start();
+    let indent = line.chars().count() - trimmed.chars().count();
+    (indent, trimmed.chars().count().max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_paths_read_like_unix_ones() {
+        assert_eq!(path_str(Path::new(r"src\cli\args.rs")), "src/cli/args.rs");
+        assert_eq!(file_name(r"C:\repo\src\main.rs"), "main.rs");
+        assert_eq!(file_name("./src/lib.rs"), "lib.rs");
+        assert!(has_dir(r"src\bin\tool.rs", "bin"));
+        assert!(has_dir("./examples/demo.rs", "examples"));
+        assert!(!has_dir(r"src\bin.rs", "bin"));
+    }
+
+    #[test]
+    fn line_endings_become_lf() {
+        assert_eq!(line_endings("a\r\nb\rc\n".into()), "a\nb\nc\n");
+        assert_eq!(line_endings("plain\n".into()), "plain\n");
+    }
+
+    #[test]
+    fn invalid_utf8_is_replaced_not_dropped() {
+        let latin1 = b"fn main() {}\r\n// caf\xe9\r\nfn f() {}\r\n".to_vec();
+        let source = decode(latin1);
+        assert_eq!(source.text, "fn main() {}\n// caf\u{fffd}\nfn f() {}\n");
+        assert_eq!(source.invalid_utf8_line, Some(2));
+        assert_eq!(decode(b"ok\n".to_vec()).invalid_utf8_line, None);
+    }
+
+    #[test]
+    fn clean_sources_are_borrowed_from_the_buffer() -> io::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let (lf, crlf) = (dir.path().join("lf.rs"), dir.path().join("crlf.rs"));
+        fs::write(&lf, "fn f() {}\n")?;
+        fs::write(&crlf, "fn f() {}\r\n")?;
+
+        let mut buf = Vec::with_capacity(64);
+        let (text, invalid) = read_source_into(&lf, &mut buf)?;
+        assert!(matches!(text, Cow::Borrowed("fn f() {}\n")));
+        assert_eq!(invalid, None);
+
+        let (text, _) = read_source_into(&crlf, &mut buf)?;
+        assert!(matches!(text, Cow::Owned(ref t) if t == "fn f() {}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn underline_counts_characters() {
+        assert_eq!(underline("    let x = 1;\r"), (4, 10));
+        assert_eq!(underline("\tlet é = 1;"), (1, 10));
+        assert_eq!(underline(""), (0, 1));
+    }
+}
@@ -0,0 +1,218 @@
+// src/notify.rs
+//! Completion notifications (`[notifications]`).
+//!
+//! When `neti check` or `neti mutate` finishes, the result goes to every
+//! configured channel: a command hook, a Slack webhook and the desktop.
+//! Delivery is best effort. A channel that fails logs a warning and never
+//! changes the exit code of the run it reports on.
+
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::config::NotificationsConfig;
+
+/// A finished run, as notifications describe it.
+#[derive(Debug, Clone)]
+pub struct Outcome<'a> {
+    /// The neti subcommand that finished, e.g. `check`.
+    pub command: &'a str,
+    pub passed: bool,
+    pub duration: Duration,
+    /// One line of detail, e.g. `0 errors, 3 warnings`.
+    pub summary: String,
+}
+
+impl Outcome<'_> {
+    /// `passed` or `failed`.
+    #[must_use]
+    pub fn status(&self) -> &'static str {
+        if self.passed {
+            "passed"
+        } else {
+            "failed"
+        }
+    }
+
+    /// The headline, e.g. `neti check passed in 12.4s`.
+    #[must_use]
+    pub fn title(&self) -> String {
+        format!(
+            "neti {} {} in {:.1}s",
+            self.command,
+            self.status(),
+            self.duration.as_secs_f64()
+        )
+    }
+}
+
+/// Sends `outcome` to each configured channel, if the config wants it.
+pub fn send(config: &NotificationsConfig, repo_root: &Path, outcome: &Outcome) {
+    if !config.wants(outcome.passed, outcome.duration.as_secs()) {
+        return;
+    }
+    if let Some(hook) = &config.command {
+        warn_on_failure("command", run_hook(hook, repo_root, outcome));
+    }
+    if let Some(var) = &config.slack_webhook_env {
+        warn_on_failure("Slack", post_slack(var, outcome));
+    }
+    if config.desktop {
+        warn_on_failure("desktop", show_desktop(outcome));
+    }
+}
+
+fn warn_on_failure(channel: &str, result: Result<()>) {
+    if let Err(e) = result {
+        log::warn!("{channel} notification failed: {e:#}");
+    }
+}
+
+/// Runs the hook like a `[commands]` entry, with the result in its
+/// environment.
+fn run_hook(hook: &str, repo_root: &Path, outcome: &Outcome) -> Result<()> {
+    let parts = shell_words::split(hook).context("cannot parse command")?;
+    let Some((program, args)) = parts.split_first() else {
+        bail!("command is empty");
+    };
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .current_dir(repo_root)
+        .env("NETI_COMMAND", outcome.command)
+        .env("NETI_STATUS", outcome.status())
+        .env("NETI_DURATION_SECS", outcome.duration.as_secs().to_string())
+        .env("NETI_SUMMARY", &outcome.summary);
+    run(&mut command)
+}
+
+/// Posts to the webhook URL in the environment variable `var`. The URL and
+/// body go to curl as a config file on stdin, keeping the URL out of the
+/// process list.
+fn post_slack(var: &str, outcome: &Outcome) -> Result<()> {
+    let url = std::env::var(var)
+        .ok()
+        .filter(|u| !u.is_empty())
+        .with_context(|| format!("${var} is not set"))?;
+    let body = serde_json::json!({ "text": slack_text(outcome) }).to_string();
+    run_with_input(
+        Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--max-time", "10"])
+            .args(["--header", "Content-Type: application/json"])
+            .args(["--config", "-"]),
+        &curl_config(&url, &body),
+    )
+}
+
+/// A curl config file that posts `body` to `url`.
+fn curl_config(url: &str, body: &str) -> String {
+    format!("url = {}\ndata = {}\n", quoted(url), quoted(body))
+}
+
+/// The Slack message: an icon, the headline in bold and the summary.
+#[must_use]
+pub fn slack_text(outcome: &Outcome) -> String {
+    let icon = if outcome.passed {
+        ":white_check_mark:"
+    } else {
+        ":x:"
+    };
+    format!("{icon} *{}*\n{}", outcome.title(), outcome.summary)
+}
+
+fn show_desktop(outcome: &Outcome) -> Result<()> {
+    let title = outcome.title();
+    if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            quoted(&outcome.summary),
+            quoted(&title)
+        );
+        run(Command::new("osascript").args(["-e", &script]))
+    } else if cfg!(unix) {
+        run(Command::new("notify-send").args([title.as_str(), outcome.summary.as_str()]))
+    } else {
+        bail!("desktop notifications are not supported on this platform")
+    }
+}
+
+/// Double-quotes `s`, escaping backslashes and quotes, as AppleScript and
+/// curl config files expect.
+fn quoted(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn run(command: &mut Command) -> Result<()> {
+    run_with_input(command, "")
+}
+
+/// Runs `command` with `input` on its stdin.
+fn run_with_input(command: &mut Command, input: &str) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("cannot run `{program}`"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "`{program}` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(passed: bool) -> Outcome<'static> {
+        Outcome {
+            command: "check",
+            passed,
+            duration: Duration::from_millis(12_400),
+            summary: "2 errors, 1 warning".into(),
+        }
+    }
+
+    #[test]
+    fn messages_carry_status_and_duration() {
+        assert_eq!(outcome(true).title(), "neti check passed in 12.4s");
+        assert_eq!(
+            slack_text(&outcome(false)),
+            ":x: *neti check failed in 12.4s*\n2 errors, 1 warning"
+        );
+        assert_eq!(quoted(r#"say "hi""#), r#""say \"hi\"""#);
+    }
+
+    #[test]
+    fn curl_config_quotes_url_and_body() {
+        assert_eq!(
+            curl_config("https://hooks.example/T", r#"{"text":"a\nb"}"#),
+            "url = \"https://hooks.example/T\"\ndata = \"{\\\"text\\\":\\\"a\\\\nb\\\"}\"\n"
+        );
+    }
+
+    #[test]
+    fn config_filters_by_status_and_duration() {
+        let mut config = NotificationsConfig {
+            desktop: true,
+            when: "failure".into(),
+            ..NotificationsConfig::default()
+        };
+        assert!(config.wants(false, 0));
+        assert!(!config.wants(true, 0));
+        config.min_duration_secs = 60;
+        assert!(!config.wants(false, 59));
+        assert!(!NotificationsConfig::default().wants(false, 100));
+    }
+}
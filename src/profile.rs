@@ -0,0 +1,192 @@
+// src/profile.rs
+//! Per-rule timing for `neti scan --profile-rules`.
+//!
+//! Detectors wrap their work in [`time`] with a rule label and the file
+//! being analyzed; whole-project passes such as deep analysis and the
+//! locality graph use [`time_stage`]. Recording is off unless [`enable`]
+//! was called, and then costs one uncontended lock per detector per file.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDER: LazyLock<Mutex<Recorder>> = LazyLock::new(Mutex::default);
+
+#[derive(Default)]
+struct Recorder {
+    rules: HashMap<&'static str, RuleStat>,
+    files: HashMap<String, Duration>,
+}
+
+#[derive(Default)]
+struct RuleStat {
+    total: Duration,
+    calls: usize,
+    slowest: Option<(Duration, String)>,
+}
+
+/// Timing for one rule across the scan.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleTime {
+    pub rule: &'static str,
+    pub total_ms: f64,
+    pub calls: usize,
+    /// The file this rule spent longest on, if it runs per file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slowest_file: Option<String>,
+}
+
+/// Rule time spent on one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileTime {
+    pub path: String,
+    pub total_ms: f64,
+}
+
+/// Recorded timings, slowest first.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Profile {
+    pub rules: Vec<RuleTime>,
+    pub files: Vec<FileTime>,
+}
+
+/// Starts recording.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f`, charging its time to `rule` on `file` when recording.
+pub fn time<T>(rule: &'static str, file: &str, f: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let out = f();
+    record(rule, Some(file), start.elapsed());
+    out
+}
+
+/// Runs a whole-project pass, charging its time to `rule` only.
+pub fn time_stage<T>(rule: &'static str, f: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let out = f();
+    record(rule, None, start.elapsed());
+    out
+}
+
+fn record(rule: &'static str, file: Option<&str>, elapsed: Duration) {
+    let Ok(mut recorder) = RECORDER.lock() else {
+        return;
+    };
+    let stat = recorder.rules.entry(rule).or_default();
+    stat.total += elapsed;
+    stat.calls += 1;
+    if let Some(file) = file {
+        if stat.slowest.as_ref().is_none_or(|(d, _)| elapsed > *d) {
+            stat.slowest = Some((elapsed, file.to_owned()));
+        }
+        *recorder.files.entry(file.to_owned()).or_default() += elapsed;
+    }
+}
+
+/// Returns what was recorded and clears it.
+#[must_use]
+pub fn take() -> Profile {
+    let recorder = RECORDER
+        .lock()
+        .map(|mut r| std::mem::take(&mut *r))
+        .unwrap_or_default();
+    let mut rules: Vec<RuleTime> = recorder
+        .rules
+        .into_iter()
+        .map(|(rule, stat)| RuleTime {
+            rule,
+            total_ms: millis(stat.total),
+            calls: stat.calls,
+            slowest_file: stat.slowest.map(|(_, file)| file),
+        })
+        .collect();
+    rules.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms).then(a.rule.cmp(b.rule)));
+    let mut files: Vec<FileTime> = recorder
+        .files
+        .into_iter()
+        .map(|(path, total)| FileTime {
+            total_ms: millis(total),
+            path,
+        })
+        .collect();
+    files.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms).then(a.path.cmp(&b.path)));
+    Profile { rules, files }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+impl Profile {
+    /// The `limit` slowest rules and files as aligned text tables.
+    #[must_use]
+    pub fn table(&self, limit: usize) -> String {
+        let mut out = String::from("Slowest rules\n");
+        let _ = writeln!(
+            out,
+            "  {:>10}  {:>7}  {:<24} slowest file",
+            "ms", "calls", "rule"
+        );
+        for r in self.rules.iter().take(limit) {
+            let _ = writeln!(
+                out,
+                "  {:>10.1}  {:>7}  {:<24} {}",
+                r.total_ms,
+                r.calls,
+                r.rule,
+                r.slowest_file.as_deref().unwrap_or("-")
+            );
+        }
+        out.push_str("\nSlowest files\n");
+        let _ = writeln!(out, "  {:>10}  file", "ms");
+        for f in self.files.iter().take(limit) {
+            let _ = writeln!(out, "  {:>10.1}  {}", f.total_ms, f.path);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timings_aggregate_by_rule_and_file() {
+        enable();
+        time("test/a", "x.rs", || {
+            std::thread::sleep(Duration::from_millis(2))
+        });
+        time("test/a", "y.rs", || ());
+        time_stage("test/stage", || ());
+        let profile = take();
+        let a = profile.rules.iter().find(|r| r.rule == "test/a");
+        assert_eq!(a.map(|r| r.calls), Some(2));
+        assert_eq!(a.and_then(|r| r.slowest_file.as_deref()), Some("x.rs"));
+        let stage = profile.rules.iter().find(|r| r.rule == "test/stage");
+        assert_eq!(stage.map(|r| r.slowest_file.is_none()), Some(true));
+        let x = profile.files.iter().find(|f| f.path == "x.rs");
+        assert!(x.is_some_and(|f| f.total_ms >= 2.0));
+
+        let table = profile.table(5);
+        assert!(table.contains("test/a"));
+        assert!(table.contains("Slowest files"));
+    }
+}
@@ -11,6 +11,7 @@ pub enum ProjectType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum Strictness {
     Strict,
     Standard,
@@ -30,7 +31,7 @@ impl ProjectType {
         if root.join("Cargo.toml").exists() {
             return Self::Rust;
         }
-        if root.join("package.json").exists() {
+        if root.join("package.json").exists() || root.join("pnpm-workspace.yaml").exists() {
             return Self::Node;
         }
         if root.join("pyproject.toml").exists()
@@ -42,7 +43,8 @@ impl ProjectType {
         if root.join("go.mod").exists() {
             return Self::Go;
         }
-        Self::Unknown
+        crate::workspace::Workspace::detect_in(root)
+            .map_or(Self::Unknown, |ws| ws.kind.project_type())
     }
 
     /// Detects if this is a TypeScript project
@@ -76,20 +78,23 @@ pub fn generate_toml(project: ProjectType, strictness: Strictness) -> String {
 }
 
 fn rules_section(strictness: Strictness) -> String {
-    let (tokens, complexity, depth) = match strictness {
-        Strictness::Strict => (1500, 4, 2),
-        Strictness::Standard => (2000, 8, 3),
-        Strictness::Relaxed => (3000, 12, 4),
+    let (tokens, complexity, depth, args) = match strictness {
+        Strictness::Strict => (1500, 15, 2, 4),
+        Strictness::Standard => (2000, 25, 3, 5),
+        Strictness::Relaxed => (3000, 35, 4, 7),
     };
 
     format!(
         r#"[rules]
 max_file_tokens = {tokens}
-max_cyclomatic_complexity = {complexity}
+max_cognitive_complexity = {complexity}
 max_nesting_depth = {depth}
-max_function_args = 5
-max_function_words = 5
-ignore_naming_on = ["tests", "spec"]"#
+max_function_args = {args}
+max_function_words = 10
+ignore_naming_on = ["tests", "spec"]
+
+[rules.locality]
+mode = "warn""#
     )
 }
 
@@ -0,0 +1,66 @@
+// src/query_cache.rs
+//! Compiled tree-sitter queries, shared by every detector, thread and file.
+//!
+//! Compiling a `Query` costs far more than running it on a typical file,
+//! and detectors used to compile theirs for every file they saw. Queries
+//! here are compiled once per language and pattern and kept for the life
+//! of the process. Patterns are `'static` source text, so the cache holds
+//! at most one entry per query in the binary.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use tree_sitter::Query;
+
+use crate::lang::Lang;
+
+type Key = (Lang, &'static str);
+
+static CACHE: LazyLock<RwLock<HashMap<Key, Option<&'static Query>>>> =
+    LazyLock::new(RwLock::default);
+
+/// The compiled `pattern` for `lang`, or `None` if it does not compile
+/// against that grammar. Failures are cached too.
+#[must_use]
+pub fn get(lang: Lang, pattern: &'static str) -> Option<&'static Query> {
+    if let Some(&cached) = CACHE.read().ok()?.get(&(lang, pattern)) {
+        return cached;
+    }
+    let mut cache = CACHE.write().ok()?;
+    *cache
+        .entry((lang, pattern))
+        .or_insert_with(|| compile(lang, pattern))
+}
+
+/// Shorthand for [`get`] with the Rust grammar.
+#[must_use]
+pub fn rust(pattern: &'static str) -> Option<&'static Query> {
+    get(Lang::Rust, pattern)
+}
+
+fn compile(lang: Lang, pattern: &'static str) -> Option<&'static Query> {
+    match Query::new(&lang.grammar(), pattern) {
+        // Leaked on purpose: one allocation per distinct query, for the
+        // life of the process.
+        Ok(query) => Some(Box::leak(Box::new(query))),
+        Err(e) => {
+            log::debug!("{lang:?} query does not compile: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries_compile_once_and_failures_stick() {
+        let pattern = "(identifier) @id";
+        let first = rust(pattern).map(std::ptr::from_ref);
+        assert!(first.is_some());
+        assert_eq!(rust(pattern).map(std::ptr::from_ref), first);
+        assert!(get(Lang::Python, pattern).is_some());
+        assert!(rust("(not_a_node_kind) @x").is_none());
+        assert!(rust("(not_a_node_kind) @x").is_none());
+    }
+}
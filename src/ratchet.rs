@@ -0,0 +1,147 @@
+// src/ratchet.rs
+//! Per-rule violation ceilings (`neti check --ratchet`).
+//!
+//! `.neti/ratchet.json` records how many violations each rule had. A check
+//! fails when any rule has more than its recorded count, and otherwise
+//! lowers the recorded counts to the current ones, so the ceilings only ever
+//! move down. The first run records the current counts and passes. Unlike a
+//! baseline, no individual finding is accepted: fixing one violation lets
+//! another of the same rule appear, but never two.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::fsutil::atomic_write;
+use crate::types::ScanReport;
+
+#[cfg(test)]
+#[path = "ratchet_test.rs"]
+mod tests;
+
+/// Ratchet file location, relative to the project root.
+pub const RATCHET_PATH: &str = ".neti/ratchet.json";
+
+/// Violation count per rule. Rules with none are left out.
+pub type Counts = BTreeMap<String, usize>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RatchetFile {
+    rules: Counts,
+}
+
+/// A rule whose count differs from the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub rule: String,
+    pub recorded: usize,
+    pub current: usize,
+}
+
+/// Outcome of comparing a scan against the ratchet file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RatchetReport {
+    /// Rules over their recorded count.
+    pub increased: Vec<Change>,
+    /// Rules under their recorded count.
+    pub decreased: Vec<Change>,
+    /// True if the file was written: on the first run or an improvement.
+    pub updated: bool,
+}
+
+impl RatchetReport {
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.increased.is_empty()
+    }
+}
+
+/// Counts `report`'s violations per rule.
+#[must_use]
+pub fn counts(report: &ScanReport) -> Counts {
+    let mut counts = Counts::new();
+    for v in report.files.iter().flat_map(|f| &f.violations) {
+        *counts.entry(v.law.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Compares `current` with `recorded`; a rule missing from either has a
+/// count of zero there.
+#[must_use]
+pub fn compare(recorded: &Counts, current: &Counts) -> RatchetReport {
+    let mut report = RatchetReport::default();
+    let rules: BTreeSet<&String> = recorded.keys().chain(current.keys()).collect();
+    for rule in rules {
+        let change = Change {
+            rule: rule.clone(),
+            recorded: recorded.get(rule).copied().unwrap_or(0),
+            current: current.get(rule).copied().unwrap_or(0),
+        };
+        if change.current > change.recorded {
+            report.increased.push(change);
+        } else if change.current < change.recorded {
+            report.decreased.push(change);
+        }
+    }
+    report
+}
+
+/// Loads the recorded counts, if a ratchet file exists.
+///
+/// # Errors
+/// Returns error if the file exists but cannot be read or parsed.
+pub fn load(root: &Path) -> Result<Option<Counts>> {
+    let path = root.join(RATCHET_PATH);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read {}", path.display()));
+        }
+    };
+    let file: RatchetFile = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid ratchet file {}", path.display()))?;
+    Ok(Some(file.rules))
+}
+
+/// Writes `counts` as the new ceilings.
+///
+/// # Errors
+/// Returns error if the file cannot be written.
+pub fn save(root: &Path, counts: &Counts) -> Result<()> {
+    let path = root.join(RATCHET_PATH);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = RatchetFile {
+        rules: counts.clone(),
+    };
+    let json = serde_json::to_string_pretty(&file)?;
+    atomic_write(&path, json + "\n").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Checks `report` against the ratchet file under `root`, recording the
+/// counts on the first run and lowering them when no rule got worse.
+///
+/// # Errors
+/// Returns error if the ratchet file cannot be parsed or written.
+pub fn check(root: &Path, report: &ScanReport) -> Result<RatchetReport> {
+    let current = counts(report);
+    let Some(recorded) = load(root)? else {
+        save(root, &current)?;
+        return Ok(RatchetReport {
+            updated: true,
+            ..RatchetReport::default()
+        });
+    };
+    let mut result = compare(&recorded, &current);
+    if result.passed() && !result.decreased.is_empty() {
+        save(root, &current)?;
+        result.updated = true;
+    }
+    Ok(result)
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::types::{FileReport, Violation};
+use tempfile::TempDir;
+
+fn counts_of(pairs: &[(&str, usize)]) -> Counts {
+    pairs
+        .iter()
+        .map(|&(rule, n)| (rule.to_string(), n))
+        .collect()
+}
+
+fn change(rule: &str, recorded: usize, current: usize) -> Change {
+    Change {
+        rule: rule.into(),
+        recorded,
+        current,
+    }
+}
+
+#[test]
+fn any_increase_fails_and_new_rules_start_at_zero() {
+    let recorded = counts_of(&[("P01", 3), ("L03", 2)]);
+    let current = counts_of(&[("P01", 1), ("L03", 3), ("X02", 1)]);
+    let report = compare(&recorded, &current);
+    assert!(!report.passed());
+    assert_eq!(
+        report.increased,
+        vec![change("L03", 2, 3), change("X02", 0, 1)]
+    );
+    assert_eq!(report.decreased, vec![change("P01", 3, 1)]);
+}
+
+#[test]
+fn first_run_records_and_improvement_tightens() {
+    let dir = TempDir::new().expect("temp dir");
+    let root = dir.path();
+
+    let first = check(root, &ScanReport::default()).expect("first run");
+    assert!(first.passed() && first.updated);
+    assert_eq!(load(root).expect("load"), Some(Counts::new()));
+
+    save(root, &counts_of(&[("P01", 2)])).expect("save");
+    let improved = check(root, &ScanReport::default()).expect("improved run");
+    assert!(improved.passed() && improved.updated);
+    assert_eq!(improved.decreased, vec![change("P01", 2, 0)]);
+    assert_eq!(load(root).expect("load"), Some(Counts::new()));
+
+    let unchanged = check(root, &ScanReport::default()).expect("unchanged run");
+    assert_eq!(unchanged, RatchetReport::default());
+}
+
+#[test]
+fn a_worse_run_fails_and_leaves_the_file_alone() {
+    let dir = TempDir::new().expect("temp dir");
+    let root = dir.path();
+    save(root, &counts_of(&[("P01", 1)])).expect("save");
+    let report = ScanReport {
+        files: vec![FileReport {
+            path: "src/a.rs".into(),
+            token_count: 0,
+            complexity_score: 0,
+            violations: vec![
+                Violation::simple(3, "unchecked index".into(), "L03"),
+                Violation::simple(9, "clone".into(), "P01"),
+            ],
+            analysis: None,
+            suppressions: Default::default(),
+        }],
+        ..ScanReport::default()
+    };
+
+    let result = check(root, &report).expect("check");
+    assert!(!result.passed() && !result.updated);
+    assert_eq!(result.increased, vec![change("L03", 0, 1)]);
+    assert_eq!(load(root).expect("load"), Some(counts_of(&[("P01", 1)])));
+
+    std::fs::write(root.join(RATCHET_PATH), "{").expect("write");
+    assert!(check(root, &report).is_err());
+}
+
+#[test]
+fn only_a_missing_file_counts_as_absent() {
+    let dir = TempDir::new().expect("temp dir");
+    assert!(load(dir.path()).expect("missing file").is_none());
+
+    // A directory in the file's place cannot be read.
+    fs::create_dir_all(dir.path().join(RATCHET_PATH)).expect("create dir");
+    assert!(load(dir.path()).is_err());
+}
@@ -0,0 +1,215 @@
+// src/remote.rs
+//! Shared analysis state on a remote store (`[remote]`).
+//!
+//! `neti check --upload` PUTs the check report and everything under
+//! `.neti/cache/` to the configured URL, with a manifest listing the cache
+//! files. `neti remote pull` reads the manifest back and restores those
+//! files, so a fresh CI checkout starts with a warm incremental cache.
+//!
+//! Layout under the base URL:
+//!
+//! ```text
+//! check-report.json
+//! cache/manifest.json        ["mutants/3f2a….json", …]
+//! cache/mutants/3f2a….json
+//! ```
+//!
+//! Transfers use `curl`, so any store that speaks HTTP PUT and GET works.
+//! The bearer token, when set, is passed on stdin rather than the command
+//! line.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+use walkdir::WalkDir;
+
+use crate::config::RemoteConfig;
+use crate::normalize;
+
+/// Local directory mirrored by the cache artifacts.
+pub const CACHE_DIR: &str = ".neti/cache";
+/// Remote name of the check report.
+pub const REPORT: &str = "check-report.json";
+/// Remote name of the cache manifest.
+pub const MANIFEST: &str = "cache/manifest.json";
+
+/// A configured remote store.
+#[derive(Debug, Clone)]
+pub struct Remote {
+    base: String,
+    token: Option<String>,
+}
+
+impl Remote {
+    /// The remote from `[remote]`, reading the token from its environment
+    /// variable.
+    ///
+    /// # Errors
+    /// Returns error if `[remote] url` is not set.
+    pub fn from_config(config: &RemoteConfig) -> Result<Self> {
+        let Some(url) = &config.url else {
+            bail!("No remote configured: set [remote] url in neti.toml");
+        };
+        Ok(Self {
+            base: url.trim_end_matches('/').to_string(),
+            token: std::env::var(&config.token_env)
+                .ok()
+                .filter(|t| !t.is_empty()),
+        })
+    }
+
+    /// The URL of an artifact.
+    #[must_use]
+    pub fn url(&self, name: &str) -> String {
+        format!("{}/{name}", self.base)
+    }
+
+    /// Uploads `file` as `name`.
+    ///
+    /// # Errors
+    /// Returns error if `curl` fails or the store rejects the upload.
+    pub fn put(&self, name: &str, file: &Path) -> Result<()> {
+        let file = file.to_string_lossy();
+        self.curl(&["--upload-file", &file, &self.url(name)])
+            .with_context(|| format!("Failed to upload {name}"))
+    }
+
+    /// Downloads `name` to `dest`, replacing it only once the transfer has
+    /// completed.
+    ///
+    /// # Errors
+    /// Returns error if the artifact is missing or cannot be written.
+    pub fn get(&self, name: &str, dest: &Path) -> Result<()> {
+        let mut partial = dest.as_os_str().to_owned();
+        partial.push(".partial");
+        let partial = PathBuf::from(partial);
+        let target = partial.to_string_lossy();
+        let fetched = self
+            .curl(&["--create-dirs", "--output", &target, &self.url(name)])
+            .with_context(|| format!("Failed to download {name}"));
+        if let Err(e) = fetched {
+            let _ = fs::remove_file(&partial);
+            return Err(e);
+        }
+        fs::rename(&partial, dest).with_context(|| format!("Failed to write {}", dest.display()))
+    }
+
+    fn curl(&self, args: &[&str]) -> Result<()> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location"])
+            .args(["--header", "@-"])
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to run curl")?;
+        if let (Some(mut stdin), Some(token)) = (child.stdin.take(), &self.token) {
+            writeln!(stdin, "Authorization: Bearer {token}")?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(())
+    }
+}
+
+/// Uploads the check report and the cache, returning how many cache files
+/// were sent.
+///
+/// # Errors
+/// Returns error on the first artifact that fails to upload.
+pub fn upload(remote: &Remote, root: &Path, report: &Path) -> Result<usize> {
+    remote.put(REPORT, report)?;
+    let files = cache_files(root);
+    for rel in &files {
+        remote.put(&format!("cache/{rel}"), &root.join(CACHE_DIR).join(rel))?;
+    }
+    let manifest = root.join(CACHE_DIR).join("manifest.json");
+    if let Some(parent) = manifest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    crate::fsutil::atomic_write(&manifest, serde_json::to_string_pretty(&files)?)?;
+    remote.put(MANIFEST, &manifest)?;
+    Ok(files.len())
+}
+
+/// Restores the remote cache into `.neti/cache/`, returning how many files
+/// were fetched.
+///
+/// # Errors
+/// Returns error if the manifest is missing or invalid, lists a path
+/// outside the cache, or a file fails to download.
+pub fn pull(remote: &Remote, root: &Path) -> Result<usize> {
+    let dir = root.join(CACHE_DIR);
+    let manifest = dir.join("manifest.json");
+    remote.get(MANIFEST, &manifest)?;
+    let content = fs::read_to_string(&manifest)?;
+    let files: Vec<String> =
+        serde_json::from_str(&content).context("Remote cache manifest is not a list of paths")?;
+    for rel in &files {
+        let Some(local) = contained(&dir, rel) else {
+            bail!("Remote cache manifest lists '{rel}', which is outside {CACHE_DIR}");
+        };
+        remote.get(&format!("cache/{rel}"), &local)?;
+    }
+    Ok(files.len())
+}
+
+/// Cache files under `root`, as sorted `/`-separated paths relative to the
+/// cache directory. The manifest itself is excluded.
+#[must_use]
+pub fn cache_files(root: &Path) -> Vec<String> {
+    let dir = root.join(CACHE_DIR);
+    let mut files: Vec<String> = WalkDir::new(&dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.path().strip_prefix(&dir).ok().map(normalize::path_str))
+        .filter(|rel| rel != "manifest.json")
+        .collect();
+    files.sort();
+    files
+}
+
+/// `dir/rel`, if `rel` is a plain relative path that stays inside `dir`.
+#[must_use]
+pub fn contained(dir: &Path, rel: &str) -> Option<PathBuf> {
+    let path = Path::new(rel);
+    let plain = path.components().all(|c| matches!(c, Component::Normal(_)));
+    (plain && !rel.is_empty() && !rel.contains('\\')).then(|| dir.join(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_paths_cannot_escape_the_cache() {
+        let dir = Path::new(".neti/cache");
+        assert_eq!(
+            contained(dir, "mutants/a.json"),
+            Some(dir.join("mutants/a.json"))
+        );
+        assert_eq!(contained(dir, "../../.bashrc"), None);
+        assert_eq!(contained(dir, "/etc/passwd"), None);
+        assert_eq!(contained(dir, r"..\..\x"), None);
+        assert_eq!(contained(dir, ""), None);
+    }
+
+    #[test]
+    fn urls_join_the_base() {
+        let remote = Remote::from_config(&RemoteConfig {
+            url: Some("https://cache.example.com/neti/".into()),
+            token_env: "NETI_TEST_UNSET_TOKEN".into(),
+        });
+        assert_eq!(
+            remote.map(|r| r.url(MANIFEST)).ok().as_deref(),
+            Some("https://cache.example.com/neti/cache/manifest.json")
+        );
+        assert!(Remote::from_config(&RemoteConfig::default()).is_err());
+    }
+}
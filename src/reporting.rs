@@ -7,8 +7,10 @@
 
 mod console;
 mod guidance;
+pub mod porcelain;
 mod rich;
 mod shared;
+pub mod spreadsheet;
 
 use anyhow::Result;
 
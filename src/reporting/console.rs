@@ -1,12 +1,13 @@
+use crate::normalize;
 use crate::reporting::guidance::get_guidance;
 use crate::reporting::shared::{
-    collect_violations, confidence_suffix, duration, next_occurrence, pluralize, rule_counts,
+    category_line, collect_violations, confidence_suffix, duration, next_occurrence, pluralize,
+    rule_counts, suppression_lines,
 };
 use crate::types::{Confidence, ScanReport, Violation};
 use anyhow::Result;
 use colored::Colorize;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
 
 /// Prints a formatted scan report to stdout with confidence tiers and
@@ -19,6 +20,12 @@ pub fn print_report(report: &ScanReport) -> Result<()> {
         print_violations_grouped(report);
     }
     print_summary(report);
+    for line in category_line(report)
+        .into_iter()
+        .chain(suppression_lines(report))
+    {
+        println!("  {}", line.dimmed());
+    }
     Ok(())
 }
 
@@ -143,7 +150,7 @@ fn print_violation_compact(path: &Path, v: &Violation, occurrence: usize, total:
 }
 
 fn print_snippet(path: &Path, row: usize) {
-    let Ok(content) = fs::read_to_string(path) else {
+    let Ok(content) = normalize::read_text(path) else {
         return;
     };
     let lines: Vec<&str> = content.lines().collect();
@@ -161,9 +168,7 @@ fn print_snippet(path: &Path, row: usize) {
 
             if i == idx {
                 println!("   {} {}", gutter.blue(), line);
-                let trimmed = line.trim_start();
-                let padding = line.len() - trimmed.len();
-                let underline_len = trimmed.len().max(1);
+                let (padding, underline_len) = normalize::underline(line);
                 let spaces = " ".repeat(padding);
                 let carets = "^".repeat(underline_len);
                 println!("   {} {}{}", "|".blue(), spaces, carets.red().bold());
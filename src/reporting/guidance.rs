@@ -1,5 +1,8 @@
 //! Static educational guidance per rule code.
 
+#[path = "guidance_heuristics.rs"]
+mod heuristics;
+
 /// Static educational guidance per rule.
 pub(crate) struct RuleGuidance {
     pub(crate) why: &'static str,
@@ -25,6 +28,13 @@ pub(crate) fn get_guidance(rule: &str) -> Option<RuleGuidance> {
             why: "Linear search (.find/.position/.index) inside a loop produces O(n·m) complexity.",
             fix: "Pre-build a lookup structure (HashSet/HashMap/dict/Set) for O(1) access, or confirm the inner collection is bounded to a small constant size.",
         },
+        _ => return law_guidance(rule),
+    })
+}
+
+/// Guidance for logic and structural rules.
+fn law_guidance(rule: &str) -> Option<RuleGuidance> {
+    Some(match rule {
         "L02" => RuleGuidance {
             why: "Using <= or >= with .len() in index bounds can reach len, which is one past the last valid index.",
             fix: "Use < len for upper bounds on indices. The valid index range is 0..len-1.",
@@ -41,14 +51,6 @@ pub(crate) fn get_guidance(rule: &str) -> Option<RuleGuidance> {
             why: "Executing external commands with dynamic arguments risks injection (shell) or untrusted binary resolution (direct exec).",
             fix: "For shell commands: validate and sanitize inputs, or avoid shell invocation entirely. For direct exec: use absolute paths, allowlists, or signature verification.",
         },
-        "C03" => RuleGuidance {
-            why: "Holding a lock guard across an await point blocks the executor thread (sync mutex) or starves other tasks (async mutex).",
-            fix: "Scope the guard so it drops before the await, or extract the critical section into a synchronous helper function.",
-        },
-        "C04" => RuleGuidance {
-            why: "Synchronization primitives without documentation make concurrent code harder to reason about and audit.",
-            fix: "Add a comment explaining what the lock protects and the expected contention pattern.",
-        },
         "I01" => RuleGuidance {
             why: "Manual From implementations are boilerplate that can be generated with derive macros.",
             fix: "Use derive_more::From if your project already depends on proc macros. Manual impls are perfectly fine for zero-dependency crates.",
@@ -74,13 +76,36 @@ pub(crate) fn get_guidance(rule: &str) -> Option<RuleGuidance> {
             fix: "Add a // SAFETY: comment immediately above the unsafe block explaining why the invariants hold.",
         },
         "LAW OF ATOMICITY" => RuleGuidance {
-            why: "Files beyond the token limit are too large for a single unit of work, increasing cognitive load and merge conflict risk.",
-            fix: "Split the file into smaller, focused modules. Extract related functions into their own files.",
+            why: "Files, functions and types beyond their size limits are too large for a single unit of work, increasing cognitive load and merge conflict risk.",
+            fix: "Split the file into smaller, focused modules, extract helper functions from long functions, and group related fields into their own types.",
         },
         "LAW OF INTEGRITY" => RuleGuidance {
             why: "Syntax errors prevent analysis and indicate malformed or unparseable code.",
             fix: "Fix the syntax error, or if this is valid modern syntax that Neti's parser doesn't support, file an issue.",
         },
-        _ => return None,
+        _ => return concurrency_guidance(rule),
+    })
+}
+
+/// Guidance for concurrency and async rules.
+fn concurrency_guidance(rule: &str) -> Option<RuleGuidance> {
+    Some(match rule {
+        "C03" => RuleGuidance {
+            why: "Holding a lock guard across an await point blocks the executor thread (sync mutex) or starves other tasks (async mutex).",
+            fix: "Scope the guard so it drops before the await, or extract the critical section into a synchronous helper function.",
+        },
+        "C04" => RuleGuidance {
+            why: "Synchronization primitives without documentation make concurrent code harder to reason about and audit.",
+            fix: "Add a comment explaining what the lock protects and the expected contention pattern.",
+        },
+        "A01" => RuleGuidance {
+            why: "An async executor runs many tasks on few threads; a blocking call holds one of them, so every task scheduled there waits too.",
+            fix: "Use the runtime's async equivalent (tokio::fs, tokio::time::sleep, an async channel), or move the blocking work into spawn_blocking.",
+        },
+        "A02" => RuleGuidance {
+            why: "block_on inside async code blocks the worker thread until the inner future finishes; tokio panics outright, and single-threaded executors deadlock.",
+            fix: "Await the future directly. If sync code genuinely needs a result, restructure so the block_on happens outside the runtime.",
+        },
+        _ => return heuristics::heuristic_guidance(rule),
     })
 }
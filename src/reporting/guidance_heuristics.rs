@@ -0,0 +1,53 @@
+//! Guidance for heuristic, naming and import rules.
+
+use super::RuleGuidance;
+
+/// Guidance for error-handling and repository-level heuristics.
+pub(super) fn heuristic_guidance(rule: &str) -> Option<RuleGuidance> {
+    Some(match rule {
+        "T01" => RuleGuidance {
+            why: "Complex code without tests is where regressions hide; every change to it is unverified.",
+            fix: "Add tests that cover the branches behind the complexity score, or lower the complexity so the module is easy to verify by reading.",
+        },
+        "E01" => RuleGuidance {
+            why: "A discarded Result hides the failure; the program carries on as if the operation succeeded.",
+            fix: "Propagate with ?, handle the error, or log it. If ignoring it is genuinely right, say why in a comment.",
+        },
+        "E02" => RuleGuidance {
+            why: "Box<dyn Error> erases the failure type, so callers can only print it, not react to it.",
+            fix: "Return a concrete error enum with one variant per failure callers may want to handle.",
+        },
+        "D02" => RuleGuidance {
+            why: "A caller reading the docs cannot see when a function fails, panics or is unsound to call; they find out in production.",
+            fix: "Add the missing # Errors, # Panics or # Safety section. For panics, consider returning an error instead.",
+        },
+        "D01" => RuleGuidance {
+            why: "A TODO that has outlived its context is forgotten work; the longer it waits, the less anyone remembers what it meant.",
+            fix: "Do the work, move it to the issue tracker, or delete the comment if it no longer applies.",
+        },
+        "D03" => RuleGuidance {
+            why: "Most of the file is exempt from every check, so problems in it go unreported; handwritten code tends to creep into such regions.",
+            fix: "Narrow the regions or name the rules they silence (neti:off(P01)). Move generated code into its own file and list it in .netiignore.",
+        },
+        _ => return structure_guidance(rule),
+    })
+}
+
+/// Guidance for naming and import conventions.
+fn structure_guidance(rule: &str) -> Option<RuleGuidance> {
+    Some(match rule {
+        "N01" => RuleGuidance {
+            why: "Names that break the house convention make code harder to scan and search, and hide what kind of thing a name refers to.",
+            fix: "Rename to match the pattern, or change the policy under [rules.naming] if the convention itself has moved.",
+        },
+        "I03" => RuleGuidance {
+            why: "A wildcard import hides where names come from, and anything added upstream can shadow a local name.",
+            fix: "Import the names you use. Glob-import only preludes, or list the module under [rules.imports] wildcard_allow.",
+        },
+        "I04" => RuleGuidance {
+            why: "An import that climbs many directories couples the file to distant folder layout; moving either side breaks it.",
+            fix: "Import from the package root or a path alias, or move the module nearer to what it depends on.",
+        },
+        _ => return None,
+    })
+}
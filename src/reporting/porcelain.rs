@@ -0,0 +1,174 @@
+// src/reporting/porcelain.rs
+//! Script-friendly output: `--porcelain` rows and the `--quiet` summary.
+//!
+//! The porcelain format is stable across versions. Each finding is one line
+//! of five tab-separated columns:
+//!
+//! ```text
+//! path<TAB>line<TAB>rule<TAB>confidence<TAB>message
+//! ```
+//!
+//! Paths use `/`, `line` is 1-based (0 when the finding has no line),
+//! `confidence` is `error`, `warn` or `info`, and tabs and newlines inside a
+//! message become spaces. Rows are sorted by path, line and rule. Columns
+//! are never added, removed or reordered; a different layout would get a
+//! new flag.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::normalize;
+use crate::types::{CheckReport, LocalityReport, ScanReport};
+
+/// Rule column for locality findings.
+const LOCALITY: &str = "LAW OF LOCALITY";
+/// Rule column for failed `[commands]`.
+const COMMAND: &str = "COMMAND";
+/// Rule column for rules over their `--ratchet` count.
+const RATCHET: &str = "RATCHET";
+
+struct Row {
+    path: String,
+    line: usize,
+    rule: &'static str,
+    confidence: &'static str,
+    message: String,
+}
+
+/// Porcelain rows for a scan.
+#[must_use]
+pub fn scan_rows(report: &ScanReport) -> String {
+    render(scan_findings(report))
+}
+
+/// Porcelain rows for a check: scan findings, then locality findings,
+/// failed commands and rules over their ratchet count.
+#[must_use]
+pub fn check_rows(report: &CheckReport) -> String {
+    let mut rows = scan_findings(&report.scan);
+    if let Some(locality) = &report.locality {
+        rows.extend(locality_findings(locality));
+    }
+    rows.extend(report.commands.iter().filter(|c| !c.passed()).map(|c| Row {
+        path: "-".into(),
+        line: 0,
+        rule: COMMAND,
+        confidence: "error",
+        message: format!("`{}` failed with exit code {}", c.command(), c.exit_code()),
+    }));
+    let increased = report.ratchet.iter().flat_map(|r| &r.increased);
+    rows.extend(increased.map(|c| Row {
+        path: "-".into(),
+        line: 0,
+        rule: RATCHET,
+        confidence: "error",
+        message: format!(
+            "{}: {} violations, up from {}",
+            c.rule, c.current, c.recorded
+        ),
+    }));
+    render(rows)
+}
+
+/// The `--quiet` line for a scan.
+#[must_use]
+pub fn scan_summary(report: &ScanReport) -> String {
+    let status = if report.has_errors() {
+        "failed"
+    } else {
+        "passed"
+    };
+    format!(
+        "neti scan: {status}, {} errors, {} warnings, {} files",
+        report.error_count(),
+        report.warning_count(),
+        report.files.len()
+    )
+}
+
+/// The `--quiet` line for a check.
+#[must_use]
+pub fn check_summary(report: &CheckReport) -> String {
+    let status = if report.passed { "passed" } else { "failed" };
+    let locality = report.locality.as_ref().map_or(0, |l| l.violation_count);
+    let passed = report.commands.iter().filter(|c| c.passed()).count();
+    format!(
+        "neti check: {status}, {} errors, {} warnings, {locality} locality violations, {passed}/{} commands passed",
+        report.scan.error_count(),
+        report.scan.warning_count(),
+        report.commands.len()
+    )
+}
+
+fn scan_findings(report: &ScanReport) -> Vec<Row> {
+    let mut rows: Vec<Row> = report
+        .files
+        .iter()
+        .flat_map(|f| {
+            let path = slashed(&f.path);
+            f.violations.iter().map(move |v| Row {
+                path: path.clone(),
+                line: v.row,
+                rule: v.law,
+                confidence: v.confidence.prefix(),
+                message: v.message.clone(),
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| (&a.path, a.line, a.rule).cmp(&(&b.path, b.line, b.rule)));
+    rows
+}
+
+fn locality_findings(report: &LocalityReport) -> Vec<Row> {
+    let confidence = if report.mode == "error" {
+        "error"
+    } else {
+        "warn"
+    };
+    let edges = report.violations.iter().map(|v| Row {
+        path: slashed(&v.from),
+        line: 0,
+        rule: LOCALITY,
+        confidence,
+        message: format!(
+            "imports {} (distance {}, target {})",
+            slashed(&v.to),
+            v.distance,
+            v.target_role
+        ),
+    });
+    let expired = report.expired_exemptions.iter().map(|e| Row {
+        path: slashed(&e.from),
+        line: 0,
+        rule: LOCALITY,
+        confidence,
+        message: format!(
+            "exemption for {} expired {} (owner {})",
+            slashed(&e.to),
+            e.expires,
+            e.owner
+        ),
+    });
+    edges.chain(expired).collect()
+}
+
+fn slashed(path: &Path) -> String {
+    let path = normalize::path_str(path);
+    match path.strip_prefix("./") {
+        Some(rest) => rest.to_string(),
+        None => path,
+    }
+}
+
+fn render(rows: Vec<Row>) -> String {
+    let mut out = String::new();
+    for row in rows {
+        let message = row.message.replace(['\t', '\n', '\r'], " ");
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{message}",
+            row.path, row.line, row.rule, row.confidence
+        );
+    }
+    out
+}
@@ -1,12 +1,13 @@
+use crate::normalize;
 use crate::reporting::guidance::get_guidance;
 use crate::reporting::shared::{
-    collect_violations, confidence_suffix, duration, next_occurrence, pluralize, rule_counts,
+    category_line, collect_violations, confidence_suffix, duration, next_occurrence, pluralize,
+    rule_counts, suppression_lines,
 };
 use crate::types::{ScanReport, Violation};
 use anyhow::Result;
 use std::collections::HashMap;
 use std::fmt::Write;
-use std::fs;
 use std::path::Path;
 
 /// Formats a report as a string (for embedding in context files).
@@ -59,6 +60,12 @@ pub fn build_rich_report(report: &ScanReport) -> Result<String> {
     }
 
     write_summary(&mut out, report)?;
+    for line in category_line(report)
+        .into_iter()
+        .chain(suppression_lines(report))
+    {
+        writeln!(out, "  {line}")?;
+    }
     Ok(out)
 }
 
@@ -140,7 +147,7 @@ fn write_violation_compact(
 }
 
 fn write_snippet(out: &mut String, path: &Path, row: usize) -> Result<()> {
-    let Ok(content) = fs::read_to_string(path) else {
+    let Ok(content) = normalize::read_text(path) else {
         return Ok(());
     };
     let lines: Vec<&str> = content.lines().collect();
@@ -158,9 +165,7 @@ fn write_snippet(out: &mut String, path: &Path, row: usize) -> Result<()> {
 
             if i == idx {
                 writeln!(out, "   {} {}", gutter, line)?;
-                let trimmed = line.trim_start();
-                let padding = line.len() - trimmed.len();
-                let underline_len = trimmed.len().max(1);
+                let (padding, underline_len) = normalize::underline(line);
                 let spaces = " ".repeat(padding);
                 let carets = "^".repeat(underline_len);
                 writeln!(out, "   | {}{}", spaces, carets)?;
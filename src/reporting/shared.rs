@@ -1,3 +1,4 @@
+use crate::normalize;
 use crate::types::{Confidence, ScanReport, Violation};
 use std::collections::HashMap;
 use std::path::Path;
@@ -43,6 +44,40 @@ pub(crate) fn confidence_suffix(v: &Violation) -> String {
     }
 }
 
+/// Violation subtotals per rule category, when there are any.
+pub(crate) fn category_line(report: &ScanReport) -> Option<String> {
+    let counts = report.category_counts();
+    if counts.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = counts
+        .iter()
+        .map(|(category, n)| format!("{n} {}", category.name()))
+        .collect();
+    Some(format!("By category: {}", parts.join(", ")))
+}
+
+/// One line per file with `neti:off` regions, most suppressed first.
+pub(crate) fn suppression_lines(report: &ScanReport) -> Vec<String> {
+    let mut files: Vec<_> = report
+        .files
+        .iter()
+        .filter(|f| f.suppressions.lines() > 0)
+        .collect();
+    files.sort_by(|a, b| (b.suppressions.lines(), &a.path).cmp(&(a.suppressions.lines(), &b.path)));
+    files
+        .iter()
+        .map(|f| {
+            let lines = f.suppressions.lines();
+            format!(
+                "{}: {lines} {} suppressed by neti:off",
+                normalize::path_str(&f.path),
+                pluralize("line", lines)
+            )
+        })
+        .collect()
+}
+
 pub(crate) fn pluralize(word: &str, count: usize) -> String {
     if count == 1 {
         word.to_string()
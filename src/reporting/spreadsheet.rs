@@ -0,0 +1,93 @@
+// src/reporting/spreadsheet.rs
+//! Scan results as spreadsheet tables (`neti report --xlsx`).
+//!
+//! A Summary sheet with one row per rule and a Violations sheet with one
+//! row per finding, in the same path/line/rule order as `--porcelain`.
+
+use std::collections::BTreeMap;
+
+use crate::normalize;
+use crate::types::{Confidence, ScanReport};
+use crate::xlsx::{Cell, Sheet};
+
+#[derive(Default)]
+struct RuleTotals {
+    violations: usize,
+    errors: usize,
+    files: usize,
+}
+
+/// The Summary and Violations sheets for a scan.
+#[must_use]
+pub fn sheets(report: &ScanReport) -> Vec<Sheet> {
+    vec![summary(report), violations(report)]
+}
+
+fn summary(report: &ScanReport) -> Sheet {
+    let mut by_rule: BTreeMap<&str, RuleTotals> = BTreeMap::new();
+    for file in &report.files {
+        let mut seen: Vec<&str> = Vec::new();
+        for v in &file.violations {
+            let totals = by_rule.entry(v.law).or_default();
+            totals.violations += 1;
+            totals.errors += usize::from(v.confidence == Confidence::High);
+            if !seen.contains(&v.law) {
+                seen.push(v.law);
+                totals.files += 1;
+            }
+        }
+    }
+    Sheet {
+        name: "Summary".into(),
+        header: vec!["Rule", "Violations", "Errors", "Warnings", "Files"],
+        rows: by_rule
+            .into_iter()
+            .map(|(rule, t)| {
+                vec![
+                    rule.into(),
+                    t.violations.into(),
+                    t.errors.into(),
+                    (t.violations - t.errors).into(),
+                    t.files.into(),
+                ]
+            })
+            .collect(),
+    }
+}
+
+fn violations(report: &ScanReport) -> Sheet {
+    let mut rows: Vec<(String, usize, &str, Vec<Cell>)> = Vec::new();
+    for file in &report.files {
+        let full = normalize::path_str(&file.path);
+        let path = full.strip_prefix("./").unwrap_or(&full);
+        for v in &file.violations {
+            let suggestion = v
+                .details
+                .as_ref()
+                .and_then(|d| d.suggestion.clone())
+                .unwrap_or_default();
+            let cells = vec![
+                v.law.into(),
+                path.into(),
+                v.row.into(),
+                v.confidence.prefix().into(),
+                v.message.as_str().into(),
+                suggestion.into(),
+            ];
+            rows.push((path.to_owned(), v.row, v.law, cells));
+        }
+    }
+    rows.sort_by(|a, b| (&a.0, a.1, a.2).cmp(&(&b.0, b.1, b.2)));
+    Sheet {
+        name: "Violations".into(),
+        header: vec![
+            "Rule",
+            "File",
+            "Line",
+            "Confidence",
+            "Message",
+            "Suggestion",
+        ],
+        rows: rows.into_iter().map(|(.., cells)| cells).collect(),
+    }
+}
@@ -0,0 +1,107 @@
+// src/schema/check.rs
+//! Schema of `CheckReport`: a scan plus command, locality and ratchet results.
+
+use serde_json::{json, Map, Value};
+
+use super::{array_of, count, object, reference, scan};
+
+pub(super) const REQUIRED: &[&str] = &["scan", "commands", "passed"];
+
+pub(super) fn properties() -> Value {
+    json!({
+        "scan": reference("ScanReport"),
+        "commands": array_of(reference("CommandResult")),
+        "locality": reference("LocalityReport"),
+        "ratchet": reference("RatchetReport"),
+        "passed": { "type": "boolean" },
+    })
+}
+
+pub(super) fn defs() -> Map<String, Value> {
+    let string = json!({ "type": "string" });
+    let mut defs = scan::defs();
+    defs.insert(
+        "CommandResult".into(),
+        object(
+            json!({
+                "command": string,
+                "passed": { "type": "boolean" },
+                "exit_code": { "type": "integer" },
+                "stdout": string,
+                "stderr": string,
+                "duration_ms": count(),
+            }),
+            &[
+                "command",
+                "passed",
+                "exit_code",
+                "stdout",
+                "stderr",
+                "duration_ms",
+            ],
+        ),
+    );
+    defs.insert(
+        "LocalityReport".into(),
+        object(
+            json!({
+                "violation_count": count(),
+                "violations": array_of(reference("LocalityViolation")),
+                "expired_exemptions": array_of(reference("ExpiredExemption")),
+                "cycle_count": count(),
+                "cycles": array_of(array_of(string.clone())),
+                "total_edges": count(),
+                "mode": { "enum": ["off", "warn", "error"] },
+                "passed": { "type": "boolean" },
+            }),
+            &[
+                "violation_count",
+                "violations",
+                "expired_exemptions",
+                "cycle_count",
+                "cycles",
+                "total_edges",
+                "mode",
+                "passed",
+            ],
+        ),
+    );
+    defs.insert(
+        "LocalityViolation".into(),
+        object(
+            json!({
+                "from": string,
+                "to": string,
+                "distance": count(),
+                "target_role": string,
+            }),
+            &["from", "to", "distance", "target_role"],
+        ),
+    );
+    defs.insert(
+        "RatchetReport".into(),
+        object(
+            json!({
+                "increased": array_of(reference("RatchetChange")),
+                "decreased": array_of(reference("RatchetChange")),
+                "updated": { "type": "boolean" },
+            }),
+            &["increased", "decreased", "updated"],
+        ),
+    );
+    defs.insert(
+        "RatchetChange".into(),
+        object(
+            json!({ "rule": string, "recorded": count(), "current": count() }),
+            &["rule", "recorded", "current"],
+        ),
+    );
+    defs.insert(
+        "ExpiredExemption".into(),
+        object(
+            json!({ "from": string, "to": string, "owner": string, "expires": string }),
+            &["from", "to", "owner", "expires"],
+        ),
+    );
+    defs
+}
@@ -0,0 +1,150 @@
+// src/schema/mod.rs
+//! JSON Schemas for the machine-readable reports, printed by
+//! `neti schema <report>`.
+//!
+//! Schemas are built from shared definitions so a report embedded in
+//! another (the scan inside a check) is described once. Every top-level
+//! report carries `schema_version`, bumped whenever a field is removed or
+//! changes meaning; added fields do not bump it.
+
+mod check;
+mod mutate;
+mod scan;
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// Version of the report formats described here.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The reports that have a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum ReportKind {
+    /// `neti scan --json`
+    Scan,
+    /// `neti check --json`
+    Check,
+    /// `neti mutate --json`
+    Mutate,
+}
+
+impl ReportKind {
+    /// The JSON Schema (draft 2020-12) for this report.
+    #[must_use]
+    pub fn schema(self) -> Value {
+        let (title, properties, required, defs) = match self {
+            Self::Scan => (
+                "ScanReport",
+                scan::properties(),
+                scan::REQUIRED,
+                scan::defs(),
+            ),
+            Self::Check => (
+                "CheckReport",
+                check::properties(),
+                check::REQUIRED,
+                check::defs(),
+            ),
+            Self::Mutate => (
+                "MutateReport",
+                mutate::properties(),
+                mutate::REQUIRED,
+                mutate::defs(),
+            ),
+        };
+        let mut root = object(properties, required);
+        if let Some(root) = root.as_object_mut() {
+            add_version(root);
+            root.insert(
+                "$schema".into(),
+                json!("https://json-schema.org/draft/2020-12/schema"),
+            );
+            root.insert("title".into(), json!(title));
+            root.insert("$defs".into(), Value::Object(defs));
+        }
+        root
+    }
+}
+
+fn add_version(root: &mut Map<String, Value>) {
+    if let Some(Value::Object(props)) = root.get_mut("properties") {
+        props.insert(
+            "schema_version".into(),
+            json!({ "type": "integer", "const": SCHEMA_VERSION }),
+        );
+    }
+    if let Some(Value::Array(required)) = root.get_mut("required") {
+        required.insert(0, json!("schema_version"));
+    }
+}
+
+/// A report serialized with `schema_version` as its first field.
+#[derive(Serialize)]
+pub struct Versioned<'a, T: Serialize> {
+    schema_version: u32,
+    #[serde(flatten)]
+    report: &'a T,
+}
+
+/// Wraps `report` for output with the current [`SCHEMA_VERSION`].
+#[must_use]
+pub fn versioned<T: Serialize>(report: &T) -> Versioned<'_, T> {
+    Versioned {
+        schema_version: SCHEMA_VERSION,
+        report,
+    }
+}
+
+/// A closed object: every property listed, `required` ones mandatory.
+fn object(properties: Value, required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+fn array_of(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn count() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versioned_reports_lead_with_the_version() {
+        let json = serde_json::to_string(&versioned(&json!({ "passed": true })));
+        assert_eq!(
+            json.ok().as_deref(),
+            Some(r#"{"schema_version":1,"passed":true}"#)
+        );
+    }
+
+    #[test]
+    fn every_reference_resolves() {
+        let schemas =
+            [ReportKind::Scan, ReportKind::Check, ReportKind::Mutate].map(ReportKind::schema);
+        let texts = schemas.each_ref().map(Value::to_string);
+        for (schema, text) in schemas.iter().zip(&texts) {
+            for name in text.split("#/$defs/").skip(1) {
+                let name = name.split('"').next().unwrap_or_default();
+                assert!(
+                    schema["$defs"].get(name).is_some(),
+                    "{}: {name}",
+                    schema["title"]
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// src/schema/mutate.rs
+//! Schema of the `neti mutate --json` report.
+
+use serde_json::{json, Map, Value};
+
+use super::{array_of, count, object, reference};
+
+pub(super) const REQUIRED: &[&str] = &["summary", "survivors", "not_covered"];
+
+pub(super) fn properties() -> Value {
+    json!({
+        "summary": reference("MutationSummary"),
+        "survivors": array_of(reference("Mutant")),
+        "not_covered": array_of(reference("Mutant")),
+    })
+}
+
+pub(super) fn defs() -> Map<String, Value> {
+    let string = json!({ "type": "string" });
+    let mut defs = Map::new();
+    defs.insert(
+        "MutationSummary".into(),
+        object(
+            json!({
+                "total": count(),
+                "killed": count(),
+                "survived": count(),
+                "not_covered": count(),
+                "score": { "type": "number", "minimum": 0, "maximum": 100 },
+                "duration_ms": count(),
+            }),
+            &[
+                "total",
+                "killed",
+                "survived",
+                "not_covered",
+                "score",
+                "duration_ms",
+            ],
+        ),
+    );
+    defs.insert(
+        "Mutant".into(),
+        object(
+            json!({
+                "file": string,
+                "line": count(),
+                "column": count(),
+                "original": string,
+                "mutated": string,
+                "kind": string,
+            }),
+            &["file", "line", "column", "original", "mutated", "kind"],
+        ),
+    );
+    defs
+}
@@ -0,0 +1,123 @@
+// src/schema/scan.rs
+//! Schema of `ScanReport` and the per-file records inside it.
+
+use serde_json::{json, Map, Value};
+
+use super::{array_of, count, object, reference};
+
+pub(super) const REQUIRED: &[&str] = &["files", "total_tokens", "total_violations", "duration_ms"];
+
+pub(super) fn properties() -> Value {
+    json!({
+        "files": array_of(reference("FileReport")),
+        "total_tokens": count(),
+        "total_violations": count(),
+        "duration_ms": count(),
+    })
+}
+
+/// Definitions for a scan report and everything it references.
+pub(super) fn defs() -> Map<String, Value> {
+    let string = json!({ "type": "string" });
+    let strings = array_of(string.clone());
+    let optional_string = json!({ "type": ["string", "null"] });
+    let mut defs = Map::new();
+    defs.insert("ScanReport".into(), object(properties(), REQUIRED));
+    defs.insert(
+        "FileReport".into(),
+        object(
+            json!({
+                "path": string,
+                "token_count": count(),
+                "complexity_score": count(),
+                "violations": array_of(reference("Violation")),
+                "analysis": reference("FileAnalysis"),
+                "suppressed_lines": count(),
+            }),
+            &["path", "token_count", "complexity_score", "violations"],
+        ),
+    );
+    defs.insert(
+        "Violation".into(),
+        object(
+            json!({
+                "row": count(),
+                "message": string,
+                "law": string,
+                "confidence": { "enum": ["Info", "Medium", "High"] },
+                "confidence_reason": string,
+                "details": { "oneOf": [reference("ViolationDetails"), { "type": "null" }] },
+            }),
+            &["row", "message", "law", "confidence", "details"],
+        ),
+    );
+    defs.insert(
+        "ViolationDetails".into(),
+        object(
+            json!({
+                "function_name": optional_string,
+                "analysis": strings,
+                "suggestion": optional_string,
+            }),
+            &["function_name", "analysis", "suggestion"],
+        ),
+    );
+    defs.insert(
+        "FileAnalysis".into(),
+        object(
+            json!({
+                "path_str": string,
+                "scopes": map_of(reference("Scope")),
+                "violations": array_of(reference("Violation")),
+            }),
+            &["path_str", "scopes", "violations"],
+        ),
+    );
+    defs.insert(
+        "Scope".into(),
+        object(
+            json!({
+                "name": string,
+                "row": count(),
+                "is_enum": { "type": "boolean" },
+                "fields": map_of(reference("FieldInfo")),
+                "methods": map_of(reference("Method")),
+                "derives": strings,
+            }),
+            &["name", "row", "is_enum", "fields", "methods", "derives"],
+        ),
+    );
+    defs.insert(
+        "FieldInfo".into(),
+        object(
+            json!({ "name": string, "is_public": { "type": "boolean" } }),
+            &["name", "is_public"],
+        ),
+    );
+    defs.insert(
+        "Method".into(),
+        object(
+            json!({
+                "name": string,
+                "field_access": strings,
+                "internal_calls": strings,
+                "external_calls": strings,
+                "cognitive_complexity": count(),
+                "is_mutable": { "type": "boolean" },
+            }),
+            &[
+                "name",
+                "field_access",
+                "internal_calls",
+                "external_calls",
+                "cognitive_complexity",
+                "is_mutable",
+            ],
+        ),
+    );
+    defs
+}
+
+fn map_of(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
@@ -0,0 +1,85 @@
+// src/secrets/baseline.rs
+//! `.neti/secrets-baseline`: accepted findings, one `<path>:<fingerprint>`
+//! per line.
+
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{scan, Finding, BASELINE_PATH};
+use crate::fsutil::atomic_write;
+
+const BASELINE_HEADER: &str =
+    "# Accepted secret findings (<path>:<fingerprint>). Regenerate with `neti secrets baseline`.";
+
+/// Findings accepted as false positives or known test fixtures.
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    entries: HashSet<String>,
+}
+
+impl Baseline {
+    /// Loads the baseline under `root`; empty when there is none.
+    #[must_use]
+    pub fn load(root: &Path) -> Self {
+        let entries = fs::read_to_string(root.join(BASELINE_PATH))
+            .unwrap_or_default()
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(String::from)
+            .collect();
+        Self { entries }
+    }
+
+    #[must_use]
+    pub fn accepts(&self, path: &Path, finding: &Finding) -> bool {
+        self.entries.contains(&entry(path, finding))
+    }
+
+    #[cfg(test)]
+    pub(super) fn accept(&mut self, path: &Path, findings: &[Finding]) {
+        self.entries.extend(findings.iter().map(|f| entry(path, f)));
+    }
+}
+
+/// Records every current finding in `files` as accepted. Returns the count.
+///
+/// # Errors
+/// Returns error if the baseline cannot be written.
+pub fn write_baseline(root: &Path, files: &[PathBuf]) -> Result<usize> {
+    let mut entries: Vec<String> = files
+        .iter()
+        .flat_map(|path| {
+            let content = fs::read_to_string(path).unwrap_or_default();
+            scan(path, &content)
+                .into_iter()
+                .map(|f| entry(path, &f))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    entries.sort();
+    entries.dedup();
+
+    let path = root.join(BASELINE_PATH);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let body = std::iter::once(BASELINE_HEADER.to_string())
+        .chain(entries.iter().cloned())
+        .collect::<Vec<_>>()
+        .join("\n");
+    atomic_write(&path, body + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(entries.len())
+}
+
+fn entry(path: &Path, finding: &Finding) -> String {
+    let path = path.strip_prefix(".").unwrap_or(path);
+    format!(
+        "{}:{}",
+        crate::normalize::path_str(path),
+        finding.fingerprint
+    )
+}
@@ -0,0 +1,171 @@
+// src/secrets/generic.rs
+//! Secrets without a provider format: values assigned to credential-like
+//! names, and long random-looking literals, both scored by Shannon entropy.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Minimum entropy (bits per character) for a value bound to a
+/// credential-like name.
+const NAMED_MIN_ENTROPY: f64 = 3.0;
+const NAMED_MIN_LEN: usize = 8;
+
+/// Minimum entropy for a literal with no name to go on. Hex digests top
+/// out at 4.0, so they stay below it.
+const UNNAMED_MIN_ENTROPY: f64 = 4.3;
+const UNNAMED_MIN_LEN: usize = 32;
+
+const PLACEHOLDERS: [&str; 10] = [
+    "placeholder",
+    "example",
+    "dummy",
+    "changeme",
+    "change_me",
+    "your_",
+    "your-",
+    "xxxx",
+    "redacted",
+    "sample",
+];
+
+/// `name = value`, `name: value`, `"name": value` and `const NAME: &str =
+/// value`, where the name mentions a credential.
+static ASSIGNMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?im)([a-z0-9_.-]*(?:key|secret|token|passw(?:or)?d|pwd|auth|credential)[a-z0-9_.-]*)["']?\s*(?::\s*&?'?[a-z_]+\s*)?[:=]\s*("[^"\n]*"|'[^'\n]*'|[^\s#;,]+)"#,
+    )
+    .unwrap_or_else(|_| panic!("Invalid Regex"))
+});
+
+static TOKEN_LIKE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"["']([A-Za-z0-9+/=_-]{32,})["']"#).unwrap_or_else(|_| panic!("Invalid Regex"))
+});
+
+/// A suspected secret: byte offset of the value and the value itself.
+pub(super) struct Candidate<'a> {
+    pub offset: usize,
+    pub value: &'a str,
+    pub named: bool,
+}
+
+/// Values bound to credential-like names. Unquoted values only count in
+/// config files, where they are literals rather than expressions.
+pub(super) fn named(content: &str, config_file: bool) -> Vec<Candidate<'_>> {
+    ASSIGNMENT
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(2))
+        .filter_map(|m| {
+            let (value, quoted) = unquote(m.as_str());
+            let offset = m.start() + usize::from(quoted);
+            (quoted || config_file).then_some(Candidate {
+                offset,
+                value,
+                named: true,
+            })
+        })
+        .filter(|c| looks_secret(c.value, NAMED_MIN_LEN, NAMED_MIN_ENTROPY))
+        .collect()
+}
+
+/// Long quoted literals that look random even without a telling name.
+pub(super) fn unnamed(content: &str) -> Vec<Candidate<'_>> {
+    TOKEN_LIKE
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| Candidate {
+            offset: m.start(),
+            value: m.as_str(),
+            named: false,
+        })
+        .filter(|c| looks_secret(c.value, UNNAMED_MIN_LEN, UNNAMED_MIN_ENTROPY))
+        .filter(|c| c.value.chars().any(|ch| ch.is_ascii_digit()))
+        .collect()
+}
+
+fn unquote(raw: &str) -> (&str, bool) {
+    let quoted = raw.len() >= 2
+        && ((raw.starts_with('"') && raw.ends_with('"'))
+            || (raw.starts_with('\'') && raw.ends_with('\'')));
+    match raw.get(1..raw.len().saturating_sub(1)) {
+        Some(inner) if quoted => (inner, true),
+        _ => (raw, false),
+    }
+}
+
+fn looks_secret(value: &str, min_len: usize, min_entropy: f64) -> bool {
+    value.len() >= min_len
+        && !is_placeholder(value)
+        && char_classes(value) >= 2
+        && shannon_entropy(value) >= min_entropy
+}
+
+/// Template syntax, references and obvious stand-ins are not secrets.
+#[must_use]
+pub fn is_placeholder(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    value.starts_with(['$', '{', '<', '%', '['])
+        || value.contains("://localhost")
+        || PLACEHOLDERS.iter().any(|p| lower.contains(p))
+        || lower.starts_with("test")
+}
+
+fn char_classes(value: &str) -> usize {
+    let checks: [fn(char) -> bool; 4] = [
+        |c| c.is_ascii_lowercase(),
+        |c| c.is_ascii_uppercase(),
+        |c| c.is_ascii_digit(),
+        |c| !c.is_ascii_alphanumeric(),
+    ];
+    checks
+        .iter()
+        .filter(|check| value.chars().any(*check))
+        .count()
+}
+
+/// Shannon entropy of `value` in bits per character.
+#[must_use]
+pub fn shannon_entropy(value: &str) -> f64 {
+    let mut counts = std::collections::HashMap::new();
+    for c in value.chars() {
+        *counts.entry(c).or_insert(0_usize) += 1;
+    }
+    let len = value.chars().count() as f64;
+    counts
+        .values()
+        .map(|&n| {
+            let p = n as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy_separates_words_from_keys() {
+        assert!(shannon_entropy("aaaaaaaa") < 0.1);
+        assert!(shannon_entropy("password") < NAMED_MIN_ENTROPY);
+        assert!(shannon_entropy("q8Zt2LmX9vRk4Wp1") > NAMED_MIN_ENTROPY);
+        assert!(shannon_entropy(&"0123456789abcdef".repeat(4)) <= 4.0);
+    }
+
+    #[test]
+    fn test_named_values() {
+        let rust = "let api_key = \"q8Zt2LmX9vRk4Wp1\";\nlet token_count = 12;";
+        let found: Vec<&str> = named(rust, false).iter().map(|c| c.value).collect();
+        assert_eq!(found, vec!["q8Zt2LmX9vRk4Wp1"]);
+
+        let env = "DB_PASSWORD=q8Zt2LmX9vRk4Wp1\nAPI_TOKEN=${FROM_VAULT}\nmax_file_tokens = 2000";
+        let found: Vec<&str> = named(env, true).iter().map(|c| c.value).collect();
+        assert_eq!(found, vec!["q8Zt2LmX9vRk4Wp1"]);
+        assert!(named(env, false).is_empty());
+    }
+
+    #[test]
+    fn test_placeholders_are_skipped() {
+        assert!(named("password = \"your_password_here1\"", false).is_empty());
+        assert!(named("secret: \"<insert-secret-1>\"", true).is_empty());
+    }
+}
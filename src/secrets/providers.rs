@@ -0,0 +1,44 @@
+// src/secrets/providers.rs
+//! Credentials with a recognizable shape. A match is strong evidence on its
+//! own, whatever the surrounding code calls it.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A provider credential format.
+pub(super) struct Provider {
+    pub name: &'static str,
+    /// The secret is capture group 1 when present, else the whole match.
+    pub pattern: Regex,
+}
+
+fn provider(name: &'static str, pattern: &str) -> Provider {
+    Provider {
+        name,
+        pattern: Regex::new(pattern).unwrap_or_else(|_| panic!("Invalid Regex")),
+    }
+}
+
+pub(super) static PROVIDERS: LazyLock<Vec<Provider>> = LazyLock::new(|| {
+    vec![
+        provider("AWS access key ID", r"\b((?:AKIA|ASIA)[0-9A-Z]{16})\b"),
+        provider(
+            "AWS secret access key",
+            r#"(?i)aws_?secret_?access_?key["']?\s*[:=]\s*["']?([A-Za-z0-9/+]{40})\b"#,
+        ),
+        provider(
+            "GitHub token",
+            r"\b(gh[pousr]_[A-Za-z0-9]{36,255}|github_pat_[A-Za-z0-9_]{22,255})\b",
+        ),
+        provider("Slack token", r"\b(xox[abprs]-[A-Za-z0-9-]{10,})\b"),
+        provider(
+            "Slack webhook URL",
+            r"(https://hooks\.slack\.com/services/T[A-Za-z0-9_]+/B[A-Za-z0-9_]+/[A-Za-z0-9_]+)",
+        ),
+        provider(
+            "JSON Web Token",
+            r"\b(eyJ[A-Za-z0-9_-]{10,}\.eyJ[A-Za-z0-9_-]{10,}\.[A-Za-z0-9_-]{10,})",
+        ),
+        provider("private key", r"(-----BEGIN (?:[A-Z]+ )?PRIVATE KEY-----)"),
+    ]
+});
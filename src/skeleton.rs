@@ -1,5 +1,6 @@
 // src/skeleton.rs
 use crate::lang::Lang;
+use crate::query_cache;
 use std::path::Path;
 use tree_sitter::{Language, Parser, Query, QueryCursor};
 
@@ -24,9 +25,9 @@ pub fn clean(path: &Path, content: &str) -> String {
     let query_str = lang.q_skeleton();
     let replacement = lang.skeleton_replacement();
     let grammar = lang.grammar();
-    let query = compile_query(&grammar, query_str);
+    let query = compile_query(lang, query_str);
 
-    apply_skeleton(content, &grammar, &query, replacement)
+    apply_skeleton(content, &grammar, query, replacement)
 }
 
 fn apply_skeleton(source: &str, lang: &Language, query: &Query, replacement: &str) -> String {
@@ -104,10 +105,10 @@ fn replace_ranges(source: &str, ranges: &[std::ops::Range<usize>], replacement:
     result
 }
 
-fn compile_query(lang: &Language, pattern: &str) -> Query {
-    match Query::new(lang, pattern) {
-        Ok(q) => q,
-        Err(e) => panic!("Invalid skeleton query: {e}"),
+fn compile_query(lang: Lang, pattern: &'static str) -> &'static Query {
+    match query_cache::get(lang, pattern) {
+        Some(q) => q,
+        None => panic!("Invalid skeleton query for {lang:?}"),
     }
 }
 
@@ -69,6 +69,7 @@ pub fn run_hud_loop(running: &Arc<AtomicBool>, hud: &SafeHud) {
         frame_idx += 1;
     }
 
+    // Terminal cleanup is best effort; the summary below prints either way.
     let _ = execute!(stdout, cursor::Show);
     let _ = clear_lines(total_lines);
     let (success, title, start) = hud.completion_info();
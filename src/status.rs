@@ -0,0 +1,134 @@
+// src/status.rs
+//! Headless project health snapshot (`neti status`).
+//!
+//! Aggregates the scan summary, work branch state, and recent events into one
+//! serializable report for shells, prompts, and CI bots.
+
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::branch;
+use crate::config::Config;
+use crate::events::{EventLogger, NetiEvent};
+use crate::types::ScanReport;
+
+/// Number of trailing events included in the snapshot.
+const RECENT_EVENTS: usize = 5;
+
+/// Everything `neti status` reports.
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    pub scan: ScanSummary,
+    pub branch: BranchStatus,
+    pub recent_events: Vec<NetiEvent>,
+}
+
+/// Totals from a full scan.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct ScanSummary {
+    pub files: usize,
+    pub total_tokens: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub duration_ms: u128,
+}
+
+/// Git work branch state.
+#[derive(Debug, Serialize)]
+pub struct BranchStatus {
+    /// Current branch, or `None` outside a git repository.
+    pub current: Option<String>,
+    /// The work branch `neti branch` would use for the pending goal.
+    pub work_branch: String,
+    pub on_work_branch: bool,
+    pub uncommitted_files: usize,
+    pub pending_goal: Option<String>,
+}
+
+impl ScanSummary {
+    /// Summarizes a scan with the same error and warning counts the scan
+    /// report prints.
+    #[must_use]
+    pub fn from_report(report: &ScanReport) -> Self {
+        Self {
+            files: report.files.len(),
+            total_tokens: report.total_tokens,
+            errors: report.error_count(),
+            warnings: report.warning_count(),
+            duration_ms: report.duration_ms,
+        }
+    }
+}
+
+/// Collects branch state and recent events for `root`; the scan is supplied by the caller.
+#[must_use]
+pub fn collect(root: &Path, config: &Config, scan: &ScanReport) -> StatusReport {
+    let pending_goal = pending_goal(root);
+    let work_branch = branch::resolve_work_branch(&config.git, pending_goal.as_deref());
+    let current = branch::current_branch().ok().filter(|b| !b.is_empty());
+
+    StatusReport {
+        scan: ScanSummary::from_report(scan),
+        branch: BranchStatus {
+            on_work_branch: current.as_deref() == Some(work_branch.as_str()),
+            current,
+            work_branch,
+            uncommitted_files: branch::count_modified_files(),
+            pending_goal,
+        },
+        recent_events: EventLogger::new(root).recent(RECENT_EVENTS),
+    }
+}
+
+/// The goal recorded in `.neti/pending_goal`, if any.
+#[must_use]
+pub fn pending_goal(root: &Path) -> Option<String> {
+    fs::read_to_string(root.join(".neti").join("pending_goal"))
+        .ok()
+        .map(|g| g.trim().to_string())
+        .filter(|g| !g.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Confidence, FileReport, Violation};
+    use std::path::PathBuf;
+
+    #[test]
+    fn summary_splits_errors_and_warnings() {
+        let mut warn = Violation::simple(2, "w".into(), "P01");
+        warn.confidence = Confidence::Medium;
+        let mut hint = Violation::simple(3, "i".into(), "P02");
+        hint.confidence = Confidence::Info;
+        let report = ScanReport {
+            files: vec![FileReport {
+                path: PathBuf::from("src/a.rs"),
+                token_count: 120,
+                complexity_score: 0,
+                violations: vec![
+                    Violation::simple(1, "e".into(), "LAW OF ATOMICITY"),
+                    warn,
+                    hint,
+                ],
+                analysis: None,
+                suppressions: Default::default(),
+            }],
+            total_tokens: 120,
+            total_violations: 3,
+            duration_ms: 7,
+        };
+
+        assert_eq!(
+            ScanSummary::from_report(&report),
+            ScanSummary {
+                files: 1,
+                total_tokens: 120,
+                errors: 1,
+                warnings: 1,
+                duration_ms: 7,
+            }
+        );
+    }
+}
@@ -0,0 +1,67 @@
+// src/symbols/fuzzy.rs
+//! Fuzzy matching for symbol names.
+
+/// Scores how well `query` matches `name`; `None` means no match.
+///
+/// Tiers, best first: exact, prefix, substring, then in-order subsequence.
+/// Within a tier, shorter names rank higher. Matching is case-insensitive.
+#[must_use]
+pub fn score(query: &str, name: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let lower = name.to_lowercase();
+    let length_penalty = u32::try_from(lower.len()).unwrap_or(u32::MAX).min(999);
+
+    let tier = if lower == query {
+        4000
+    } else if lower.starts_with(&query) {
+        3000
+    } else if lower.contains(&query) {
+        2000
+    } else {
+        1000 - subsequence_gaps(&query, &lower)?.min(999)
+    };
+
+    Some(tier * 1000 + (999 - length_penalty))
+}
+
+/// Returns the number of skipped characters if `query` is a subsequence of `name`.
+fn subsequence_gaps(query: &str, name: &str) -> Option<u32> {
+    let mut chars = name.chars();
+    let mut gaps = 0u32;
+
+    for wanted in query.chars() {
+        loop {
+            let c = chars.next()?;
+            if c == wanted {
+                break;
+            }
+            gaps = gaps.saturating_add(1);
+        }
+    }
+
+    Some(gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiers_rank_exact_prefix_substring_subsequence() {
+        let exact = score("scan", "scan");
+        let prefix = score("scan", "scan_file");
+        let substring = score("scan", "run_scan");
+        let subsequence = score("scf", "scan_file");
+
+        assert!(exact > prefix);
+        assert!(prefix > substring);
+        assert!(substring > subsequence);
+        assert!(subsequence.is_some());
+    }
+
+    #[test]
+    fn non_matches_and_case() {
+        assert_eq!(score("xyz", "scan_file"), None);
+        assert_eq!(score("Engine", "engine"), score("engine", "engine"));
+    }
+}
@@ -0,0 +1,180 @@
+// src/symbols/mod.rs
+//! Repo-wide symbol index (`neti symbols [query]`).
+//!
+//! Every definition (name, kind, file, line, signature) is persisted to
+//! `.neti/symbols.json`. Files whose content hash changed since the last run
+//! are re-parsed; everything else is reused from the index. Hashing content
+//! rather than comparing mtimes catches edits made within the same second.
+
+mod fuzzy;
+
+pub use fuzzy::score;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fsutil::atomic_write;
+use crate::graph::defs;
+use crate::lang::Lang;
+use crate::utils::compute_sha256;
+
+/// Index location, relative to the project root.
+pub const INDEX_PATH: &str = ".neti/symbols.json";
+
+/// A single definition in the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub signature: String,
+}
+
+/// Definitions grouped by file, with the hash of the content they came from.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SymbolIndex {
+    files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexedFile {
+    hash: String,
+    symbols: Vec<Symbol>,
+}
+
+impl SymbolIndex {
+    /// Loads the persisted index, refreshes it against `files`, and saves it back.
+    ///
+    /// # Errors
+    /// Returns error if the refreshed index cannot be written.
+    pub fn load_and_refresh(root: &Path, files: &[PathBuf]) -> Result<Self> {
+        let path = root.join(INDEX_PATH);
+        let mut index: Self = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        index.refresh(files);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        atomic_write(&path, serde_json::to_string(&index)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(index)
+    }
+
+    /// Re-indexes new or modified files and drops files that no longer exist.
+    pub fn refresh(&mut self, files: &[PathBuf]) {
+        let mut next = BTreeMap::new();
+
+        for path in files.iter().filter(|p| has_grammar(p)) {
+            let key = path.strip_prefix(".").unwrap_or(path).to_path_buf();
+            let content = crate::normalize::read_text(path).unwrap_or_default();
+            let hash = compute_sha256(&content);
+            let entry = match self.files.remove(&key) {
+                Some(cached) if cached.hash == hash => cached,
+                _ => IndexedFile {
+                    symbols: index_source(path, &key, &content),
+                    hash,
+                },
+            };
+            next.insert(key, entry);
+        }
+
+        self.files = next;
+    }
+
+    /// Returns all symbols, ordered by file then line.
+    pub fn symbols(&self) -> impl Iterator<Item = &Symbol> {
+        self.files.values().flat_map(|f| f.symbols.iter())
+    }
+
+    /// Returns symbols matching `query`, best first, at most `limit` of them.
+    #[must_use]
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&Symbol> {
+        let mut scored: Vec<(u32, &Symbol)> = self
+            .symbols()
+            .filter_map(|s| Some((score(query, &s.name)?, s)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().take(limit).map(|(_, s)| s).collect()
+    }
+}
+
+fn index_source(path: &Path, key: &Path, content: &str) -> Vec<Symbol> {
+    defs::extract(path, content)
+        .into_iter()
+        .map(|def| Symbol {
+            kind: format!("{:?}", def.kind).to_lowercase(),
+            file: key.to_path_buf(),
+            line: def.line,
+            signature: def.signature,
+            name: def.name,
+        })
+        .collect()
+}
+
+fn has_grammar(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .and_then(Lang::from_ext)
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str, line: usize) -> Symbol {
+        Symbol {
+            name: name.into(),
+            kind: "function".into(),
+            file: PathBuf::from("src/lib.rs"),
+            line,
+            signature: format!("fn {name}() {{}}"),
+        }
+    }
+
+    #[test]
+    fn search_ranks_and_limits() {
+        let mut index = SymbolIndex::default();
+        index.files.insert(
+            PathBuf::from("src/lib.rs"),
+            IndexedFile {
+                hash: String::new(),
+                symbols: vec![
+                    symbol("rescan", 1),
+                    symbol("scan_file", 2),
+                    symbol("parse", 3),
+                ],
+            },
+        );
+
+        let names: Vec<&str> = index
+            .search("scan", 10)
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["scan_file", "rescan"]);
+        assert_eq!(index.search("scan", 1).len(), 1);
+    }
+
+    #[test]
+    fn refresh_drops_missing_files() {
+        let mut index = SymbolIndex::default();
+        index.files.insert(
+            PathBuf::from("src/gone.rs"),
+            IndexedFile {
+                hash: String::new(),
+                symbols: vec![symbol("old", 1)],
+            },
+        );
+        index.refresh(&[]);
+        assert_eq!(index.symbols().count(), 0);
+    }
+}
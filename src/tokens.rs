@@ -1,26 +1,80 @@
 // src/tokens.rs
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead};
 use std::sync::LazyLock;
 use tiktoken_rs::CoreBPE;
 
-/// The tokenizer encoding (`cl100k_base`, used by GPT-4/3.5-turbo).
+/// The `cl100k_base` encoding (GPT-4/3.5-turbo).
 /// Initialization is deferred until first use. If the encoding fails to load
 /// (which should never happen with a valid tiktoken-rs installation),
 /// token counting will return 0 and log an error.
-static BPE: LazyLock<Option<CoreBPE>> = LazyLock::new(|| {
+static CL100K: LazyLock<Option<CoreBPE>> = LazyLock::new(|| {
     tiktoken_rs::cl100k_base()
-        .map_err(|e| eprintln!("Failed to load cl100k_base tokenizer: {e}"))
+        .map_err(|e| log::error!("Failed to load cl100k_base tokenizer: {e}"))
         .ok()
 });
 
+/// The `o200k_base` encoding (GPT-4o and later). Loaded on first use.
+static O200K: LazyLock<Option<CoreBPE>> = LazyLock::new(|| {
+    tiktoken_rs::o200k_base()
+        .map_err(|e| log::error!("Failed to load o200k_base tokenizer: {e}"))
+        .ok()
+});
+
+/// Text handed to the tokenizer at once when streaming. Chunks end on a
+/// line break, so BPE merges only differ from a whole-file count at the
+/// seams.
+pub const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// The Claude approximation assumes ~3.5 characters per token (7 per 2).
+const CLAUDE_CHARS_PER_TWO_TOKENS: usize = 7;
+
+/// Which tokenizer budgets are measured in, from `[preferences] tokenizer`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenizerKind {
+    /// tiktoken `cl100k_base`.
+    #[default]
+    Cl100k,
+    /// tiktoken `o200k_base`.
+    O200k,
+    /// Character-based approximation of Claude's tokenizer.
+    Claude,
+    /// One token per whitespace-separated word.
+    Whitespace,
+}
+
 pub struct Tokenizer;
 
 impl Tokenizer {
-    /// Counts the number of tokens in the given text.
+    /// Counts the number of tokens in the given text using `cl100k_base`.
     /// Returns 0 if the tokenizer failed to initialize.
     #[must_use]
     pub fn count(text: &str) -> usize {
-        BPE.as_ref()
-            .map_or(0, |bpe| bpe.encode_ordinary(text).len())
+        Self::count_as(TokenizerKind::Cl100k, text)
+    }
+
+    /// Counts the number of tokens in the given text with the chosen tokenizer.
+    /// Returns 0 if a BPE tokenizer failed to initialize.
+    #[must_use]
+    pub fn count_as(kind: TokenizerKind, text: &str) -> usize {
+        match kind {
+            TokenizerKind::Cl100k => bpe_count(&CL100K, text),
+            TokenizerKind::O200k => bpe_count(&O200K, text),
+            TokenizerKind::Claude => claude_estimate(text),
+            TokenizerKind::Whitespace => text.split_whitespace().count(),
+        }
+    }
+
+    /// Counts tokens read from `reader` in line-aligned chunks, without
+    /// holding the whole text in memory.
+    ///
+    /// # Errors
+    /// Returns an error if reading fails.
+    pub fn count_reader(kind: TokenizerKind, reader: impl BufRead) -> io::Result<usize> {
+        let mut total = 0;
+        for_each_chunk(reader, |chunk| total += Self::count_as(kind, chunk))?;
+        Ok(total)
     }
 
     /// Returns true if the text exceeds the token limit.
@@ -32,6 +86,81 @@ impl Tokenizer {
     /// Returns true if the tokenizer is available.
     #[must_use]
     pub fn is_available() -> bool {
-        BPE.is_some()
+        CL100K.is_some()
+    }
+}
+
+fn bpe_count(bpe: &LazyLock<Option<CoreBPE>>, text: &str) -> usize {
+    bpe.as_ref()
+        .map_or(0, |bpe| bpe.encode_ordinary(text).len())
+}
+
+/// Feeds `reader` to `visit` as UTF-8 chunks of whole lines, each about
+/// [`STREAM_CHUNK_BYTES`] long. Invalid UTF-8 is replaced, not rejected.
+///
+/// # Errors
+/// Returns an error if reading fails.
+pub fn for_each_chunk(mut reader: impl BufRead, mut visit: impl FnMut(&str)) -> io::Result<()> {
+    let mut buf = Vec::with_capacity(STREAM_CHUNK_BYTES);
+    loop {
+        let read = reader.read_until(b'\n', &mut buf)?;
+        if read == 0 || buf.len() >= STREAM_CHUNK_BYTES {
+            if !buf.is_empty() {
+                visit(&String::from_utf8_lossy(&buf));
+                buf.clear();
+            }
+            if read == 0 {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn claude_estimate(text: &str) -> usize {
+    (text.chars().count() * 2).div_ceil(CLAUDE_CHARS_PER_TWO_TOKENS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approximations_are_deterministic() {
+        assert_eq!(
+            Tokenizer::count_as(TokenizerKind::Whitespace, "a b\tc\n"),
+            3
+        );
+        assert_eq!(Tokenizer::count_as(TokenizerKind::Claude, "abcdefg"), 2);
+        assert_eq!(Tokenizer::count_as(TokenizerKind::Claude, ""), 0);
+    }
+
+    #[test]
+    fn streamed_counts_match_whole_text() {
+        let line = "let value = compute(alpha, beta);\n";
+        let text = line.repeat(STREAM_CHUNK_BYTES / line.len() * 3);
+        let kind = TokenizerKind::Whitespace;
+        let streamed = Tokenizer::count_reader(kind, text.as_bytes()).ok();
+        assert_eq!(streamed, Some(Tokenizer::count_as(kind, &text)));
+    }
+
+    #[test]
+    fn chunks_end_on_line_breaks() {
+        let text = format!("{}\n", "x".repeat(100)).repeat(STREAM_CHUNK_BYTES / 50);
+        let mut chunks = Vec::new();
+        assert!(for_each_chunk(text.as_bytes(), |c| chunks.push(c.to_string())).is_ok());
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.ends_with('\n')));
+        assert_eq!(chunks.concat(), text);
+    }
+
+    #[test]
+    fn bpe_encodings_count_tokens() {
+        let text = "fn main() { println!(\"hello\"); }";
+        assert!(Tokenizer::count_as(TokenizerKind::Cl100k, text) > 0);
+        assert!(Tokenizer::count_as(TokenizerKind::O200k, text) > 0);
+        assert_eq!(
+            Tokenizer::count(text),
+            Tokenizer::count_as(TokenizerKind::Cl100k, text)
+        );
     }
 }
@@ -0,0 +1,110 @@
+//! Rule categories, for `scan --only` / `--skip` and the summary subtotals.
+
+use serde::Serialize;
+
+/// The broad concern a rule belongs to. Reports list categories in this
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    /// Injection, secrets, unsafe code and panic paths.
+    Security,
+    /// Allocation and search costs in loops, N+1 queries.
+    Performance,
+    /// Locks, async blocking and shared mutable state.
+    Concurrency,
+    /// Size, complexity, coupling, API compatibility, tests and debt.
+    Structure,
+    /// Naming, idioms and doc contracts.
+    Style,
+}
+
+impl Category {
+    /// The category of the rule with code or law name `law`. Rules not
+    /// listed here count as structure.
+    #[must_use]
+    pub fn of(law: &str) -> Self {
+        match law {
+            "X01" | "X02" | "X03" | "LAW OF PARANOIA" | "L02" | "L03" => Self::Security,
+            "P01" | "P02" | "P03" | "P04" | "P06" => Self::Performance,
+            "C03" | "C04" | "A01" | "A02" | "S01" | "S02" | "S03" => Self::Concurrency,
+            "I01" | "I02" | "I03" | "N01" | "M03" | "M04" | "M05" | "D02" => Self::Style,
+            _ => Self::Structure,
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Security => "security",
+            Self::Performance => "performance",
+            Self::Concurrency => "concurrency",
+            Self::Structure => "structure",
+            Self::Style => "style",
+        }
+    }
+}
+
+/// Which categories a scan reports. Empty `only` means all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::Args))]
+pub struct CategoryFilter {
+    /// Report only these rule categories, e.g. `security,performance`
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_enum, value_delimiter = ',', value_name = "CATEGORY")
+    )]
+    pub only: Vec<Category>,
+    /// Leave out these rule categories
+    #[cfg_attr(
+        feature = "cli",
+        arg(long, value_enum, value_delimiter = ',', value_name = "CATEGORY")
+    )]
+    pub skip: Vec<Category>,
+}
+
+impl CategoryFilter {
+    /// True if findings in `category` are reported.
+    #[must_use]
+    pub fn includes(&self, category: Category) -> bool {
+        (self.only.is_empty() || self.only.contains(&category)) && !self.skip.contains(&category)
+    }
+
+    /// True if findings of the rule `law` are reported.
+    #[must_use]
+    pub fn allows(&self, law: &str) -> bool {
+        self.includes(Category::of(law))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_map_to_categories() {
+        assert_eq!(Category::of("X03"), Category::Security);
+        assert_eq!(Category::of("LAW OF PARANOIA"), Category::Security);
+        assert_eq!(Category::of("P04"), Category::Performance);
+        assert_eq!(Category::of("A01"), Category::Concurrency);
+        for law in ["S01", "S02", "S03"] {
+            assert_eq!(Category::of(law), Category::Concurrency, "{law}");
+        }
+        assert_eq!(Category::of("N01"), Category::Style);
+        assert_eq!(Category::of("LAW OF ATOMICITY"), Category::Structure);
+        assert_eq!(Category::of("Z99"), Category::Structure);
+    }
+
+    #[test]
+    fn skip_wins_over_only() {
+        let filter = CategoryFilter {
+            only: vec![Category::Security, Category::Style],
+            skip: vec![Category::Style],
+        };
+        assert!(filter.allows("X01"));
+        assert!(!filter.allows("N01"));
+        assert!(!filter.allows("P01"));
+        assert!(CategoryFilter::default().allows("P01"));
+    }
+}
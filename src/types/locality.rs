@@ -16,6 +16,19 @@ pub struct LocalityViolation {
     pub target_role: String,
 }
 
+/// A grandfathered edge whose exemption has expired.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpiredExemption {
+    /// Source file of the exempted dependency.
+    pub from: PathBuf,
+    /// Target file of the exempted dependency.
+    pub to: PathBuf,
+    /// Who agreed to clean the edge up.
+    pub owner: String,
+    /// Expiry date as written in the exemptions file.
+    pub expires: String,
+}
+
 /// Result of locality (Law of Locality) validation.
 #[derive(Debug, Clone, Serialize)]
 pub struct LocalityReport {
@@ -23,6 +36,8 @@ pub struct LocalityReport {
     pub violation_count: usize,
     /// Individual violation details.
     pub violations: Vec<LocalityViolation>,
+    /// Exemptions past their expiry date (counted in `violation_count`).
+    pub expired_exemptions: Vec<ExpiredExemption>,
     /// Number of dependency cycles detected.
     pub cycle_count: usize,
     /// Cycle paths (each cycle is a list of files).
@@ -2,11 +2,14 @@ use serde::Serialize;
 use std::path::PathBuf;
 
 use crate::analysis::aggregator::FileAnalysis;
+use crate::analysis::suppress::Suppressions;
 
+mod category;
 mod command;
 mod locality;
+pub use category::{Category, CategoryFilter};
 pub use command::CommandResult;
-pub use locality::{LocalityReport, LocalityViolation};
+pub use locality::{ExpiredExemption, LocalityReport, LocalityViolation};
 
 /// Confidence level for a violation — how certain Neti is that this is a real problem.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
@@ -106,6 +109,9 @@ pub struct FileReport {
     pub violations: Vec<Violation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis: Option<FileAnalysis>,
+    /// `neti:off` regions; serialized as `suppressed_lines` when present.
+    #[serde(flatten, skip_serializing_if = "Suppressions::is_empty")]
+    pub suppressions: Suppressions,
 }
 
 impl FileReport {
@@ -129,7 +135,39 @@ pub struct ScanReport {
     pub duration_ms: u128,
 }
 
+/// Orders violations by line, then rule, then message.
+pub fn sort_violations(violations: &mut [Violation]) {
+    violations.sort_by(|a, b| (a.row, a.law, &a.message).cmp(&(b.row, b.law, &b.message)));
+}
+
 impl ScanReport {
+    /// Puts files in path order and each file's violations in line, then
+    /// rule order, so the report does not depend on scheduling.
+    pub fn sort(&mut self) {
+        self.files.sort_by(|a, b| a.path.cmp(&b.path));
+        for file in &mut self.files {
+            sort_violations(&mut file.violations);
+        }
+    }
+
+    /// Drops violations outside `filter` and recounts the total.
+    pub fn retain_categories(&mut self, filter: &CategoryFilter) {
+        for file in &mut self.files {
+            file.violations.retain(|v| filter.allows(v.law));
+        }
+        self.total_violations = self.files.iter().map(|f| f.violations.len()).sum();
+    }
+
+    /// Violation count per category, for categories with any.
+    #[must_use]
+    pub fn category_counts(&self) -> Vec<(Category, usize)> {
+        let mut counts = std::collections::BTreeMap::new();
+        for v in self.files.iter().flat_map(|f| &f.violations) {
+            *counts.entry(Category::of(v.law)).or_insert(0) += 1;
+        }
+        counts.into_iter().collect()
+    }
+
     #[must_use]
     pub fn has_errors(&self) -> bool {
         self.total_violations > 0
@@ -177,6 +215,7 @@ impl ScanReport {
     }
 
     #[must_use]
+    #[cfg(feature = "fs")]
     pub fn is_small_codebase(&self) -> bool {
         crate::analysis::Engine::small_codebase_threshold() >= self.files.len()
     }
@@ -190,5 +229,8 @@ pub struct CheckReport {
     /// Locality analysis results, if enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locality: Option<LocalityReport>,
+    /// Per-rule comparison with `.neti/ratchet.json`, under `--ratchet`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratchet: Option<crate::ratchet::RatchetReport>,
     pub passed: bool,
 }
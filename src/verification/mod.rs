@@ -8,6 +8,7 @@ mod runner;
 use std::path::Path;
 
 use crate::config::Config;
+use crate::mutate;
 
 // Re-export the canonical CommandResult from types
 pub use crate::types::CommandResult;
@@ -34,6 +35,13 @@ impl VerificationReport {
         }
     }
 
+    /// Appends a result from a non-shell stage, failing the report if it failed.
+    pub fn push(&mut self, result: CommandResult) {
+        self.passed &= result.passed();
+        self.duration_ms += result.duration_ms();
+        self.commands.push(result);
+    }
+
     /// Total number of commands run.
     #[must_use]
     pub fn total_commands(&self) -> usize {
@@ -65,15 +73,26 @@ impl VerificationReport {
     }
 }
 
-/// Runs the verification pipeline using commands from config.
+/// Runs the verification pipeline using commands from config, followed by
+/// the `[commands] mutate` sample when configured.
 ///
 /// The `on_command` callback is invoked before each command executes.
-pub fn run<F>(repo_root: &Path, on_command: F) -> VerificationReport
+pub fn run<F>(repo_root: &Path, mut on_command: F) -> VerificationReport
 where
     F: FnMut(&str, usize, usize),
 {
+    let _span = crate::logging::span("check");
     let config = Config::load();
     let commands = config.commands.get("check").cloned().unwrap_or_default();
+    let Some(gate) = config.mutate_gate else {
+        return runner::run_commands(repo_root, &commands, on_command);
+    };
 
-    runner::run_commands(repo_root, &commands, on_command)
+    let total = commands.len() + 1;
+    let mut report = runner::run_commands(repo_root, &commands, |cmd, current, _| {
+        on_command(cmd, current, total);
+    });
+    on_command("neti mutate (sampled)", total, total);
+    report.push(mutate::gate::check(repo_root, &gate));
+    report
 }
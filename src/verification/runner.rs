@@ -70,6 +70,7 @@ fn run_single_command(repo_root: &Path, cmd_str: &str) -> CommandResult {
         );
     };
     let args = &parts[1..];
+    log::debug!("running `{cmd_str}` in {}", repo_root.display());
 
     let output = Command::new(program)
         .args(args)
@@ -81,6 +82,7 @@ fn run_single_command(repo_root: &Path, cmd_str: &str) -> CommandResult {
     match output {
         Ok(output) => {
             let exit_code = output.status.code().unwrap_or(-1);
+            log::debug!("`{cmd_str}` exited {exit_code} after {duration_ms} ms");
             let stdout = String::from_utf8_lossy(&output.stdout).to_string();
             let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
@@ -0,0 +1,162 @@
+// src/workspace/manifests.rs
+//! Reading workspace member patterns and package names from manifests.
+
+use std::fs;
+use std::path::Path;
+
+use super::WorkspaceKind;
+
+/// Member patterns and exclusions declared by the workspace root.
+pub(super) struct Declared {
+    pub kind: WorkspaceKind,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+/// Where Python monorepos without a uv workspace usually keep packages.
+const PYTHON_CONVENTIONS: [&str; 5] = ["*", "packages/*", "libs/*", "services/*", "apps/*"];
+
+/// Reads the root manifests in priority order.
+pub(super) fn declared(root: &Path) -> Option<Declared> {
+    cargo(root)
+        .or_else(|| pnpm(root))
+        .or_else(|| npm(root))
+        .or_else(|| uv(root))
+        .or_else(|| python_convention(root))
+}
+
+fn cargo(root: &Path) -> Option<Declared> {
+    let manifest = read_toml(&root.join("Cargo.toml"))?;
+    let workspace = manifest.get("workspace")?;
+    Some(Declared {
+        kind: WorkspaceKind::Cargo,
+        include: toml_strings(workspace.get("members")),
+        exclude: toml_strings(workspace.get("exclude")),
+    })
+}
+
+/// `pnpm-workspace.yaml`: the `packages:` list, `!` entries excluded.
+fn pnpm(root: &Path) -> Option<Declared> {
+    let content = fs::read_to_string(root.join("pnpm-workspace.yaml")).ok()?;
+    let entries = content
+        .lines()
+        .skip_while(|line| !line.starts_with("packages:"))
+        .skip(1)
+        .take_while(|line| line.starts_with(' ') || line.starts_with('-') || line.is_empty())
+        .filter_map(|line| line.trim().strip_prefix('-'))
+        .map(|item| {
+            item.trim()
+                .trim_matches(|c| c == '\'' || c == '"')
+                .to_string()
+        });
+    Some(split_negations(WorkspaceKind::Pnpm, entries))
+}
+
+/// npm/Yarn: `"workspaces": [...]` or `"workspaces": { "packages": [...] }`.
+fn npm(root: &Path) -> Option<Declared> {
+    let content = fs::read_to_string(root.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let workspaces = manifest.get("workspaces")?;
+    let list = workspaces
+        .as_array()
+        .or_else(|| workspaces.get("packages")?.as_array())?;
+    let entries = list.iter().filter_map(|v| v.as_str().map(str::to_string));
+    Some(split_negations(WorkspaceKind::Npm, entries))
+}
+
+/// uv: `[tool.uv.workspace] members = [...]`.
+fn uv(root: &Path) -> Option<Declared> {
+    let manifest = read_toml(&root.join("pyproject.toml"))?;
+    let workspace = manifest.get("tool")?.get("uv")?.get("workspace")?;
+    Some(Declared {
+        kind: WorkspaceKind::Python,
+        include: toml_strings(workspace.get("members")),
+        exclude: toml_strings(workspace.get("exclude")),
+    })
+}
+
+/// Several `pyproject.toml` packages in conventional directories, with no
+/// Cargo or Node project at the root.
+fn python_convention(root: &Path) -> Option<Declared> {
+    if root.join("Cargo.toml").exists() || root.join("package.json").exists() {
+        return None;
+    }
+    let include: Vec<String> = PYTHON_CONVENTIONS
+        .iter()
+        .map(|p| (*p).to_string())
+        .collect();
+    let count = include
+        .iter()
+        .flat_map(|p| super::expand(root, p))
+        .filter(|dir| has_manifest(WorkspaceKind::Python, &root.join(dir)))
+        .count();
+    (count >= 2).then_some(Declared {
+        kind: WorkspaceKind::Python,
+        include,
+        exclude: Vec::new(),
+    })
+}
+
+fn split_negations(kind: WorkspaceKind, entries: impl Iterator<Item = String>) -> Declared {
+    let (exclude, include): (Vec<String>, Vec<String>) =
+        entries.partition(|entry| entry.starts_with('!'));
+    Declared {
+        kind,
+        include,
+        exclude: exclude
+            .into_iter()
+            .map(|e| e.trim_start_matches('!').to_string())
+            .collect(),
+    }
+}
+
+/// Returns true if `dir` holds a package of the workspace's ecosystem.
+pub(super) fn has_manifest(kind: WorkspaceKind, dir: &Path) -> bool {
+    match kind {
+        WorkspaceKind::Cargo => dir.join("Cargo.toml").is_file(),
+        WorkspaceKind::Pnpm | WorkspaceKind::Npm => dir.join("package.json").is_file(),
+        WorkspaceKind::Python => {
+            dir.join("pyproject.toml").is_file() || dir.join("setup.py").is_file()
+        }
+    }
+}
+
+/// Reads the package name a member declares.
+pub(super) fn package_name(kind: WorkspaceKind, dir: &Path) -> Option<String> {
+    match kind {
+        WorkspaceKind::Cargo => toml_name(&dir.join("Cargo.toml"), &["package"]),
+        WorkspaceKind::Pnpm | WorkspaceKind::Npm => {
+            let content = fs::read_to_string(dir.join("package.json")).ok()?;
+            let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+            manifest.get("name")?.as_str().map(str::to_string)
+        }
+        WorkspaceKind::Python => {
+            let path = dir.join("pyproject.toml");
+            toml_name(&path, &["project"]).or_else(|| toml_name(&path, &["tool", "poetry"]))
+        }
+    }
+}
+
+fn toml_name(path: &Path, table: &[&str]) -> Option<String> {
+    let manifest = read_toml(path)?;
+    let section = table
+        .iter()
+        .try_fold(&manifest, |value, key| value.get(*key))?;
+    section.get("name")?.as_str().map(str::to_string)
+}
+
+fn read_toml(path: &Path) -> Option<toml::Value> {
+    fs::read_to_string(path).ok()?.parse().ok()
+}
+
+fn toml_strings(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(toml::Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,217 @@
+// src/workspace/mod.rs
+//! Monorepo awareness: Cargo, pnpm, npm/yarn, and Python workspaces.
+//!
+//! A workspace is a root plus named members. Commands use it to narrow their
+//! output to one member (`--package`) while still analyzing the whole tree,
+//! so edges between members stay visible.
+
+mod manifests;
+
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::project::ProjectType;
+
+/// How many directory levels a `**` member pattern may descend.
+const MAX_GLOB_DEPTH: usize = 4;
+
+/// The tool that declares the workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    Cargo,
+    Pnpm,
+    /// npm or Yarn `workspaces` in package.json.
+    Npm,
+    /// uv workspace, or several `pyproject.toml` packages under one root.
+    Python,
+}
+
+impl WorkspaceKind {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Pnpm => "pnpm",
+            Self::Npm => "npm",
+            Self::Python => "python",
+        }
+    }
+
+    #[must_use]
+    pub fn project_type(self) -> ProjectType {
+        match self {
+            Self::Cargo => ProjectType::Rust,
+            Self::Pnpm | Self::Npm => ProjectType::Node,
+            Self::Python => ProjectType::Python,
+        }
+    }
+}
+
+/// One package in the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    /// Package name from its manifest, or the directory name.
+    pub name: String,
+    /// Directory relative to the workspace root.
+    pub path: PathBuf,
+}
+
+impl Member {
+    /// Returns true if `file` (root-relative) belongs to this member.
+    #[must_use]
+    pub fn contains(&self, file: &Path) -> bool {
+        file.strip_prefix(".")
+            .unwrap_or(file)
+            .starts_with(&self.path)
+    }
+}
+
+/// A detected workspace and its members, sorted by path.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub kind: WorkspaceKind,
+    pub members: Vec<Member>,
+}
+
+impl Workspace {
+    /// Detects a workspace rooted at `root`. Returns `None` for single-package
+    /// projects.
+    #[must_use]
+    pub fn detect_in(root: &Path) -> Option<Self> {
+        let declared = manifests::declared(root)?;
+        let kind = declared.kind;
+        let excluded: Vec<PathBuf> = declared
+            .exclude
+            .iter()
+            .flat_map(|pattern| expand(root, pattern))
+            .collect();
+        let mut members: Vec<Member> = declared
+            .include
+            .iter()
+            .flat_map(|pattern| expand(root, pattern))
+            .filter(|dir| !excluded.contains(dir) && manifests::has_manifest(kind, &root.join(dir)))
+            .map(|dir| member_at(root, kind, dir))
+            .collect();
+        members.sort_by(|a, b| a.path.cmp(&b.path));
+        members.dedup_by(|a, b| a.path == b.path);
+
+        (!members.is_empty()).then_some(Self { kind, members })
+    }
+
+    /// Finds a member by package name or directory.
+    #[must_use]
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        let as_path = Path::new(name.trim_end_matches('/'));
+        self.members
+            .iter()
+            .find(|m| m.name == name)
+            .or_else(|| self.members.iter().find(|m| m.path == as_path))
+    }
+
+    /// Returns the member owning `file`, if any.
+    #[must_use]
+    pub fn member_for(&self, file: &Path) -> Option<&Member> {
+        self.members.iter().find(|m| m.contains(file))
+    }
+}
+
+/// Resolves `--package <name>` against the workspace at `root`.
+///
+/// # Errors
+/// Returns error if `root` is not a workspace or no member matches `name`.
+pub fn select_member(root: &Path, name: &str) -> Result<Member> {
+    let Some(workspace) = Workspace::detect_in(root) else {
+        bail!("--package '{name}': no Cargo, pnpm, npm/yarn, or Python workspace found");
+    };
+    if let Some(member) = workspace.member(name) {
+        return Ok(member.clone());
+    }
+    let available: Vec<&str> = workspace.members.iter().map(|m| m.name.as_str()).collect();
+    bail!(
+        "No {} workspace member named '{name}'. Available: {}",
+        workspace.kind.label(),
+        available.join(", ")
+    )
+}
+
+fn member_at(root: &Path, kind: WorkspaceKind, dir: PathBuf) -> Member {
+    let name = manifests::package_name(kind, &root.join(&dir)).unwrap_or_else(|| {
+        dir.file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().into_owned())
+    });
+    Member { name, path: dir }
+}
+
+/// Expands a member pattern (`crates/*`, `packages/**`, `apps/web`) into
+/// existing root-relative directories.
+fn expand(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let segments: Vec<&str> = pattern
+        .trim_start_matches("./")
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    let mut found = Vec::new();
+    expand_into(root, PathBuf::new(), &segments, 0, &mut found);
+    found
+}
+
+fn expand_into(
+    root: &Path,
+    prefix: PathBuf,
+    segments: &[&str],
+    depth: usize,
+    found: &mut Vec<PathBuf>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        found.push(prefix);
+        return;
+    };
+    if !segment.contains('*') {
+        let next = prefix.join(segment);
+        if root.join(&next).is_dir() {
+            expand_into(root, next, rest, depth, found);
+        }
+        return;
+    }
+    if *segment == "**" {
+        expand_into(root, prefix.clone(), rest, depth, found);
+    }
+    if depth >= MAX_GLOB_DEPTH {
+        return;
+    }
+    for child in child_dirs(&root.join(&prefix)) {
+        let next = prefix.join(&child);
+        if *segment == "**" {
+            expand_into(root, next, segments, depth + 1, found);
+        } else if segment_matches(segment, &child) {
+            expand_into(root, next, rest, depth + 1, found);
+        }
+    }
+}
+
+fn child_dirs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|name| !crate::constants::should_prune(name))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Matches one path segment against a pattern with at most one `*`.
+fn segment_matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            name.len() >= prefix.len() + suffix.len()
+                && name.starts_with(prefix)
+                && name.ends_with(suffix)
+        }
+        None => pattern == name,
+    }
+}
@@ -0,0 +1,148 @@
+// src/xlsx/mod.rs
+//! Minimal XLSX (Office Open XML spreadsheet) writer.
+//!
+//! Writes plain tables: one header row in bold with a filter, then data
+//! rows of text and numbers. Strings are stored inline in each cell, so the
+//! workbook needs no shared-string table. That is all `neti report` needs,
+//! and it opens in Excel, LibreOffice and Google Sheets.
+
+mod parts;
+mod zip;
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt::Write;
+
+use parts::{MAIN_NS, XML_DECL};
+use zip::ZipWriter;
+
+/// One cell value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Text(String),
+    Number(f64),
+}
+
+impl From<&str> for Cell {
+    fn from(s: &str) -> Self {
+        Self::Text(s.into())
+    }
+}
+
+impl From<String> for Cell {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<usize> for Cell {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(n: usize) -> Self {
+        Self::Number(n as f64)
+    }
+}
+
+/// A worksheet: a header row and data rows.
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub name: String,
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+/// The `.xlsx` bytes for `sheets`, in order.
+#[must_use]
+pub fn workbook(sheets: &[Sheet]) -> Vec<u8> {
+    let mut zip = ZipWriter::default();
+    zip.add(
+        "[Content_Types].xml",
+        parts::content_types(sheets.len()).as_bytes(),
+    );
+    zip.add("_rels/.rels", parts::root_rels().as_bytes());
+    zip.add("xl/workbook.xml", parts::workbook_xml(sheets).as_bytes());
+    zip.add(
+        "xl/_rels/workbook.xml.rels",
+        parts::workbook_rels(sheets.len()).as_bytes(),
+    );
+    zip.add("xl/styles.xml", parts::styles().as_bytes());
+    for (i, sheet) in sheets.iter().enumerate() {
+        let name = format!("xl/worksheets/sheet{}.xml", i + 1);
+        zip.add(&name, sheet_xml(sheet).as_bytes());
+    }
+    zip.finish()
+}
+
+fn sheet_xml(sheet: &Sheet) -> String {
+    let mut out = format!("{XML_DECL}<worksheet xmlns=\"{MAIN_NS}\"><sheetData>");
+    let header: Vec<Cell> = sheet.header.iter().map(|&h| Cell::from(h)).collect();
+    write_row(&mut out, 1, &header, true);
+    for (i, row) in sheet.rows.iter().enumerate() {
+        write_row(&mut out, i + 2, row, false);
+    }
+    out.push_str("</sheetData>");
+    if !sheet.header.is_empty() {
+        let _ = write!(
+            out,
+            "<autoFilter ref=\"A1:{}{}\"/>",
+            column(sheet.header.len() - 1),
+            sheet.rows.len() + 1
+        );
+    }
+    out.push_str("</worksheet>");
+    out
+}
+
+fn write_row(out: &mut String, r: usize, cells: &[Cell], bold: bool) {
+    let style = if bold { " s=\"1\"" } else { "" };
+    let _ = write!(out, "<row r=\"{r}\">");
+    for (c, cell) in cells.iter().enumerate() {
+        let at = format!("{}{r}", column(c));
+        let _ = match cell {
+            Cell::Text(s) => write!(
+                out,
+                "<c r=\"{at}\" t=\"inlineStr\"{style}><is><t xml:space=\"preserve\">{}</t></is></c>",
+                escape(s)
+            ),
+            Cell::Number(n) => write!(out, "<c r=\"{at}\"{style}><v>{n}</v></c>"),
+        };
+    }
+    out.push_str("</row>");
+}
+
+/// Spreadsheet column letters for a 0-based index: A..Z, AA..
+fn column(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(char::from(b'A' + u8::try_from(index % 26).unwrap_or(0)));
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Sheet names are at most 31 characters and may not contain `[]:*?/\`.
+pub(super) fn sheet_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .take(31)
+        .collect()
+}
+
+/// XML text escaping. Control characters XML 1.0 cannot carry are dropped.
+pub(super) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c if c >= ' ' || matches!(c, '\t' | '\n' | '\r') => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
@@ -0,0 +1,85 @@
+// src/xlsx/parts.rs
+//! The fixed package parts around the worksheets: content types,
+//! relationships, the workbook and the style sheet.
+
+use std::fmt::Write;
+
+use super::{escape, sheet_name, Sheet};
+
+pub(super) const MAIN_NS: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const REL_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const PKG_REL_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+pub(super) const XML_DECL: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n";
+
+pub(super) fn content_types(sheets: usize) -> String {
+    let mut out = format!(
+        "{XML_DECL}<Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+         <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+         <Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+         <Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>\
+         <Override PartName=\"/xl/styles.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml\"/>"
+    );
+    for i in 1..=sheets {
+        let _ = write!(
+            out,
+            "<Override PartName=\"/xl/worksheets/sheet{i}.xml\" \
+             ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>"
+        );
+    }
+    out.push_str("</Types>");
+    out
+}
+
+pub(super) fn root_rels() -> String {
+    format!(
+        "{XML_DECL}<Relationships xmlns=\"{PKG_REL_NS}\">\
+         <Relationship Id=\"rId1\" Type=\"{REL_NS}/officeDocument\" Target=\"xl/workbook.xml\"/>\
+         </Relationships>"
+    )
+}
+
+pub(super) fn workbook_xml(sheets: &[Sheet]) -> String {
+    let mut out = format!("{XML_DECL}<workbook xmlns=\"{MAIN_NS}\" xmlns:r=\"{REL_NS}\"><sheets>");
+    for (i, sheet) in sheets.iter().enumerate() {
+        let _ = write!(
+            out,
+            "<sheet name=\"{}\" sheetId=\"{n}\" r:id=\"rId{n}\"/>",
+            escape(&sheet_name(&sheet.name)),
+            n = i + 1
+        );
+    }
+    out.push_str("</sheets></workbook>");
+    out
+}
+
+pub(super) fn workbook_rels(sheets: usize) -> String {
+    let mut out = format!("{XML_DECL}<Relationships xmlns=\"{PKG_REL_NS}\">");
+    for i in 1..=sheets {
+        let _ = write!(
+            out,
+            "<Relationship Id=\"rId{i}\" Type=\"{REL_NS}/worksheet\" Target=\"worksheets/sheet{i}.xml\"/>"
+        );
+    }
+    let _ = write!(
+        out,
+        "<Relationship Id=\"rId{}\" Type=\"{REL_NS}/styles\" Target=\"styles.xml\"/></Relationships>",
+        sheets + 1
+    );
+    out
+}
+
+/// Two cell formats: 0 is the default, 1 is bold (header row).
+pub(super) fn styles() -> String {
+    format!(
+        "{XML_DECL}<styleSheet xmlns=\"{MAIN_NS}\">\
+         <fonts count=\"2\"><font><sz val=\"11\"/><name val=\"Calibri\"/></font>\
+         <font><b/><sz val=\"11\"/><name val=\"Calibri\"/></font></fonts>\
+         <fills count=\"2\"><fill><patternFill patternType=\"none\"/></fill>\
+         <fill><patternFill patternType=\"gray125\"/></fill></fills>\
+         <borders count=\"1\"><border><left/><right/><top/><bottom/><diagonal/></border></borders>\
+         <cellStyleXfs count=\"1\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\"/></cellStyleXfs>\
+         <cellXfs count=\"2\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\" xfId=\"0\"/>\
+         <xf numFmtId=\"0\" fontId=\"1\" fillId=\"0\" borderId=\"0\" xfId=\"0\" applyFont=\"1\"/></cellXfs>\
+         </styleSheet>"
+    )
+}
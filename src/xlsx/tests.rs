@@ -0,0 +1,47 @@
+// src/xlsx/tests.rs
+use super::*;
+
+#[test]
+fn columns_are_lettered() {
+    assert_eq!(column(0), "A");
+    assert_eq!(column(25), "Z");
+    assert_eq!(column(26), "AA");
+    assert_eq!(column(27), "AB");
+    assert_eq!(column(701), "ZZ");
+    assert_eq!(column(702), "AAA");
+}
+
+#[test]
+fn cells_are_escaped_and_typed() {
+    let sheet = Sheet {
+        name: "Rules/Files".into(),
+        header: vec!["Rule", "Count"],
+        rows: vec![vec!["<P01> & co\u{1}".into(), 3.into()]],
+    };
+    let xml = sheet_xml(&sheet);
+    assert!(xml.contains("<c r=\"A1\" t=\"inlineStr\" s=\"1\">"));
+    assert!(xml.contains(">&lt;P01&gt; &amp; co</t>"));
+    assert!(xml.contains("<c r=\"B2\"><v>3</v></c>"));
+    assert!(xml.contains("<autoFilter ref=\"A1:B2\"/>"));
+    assert_eq!(sheet_name(&sheet.name), "RulesFiles");
+}
+
+#[test]
+fn workbook_contains_every_part() {
+    let sheet = Sheet {
+        name: "Violations".into(),
+        header: vec!["Rule"],
+        rows: Vec::new(),
+    };
+    let bytes = workbook(&[sheet.clone(), sheet]);
+    let text = String::from_utf8_lossy(&bytes);
+    for part in [
+        "[Content_Types].xml",
+        "_rels/.rels",
+        "xl/workbook.xml",
+        "xl/styles.xml",
+        "xl/worksheets/sheet2.xml",
+    ] {
+        assert!(text.contains(part), "missing {part}");
+    }
+}
@@ -0,0 +1,141 @@
+// src/xlsx/zip.rs
+//! Just enough ZIP to package a workbook: stored (uncompressed) entries, no
+//! extra fields, no ZIP64. Workbook parts are small XML files, so skipping
+//! compression costs little and keeps this dependency-free.
+
+/// DOS date for 1980-01-01, the earliest ZIP can express. Entries are
+/// undated so the same report always produces the same bytes.
+const DOS_DATE: u16 = 0x21;
+
+/// An in-memory ZIP archive.
+#[derive(Default)]
+pub struct ZipWriter {
+    data: Vec<u8>,
+    central: Vec<u8>,
+    entries: u16,
+}
+
+impl ZipWriter {
+    /// Appends a file.
+    pub fn add(&mut self, name: &str, content: &[u8]) {
+        let offset = self.data.len();
+        let crc = crc32(content);
+        let header = Header {
+            name,
+            crc,
+            size: to_u32(content.len()),
+        };
+
+        put_u32(&mut self.data, 0x0403_4b50);
+        header.write_common(&mut self.data);
+        self.data.extend_from_slice(name.as_bytes());
+        self.data.extend_from_slice(content);
+
+        put_u32(&mut self.central, 0x0201_4b50);
+        put_u16(&mut self.central, 20);
+        header.write_common(&mut self.central);
+        put_u16(&mut self.central, 0); // comment length
+        put_u16(&mut self.central, 0); // disk number
+        put_u16(&mut self.central, 0); // internal attributes
+        put_u32(&mut self.central, 0); // external attributes
+        put_u32(&mut self.central, to_u32(offset));
+        self.central.extend_from_slice(name.as_bytes());
+        self.entries += 1;
+    }
+
+    /// The finished archive.
+    #[must_use]
+    pub fn finish(mut self) -> Vec<u8> {
+        let offset = to_u32(self.data.len());
+        let size = to_u32(self.central.len());
+        self.data.append(&mut self.central);
+        put_u32(&mut self.data, 0x0605_4b50);
+        put_u16(&mut self.data, 0); // this disk
+        put_u16(&mut self.data, 0); // disk with the directory
+        put_u16(&mut self.data, self.entries);
+        put_u16(&mut self.data, self.entries);
+        put_u32(&mut self.data, size);
+        put_u32(&mut self.data, offset);
+        put_u16(&mut self.data, 0); // comment length
+        self.data
+    }
+}
+
+/// Fields shared by the local header and the central directory entry.
+struct Header<'a> {
+    name: &'a str,
+    crc: u32,
+    size: u32,
+}
+
+impl Header<'_> {
+    fn write_common(&self, out: &mut Vec<u8>) {
+        put_u16(out, 20); // version needed
+        put_u16(out, 0x0800); // flags: UTF-8 names
+        put_u16(out, 0); // method: stored
+        put_u16(out, 0); // time
+        put_u16(out, DOS_DATE);
+        put_u32(out, self.crc);
+        put_u32(out, self.size); // compressed
+        put_u32(out, self.size); // uncompressed
+        put_u16(out, u16::try_from(self.name.len()).unwrap_or(u16::MAX));
+        put_u16(out, 0); // extra length
+    }
+}
+
+/// CRC-32 (IEEE), as ZIP requires.
+#[must_use]
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn to_u32(n: usize) -> u32 {
+    u32::try_from(n).unwrap_or(u32::MAX)
+}
+
+fn put_u16(out: &mut Vec<u8>, n: u16) {
+    out.extend_from_slice(&n.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, n: u32) {
+    out.extend_from_slice(&n.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_matches_the_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn archive_has_headers_directory_and_end_record() {
+        let mut zip = ZipWriter::default();
+        zip.add("a.txt", b"hello");
+        zip.add("b/c.txt", b"world");
+        let bytes = zip.finish();
+
+        assert!(bytes.starts_with(&[0x50, 0x4b, 0x03, 0x04]));
+        let end = bytes.len() - 22;
+        assert_eq!(bytes[end..end + 4], [0x50, 0x4b, 0x05, 0x06]);
+        assert_eq!(u16::from_le_bytes([bytes[end + 10], bytes[end + 11]]), 2);
+        let dir = u32::from_le_bytes([
+            bytes[end + 16],
+            bytes[end + 17],
+            bytes[end + 18],
+            bytes[end + 19],
+        ]) as usize;
+        assert_eq!(bytes[dir..dir + 4], [0x50, 0x4b, 0x01, 0x02]);
+    }
+}
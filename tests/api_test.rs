@@ -0,0 +1,56 @@
+//! Integration test: API compatibility on partial scans.
+//!
+//! Scanning one workspace member must compare only that member's part of
+//! the API baseline; the rest of the project is not "removed".
+
+#![cfg(feature = "cli")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn write(root: &Path, path: &str, content: &str) {
+    let full = root.join(path);
+    if let Some(parent) = full.parent() {
+        std::fs::create_dir_all(parent).expect("failed to create dirs");
+    }
+    std::fs::write(full, content).expect("failed to write file");
+}
+
+fn neti(root: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(args)
+        .current_dir(root)
+        .output()
+        .expect("failed to execute neti")
+}
+
+#[test]
+fn package_scan_compares_only_its_own_baseline() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let root = dir.path();
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"a\", \"b\"]\n",
+    );
+    write(root, "a/Cargo.toml", "[package]\nname = \"a\"\n");
+    write(root, "a/src/lib.rs", "pub fn alpha() {}\n");
+    write(root, "b/Cargo.toml", "[package]\nname = \"b\"\n");
+    write(root, "b/src/lib.rs", "pub fn beta() {}\n");
+    assert!(neti(root, &["api", "snapshot"]).status.success());
+
+    let scan = neti(root, &["scan", "--package", "a", "--porcelain"]);
+    let rows = String::from_utf8_lossy(&scan.stdout);
+    assert!(!rows.contains("LAW OF COMPATIBILITY"), "{rows}");
+    assert!(scan.status.success());
+
+    write(root, "a/src/lib.rs", "pub fn other() {}\n");
+    let rows =
+        String::from_utf8_lossy(&neti(root, &["scan", "--package", "a", "--porcelain"]).stdout)
+            .into_owned();
+    assert!(rows.contains(
+        "a/src/lib.rs\t1\tLAW OF COMPATIBILITY\terror\tPublic function `alpha` was removed"
+    ));
+    assert!(!rows.contains("beta"), "{rows}");
+}
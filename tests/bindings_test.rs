@@ -0,0 +1,31 @@
+//! Integration test: the Python bindings' manifest loads on its own.
+//!
+//! `bindings/python` is excluded from the workspace and built with maturin,
+//! so `cargo test --workspace` never touches it. Loading its metadata catches
+//! manifest errors such as a missing `[workspace]` table.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn python_bindings_manifest_loads_outside_the_workspace() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("bindings/python/Cargo.toml");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args([
+            "metadata",
+            "--no-deps",
+            "--offline",
+            "--format-version",
+            "1",
+        ])
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .output()
+        .expect("failed to execute cargo");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,66 @@
+//! Integration test: `neti branch` validates the `[git]` settings it uses
+//! and finds the pending goal at the repository root.
+
+#![cfg(feature = "cli")]
+
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn branch_rejects_an_unknown_strategy() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[git]\nbranch_strategy = \"per_goal\"\n",
+    )
+    .expect("failed to write neti.toml");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .arg("branch")
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("git.branch_strategy: 'per_goal' is not one of: single, per-goal"),
+        "{stderr}"
+    );
+}
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(output.status.success(), "git {args:?} failed");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn branch_reads_the_goal_from_the_repo_root() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let root = dir.path();
+    git(root, &["init", "-q", "-b", "main"]);
+    std::fs::write(root.join("README.md"), "x\n").expect("failed to write README.md");
+    git(root, &["add", "README.md"]);
+    git(root, &["commit", "-q", "-m", "init"]);
+    std::fs::create_dir_all(root.join(".neti")).expect("failed to create .neti");
+    std::fs::write(root.join(".neti/pending_goal"), "Add layers\n").expect("write goal");
+    std::fs::create_dir(root.join("sub")).expect("failed to create sub");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["--set", "git.branch_strategy=per-goal", "branch"])
+        .current_dir(root.join("sub"))
+        .output()
+        .expect("failed to execute neti");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(git(root, &["branch", "--show-current"]), "neti/add-layers");
+}
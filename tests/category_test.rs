@@ -0,0 +1,48 @@
+//! Integration test: `neti scan --only` / `--skip` narrow findings by category.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+const SOURCE: &str = "fn a(name: &String, x: Option<u8>) -> u8 {
+    for _ in 0..10 {
+        let s = name.clone();
+        process(s);
+    }
+    x.unwrap()
+}
+";
+
+fn rules(dir: &TempDir, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["scan", "--porcelain"])
+        .args(args)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|row| row.split('\t').nth(2).map(String::from))
+        .collect()
+}
+
+#[test]
+fn only_and_skip_select_categories() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("lib.rs"), SOURCE).expect("failed to write lib.rs");
+
+    let all = rules(&dir, &[]);
+    assert!(all.iter().any(|r| r == "P01"), "{all:?}");
+    assert!(all.iter().any(|r| r == "LAW OF PARANOIA"), "{all:?}");
+
+    assert_eq!(rules(&dir, &["--only", "security"]), ["LAW OF PARANOIA"]);
+    assert_eq!(
+        rules(
+            &dir,
+            &["--only", "security,performance", "--skip", "security"]
+        ),
+        ["P01"]
+    );
+    assert!(rules(&dir, &["--only", "style"]).is_empty());
+}
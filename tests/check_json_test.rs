@@ -8,6 +8,8 @@
 //! recursive invocation (the real config runs `cargo test` as a check
 //! command, which would re-enter these tests).
 
+#![cfg(feature = "cli")]
+
 use std::process::Command;
 use tempfile::TempDir;
 
@@ -114,3 +116,28 @@ fn check_json_exit_code_matches_passed() {
         );
     }
 }
+
+#[test]
+fn check_records_rule_counts_for_promote() {
+    let dir = test_workspace();
+    run_check_json(&dir);
+    let recorded = std::fs::read_to_string(dir.path().join(".neti/last-check.json"))
+        .expect("check should record its counts");
+    let counts: serde_json::Value = serde_json::from_str(&recorded).expect("counts are JSON");
+    assert!(counts.is_object(), "{recorded}");
+}
+
+#[test]
+fn check_error_logs_check_failed_event() {
+    let dir = test_workspace();
+    // A directory in place of the report file makes the check itself error.
+    std::fs::create_dir(dir.path().join("neti-report.txt")).expect("failed to create dir");
+
+    let output = run_check_json(&dir);
+    assert_eq!(output.status.code(), Some(1));
+
+    let events = std::fs::read_to_string(dir.path().join(".neti/events.jsonl"))
+        .expect("events log should exist");
+    let last = events.lines().last().unwrap_or_default();
+    assert!(last.contains("check_failed"), "last event: {last}");
+}
@@ -4,6 +4,8 @@
 //! gated by config mode (off/warn/error), included in JSON output and
 //! neti-report.txt.
 
+#![cfg(feature = "cli")]
+
 use std::process::Command;
 use tempfile::TempDir;
 
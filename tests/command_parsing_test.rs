@@ -5,6 +5,8 @@
 //! runner. Tests invoke the compiled `neti` binary with `neti.toml`
 //! configs containing quoted commands.
 
+#![cfg(feature = "cli")]
+
 use std::process::Command;
 use tempfile::TempDir;
 
@@ -0,0 +1,50 @@
+//! Integration test: reading a previous `neti scan --json` report.
+
+use neti_core::compare::{diff, findings, load};
+use neti_core::types::{FileReport, ScanReport, Violation};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn report(row: usize) -> ScanReport {
+    ScanReport {
+        files: vec![FileReport {
+            path: PathBuf::from("./src/a.rs"),
+            token_count: 10,
+            complexity_score: 0,
+            violations: vec![Violation::simple(row, "clone in loop".into(), "P01")],
+            analysis: None,
+            suppressions: Default::default(),
+        }],
+        total_tokens: 10,
+        total_violations: 1,
+        duration_ms: 0,
+    }
+}
+
+#[test]
+fn scan_json_round_trips_into_a_diff() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join("old.json");
+    let json = serde_json::to_string(&report(3)).expect("serialize");
+    fs::write(&path, json).expect("write");
+
+    let old = load(&path).expect("load");
+    assert_eq!(old.len(), 1);
+    assert_eq!(old[0].file, PathBuf::from("src/a.rs"));
+
+    let d = diff(old, findings(&report(7)));
+    assert_eq!((d.new.len(), d.fixed.len(), d.persisting.len()), (0, 0, 1));
+}
+
+#[test]
+fn other_json_is_rejected() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join("old.json");
+    fs::write(&path, r#"{"scan": {}}"#).expect("write");
+    let err = load(&path).expect_err("not a scan report");
+    assert!(err
+        .to_string()
+        .contains("is not a `neti scan --json` report"));
+    assert!(load(&dir.path().join("missing.json")).is_err());
+}
@@ -0,0 +1,116 @@
+//! Integration test: nested `neti.toml` rule overrides and profiles.
+//!
+//! Lays out a project with subdirectory configs and checks which rules each
+//! path resolves to.
+
+#![cfg(feature = "fs")]
+
+use neti_core::config::overrides::{collect, resolve_document};
+use neti_core::config::Config;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn project(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full = dir.path().join(path);
+        if let Some(parent) = full.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create dirs");
+        }
+        std::fs::write(full, content).expect("failed to write file");
+    }
+    dir
+}
+
+fn empty() -> toml::Value {
+    toml::Value::Table(toml::Table::new())
+}
+
+fn config_for(dir: &TempDir, profile: Option<&str>) -> Config {
+    let content = std::fs::read_to_string(dir.path().join("neti.toml")).unwrap_or_default();
+    let doc = resolve_document(&content, profile).expect("valid root config");
+    let mut config = Config::new();
+    config.rules = doc
+        .get("rules")
+        .cloned()
+        .map(|r| r.try_into().expect("valid rules"))
+        .unwrap_or_default();
+    let base_rules = doc
+        .get("rules")
+        .cloned()
+        .unwrap_or_else(|| toml::Value::Table(toml::Table::new()));
+    config.rule_overrides =
+        collect(dir.path(), &base_rules, &empty(), profile).expect("valid overrides");
+    config
+}
+
+const ROOT: &str = r#"
+[rules]
+max_file_tokens = 2000
+max_nesting_depth = 3
+
+[profile.strict.rules]
+max_nesting_depth = 2
+"#;
+
+#[test]
+fn nested_configs_override_their_subtree() {
+    let dir = project(&[
+        ("neti.toml", ROOT),
+        ("legacy/neti.toml", "[rules]\nmax_file_tokens = 6000\n"),
+        (
+            "legacy/vendor_shim/neti.toml",
+            "[rules]\nmax_nesting_depth = 6\n",
+        ),
+        ("src/lib.rs", ""),
+    ]);
+    let config = config_for(&dir, None);
+
+    let src = config.rules_for(Path::new("src/lib.rs"));
+    assert_eq!(src.max_file_tokens, 2000);
+
+    let legacy = config.rules_for(Path::new("legacy/old.rs"));
+    assert_eq!(legacy.max_file_tokens, 6000);
+    assert_eq!(legacy.max_nesting_depth, 3, "unset keys inherit from root");
+
+    let shim = config.rules_for(Path::new("./legacy/vendor_shim/a.rs"));
+    assert_eq!(shim.max_file_tokens, 6000, "inherits from legacy/neti.toml");
+    assert_eq!(shim.max_nesting_depth, 6);
+
+    let sibling = config.rules_for(Path::new("legacy_v2/a.rs"));
+    assert_eq!(
+        sibling.max_file_tokens, 2000,
+        "prefix match is per component"
+    );
+}
+
+#[test]
+fn profiles_apply_to_root_and_nested_files() {
+    let dir = project(&[
+        ("neti.toml", ROOT),
+        (
+            "legacy/neti.toml",
+            "[rules]\nmax_file_tokens = 6000\n\n[profile.strict.rules]\nmax_file_tokens = 4000\n",
+        ),
+        ("tools/neti.toml", "[rules]\nmax_function_args = 8\n"),
+    ]);
+    let config = config_for(&dir, Some("strict"));
+
+    assert_eq!(config.rules.max_nesting_depth, 2);
+    let legacy = config.rules_for(Path::new("legacy/old.rs"));
+    assert_eq!(legacy.max_file_tokens, 4000);
+    assert_eq!(legacy.max_nesting_depth, 2);
+    let tools = config.rules_for(Path::new("tools/gen.rs"));
+    assert_eq!(
+        tools.max_function_args, 8,
+        "nested files need not define the profile"
+    );
+    assert_eq!(tools.max_nesting_depth, 2);
+}
+
+#[test]
+fn malformed_nested_config_is_reported() {
+    let dir = project(&[("neti.toml", ROOT), ("legacy/neti.toml", "[rules\n")]);
+    let err = collect(dir.path(), &empty(), &empty(), None).expect_err("malformed");
+    assert!(err.to_string().contains("legacy"), "{err}");
+}
@@ -0,0 +1,42 @@
+//! Integration test: saving an edited config writes only the edits.
+//!
+//! `NETI_*` variables and `--set` values are part of the loaded config but
+//! must not end up in `neti.toml`. Runs as its own test binary because it
+//! changes the working directory and environment.
+
+use neti_core::config::Config;
+use tempfile::TempDir;
+
+#[test]
+fn env_overrides_are_not_saved() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\nmax_file_tokens = 1800\n\n[profile.strict.rules]\nmax_nesting_depth = 2\n",
+    )
+    .expect("failed to write neti.toml");
+    std::env::set_current_dir(dir.path()).expect("failed to enter temp dir");
+    std::env::set_var("NETI_RULES_MAX_NESTING_DEPTH", "9");
+
+    let mut config = Config::load();
+    assert_eq!(config.rules.max_nesting_depth, 9);
+    config.rules.max_function_args = 3;
+    config.save().expect("save");
+
+    let saved: toml::Value = std::fs::read_to_string("neti.toml")
+        .expect("read neti.toml")
+        .parse()
+        .expect("valid toml");
+    let rules = &saved["rules"];
+    assert_eq!(rules["max_function_args"].as_integer(), Some(3));
+    assert_eq!(rules["max_file_tokens"].as_integer(), Some(1800));
+    assert!(rules.get("max_nesting_depth").is_none(), "{saved}");
+    assert!(
+        saved.get("commands").is_none(),
+        "project defaults stay unpinned"
+    );
+    assert_eq!(
+        saved["profile"]["strict"]["rules"]["max_nesting_depth"].as_integer(),
+        Some(2)
+    );
+}
@@ -0,0 +1,217 @@
+//! Integration test: config validation diagnostics and `config doctor`
+//! provenance.
+
+use neti_core::config::doctor::{explain, Source};
+use neti_core::config::validate::{check_file, check_ignore_file, diagnose, Diagnostic};
+use neti_core::config::Config;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn messages(diagnostics: &[Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn unknown_keys_point_at_their_line() {
+    let content =
+        "[rules]\nmax_file_token = 1\n\n[preferences]\nauto_copy = true\n\n[tuii]\ntheme = \"x\"\n";
+    let found = messages(&check_file(Path::new("neti.toml"), content, false));
+    assert_eq!(
+        found,
+        vec![
+            "neti.toml:2: rules.max_file_token: unknown key (did you mean `max_file_tokens`?)",
+            "neti.toml:7: tuii: unknown key (did you mean `tui`?)",
+        ]
+    );
+}
+
+#[test]
+fn free_form_tables_and_optional_keys_are_accepted() {
+    let content = r#"
+[rules.locality.layers]
+domain = ["src/core/**"]
+
+[rules.locality.layer_deps]
+domain = []
+
+[commands]
+lint = "cargo clippy"
+mutate = { budget_secs = 60, min_score = 50 }
+
+[git]
+author = "Bot <bot@example.com>"
+
+[notifications]
+slack_webhook_env = "SLACK_WEBHOOK_URL"
+command = "./notify.sh"
+
+[profile.ci.commands]
+check = ["cargo test"]
+"#;
+    let found = check_file(Path::new("neti.toml"), content, false);
+    assert!(found.is_empty(), "{:?}", messages(&found));
+}
+
+#[test]
+fn out_of_range_values_are_reported_once() {
+    let content = "[rules]\nmax_nesting_depth = 0\nmin_ahf = 140.0\n\n[rules.locality]\nmode = \"loud\"\nmax_distance = 2\nl1_threshold = 3\n\n[profile.strict.rules]\nmax_cbo = 0\n";
+    let found = messages(&check_file(Path::new("neti.toml"), content, false));
+    assert_eq!(
+        found,
+        vec![
+            "neti.toml:2: rules.max_nesting_depth: must be at least 1",
+            "neti.toml:3: rules.min_ahf: must be a percentage (0-100)",
+            "neti.toml:6: rules.locality.mode: 'loud' is not one of: error, warn, off",
+            "neti.toml:8: rules.locality.l1_threshold: exceeds max_distance (2)",
+            "neti.toml:11: profile.strict.rules.max_cbo: must be at least 1",
+        ]
+    );
+}
+
+#[test]
+fn type_errors_and_syntax_errors_carry_lines() {
+    let wrong_type = check_file(
+        Path::new("neti.toml"),
+        "[rules]\nmax_file_tokens = \"many\"\n",
+        false,
+    );
+    assert_eq!(wrong_type.first().and_then(|d| d.line), Some(2));
+
+    let broken = check_file(Path::new("neti.toml"), "[rules]\n\n[rules\n", false);
+    assert_eq!(broken.first().and_then(|d| d.line), Some(3));
+}
+
+#[test]
+fn nested_files_only_accept_rules() {
+    let content = "[rules]\nmax_function_args = 8\n\n[git]\npush = true\n";
+    let found = messages(&check_file(Path::new("legacy/neti.toml"), content, true));
+    assert_eq!(
+        found,
+        vec!["legacy/neti.toml:4: git: only [rules] is read from a nested neti.toml"]
+    );
+}
+
+#[test]
+fn ignore_file_and_pattern_contradictions() {
+    let found = messages(&check_ignore_file(
+        Path::new(".netiignore"),
+        "# comment\ntarget/\n(unclosed\n",
+    ));
+    assert_eq!(
+        found,
+        vec![".netiignore:3: invalid pattern: unclosed group"]
+    );
+
+    let mut config = Config::new();
+    let pattern = regex::Regex::new("generated/").expect("valid regex");
+    config.include_patterns.push(pattern.clone());
+    config.exclude_patterns.push(pattern);
+    config
+        .exclude_patterns
+        .push(regex::Regex::new(".*").expect("valid regex"));
+    let dir = TempDir::new().expect("temp dir");
+    let found = messages(&diagnose(&config, dir.path()));
+    assert_eq!(
+        found,
+        vec![
+            ".netiignore: pattern 'generated/' is both included and excluded",
+            ".netiignore: pattern '.*' excludes every file",
+        ]
+    );
+}
+
+#[test]
+fn doctor_attributes_values_to_their_source() {
+    let dir = TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\nmax_file_tokens = 1800\n\n[profile.strict.rules]\nmax_nesting_depth = 2\n",
+    )
+    .expect("write config");
+
+    let mut config = Config::new();
+    config.rules.max_file_tokens = 1800;
+    config.rules.max_nesting_depth = 2;
+    let settings = explain(&config, dir.path(), Some("strict"), &[]);
+    let source_of = |key: &str| {
+        settings
+            .iter()
+            .find(|s| s.key == key)
+            .map(|s| s.source.clone())
+    };
+
+    let file = PathBuf::from("neti.toml");
+    assert_eq!(
+        source_of("rules.max_file_tokens"),
+        Some(Source::File {
+            file: file.clone(),
+            line: Some(2),
+            profile: None
+        })
+    );
+    assert_eq!(
+        source_of("rules.max_nesting_depth"),
+        Some(Source::File {
+            file,
+            line: Some(5),
+            profile: Some("strict".to_string())
+        })
+    );
+    assert_eq!(source_of("rules.max_cbo"), Some(Source::Default));
+}
+
+#[test]
+fn doctor_reports_env_and_cli_overrides() {
+    use neti_core::config::layers::{from_env, parse_assignment, Pinned};
+
+    let dir = TempDir::new().expect("temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\nmax_file_tokens = 1800\n",
+    )
+    .expect("write config");
+    let (mut pinned, _) =
+        from_env(|var| (var == "NETI_RULES_MAX_FILE_TOKENS").then(|| "1500".to_string()));
+    let (key, value) = parse_assignment("rules.max_cbo=12").expect("valid assignment");
+    pinned.push(Pinned {
+        key,
+        value,
+        env_var: None,
+    });
+
+    let settings = explain(&Config::new(), dir.path(), None, &pinned);
+    let source_of = |key: &str| {
+        settings
+            .iter()
+            .find(|s| s.key == key)
+            .map(|s| s.source.clone())
+    };
+    assert_eq!(
+        source_of("rules.max_file_tokens"),
+        Some(Source::Env("NETI_RULES_MAX_FILE_TOKENS".to_string()))
+    );
+    assert_eq!(source_of("rules.max_cbo"), Some(Source::Cli));
+}
+
+#[test]
+fn naming_policies_are_validated() {
+    let content = "[rules.naming.rust]\nfunction = \"^(db|http)_[a-z_]+$\"\n\n[rules.naming.typescript]\ntype = \"^I[A-Z\"\n";
+    let found = messages(&check_file(Path::new("neti.toml"), content, false));
+    assert_eq!(found.len(), 1, "{found:?}");
+    assert!(
+        found[0].starts_with("neti.toml:5: rules.naming.typescript.type: invalid regex"),
+        "{found:?}"
+    );
+}
+
+#[test]
+fn notification_filter_is_validated() {
+    let content = "[notifications]\nwhen = \"sometimes\"\ndesktop = true\n";
+    let found = messages(&check_file(Path::new("neti.toml"), content, false));
+    assert_eq!(
+        found,
+        vec![
+            "neti.toml:2: notifications.when: 'sometimes' is not one of: always, failure, success"
+        ]
+    );
+}
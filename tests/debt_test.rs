@@ -0,0 +1,47 @@
+//! Integration test: debt comments dated by `git blame`.
+
+#![cfg(feature = "fs")]
+
+use neti_core::config::DebtConfig;
+use neti_core::debt;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) -> bool {
+    Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .env("GIT_AUTHOR_DATE", "2020-01-15T12:00:00Z")
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+#[test]
+fn committed_debt_is_dated_and_new_lines_are_not() {
+    let dir = TempDir::new().expect("temp dir");
+    let file = dir.path().join("lib.rs");
+    fs::write(&file, "// LATER: old one\nfn a() {}\n").expect("write");
+    if !(git(dir.path(), &["init", "-q"]) && git(dir.path(), &["add", "."])) {
+        return; // git unavailable
+    }
+    assert!(git(dir.path(), &["commit", "-q", "-m", "init"]));
+    fs::write(&file, "// LATER: old one\nfn a() {} // XXX: new one\n").expect("write");
+
+    let today = chrono::NaiveDate::from_ymd_opt(2020, 12, 31).expect("date");
+    // Custom markers, so this fixture is not itself debt.
+    let rules = DebtConfig {
+        markers: vec!["LATER".into(), "XXX".into()],
+        ..DebtConfig::default()
+    };
+    let items = debt::collect(&file, &rules, today);
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].date.as_deref(), Some("2020-01-15"));
+    assert_eq!(items[0].age_days, Some(351));
+    assert!(items[0].overdue);
+    assert_eq!(items[1].marker, "XXX");
+    assert_eq!(items[1].age_days, None);
+    assert!(!items[1].overdue);
+}
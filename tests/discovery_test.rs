@@ -0,0 +1,90 @@
+//! Integration test: discovery heuristics and `[discovery] force_include`.
+
+#![cfg(feature = "fs")]
+
+use neti_core::config::DiscoveryConfig;
+use neti_core::discovery::{HeuristicFilter, IgnoreStack, Skip};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn generated_and_minified_files_are_skipped_unless_forced() {
+    let dir = TempDir::new().expect("temp dir");
+    let generated = dir.path().join("schema.rs");
+    let minified = dir.path().join("vendor.js");
+    let code = dir.path().join("lib.rs");
+    fs::write(
+        &generated,
+        "// @generated by build.rs\npub const A: u8 = 1;\n",
+    )
+    .expect("write");
+    fs::write(&minified, "var x=1;".repeat(400)).expect("write");
+    fs::write(&code, "pub fn one() -> u8 {\n    1\n}\n".repeat(60)).expect("write");
+
+    let filter = HeuristicFilter::new(&DiscoveryConfig::default());
+    assert_eq!(filter.skip_reason(&generated), Some(Skip::Generated));
+    assert_eq!(filter.skip_reason(&minified), Some(Skip::Minified));
+    assert_eq!(filter.skip_reason(&code), None);
+
+    let forced = HeuristicFilter::new(&DiscoveryConfig {
+        force_include: vec![r"schema\.rs$".to_string()],
+        ..DiscoveryConfig::default()
+    });
+    assert_eq!(forced.skip_reason(&generated), None);
+    assert_eq!(forced.skip_reason(&minified), Some(Skip::Minified));
+}
+
+#[test]
+fn nested_ignore_files_follow_git_precedence() {
+    let dir = TempDir::new().expect("temp dir");
+    let root = dir.path();
+    let write = |rel: &str, content: &str| {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+        fs::write(path, content).expect("write");
+    };
+    write(".git/info/exclude", "scratch/\n");
+    write(".gitignore", "*.log\nvendor/\n");
+    write("app/.gitignore", "!keep.log\n/build\n");
+    write("app/.netiignore", "^fixtures/\n");
+    write("global-excludes", "*.swp\n");
+
+    let mut stack = IgnoreStack::with_excludes_file(root, Some(&root.join("global-excludes")));
+    let mut ignored = |rel: &str, is_dir: bool| stack.excludes(&root.join(rel), is_dir);
+
+    assert!(ignored("vendor", true));
+    assert!(ignored("app/vendor", true));
+    assert!(ignored("scratch", true));
+    assert!(ignored("src/main.rs.swp", false));
+    assert!(ignored("app/debug.log", false));
+    assert!(!ignored("app/keep.log", false));
+    assert!(ignored("keep.log", false));
+    assert!(ignored("app/build", true));
+    assert!(!ignored("build", true));
+    assert!(ignored("app/fixtures", true));
+    assert!(!ignored("fixtures", true));
+    assert!(!ignored("app/src/lib.rs", false));
+    assert!(!stack.excludes(root, true));
+}
+
+#[test]
+fn negation_re_includes_a_child_of_an_ignored_glob() {
+    let dir = TempDir::new().expect("temp dir");
+    let root = dir.path();
+    fs::write(
+        root.join(".gitignore"),
+        "third/*
+!third/x/
+",
+    )
+    .expect("write");
+
+    let mut stack = IgnoreStack::new(root);
+    let mut ignored = |rel: &str, is_dir: bool| stack.excludes(&root.join(rel), is_dir);
+
+    assert!(!ignored("third", true), "the parent stays walkable");
+    assert!(!ignored("third/x", true));
+    assert!(!ignored("third/x/y.rs", false));
+    assert!(ignored("third/z", true));
+    assert!(ignored("third/a.rs", false));
+}
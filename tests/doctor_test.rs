@@ -0,0 +1,48 @@
+//! Integration test: `neti doctor` reports config and cache problems with fixes.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn doctor_reports_broken_config_and_cache() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\nmax_file_token = 1\n",
+    )
+    .expect("failed to write neti.toml");
+    let cache = dir.path().join(".neti/cache");
+    std::fs::create_dir_all(&cache).expect("failed to create cache");
+    std::fs::write(cache.join("manifest.json"), "[\"trunc").expect("failed to write manifest");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["doctor", "--json"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    let checks: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("doctor --json is JSON");
+    let find = |name: &str| {
+        checks
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|c| c["name"] == name)
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    assert!(!output.status.success());
+    let config = find("config");
+    assert_eq!(config["status"], "fail");
+    assert!(config["detail"]
+        .as_str()
+        .is_some_and(|d| d.contains("max_file_token")));
+    let cache = find("cache");
+    assert_eq!(cache["status"], "warn");
+    assert_eq!(cache["detail"], "unreadable: .neti/cache/manifest.json");
+    assert!(cache["fix"].is_string());
+    assert_eq!(find("tokenizer")["status"], "ok");
+}
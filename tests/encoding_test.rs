@@ -0,0 +1,33 @@
+//! Integration test: files with invalid UTF-8 are scanned, not dropped.
+
+use neti_core::analysis::worker::scan_file;
+use neti_core::config::Config;
+use neti_core::secrets::Baseline;
+use neti_core::types::Confidence;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn latin1_files_are_scanned_with_a_notice() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join("legacy.rs");
+    let mut bytes = b"// Cr\xe9\xe9 par l'\xe9quipe\n".to_vec();
+    bytes.extend_from_slice(b"pub fn first(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n");
+    fs::write(&path, bytes).expect("write");
+
+    let report = scan_file(&path, &Config::default(), &Baseline::default());
+
+    assert!(report.token_count > 0);
+    assert!(report.analysis.is_some());
+    let notice = report
+        .violations
+        .iter()
+        .find(|v| v.message.contains("not valid UTF-8"))
+        .expect("encoding notice");
+    assert_eq!(notice.confidence, Confidence::Info);
+    assert_eq!(notice.row, 1);
+    assert!(report
+        .violations
+        .iter()
+        .any(|v| v.law == "LAW OF PARANOIA" && v.row == 3));
+}
@@ -0,0 +1,76 @@
+//! Integration test: atomic writes survive interrupted runs.
+
+use neti_core::fsutil::{atomic_write, AtomicFile};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn entries(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .expect("read dir")
+        .filter_map(Result::ok)
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn atomic_write_replaces_content_without_leftovers() {
+    let dir = TempDir::new().expect("temp dir");
+    let target = dir.path().join("lib.rs");
+    fs::write(&target, "old").expect("seed");
+
+    atomic_write(&target, "new").expect("atomic write");
+    assert_eq!(fs::read_to_string(&target).expect("read"), "new");
+    assert_eq!(entries(dir.path()), vec!["lib.rs"]);
+}
+
+#[test]
+fn interrupted_write_leaves_target_intact() {
+    let dir = TempDir::new().expect("temp dir");
+    let target = dir.path().join("tasks.toml");
+    fs::write(&target, "[[task]]\nid = 1\n").expect("seed");
+
+    // Unwound mid-write: the partial file is discarded.
+    {
+        let mut file = AtomicFile::create(&target).expect("create");
+        file.write_all(b"[[task]]\nid =").expect("partial write");
+    }
+    assert_eq!(
+        fs::read_to_string(&target).expect("read"),
+        "[[task]]\nid = 1\n"
+    );
+    assert_eq!(entries(dir.path()), vec!["tasks.toml"]);
+
+    // Killed mid-write: the temp file is stranded, the target is not touched,
+    // and the next write still succeeds.
+    let mut file = AtomicFile::create(&target).expect("create");
+    file.write_all(b"[[task]]\nid =").expect("partial write");
+    std::mem::forget(file);
+    assert_eq!(
+        fs::read_to_string(&target).expect("read"),
+        "[[task]]\nid = 1\n"
+    );
+    atomic_write(&target, "[[task]]\nid = 2\n").expect("atomic write");
+    assert_eq!(
+        fs::read_to_string(&target).expect("read"),
+        "[[task]]\nid = 2\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn atomic_write_keeps_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = TempDir::new().expect("temp dir");
+    let hook = dir.path().join("pre-commit");
+    fs::write(&hook, "#!/bin/sh\n").expect("seed");
+    fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).expect("chmod");
+
+    atomic_write(&hook, "#!/bin/sh\nexec neti check\n").expect("atomic write");
+    let mode = fs::metadata(&hook).expect("meta").permissions().mode();
+    assert_eq!(mode & 0o777, 0o755);
+}
@@ -0,0 +1,80 @@
+//! Integration test: `neti init` starter files and hook installation.
+
+#![cfg(feature = "fs")]
+
+use neti_core::config::validate::{check_file, check_ignore_file};
+use neti_core::init::{ignore_patterns, install_hook, write_ignore, HookOutcome};
+use neti_core::project::{generate_toml, ProjectType, Strictness};
+use std::path::Path;
+use tempfile::TempDir;
+
+const PROJECTS: [ProjectType; 5] = [
+    ProjectType::Rust,
+    ProjectType::Node,
+    ProjectType::Python,
+    ProjectType::Go,
+    ProjectType::Unknown,
+];
+
+#[test]
+fn generated_configs_pass_validation() {
+    for project in PROJECTS {
+        for strictness in [
+            Strictness::Strict,
+            Strictness::Standard,
+            Strictness::Relaxed,
+        ] {
+            let content = generate_toml(project, strictness);
+            let found = check_file(Path::new("neti.toml"), &content, false);
+            assert!(found.is_empty(), "{project:?}/{strictness:?}: {found:?}");
+        }
+    }
+}
+
+#[test]
+fn ignore_patterns_are_valid_regexes() {
+    for project in PROJECTS {
+        let content = ignore_patterns(project);
+        let found = check_ignore_file(Path::new(".netiignore"), &content);
+        assert!(found.is_empty(), "{project:?}: {found:?}");
+    }
+}
+
+#[test]
+fn existing_ignore_file_is_kept() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join(".netiignore");
+    std::fs::write(&path, "mine\n").expect("write");
+
+    assert!(!write_ignore(dir.path(), ProjectType::Rust).expect("write_ignore"));
+    assert_eq!(std::fs::read_to_string(&path).expect("read"), "mine\n");
+}
+
+#[test]
+fn hook_is_installed_but_never_clobbers_a_foreign_one() {
+    let dir = TempDir::new().expect("temp dir");
+    let hooks = dir.path().join("hooks");
+    let hook = hooks.join("pre-commit");
+
+    assert_eq!(
+        install_hook(&hooks).expect("install"),
+        HookOutcome::Installed(hook.clone())
+    );
+    let content = std::fs::read_to_string(&hook).expect("read hook");
+    assert!(content.contains("neti check"), "{content}");
+    assert_eq!(
+        install_hook(&hooks).expect("reinstall"),
+        HookOutcome::Installed(hook.clone()),
+        "our own hook is refreshed"
+    );
+
+    std::fs::write(&hook, "#!/bin/sh\nmake lint\n").expect("write foreign hook");
+    assert_eq!(
+        install_hook(&hooks).expect("install"),
+        HookOutcome::Foreign(hook.clone())
+    );
+    assert_eq!(
+        std::fs::read_to_string(&hook).expect("read hook"),
+        "#!/bin/sh\nmake lint\n"
+    );
+}
@@ -0,0 +1,41 @@
+//! Integration test: files over `max_analysis_bytes` are streamed, not parsed.
+
+use neti_core::analysis::worker::scan_file;
+use neti_core::config::Config;
+use neti_core::secrets::Baseline;
+use neti_core::types::Confidence;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn oversized_files_skip_deep_analysis_but_keep_secrets() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join("big.rs");
+    let body = "pub fn f() { let v: Vec<u8> = Vec::new(); drop(v); }\n".repeat(2000);
+    let secret = format!("const T: &str = \"ghp_{}\";\n", "a1B2".repeat(9));
+    fs::write(&path, format!("{body}{secret}")).expect("write");
+
+    let mut config = Config::default();
+    config.discovery.max_analysis_bytes = 4096;
+    let report = scan_file(&path, &config, &Baseline::default());
+
+    assert!(report.token_count > 0);
+    assert!(report.analysis.is_none());
+    let notice = report
+        .violations
+        .iter()
+        .find(|v| v.message.contains("skipped deep analysis"))
+        .expect("skip notice");
+    assert_eq!(notice.confidence, Confidence::Info);
+    let secret_row = report
+        .violations
+        .iter()
+        .find(|v| v.law == "X03")
+        .map(|v| v.row);
+    assert_eq!(secret_row, Some(2001));
+
+    config.discovery.max_analysis_bytes = u64::MAX;
+    let full = scan_file(&path, &config, &Baseline::default());
+    assert!(full.analysis.is_some());
+    assert_eq!(full.token_count, report.token_count);
+}
@@ -0,0 +1,88 @@
+//! Integration test: the library `scan` API, used without the CLI.
+//!
+//! `scan` discovers from the current directory, so each scan test runs in
+//! its own fixture project while holding a lock on the working directory.
+
+use neti_core::{scan_source, Config, Lang};
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+/// Serializes tests that change the process's working directory.
+#[cfg(feature = "fs")]
+static CWD: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Runs `test` from inside a fresh project with files under `src/xlsx`,
+/// `src/normalize.rs` and `other`.
+#[cfg(feature = "fs")]
+fn in_fixture(test: impl FnOnce()) {
+    let _lock = CWD
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let dir = tempfile::TempDir::new().expect("temp dir");
+    for (path, body) in [
+        ("src/xlsx/mod.rs", "pub mod cell;\n"),
+        (
+            "src/xlsx/cell.rs",
+            "pub fn f(v: Option<u8>) -> u8 {\n    v.unwrap()\n}\n",
+        ),
+        ("src/normalize.rs", "pub fn g() {}\n"),
+        ("other/lib.rs", "pub fn h() {}\n"),
+    ] {
+        let path = dir.path().join(path);
+        std::fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+        std::fs::write(path, body).expect("write fixture");
+    }
+    let previous = std::env::current_dir().expect("cwd");
+    std::env::set_current_dir(dir.path()).expect("enter fixture");
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(test));
+    std::env::set_current_dir(previous).expect("leave fixture");
+    if let Err(panic) = outcome {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn scan_narrows_discovery_to_the_given_paths() {
+    in_fixture(|| {
+        let config = Config::load();
+        let report = neti_core::scan(&[PathBuf::from("./src/xlsx")], &config).expect("scan");
+        let mut paths: Vec<&Path> = report.files.iter().map(|f| f.path.as_path()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![Path::new("src/xlsx/cell.rs"), Path::new("src/xlsx/mod.rs")]
+        );
+        assert!(report.total_violations > 0);
+        assert_eq!(
+            report.total_violations,
+            report
+                .files
+                .iter()
+                .map(|f| f.violations.len())
+                .sum::<usize>()
+        );
+    });
+}
+
+#[cfg(feature = "fs")]
+#[test]
+fn scan_of_a_single_file_reports_only_that_file() {
+    in_fixture(|| {
+        let config = Config::load();
+        let file = PathBuf::from("src/normalize.rs");
+        let report = neti_core::scan(std::slice::from_ref(&file), &config).expect("scan");
+        let paths: Vec<&Path> = report.files.iter().map(|f| f.path.as_path()).collect();
+        assert_eq!(paths, vec![file.as_path()]);
+    });
+}
+
+#[test]
+fn scan_source_needs_no_files() {
+    let config = Config::default();
+    let source = "fn f() -> u8 {\r\n    let v: Option<u8> = None;\r\n    v.unwrap()\r\n}\r\n";
+    let violations = scan_source(Lang::Rust, source, &config);
+    let unwrap = violations.iter().find(|v| v.law == "LAW OF PARANOIA");
+    assert_eq!(unwrap.map(|v| v.row), Some(3));
+    assert!(scan_source(Lang::Python, "", &config).is_empty());
+}
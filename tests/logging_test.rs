@@ -0,0 +1,24 @@
+//! Integration test: `--log-file` diagnostics.
+
+use neti_core::logging;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn log_file_records_levels_targets_and_spans() {
+    let dir = TempDir::new().expect("temp dir");
+    let path = dir.path().join(".neti/neti.log");
+    logging::init(Some(log::LevelFilter::Debug), Some(&path)).expect("init");
+
+    {
+        let _span = logging::span("scan");
+        log::debug!("scanning 3 files");
+        log::trace!("not recorded at debug");
+    }
+
+    let text = fs::read_to_string(&path).expect("read log");
+    assert!(text.contains("DEBUG neti::span: scan: start"));
+    assert!(text.contains("logging_test: scanning 3 files"));
+    assert!(text.contains("scan: done in"));
+    assert!(!text.contains("not recorded"));
+}
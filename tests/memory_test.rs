@@ -0,0 +1,58 @@
+//! Integration test: `--max-mem` batches the scan without changing results.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+fn scan_json(dir: &TempDir, extra: &[&str]) -> (serde_json::Value, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["scan", "--json"])
+        .args(extra)
+        .current_dir(dir.path())
+        .env("NETI_LOG", "debug")
+        .output()
+        .expect("failed to execute neti");
+    let report = serde_json::from_slice(&output.stdout).expect("stdout is the scan report");
+    (report, String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+fn findings(report: &serde_json::Value) -> Vec<(String, String, u64)> {
+    let mut out: Vec<_> = report["files"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|f| {
+            let path = f["path"].as_str().unwrap_or_default().to_string();
+            f["violations"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |v| {
+                    let law = v["law"].as_str().unwrap_or_default().to_string();
+                    (path.clone(), law, v["row"].as_u64().unwrap_or(0))
+                })
+        })
+        .collect();
+    out.sort();
+    out
+}
+
+#[test]
+fn batched_scan_matches_unbounded_scan() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("neti.toml"), "[rules]\n").expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    let body = "pub fn get(v: Option<u8>) -> u8 {\n    v.unwrap()\n}\n".repeat(400);
+    for i in 0..6 {
+        std::fs::write(dir.path().join(format!("src/m{i}.rs")), &body).expect("write source");
+    }
+
+    let (unbounded, _) = scan_json(&dir, &[]);
+    let (batched, stderr) = scan_json(&dir, &["--max-mem", "1"]);
+
+    assert!(stderr.contains("max_memory_mb = 1: 7 files in"), "{stderr}");
+    assert!(!stderr.contains("in 1 batches"), "{stderr}");
+    assert!(!findings(&unbounded).is_empty());
+    assert_eq!(findings(&batched), findings(&unbounded));
+}
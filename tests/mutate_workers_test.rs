@@ -0,0 +1,117 @@
+//! Integration test: parallel mutation workers run in isolated project copies.
+//!
+//! Uses a shell "test suite" that only detects one of three mutations, so the
+//! results show each mutation was applied alone and the project was untouched.
+//! A small Cargo project checks that copies build and test for real, so a
+//! surviving mutant is not reported as killed.
+
+#![cfg(feature = "fs")]
+
+use neti_core::mutate::mutations::{MutationKind, MutationPoint};
+use neti_core::mutate::runner::{run_mutations, RunnerConfig};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tempfile::TempDir;
+
+const SOURCE: &str = "a == b && c < d\n";
+
+fn point(byte_start: usize, original: &str, mutated: &str, kind: MutationKind) -> MutationPoint {
+    MutationPoint {
+        file: PathBuf::from("src/lib.rs"),
+        line: 1,
+        column: byte_start + 1,
+        byte_start,
+        byte_end: byte_start + original.len(),
+        original: original.into(),
+        mutated: mutated.into(),
+        kind,
+    }
+}
+
+#[test]
+fn parallel_workers_isolate_mutations() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(dir.path().join("src/lib.rs"), SOURCE).expect("failed to write lib.rs");
+
+    let points = vec![
+        point(2, "==", "!=", MutationKind::Comparison),
+        point(7, "&&", "||", MutationKind::Logical),
+        point(12, "<", ">=", MutationKind::Comparison),
+    ];
+    let config = RunnerConfig {
+        test_command: "sh".into(),
+        test_args: vec!["-c".into(), "grep -q 'a == b' src/lib.rs".into()],
+        timeout_secs: 30,
+        workers: 3,
+        targeting: None,
+        deadline: None,
+        target_dir: None,
+    };
+
+    let calls = AtomicUsize::new(0);
+    let results = run_mutations(&points, &config, dir.path(), |done, total, _| {
+        assert_eq!(total, 3);
+        assert_eq!(done, calls.fetch_add(1, Ordering::SeqCst) + 1);
+    })
+    .expect("mutation run failed");
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    let survived: Vec<_> = results
+        .iter()
+        .map(|r| (r.point.byte_start, r.survived))
+        .collect();
+    assert_eq!(survived, vec![(2, false), (7, true), (12, true)]);
+
+    let untouched = std::fs::read_to_string(dir.path().join("src/lib.rs")).expect("read lib.rs");
+    assert_eq!(untouched, SOURCE);
+}
+
+const CRATE_SOURCE: &str = "pub fn is_small(n: u32) -> bool {
+    n < 10
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn zero_is_small() {
+        assert!(super::is_small(0));
+    }
+}
+";
+
+#[test]
+fn parallel_workers_keep_cargo_survivors() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"probe\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .expect("failed to write Cargo.toml");
+    std::fs::write(dir.path().join("src/lib.rs"), CRATE_SOURCE).expect("failed to write lib.rs");
+
+    let at = CRATE_SOURCE.find('<').expect("comparison");
+    let mutant = |mutated: &str| MutationPoint {
+        line: 2,
+        column: 7,
+        ..point(at, "<", mutated, MutationKind::Comparison)
+    };
+    // `<=` still passes the only test; `>=` fails it.
+    let points = vec![mutant("<="), mutant(">=")];
+    let config = RunnerConfig {
+        test_args: vec!["test".into(), "--offline".into()],
+        workers: 2,
+        ..RunnerConfig::rust()
+    };
+
+    let results =
+        run_mutations(&points, &config, dir.path(), |_, _, _| {}).expect("mutation run failed");
+
+    let survived: Vec<_> = results
+        .iter()
+        .map(|r| (r.point.mutated.as_str(), r.survived))
+        .collect();
+    assert_eq!(survived, vec![("<=", true), (">=", false)]);
+    assert!(dir.path().join("target/neti-mutate/worker-1").is_dir());
+}
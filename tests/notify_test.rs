@@ -0,0 +1,42 @@
+//! Integration test: `[notifications]` hooks run when `neti check` finishes.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn check_runs_the_notification_hook() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        r#"[commands]
+check = ["false"]
+
+[notifications]
+when = "failure"
+command = "sh -c 'echo $NETI_COMMAND $NETI_STATUS \"$NETI_SUMMARY\" > notified.txt'"
+"#,
+    )
+    .expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "pub fn one() -> u8 {\n    1\n}\n",
+    )
+    .expect("failed to write lib.rs");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["check", "--quiet"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    assert!(!output.status.success());
+
+    let notified =
+        std::fs::read_to_string(dir.path().join("notified.txt")).expect("hook did not run");
+    assert_eq!(
+        notified.trim(),
+        "check failed 0 errors, 0 warnings, 0 locality violations, 0/1 commands passed"
+    );
+}
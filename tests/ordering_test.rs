@@ -0,0 +1,69 @@
+//! Integration test: scan reports come out in path, line, rule order.
+
+#![cfg(feature = "cli")]
+
+use neti_core::{scan, Config, ScanReport};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn scan_tree() -> ScanReport {
+    let config = Config::load();
+    let mut report = scan(&[PathBuf::from("./src/analysis")], &config).expect("scan");
+    report.duration_ms = 0;
+    report
+}
+
+#[test]
+fn reports_are_sorted_by_path_line_and_rule() {
+    let report = scan_tree();
+    assert!(report.files.windows(2).all(|w| w[0].path < w[1].path));
+    for file in &report.files {
+        assert!(file
+            .violations
+            .windows(2)
+            .all(|w| (w[0].row, w[0].law) <= (w[1].row, w[1].law)));
+    }
+}
+
+#[test]
+fn repeated_scans_serialize_identically() {
+    let first = serde_json::to_string(&scan_tree()).expect("json");
+    let second = serde_json::to_string(&scan_tree()).expect("json");
+    assert_eq!(first, second);
+}
+
+fn porcelain(dir: &Path, extra: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .arg("scan")
+        .args(extra)
+        .arg("--porcelain")
+        .current_dir(dir)
+        .output()
+        .expect("failed to execute neti");
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn unsorted_scans_report_the_same_findings() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("neti.toml"), "[rules]\n").expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    for name in ["a", "b", "c", "d"] {
+        std::fs::write(
+            dir.path().join(format!("src/{name}.rs")),
+            "fn f(x: Option<u8>, y: Option<u8>) -> u8 {\n    x.unwrap() + y.unwrap()\n}\n",
+        )
+        .expect("failed to write source");
+    }
+    let sorted = porcelain(dir.path(), &[]);
+    assert!(sorted.len() >= 4, "{sorted:?}");
+    let mut unsorted = porcelain(dir.path(), &["--unsorted"]);
+    unsorted.sort();
+    let mut expected = sorted;
+    expected.sort();
+    assert_eq!(unsorted, expected);
+}
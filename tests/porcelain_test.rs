@@ -0,0 +1,77 @@
+//! Integration test: `--quiet` and `--porcelain` output of scan and check.
+
+#![cfg(feature = "cli")]
+
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn workspace() -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\n[commands]\ncheck = [\"false\"]\n",
+    )
+    .expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "fn first(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n",
+    )
+    .expect("failed to write lib.rs");
+    dir
+}
+
+fn neti(dir: &Path, args: &[&str]) -> (String, bool) {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to execute neti");
+    (
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        output.status.success(),
+    )
+}
+
+#[test]
+fn scan_porcelain_is_tab_separated() {
+    let dir = workspace();
+    let (stdout, ok) = neti(dir.path(), &["scan", "--porcelain"]);
+    assert!(!ok);
+    assert!(
+        stdout.lines().any(|l| l
+            == "src/lib.rs\t2\tLAW OF PARANOIA\terror\tBanned: '.unwrap()' found. Use ? or proper error handling."),
+        "{stdout}"
+    );
+    assert!(
+        stdout.lines().all(|l| l.split('\t').count() == 5),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn scan_quiet_prints_one_line() {
+    let dir = workspace();
+    let (stdout, _) = neti(dir.path(), &["scan", "--quiet"]);
+    assert_eq!(stdout.lines().count(), 1, "{stdout}");
+    assert!(
+        stdout.starts_with("neti scan: failed, 1 errors, "),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn check_porcelain_lists_failed_commands() {
+    let dir = workspace();
+    let (stdout, ok) = neti(dir.path(), &["check", "--porcelain"]);
+    assert!(!ok);
+    assert!(
+        stdout.contains("-\t0\tCOMMAND\terror\t`false` failed with exit code 1\n"),
+        "{stdout}"
+    );
+
+    let (quiet, _) = neti(dir.path(), &["check", "-q"]);
+    assert_eq!(quiet.lines().count(), 1, "{quiet}");
+    assert!(quiet.contains("0/1 commands passed"), "{quiet}");
+}
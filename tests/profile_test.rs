@@ -0,0 +1,44 @@
+//! Integration test: `neti scan --profile-rules` times each rule.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn profile_is_printed_and_written_without_touching_json_output() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("neti.toml"), "[rules]\n").expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "pub fn one() -> u8 {\n    1\n}\n",
+    )
+    .expect("failed to write lib.rs");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["scan", "--json", "--profile-rules"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout is the scan report");
+    assert!(report["files"].is_array());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Slowest rules"), "{stderr}");
+    assert!(stderr.contains("src/lib.rs"), "{stderr}");
+
+    let profile =
+        std::fs::read_to_string(dir.path().join(".neti/profile.json")).expect("profile written");
+    let profile: serde_json::Value = serde_json::from_str(&profile).expect("profile is JSON");
+    let rules: Vec<&str> = profile["rules"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|r| r["rule"].as_str())
+        .collect();
+    assert!(rules.contains(&"naming"), "{rules:?}");
+    assert!(rules.contains(&"logic/L03"), "{rules:?}");
+}
@@ -0,0 +1,47 @@
+//! Integration test: `neti check --ratchet` fails on any rule's increase and
+//! lowers the recorded counts on improvement.
+
+#![cfg(feature = "cli")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+const ONE_UNWRAP: &str = "fn a(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n";
+const TWO_UNWRAPS: &str =
+    "fn a(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n\nfn b(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n";
+
+fn check(dir: &Path, source: &str) -> Output {
+    std::fs::write(dir.join("lib.rs"), source).expect("failed to write lib.rs");
+    Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["check", "--ratchet", "--porcelain"])
+        .current_dir(dir)
+        .output()
+        .expect("failed to execute neti")
+}
+
+fn recorded(dir: &Path) -> serde_json::Value {
+    let text = std::fs::read_to_string(dir.join(".neti/ratchet.json")).expect("ratchet file");
+    serde_json::from_str::<serde_json::Value>(&text).expect("ratchet file is JSON")["rules"].clone()
+}
+
+#[test]
+fn ratchet_only_moves_down() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    let root = dir.path();
+    std::fs::write(root.join("neti.toml"), "[commands]\n").expect("failed to write neti.toml");
+
+    let first = check(root, ONE_UNWRAP);
+    assert!(first.status.success(), "the first run records and passes");
+    assert_eq!(recorded(root)["LAW OF PARANOIA"], 1);
+
+    let worse = check(root, TWO_UNWRAPS);
+    assert!(!worse.status.success());
+    let rows = String::from_utf8_lossy(&worse.stdout);
+    assert!(rows.contains("-\t0\tRATCHET\terror\tLAW OF PARANOIA: 2 violations, up from 1"));
+    assert_eq!(recorded(root)["LAW OF PARANOIA"], 1);
+
+    let better = check(root, "fn a() {}\n");
+    assert!(better.status.success());
+    assert_eq!(recorded(root), serde_json::json!({}));
+}
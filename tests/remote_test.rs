@@ -0,0 +1,140 @@
+//! Integration test: `neti check --upload` and `neti remote pull` against a
+//! local HTTP store.
+
+#![cfg(feature = "cli")]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+#[derive(Default)]
+struct Store {
+    objects: HashMap<String, Vec<u8>>,
+    auth: Vec<String>,
+}
+
+/// Serves PUT and GET for any path until the test process exits.
+fn serve() -> (String, Arc<Mutex<Store>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let url = format!("http://{}", listener.local_addr().expect("addr"));
+    let store = Arc::new(Mutex::new(Store::default()));
+    let shared = Arc::clone(&store);
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            handle(stream, &shared);
+        }
+    });
+    (url, store)
+}
+
+fn handle(mut stream: TcpStream, store: &Mutex<Store>) {
+    let mut reader = BufReader::new(stream.try_clone().expect("clone"));
+    let mut request = String::new();
+    reader.read_line(&mut request).expect("request line");
+    let mut parts = request.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let mut length = 0;
+    let mut line = String::new();
+    while reader.read_line(&mut line).is_ok() && line.trim() != "" {
+        let (name, value) = line.split_once(':').unwrap_or(("", ""));
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.trim().parse().unwrap_or(0),
+            "authorization" => store.lock().expect("lock").auth.push(value.trim().into()),
+            "expect" => stream
+                .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+                .expect("continue"),
+            _ => {}
+        }
+        line.clear();
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).expect("body");
+
+    let mut store = store.lock().expect("lock");
+    let response = match method {
+        "PUT" => {
+            store.objects.insert(path.to_string(), body);
+            Vec::new()
+        }
+        _ => match store.objects.get(path) {
+            Some(object) => object.clone(),
+            None => {
+                let _ = stream.write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+                return;
+            }
+        },
+    };
+    let header = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.len()
+    );
+    let _ = stream.write_all(header.as_bytes());
+    let _ = stream.write_all(&response);
+}
+
+fn workspace(url: &str) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        format!("[commands]\ncheck = [\"true\"]\n\n[remote]\nurl = \"{url}/team\"\n"),
+    )
+    .expect("failed to write neti.toml");
+    dir
+}
+
+fn neti(dir: &Path, args: &[&str]) -> bool {
+    Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(args)
+        .current_dir(dir)
+        .env("NETI_REMOTE_TOKEN", "t0ken")
+        .output()
+        .expect("failed to execute neti")
+        .status
+        .success()
+}
+
+#[test]
+fn uploaded_cache_can_be_pulled_into_a_fresh_checkout() {
+    let (url, store) = serve();
+    let first = workspace(&url);
+    let cached = first.path().join(".neti/cache/mutants/lib.json");
+    std::fs::create_dir_all(cached.parent().expect("parent")).expect("mkdir");
+    std::fs::write(&cached, "{\"key\": \"abc\"}").expect("write cache");
+
+    assert!(neti(first.path(), &["check", "--quiet", "--upload"]));
+    {
+        let store = store.lock().expect("lock");
+        let report = store
+            .objects
+            .get("/team/check-report.json")
+            .expect("report");
+        assert!(String::from_utf8_lossy(report).contains("\"passed\": true"));
+        assert_eq!(
+            store
+                .objects
+                .get("/team/cache/manifest.json")
+                .map(Vec::as_slice),
+            Some(&b"[\n  \"mutants/lib.json\"\n]"[..])
+        );
+        assert!(store.auth.iter().all(|a| a == "Bearer t0ken"));
+        assert!(!store.auth.is_empty());
+    }
+
+    let second = workspace(&url);
+    assert!(neti(second.path(), &["remote", "pull"]));
+    let restored = std::fs::read_to_string(second.path().join(".neti/cache/mutants/lib.json"))
+        .expect("cache restored");
+    assert_eq!(restored, "{\"key\": \"abc\"}");
+}
+
+#[test]
+fn pull_without_a_remote_store_fails() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    assert!(!neti(dir.path(), &["remote", "pull"]));
+}
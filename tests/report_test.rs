@@ -0,0 +1,34 @@
+//! Integration test: `neti report --xlsx` writes a workbook.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn report_writes_summary_and_violation_sheets() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("neti.toml"), "[rules]\n").expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "fn first(x: Option<u8>) -> u8 {\n    x.unwrap()\n}\n",
+    )
+    .expect("failed to write lib.rs");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["report", "--xlsx", "out.xlsx"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    assert!(output.status.success());
+
+    let bytes = std::fs::read(dir.path().join("out.xlsx")).expect("workbook written");
+    assert!(bytes.starts_with(b"PK\x03\x04"));
+    // Entries are stored uncompressed, so the sheet XML is readable as is.
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains("<sheet name=\"Summary\""));
+    assert!(text.contains("<sheet name=\"Violations\""));
+    assert!(text.contains(">LAW OF PARANOIA</t>"));
+    assert!(text.contains(">src/lib.rs</t>"));
+}
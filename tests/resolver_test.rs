@@ -0,0 +1,177 @@
+//! Integration test: import resolution for TypeScript and Python projects.
+//!
+//! Builds small project trees on disk and checks that aliases, package
+//! subpath imports, and Python package semantics resolve to the right files.
+
+use neti_core::graph::imports;
+use neti_core::graph::resolver::resolve;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn project(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full = dir.path().join(path);
+        if let Some(parent) = full.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create dirs");
+        }
+        std::fs::write(full, content).expect("failed to write file");
+    }
+    dir
+}
+
+fn resolved(dir: &TempDir, from: &str, import: &str) -> Option<PathBuf> {
+    let root = dir.path();
+    resolve(root, &root.join(from), import)
+        .map(|p| p.strip_prefix(root).map(Path::to_path_buf).unwrap_or(p))
+}
+
+#[test]
+fn typescript_relative_imports() {
+    let dir = project(&[
+        ("src/app.ts", ""),
+        ("src/user.service.ts", ""),
+        ("src/lib/format.ts", ""),
+        ("src/ui/index.tsx", ""),
+    ]);
+
+    let cases = [
+        ("./user.service", "src/user.service.ts"),
+        ("./lib/format.js", "src/lib/format.ts"),
+        ("./ui", "src/ui/index.tsx"),
+    ];
+    for (import, expected) in cases {
+        assert_eq!(
+            resolved(&dir, "src/app.ts", import),
+            Some(PathBuf::from(expected)),
+            "{import}"
+        );
+    }
+    assert_eq!(
+        resolved(&dir, "src/lib/format.ts", "../user.service"),
+        Some(PathBuf::from("src/user.service.ts")),
+        "parent segments are folded"
+    );
+}
+
+#[test]
+fn typescript_tsconfig_aliases() {
+    let dir = project(&[
+        (
+            "tsconfig.json",
+            r#"{
+                // comments are allowed
+                "compilerOptions": {
+                    "baseUrl": ".",
+                    "paths": { "@app/*": ["src/*"], "config": ["src/config/index.ts"] }
+                }
+            }"#,
+        ),
+        ("src/main.ts", ""),
+        ("src/utils/date.ts", ""),
+        ("src/config/index.ts", ""),
+    ]);
+
+    assert_eq!(
+        resolved(&dir, "src/main.ts", "@app/utils/date"),
+        Some(PathBuf::from("src/utils/date.ts"))
+    );
+    assert_eq!(
+        resolved(&dir, "src/main.ts", "config"),
+        Some(PathBuf::from("src/config/index.ts"))
+    );
+    assert_eq!(
+        resolved(&dir, "src/main.ts", "src/utils/date"),
+        Some(PathBuf::from("src/utils/date.ts")),
+        "baseUrl-relative import"
+    );
+    assert_eq!(resolved(&dir, "src/main.ts", "react"), None);
+}
+
+#[test]
+fn typescript_package_subpath_imports() {
+    let dir = project(&[
+        (
+            "package.json",
+            r##"{
+                "imports": {
+                    "#db": { "types": "./src/db/index.ts", "default": "./dist/db/index.js" },
+                    "#utils/*": "./src/utils/*.js"
+                }
+            }"##,
+        ),
+        ("src/server/main.ts", ""),
+        ("src/db/index.ts", ""),
+        ("src/utils/log.ts", ""),
+    ]);
+
+    assert_eq!(
+        resolved(&dir, "src/server/main.ts", "#db"),
+        Some(PathBuf::from("src/db/index.ts"))
+    );
+    assert_eq!(
+        resolved(&dir, "src/server/main.ts", "#utils/log"),
+        Some(PathBuf::from("src/utils/log.ts"))
+    );
+    assert_eq!(resolved(&dir, "src/server/main.ts", "#missing"), None);
+}
+
+#[test]
+fn python_package_semantics() {
+    let dir = project(&[
+        ("app/__init__.py", ""),
+        ("app/main.py", ""),
+        ("app/models/__init__.py", ""),
+        ("app/models/user.py", ""),
+        ("app/services/billing.py", ""),
+    ]);
+
+    let cases = [
+        ("app.models.user", "app/models/user.py"),
+        ("app.models", "app/models/__init__.py"),
+        ("app.models.Account", "app/models/__init__.py"),
+        ("app.services.billing", "app/services/billing.py"),
+    ];
+    for (import, expected) in cases {
+        assert_eq!(
+            resolved(&dir, "app/main.py", import),
+            Some(PathBuf::from(expected)),
+            "{import}"
+        );
+    }
+    assert_eq!(resolved(&dir, "app/main.py", "requests"), None);
+}
+
+#[test]
+fn python_relative_and_src_layout() {
+    let dir = project(&[
+        ("src/shop/__init__.py", ""),
+        ("src/shop/cart.py", ""),
+        ("src/shop/api/views.py", ""),
+    ]);
+
+    let views = "src/shop/api/views.py";
+    assert_eq!(
+        resolved(&dir, views, "..cart"),
+        Some(PathBuf::from("src/shop/cart.py"))
+    );
+    assert_eq!(
+        resolved(&dir, views, ".."),
+        Some(PathBuf::from("src/shop/__init__.py"))
+    );
+    assert_eq!(
+        resolved(&dir, views, "shop.cart"),
+        Some(PathBuf::from("src/shop/cart.py")),
+        "absolute imports search src/"
+    );
+}
+
+#[test]
+fn python_relative_imports_are_extracted() {
+    let found = imports::extract(
+        Path::new("pkg/main.py"),
+        "from . import helpers\nfrom ..core.models import User\n",
+    );
+    assert!(found.contains(&".".to_string()), "{found:?}");
+    assert!(found.contains(&"..core.models".to_string()), "{found:?}");
+}
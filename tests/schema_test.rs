@@ -0,0 +1,200 @@
+//! Integration test: real `--json` output validates against `neti schema`.
+
+#![cfg(feature = "cli")]
+
+use neti_core::mutate::mutations::{MutationKind, MutationPoint};
+use neti_core::mutate::report::format_json;
+use neti_core::mutate::runner::{MutationResult, MutationSummary};
+use neti_core::schema::ReportKind;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Checks the subset of JSON Schema the report schemas use.
+fn validate(value: &Value, schema: &Value, root: &Value, at: &str, errors: &mut Vec<String>) {
+    if let Some(target) = schema["$ref"].as_str() {
+        let name = target.trim_start_matches("#/$defs/");
+        return validate(value, &root["$defs"][name], root, at, errors);
+    }
+    if let Some(options) = schema["oneOf"].as_array() {
+        let matching = options
+            .iter()
+            .filter(|option| {
+                let mut found = Vec::new();
+                validate(value, option, root, at, &mut found);
+                found.is_empty()
+            })
+            .count();
+        if matching != 1 {
+            errors.push(format!("{at}: matches {matching} alternatives"));
+        }
+        return;
+    }
+    if !type_matches(value, &schema["type"]) {
+        errors.push(format!("{at}: expected {}, got {value}", schema["type"]));
+        return;
+    }
+    let allowed = schema["enum"].as_array();
+    if allowed.is_some_and(|allowed| !allowed.contains(value)) {
+        errors.push(format!("{at}: {value} is not one of {}", schema["enum"]));
+    }
+    if !schema["const"].is_null() && schema["const"] != *value {
+        errors.push(format!("{at}: expected {}", schema["const"]));
+    }
+    if let Some(items) = value.as_array() {
+        for (i, item) in items.iter().enumerate() {
+            validate(item, &schema["items"], root, &format!("{at}[{i}]"), errors);
+        }
+    }
+    if let Some(fields) = value.as_object() {
+        validate_object(fields, schema, root, at, errors);
+    }
+}
+
+fn validate_object(
+    fields: &serde_json::Map<String, Value>,
+    schema: &Value,
+    root: &Value,
+    at: &str,
+    errors: &mut Vec<String>,
+) {
+    for required in schema["required"].as_array().into_iter().flatten() {
+        let key = required.as_str().unwrap_or_default();
+        if !fields.contains_key(key) {
+            errors.push(format!("{at}: missing {key}"));
+        }
+    }
+    for (key, field) in fields {
+        let path = format!("{at}.{key}");
+        match (&schema["properties"][key], &schema["additionalProperties"]) {
+            (Value::Null, Value::Bool(false)) => errors.push(format!("{path}: not in the schema")),
+            (Value::Null, Value::Object(_)) => {
+                validate(field, &schema["additionalProperties"], root, &path, errors);
+            }
+            (Value::Null, _) => {}
+            (property, _) => validate(field, property, root, &path, errors),
+        }
+    }
+}
+
+fn type_matches(value: &Value, expected: &Value) -> bool {
+    let one = |name: &str| match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_u64() || value.is_i64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => false,
+    };
+    match expected {
+        Value::String(name) => one(name),
+        Value::Array(names) => names.iter().filter_map(Value::as_str).any(one),
+        _ => true,
+    }
+}
+
+fn assert_valid(kind: ReportKind, report: &Value) {
+    let schema = kind.schema();
+    let mut errors = Vec::new();
+    validate(report, &schema, &schema, "$", &mut errors);
+    assert!(errors.is_empty(), "{kind:?}: {errors:#?}");
+}
+
+fn workspace() -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\n[commands]\ncheck = [\"true\"]\n",
+    )
+    .expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "#[derive(Debug)]\npub struct Counter {\n    pub count: u8,\n}\n\nimpl Counter {\n    \
+         pub fn bump(&mut self, x: Option<u8>) {\n        self.count += x.unwrap();\n    }\n}\n",
+    )
+    .expect("failed to write lib.rs");
+    dir
+}
+
+fn neti_json(dir: &Path, args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to execute neti");
+    serde_json::from_slice(&output.stdout).expect("output is JSON")
+}
+
+#[test]
+fn scan_json_matches_its_schema() {
+    let dir = workspace();
+    let report = neti_json(dir.path(), &["scan", "--json"]);
+    assert_eq!(report["schema_version"], 1);
+    let files = report["files"].as_array().cloned().unwrap_or_default();
+    assert!(files.iter().any(|f| f["analysis"].is_object()));
+    assert!(files
+        .iter()
+        .any(|f| f["violations"].as_array().is_some_and(|v| !v.is_empty())));
+    assert_valid(ReportKind::Scan, &report);
+}
+
+#[test]
+fn check_json_matches_its_schema() {
+    let dir = workspace();
+    let report = neti_json(dir.path(), &["check", "--json"]);
+    assert_eq!(report["schema_version"], 1);
+    assert!(report["locality"].is_object());
+    assert_valid(ReportKind::Check, &report);
+}
+
+#[test]
+fn mutate_json_matches_its_schema() {
+    let point = MutationPoint {
+        file: PathBuf::from("src/lib.rs"),
+        line: 7,
+        column: 20,
+        byte_start: 0,
+        byte_end: 2,
+        original: "+=".into(),
+        mutated: "-=".into(),
+        kind: MutationKind::Arithmetic,
+    };
+    let summary = MutationSummary {
+        total: 1,
+        killed: 0,
+        survived: 0,
+        not_covered: 1,
+        score: 0.0,
+        total_duration_ms: 0,
+    };
+    let json = format_json(&[MutationResult::not_covered(point)], &summary);
+    let report: Value = serde_json::from_str(&json).expect("mutate report is JSON");
+    assert_valid(ReportKind::Mutate, &report);
+}
+
+#[test]
+fn schemas_reject_unknown_fields() {
+    let mut errors = Vec::new();
+    let schema = ReportKind::Mutate.schema();
+    let report = serde_json::json!({ "schema_version": 1, "summary": {}, "extra": true });
+    validate(&report, &schema, &schema, "$", &mut errors);
+    assert!(
+        errors.iter().any(|e| e == "$.extra: not in the schema"),
+        "{errors:?}"
+    );
+    assert!(
+        errors.iter().any(|e| e == "$: missing survivors"),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn schema_command_prints_the_schema() {
+    let dir = workspace();
+    let schema = neti_json(dir.path(), &["schema", "check"]);
+    assert_eq!(schema, ReportKind::Check.schema());
+}
@@ -0,0 +1,28 @@
+//! Integration test: secrets in env/config files and the accepted baseline.
+
+use neti_core::secrets::{self, write_baseline, Baseline};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn baseline_silences_accepted_findings_only() {
+    let dir = TempDir::new().expect("temp dir");
+    let env = dir.path().join(".env");
+    let config = dir.path().join("settings.yaml");
+    fs::write(&env, "DB_PASSWORD=q8Zt2LmX9vRk4Wp1\n").expect("write env");
+    fs::write(&config, "api:\n  timeout: 30\n").expect("write config");
+
+    let count = write_baseline(dir.path(), &[env.clone(), config.clone()]).expect("baseline");
+    assert_eq!(count, 1);
+
+    let slack = format!("api:\n  token: xoxp-{}\n", "2041-8837-abcdefghij");
+    fs::write(&config, &slack).expect("write config");
+
+    let baseline = Baseline::load(dir.path());
+    let env_content = fs::read_to_string(&env).expect("read env");
+    assert!(secrets::violations(&env, &env_content, &baseline).is_empty());
+
+    let found = secrets::violations(&config, &slack, &baseline);
+    assert_eq!(found.len(), 1);
+    assert!(found.iter().all(|v| v.law == secrets::LAW && v.row == 2));
+}
@@ -0,0 +1,43 @@
+//! Integration test: `neti:off` regions hide findings and are counted.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+const SOURCE: &str = "fn a(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+
+// neti:off generated
+fn b(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+// neti:on
+";
+
+#[test]
+fn off_regions_hide_findings_and_flag_overuse() {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(dir.path().join("lib.rs"), SOURCE).expect("failed to write lib.rs");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["scan", "--json"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("scan --json is JSON");
+    let file = &report["files"][0];
+    let found: Vec<(&str, u64)> = file["violations"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| Some((v["law"].as_str()?, v["row"].as_u64()?)))
+        .collect();
+
+    assert_eq!(file["suppressed_lines"], 5);
+    assert!(found.contains(&("LAW OF PARANOIA", 2)));
+    assert!(!found.iter().any(|&(_, row)| row == 7));
+    assert!(found.contains(&("D03", 5)), "5 of 9 lines is over 50%");
+}
@@ -0,0 +1,63 @@
+//! Integration test: `neti symbols` builds a persisted index and fuzzy-searches it.
+//!
+//! Runs the binary in a temp workspace, checks the JSON output and that the
+//! index is written to `.neti/symbols.json` and picks up edited files.
+
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use tempfile::TempDir;
+
+fn workspace() -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    std::fs::write(
+        dir.path().join("neti.toml"),
+        "[rules]\n[preferences]\n[commands]\n",
+    )
+    .expect("failed to write neti.toml");
+    std::fs::create_dir(dir.path().join("src")).expect("failed to create src");
+    std::fs::write(
+        dir.path().join("src/lib.rs"),
+        "pub struct RuleEngine;\n\npub fn scan_file() {}\n\nfn parse() {}\n",
+    )
+    .expect("failed to write lib.rs");
+    dir
+}
+
+fn search(dir: &TempDir, query: &str) -> serde_json::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_neti"))
+        .args(["symbols", query, "--json"])
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to execute neti");
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("stdout is not valid JSON")
+}
+
+#[test]
+fn symbols_fuzzy_search_returns_locations() {
+    let dir = workspace();
+    let json = search(&dir, "scn");
+
+    let first = json.get(0).cloned().unwrap_or_default();
+    assert_eq!(first["name"], "scan_file");
+    assert_eq!(first["kind"], "function");
+    assert_eq!(first["file"], "src/lib.rs");
+    assert_eq!(first["line"], 3);
+    assert!(dir.path().join(".neti/symbols.json").exists());
+}
+
+#[test]
+fn symbols_index_refreshes_after_edit() {
+    let dir = workspace();
+    assert_eq!(search(&dir, "render").as_array().map(Vec::len), Some(0));
+
+    std::fs::write(dir.path().join("src/lib.rs"), "pub fn render() {}\n")
+        .expect("failed to rewrite lib.rs");
+
+    let json = search(&dir, "render");
+    assert_eq!(
+        json.get(0).and_then(|s| s.get("name")),
+        Some(&"render".into())
+    );
+}
@@ -0,0 +1,89 @@
+//! Integration test: Windows paths and CRLF sources get the same results as
+//! their Unix equivalents.
+
+use neti_core::analysis::patterns::performance;
+use neti_core::analysis::worker::scan_file;
+use neti_core::compare;
+use neti_core::config::Config;
+use neti_core::secrets::Baseline;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+use tree_sitter::Parser;
+
+const SOURCE: &str = "\
+pub fn total(items: &[String]) -> usize {
+    let mut n = 0;
+    for item in items {
+        let owned = item.clone();
+        n += owned.len();
+    }
+    n
+}
+
+pub fn first(x: Option<u8>) -> u8 {
+    x.unwrap()
+}
+";
+
+fn rules(report: &neti_core::types::FileReport) -> Vec<(&'static str, usize)> {
+    let mut rules: Vec<_> = report.violations.iter().map(|v| (v.law, v.row)).collect();
+    rules.sort_unstable();
+    rules
+}
+
+#[test]
+fn crlf_sources_scan_like_lf_sources() {
+    let dir = TempDir::new().expect("temp dir");
+    let lf = dir.path().join("lf.rs");
+    let crlf = dir.path().join("crlf.rs");
+    fs::write(&lf, SOURCE).expect("write");
+    fs::write(&crlf, SOURCE.replace('\n', "\r\n")).expect("write");
+
+    let config = Config::default();
+    let lf = scan_file(&lf, &config, &Baseline::default());
+    let crlf = scan_file(&crlf, &config, &Baseline::default());
+
+    assert!(!lf.violations.is_empty());
+    assert_eq!(rules(&crlf), rules(&lf));
+    assert_eq!(crlf.token_count, lf.token_count);
+    assert_eq!(crlf.complexity_score, lf.complexity_score);
+}
+
+#[test]
+fn backslash_paths_skip_like_slash_paths() {
+    let source = "fn f(items: &[String]) { for _ in 0..10 { let s = name.clone(); process(s); } }";
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_rust::LANGUAGE.into())
+        .expect("rust grammar");
+    let tree = parser.parse(source, None).expect("parse");
+    let detect = |path: &str| performance::detect(source, Some(tree.root_node()), Path::new(path));
+
+    assert!(!detect("src/core.rs").is_empty());
+    assert!(detect("src/cli/args.rs").is_empty());
+    assert!(detect(r"src\cli\args.rs").is_empty());
+}
+
+#[test]
+fn reports_written_on_windows_compare_against_unix_scans() {
+    let dir = TempDir::new().expect("temp dir");
+    let old = dir.path().join("old.json");
+    let report = r#"{"files": [{"path": ".\\src\\lib.rs", "violations": [
+        {"row": 3, "law": "P01", "message": "clone in loop"}]}]}"#;
+    fs::write(&old, report).expect("write");
+
+    let old = compare::load(&old).expect("load");
+    let current: Vec<_> = old
+        .iter()
+        .map(|f| compare::Finding {
+            file: "src/lib.rs".into(),
+            ..f.clone()
+        })
+        .collect();
+    let diff = compare::diff(old, current);
+
+    assert!(diff.new.is_empty());
+    assert!(diff.fixed.is_empty());
+    assert_eq!(diff.persisting.len(), 1);
+}
@@ -0,0 +1,184 @@
+//! Integration test: workspace detection across Cargo, pnpm, npm, and Python.
+//!
+//! Builds small monorepo layouts on disk and checks that members are found,
+//! named from their manifests, and selectable by name or directory.
+
+use neti_core::project::ProjectType;
+use neti_core::workspace::{select_member, Workspace, WorkspaceKind};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn project(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().expect("failed to create temp dir");
+    for (path, content) in files {
+        let full = dir.path().join(path);
+        if let Some(parent) = full.parent() {
+            std::fs::create_dir_all(parent).expect("failed to create dirs");
+        }
+        std::fs::write(full, content).expect("failed to write file");
+    }
+    dir
+}
+
+fn members(workspace: &Workspace) -> Vec<(String, PathBuf)> {
+    workspace
+        .members
+        .iter()
+        .map(|m| (m.name.clone(), m.path.clone()))
+        .collect()
+}
+
+fn member(name: &str, path: &str) -> (String, PathBuf) {
+    (name.to_string(), PathBuf::from(path))
+}
+
+#[test]
+fn cargo_workspace_members_and_excludes() {
+    let dir = project(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\", \"tools/cli\"]\nexclude = [\"crates/scratch\"]\n",
+        ),
+        ("crates/core/Cargo.toml", "[package]\nname = \"acme-core\"\n"),
+        ("crates/io/Cargo.toml", "[package]\nname = \"acme-io\"\n"),
+        ("crates/scratch/Cargo.toml", "[package]\nname = \"scratch\"\n"),
+        ("crates/notes/README.md", ""),
+        ("tools/cli/Cargo.toml", "[package]\nname = \"acme\"\n"),
+    ]);
+
+    let workspace = Workspace::detect_in(dir.path()).expect("cargo workspace");
+    assert_eq!(workspace.kind, WorkspaceKind::Cargo);
+    assert_eq!(
+        members(&workspace),
+        vec![
+            member("acme-core", "crates/core"),
+            member("acme-io", "crates/io"),
+            member("acme", "tools/cli"),
+        ]
+    );
+    assert_eq!(
+        workspace
+            .member_for(Path::new("crates/io/src/lib.rs"))
+            .map(|m| m.name.as_str()),
+        Some("acme-io")
+    );
+}
+
+#[test]
+fn pnpm_workspace_from_yaml() {
+    let dir = project(&[
+        (
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'packages/*'\n  - \"apps/**\"\n  - '!packages/legacy'\ncatalog:\n  react: ^18\n",
+        ),
+        ("packages/ui/package.json", r#"{ "name": "@acme/ui" }"#),
+        ("packages/legacy/package.json", r#"{ "name": "@acme/legacy" }"#),
+        ("apps/web/package.json", r#"{ "name": "web" }"#),
+        ("apps/web/node_modules/dep/package.json", r#"{ "name": "dep" }"#),
+    ]);
+
+    let workspace = Workspace::detect_in(dir.path()).expect("pnpm workspace");
+    assert_eq!(workspace.kind, WorkspaceKind::Pnpm);
+    assert_eq!(
+        members(&workspace),
+        vec![member("web", "apps/web"), member("@acme/ui", "packages/ui")]
+    );
+    assert_eq!(ProjectType::detect_in(dir.path()), ProjectType::Node);
+}
+
+#[test]
+fn npm_and_yarn_workspaces() {
+    let array = project(&[
+        ("package.json", r#"{ "workspaces": ["libs/*"] }"#),
+        ("libs/a/package.json", r#"{ "name": "a" }"#),
+    ]);
+    let object = project(&[
+        (
+            "package.json",
+            r#"{ "workspaces": { "packages": ["libs/*"], "nohoist": [] } }"#,
+        ),
+        ("libs/b/package.json", "{}"),
+    ]);
+
+    let workspace = Workspace::detect_in(array.path()).expect("npm workspace");
+    assert_eq!(workspace.kind, WorkspaceKind::Npm);
+    assert_eq!(members(&workspace), vec![member("a", "libs/a")]);
+
+    let workspace = Workspace::detect_in(object.path()).expect("yarn workspace");
+    assert_eq!(
+        members(&workspace),
+        vec![member("b", "libs/b")],
+        "falls back to directory name"
+    );
+}
+
+#[test]
+fn python_monorepos() {
+    let uv = project(&[
+        (
+            "pyproject.toml",
+            "[tool.uv.workspace]\nmembers = [\"packages/*\"]\n",
+        ),
+        (
+            "packages/api/pyproject.toml",
+            "[project]\nname = \"acme-api\"\n",
+        ),
+        (
+            "packages/jobs/pyproject.toml",
+            "[tool.poetry]\nname = \"acme-jobs\"\n",
+        ),
+    ]);
+    let workspace = Workspace::detect_in(uv.path()).expect("uv workspace");
+    assert_eq!(workspace.kind, WorkspaceKind::Python);
+    assert_eq!(
+        members(&workspace),
+        vec![
+            member("acme-api", "packages/api"),
+            member("acme-jobs", "packages/jobs"),
+        ]
+    );
+
+    let loose = project(&[
+        (
+            "services/billing/pyproject.toml",
+            "[project]\nname = \"billing\"\n",
+        ),
+        ("services/auth/setup.py", ""),
+    ]);
+    let workspace = Workspace::detect_in(loose.path()).expect("python monorepo");
+    assert_eq!(
+        members(&workspace),
+        vec![
+            member("auth", "services/auth"),
+            member("billing", "services/billing"),
+        ]
+    );
+    assert_eq!(ProjectType::detect_in(loose.path()), ProjectType::Python);
+}
+
+#[test]
+fn single_packages_are_not_workspaces() {
+    let dir = project(&[
+        ("Cargo.toml", "[package]\nname = \"solo\"\n"),
+        ("src/lib.rs", ""),
+    ]);
+    assert!(Workspace::detect_in(dir.path()).is_none());
+}
+
+#[test]
+fn select_member_by_name_or_path() {
+    let dir = project(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+        (
+            "crates/core/Cargo.toml",
+            "[package]\nname = \"acme-core\"\n",
+        ),
+    ]);
+
+    let by_name = select_member(dir.path(), "acme-core").expect("by name");
+    let by_path = select_member(dir.path(), "crates/core/").expect("by path");
+    assert_eq!(by_name, by_path);
+
+    let err = select_member(dir.path(), "nope").expect_err("unknown member");
+    assert!(err.to_string().contains("acme-core"), "{err}");
+}